@@ -0,0 +1,96 @@
+//! Drop the backlog of a subscription on `stop()` instead of working through it.
+//!
+//! A custom in-memory source delivers one batch of 200 memo transactions to a callback thread
+//! (`callback_threads`) whose callback takes 10 ms per event. Stopping after 100 ms cancels the
+//! subscription's `CancellationToken`: the callback thread drops the events still queued and
+//! counts them in `dropped_events_count`, so a failover to another provider does not keep
+//! burning CPU on the old stream.
+//!
+//! Run with `cargo run --example cancel_on_stop_example`.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use solana_entry::entry::Entry;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+use solana_streamer_sdk::common::AnyResult;
+use solana_streamer_sdk::streaming::common::StreamClientConfig;
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::MEMO_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+use solana_streamer_sdk::streaming::shred::{ShredEntries, ShredSource};
+use solana_streamer_sdk::streaming::ShredStreamGrpc;
+
+const TRANSACTIONS: u64 = 200;
+
+/// Delivers one entries batch of memo transactions, then stays open without further data
+struct BacklogSource {
+    delivered: bool,
+}
+
+#[tonic::async_trait]
+impl ShredSource for BacklogSource {
+    fn name(&self) -> &str {
+        "backlog"
+    }
+
+    async fn next_entries(&mut self) -> Option<AnyResult<ShredEntries>> {
+        if self.delivered {
+            return std::future::pending().await;
+        }
+        self.delivered = true;
+        let transactions = (0..TRANSACTIONS)
+            .map(|i| {
+                let memo = Instruction::new_with_bytes(MEMO_PROGRAM_ID, &i.to_le_bytes(), vec![]);
+                let payer = Pubkey::new_unique();
+                VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+                    &[memo],
+                    Some(&payer),
+                )))
+            })
+            .collect();
+        let entries = vec![Entry { num_hashes: 0, hash: Hash::default(), transactions }];
+        Some(
+            bincode::serialize(&entries)
+                .map(|entries| ShredEntries { slot: 1, entries })
+                .map_err(Into::into),
+        )
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let config = StreamClientConfig { callback_threads: Some(1), ..Default::default() };
+    let client = ShredStreamGrpc::new_for_sources(config);
+
+    let delivered = Arc::new(AtomicU64::new(0));
+    let counter = delivered.clone();
+    client
+        .subscribe_with_sources(
+            vec![Box::new(BacklogSource { delivered: false })],
+            vec![Protocol::PumpFun],
+            None,
+            None,
+            move |_event| {
+                std::thread::sleep(Duration::from_millis(10));
+                counter.fetch_add(1, Ordering::Relaxed);
+            },
+        )
+        .await?;
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    client.stop().await;
+    // Give the callback thread time to finish the event it was running and drop the rest
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let delivered = delivered.load(Ordering::Relaxed);
+    let dropped = client.get_metrics().dropped_events_count;
+    assert!(delivered < TRANSACTIONS && dropped > 0);
+    println!("delivered {delivered} of {TRANSACTIONS} memos before stop, dropped {dropped}");
+    Ok(())
+}
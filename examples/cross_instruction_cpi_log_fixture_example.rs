@@ -0,0 +1,127 @@
+//! Merge PumpFun trade CPI logs that land under a different outer instruction than their trade.
+//!
+//! Normally a trade instruction is merged with the first CPI log found in its own inner
+//! instruction set. With `ParseConfig::transaction_level_merge` the CPI logs of the whole
+//! transaction are collected first, so a trade whose log was emitted under another outer
+//! instruction is still merged. A log is only taken from after its trade instruction, and each
+//! log is merged into at most one trade.
+//!
+//! Run with `cargo run --example cross_instruction_cpi_log_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+/// TradeEvent CPI log (current 250-byte layout) of a buy of `sol_amount` lamports
+fn trade_log(sol_amount: u64) -> Vec<u8> {
+    let mut log = discriminators::TRADE_EVENT.to_vec();
+    log.extend_from_slice(Pubkey::new_unique().as_ref()); // mint
+    log.extend_from_slice(&sol_amount.to_le_bytes());
+    log.extend_from_slice(&1_000_000_000u64.to_le_bytes()); // token_amount
+    log.push(1); // is_buy
+    log.extend_from_slice(Pubkey::new_unique().as_ref()); // user
+    log.extend_from_slice(&1_760_000_000i64.to_le_bytes()); // timestamp
+    log.extend_from_slice(&30_000_000_000u64.to_le_bytes()); // virtual_sol_reserves
+    log.extend_from_slice(&1_073_000_000_000_000u64.to_le_bytes()); // virtual_token_reserves
+    log.extend_from_slice(&0u64.to_le_bytes()); // real_sol_reserves
+    log.extend_from_slice(&793_100_000_000_000u64.to_le_bytes()); // real_token_reserves
+    log.extend_from_slice(Pubkey::new_unique().as_ref()); // fee_recipient
+    log.extend_from_slice(&95u64.to_le_bytes()); // fee_basis_points
+    log.extend_from_slice(&0u64.to_le_bytes()); // fee
+    log.extend_from_slice(Pubkey::new_unique().as_ref()); // creator
+    log.extend_from_slice(&5u64.to_le_bytes()); // creator_fee_basis_points
+    log.extend_from_slice(&0u64.to_le_bytes()); // creator_fee
+    log.push(1); // track_volume
+    log.extend_from_slice(&0u64.to_le_bytes()); // total_unclaimed_tokens
+    log.extend_from_slice(&0u64.to_le_bytes()); // total_claimed_tokens
+    log.extend_from_slice(&sol_amount.to_le_bytes()); // current_sol_volume
+    log.extend_from_slice(&1_760_000_000i64.to_le_bytes()); // last_update_timestamp
+    log
+}
+
+/// Buy instruction data with its 16 fixed accounts
+fn buy() -> (Vec<Pubkey>, Vec<u8>) {
+    let mut accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+    accounts[11] = PUMPFUN_PROGRAM_ID;
+    let mut data = discriminators::BUY_IX.to_vec();
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes()); // amount
+    data.extend_from_slice(&90_000_000_000u64.to_le_bytes()); // max_sol_cost
+    (accounts, data)
+}
+
+/// The `sol_amount` of each PumpFun trade event, in delivery order; 0 when no log was merged
+async fn parse_trades(
+    fixture: TxFixtureBuilder,
+    transaction_level_merge: bool,
+) -> Result<Vec<u64>> {
+    let signature = fixture.get_signature();
+    let parse_config = ParseConfig { transaction_level_merge, ..Default::default() };
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &parse_config,
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    Ok(events
+        .into_iter()
+        .filter_map(|event| match event {
+            DexEvent::PumpFunTradeEvent(e) => Some(e.sol_amount),
+            _ => None,
+        })
+        .collect())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let router = Pubkey::new_unique();
+    let router_accounts = [Pubkey::new_unique()];
+    let (accounts, data) = buy();
+    let (cpi_accounts, cpi_data) = buy();
+    let event_authority = [accounts[10]];
+
+    // The trade's CPI log is emitted under the next outer instruction
+    let split = TxFixtureBuilder::new()
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, data.clone())
+        .instruction(router, &router_accounts, vec![1])
+        .inner_instruction(PUMPFUN_PROGRAM_ID, &event_authority, trade_log(111));
+    assert_eq!(parse_trades(split.clone(), false).await?, vec![0]);
+    assert_eq!(parse_trades(split, true).await?, vec![111]);
+
+    // A log emitted before the trade instruction is never attached to it
+    let earlier_log = TxFixtureBuilder::new()
+        .instruction(router, &router_accounts, vec![1])
+        .inner_instruction(PUMPFUN_PROGRAM_ID, &event_authority, trade_log(111))
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, data.clone());
+    assert_eq!(parse_trades(earlier_log, true).await?, vec![0]);
+
+    // An outer trade takes the first log after it; the CPI trade that follows skips that log
+    // instead of merging it a second time
+    let shared_set = TxFixtureBuilder::new()
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, data)
+        .instruction(router, &router_accounts, vec![1])
+        .inner_instruction(PUMPFUN_PROGRAM_ID, &cpi_accounts, cpi_data)
+        .inner_instruction(PUMPFUN_PROGRAM_ID, &event_authority, trade_log(111))
+        .inner_instruction(PUMPFUN_PROGRAM_ID, &event_authority, trade_log(222));
+    assert_eq!(parse_trades(shared_set, true).await?, vec![111, 222]);
+
+    println!("cross-instruction CPI logs merged once each, never into an earlier trade");
+    Ok(())
+}
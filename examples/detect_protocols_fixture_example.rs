@@ -0,0 +1,66 @@
+//! Detect every supported protocol a transaction touches with `EventParser::detect_protocols`.
+//!
+//! Detection only scans account keys, so it finds protocols invoked as outer instructions, via
+//! CPI, or through addresses loaded from an address lookup table, without decoding any
+//! instruction data. The synthetic transactions here cover each of those cases.
+//!
+//! Run with `cargo run --example detect_protocols_fixture_example --features test-fixtures`.
+
+use std::collections::HashSet;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::COMPUTE_BUDGET_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::protocols::bonk::parser::BONK_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+fn main() {
+    let aggregator = Pubkey::new_unique();
+    let accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    // Outer instructions of two protocols plus a compute budget instruction
+    let outer = TxFixtureBuilder::new()
+        .instruction(COMPUTE_BUDGET_PROGRAM_ID, &[], vec![2, 0, 0, 0, 0])
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, vec![0; 8])
+        .instruction(PUMPSWAP_PROGRAM_ID, &accounts, vec![0; 8])
+        .build();
+    let detected = EventParser::detect_protocols(&outer);
+    assert_eq!(detected.protocols, HashSet::from([Protocol::PumpFun, Protocol::PumpSwap]));
+    assert!(detected.uses_compute_budget);
+
+    // A third protocol reached only through CPI from an unsupported aggregator
+    let cpi = TxFixtureBuilder::new()
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, vec![0; 8])
+        .instruction(aggregator, &accounts, vec![1])
+        .inner_instruction(BONK_PROGRAM_ID, &accounts, vec![0; 8])
+        .inner_instruction(PUMPSWAP_PROGRAM_ID, &accounts, vec![0; 8])
+        .build();
+    let detected = EventParser::detect_protocols(&cpi);
+    assert_eq!(
+        detected.protocols,
+        HashSet::from([Protocol::PumpFun, Protocol::Bonk, Protocol::PumpSwap])
+    );
+    assert!(!detected.uses_compute_budget);
+
+    // A program id that is only present in the address lookup table addresses
+    let mut alt =
+        TxFixtureBuilder::new().instruction(PUMPSWAP_PROGRAM_ID, &accounts, vec![0; 8]).build();
+    if let Some(meta) = alt.meta.as_mut() {
+        meta.loaded_readonly_addresses.push(RAYDIUM_CLMM_PROGRAM_ID.to_bytes().to_vec());
+        meta.loaded_writable_addresses.push(Pubkey::new_unique().to_bytes().to_vec());
+    }
+    let detected = EventParser::detect_protocols(&alt);
+    assert_eq!(detected.protocols, HashSet::from([Protocol::PumpSwap, Protocol::RaydiumClmm]));
+
+    // No supported program at all
+    let unsupported = TxFixtureBuilder::new().instruction(aggregator, &accounts, vec![1]).build();
+    let detected = EventParser::detect_protocols(&unsupported);
+    assert!(detected.protocols.is_empty());
+    assert!(!detected.uses_compute_budget);
+
+    println!("detected {:?} in the CPI transaction", EventParser::detect_protocols(&cpi).protocols);
+}
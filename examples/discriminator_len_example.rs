@@ -0,0 +1,37 @@
+//! Check the instruction discriminator length of every supported program.
+//!
+//! `EventDispatcher::discriminator_len` decides how many leading bytes of instruction data are
+//! matched against a protocol's discriminators. Anchor programs use 8 bytes, Raydium AMM V4 a
+//! single byte. A wrong length makes every instruction of that program silently fail to match.
+//!
+//! Run with `cargo run --example discriminator_len_example --features test-fixtures`.
+
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::EventDispatcher;
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+
+/// Expected discriminator length of each protocol's instructions
+fn expected_len(protocol: &Protocol) -> usize {
+    match protocol {
+        Protocol::RaydiumAmmV4 => 1,
+        Protocol::PumpSwap
+        | Protocol::PumpFun
+        | Protocol::Bonk
+        | Protocol::RaydiumCpmm
+        | Protocol::RaydiumClmm
+        | Protocol::MeteoraDammV2 => 8,
+    }
+}
+
+fn main() {
+    let map = EventDispatcher::protocol_program_map();
+    assert!(!map.is_empty());
+    for (protocol, program_id) in map {
+        let len = EventDispatcher::discriminator_len(program_id);
+        assert_eq!(len, expected_len(protocol), "{protocol:?} ({program_id})");
+        println!("{protocol:?}: {len}-byte discriminator");
+    }
+
+    // Programs outside the map fall back to 8 bytes
+    assert_eq!(EventDispatcher::discriminator_len(&Pubkey::new_unique()), 8);
+}
@@ -0,0 +1,107 @@
+//! Check that dropping a subscription stops its processing.
+//!
+//! Dropping a `SubscriptionHandle` aborts the subscription's tasks and cancels its
+//! `CancellationToken`, same as `stop`. A client stores its handle, so dropping the last clone of
+//! `ShredStreamGrpc` stops the subscription too. Both are checked against an endless in-memory
+//! source: once dropped, the event count must stay flat.
+//!
+//! Run with `cargo run --example drop_subscription_fixture_example --features test-fixtures`.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use solana_entry::entry::Entry;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+use solana_streamer_sdk::common::AnyResult;
+use solana_streamer_sdk::streaming::common::{StreamClientConfig, SubscriptionHandle};
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::MEMO_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+use solana_streamer_sdk::streaming::shred::{ShredEntries, ShredSource};
+use solana_streamer_sdk::streaming::ShredStreamGrpc;
+
+/// Delivers one memo transaction per millisecond, forever
+struct EndlessSource {
+    slot: u64,
+}
+
+#[tonic::async_trait]
+impl ShredSource for EndlessSource {
+    fn name(&self) -> &str {
+        "endless"
+    }
+
+    async fn next_entries(&mut self) -> Option<AnyResult<ShredEntries>> {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        self.slot += 1;
+        let memo = Instruction::new_with_bytes(MEMO_PROGRAM_ID, &self.slot.to_le_bytes(), vec![]);
+        let payer = Pubkey::new_unique();
+        let transaction = VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+            &[memo],
+            Some(&payer),
+        )));
+        let entries =
+            vec![Entry { num_hashes: 0, hash: Hash::default(), transactions: vec![transaction] }];
+        Some(
+            bincode::serialize(&entries)
+                .map(|entries| ShredEntries { slot: self.slot, entries })
+                .map_err(Into::into),
+        )
+    }
+}
+
+/// Waits a while and returns how much `counter` grew meanwhile
+async fn growth(counter: &AtomicU64) -> u64 {
+    let before = counter.load(Ordering::Relaxed);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    counter.load(Ordering::Relaxed) - before
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // A bare handle around a task
+    let ticks = Arc::new(AtomicU64::new(0));
+    let task_ticks = ticks.clone();
+    let task = tokio::spawn(async move {
+        loop {
+            task_ticks.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    });
+    let handle = SubscriptionHandle::new(task, None, None);
+    let cancellation = handle.cancellation_token();
+    assert!(growth(&ticks).await > 0);
+    drop(handle);
+    assert!(cancellation.is_cancelled());
+    // The task may still be mid-iteration when it is aborted
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(growth(&ticks).await, 0);
+
+    // A client subscription, stopped by dropping the client
+    let client = ShredStreamGrpc::new_for_sources(StreamClientConfig::default());
+    let events = Arc::new(AtomicU64::new(0));
+    let counter = events.clone();
+    client
+        .subscribe_with_sources(
+            vec![Box::new(EndlessSource { slot: 0 })],
+            vec![Protocol::PumpFun],
+            None,
+            None,
+            move |_event| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            },
+        )
+        .await?;
+    assert!(growth(&events).await > 0);
+    drop(client);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(growth(&events).await, 0);
+
+    println!("{} events before the client was dropped, none after", events.load(Ordering::Relaxed));
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! Filter parsed events with the `DexEventIterExt` iterator adapters, as an offline analysis
+//! pipeline over synthetic PumpFun and PumpSwap transactions would.
+//!
+//! Run with `cargo run --example event_iter_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::common::DexEventIterExt;
+use solana_streamer_sdk::streaming::event_parser::common::{EventType, ProtocolType};
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::events::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::protocols::{pumpfun, pumpswap};
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+/// Parse a single instruction with `account_count` fresh accounts, placing the program id at
+/// `program_index` and the fee payer at `user_index`
+async fn parse_single(
+    protocol: Protocol,
+    program_id: Pubkey,
+    account_count: usize,
+    (user_index, program_index): (usize, usize),
+    discriminator: &[u8],
+) -> Result<Vec<DexEvent>> {
+    let user = Pubkey::new_unique();
+    let mut accounts: Vec<Pubkey> = (0..account_count).map(|_| Pubkey::new_unique()).collect();
+    accounts[user_index] = user;
+    accounts[program_index] = program_id;
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    data.extend_from_slice(&50_000_000u64.to_le_bytes());
+
+    let fixture = TxFixtureBuilder::new().fee_payer(user).instruction(program_id, &accounts, data);
+    let signature = fixture.get_signature();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[protocol],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    Ok(events)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut events = Vec::new();
+    let pumpfun_ix = (6, 11);
+    events.extend(
+        parse_single(
+            Protocol::PumpFun,
+            PUMPFUN_PROGRAM_ID,
+            16,
+            pumpfun_ix,
+            pumpfun::discriminators::BUY_IX,
+        )
+        .await?,
+    );
+    events.extend(
+        parse_single(
+            Protocol::PumpFun,
+            PUMPFUN_PROGRAM_ID,
+            16,
+            pumpfun_ix,
+            pumpfun::discriminators::SELL_IX,
+        )
+        .await?,
+    );
+    events.extend(
+        parse_single(
+            Protocol::PumpSwap,
+            PUMPSWAP_PROGRAM_ID,
+            23,
+            (1, 16),
+            pumpswap::discriminators::SELL_IX,
+        )
+        .await?,
+    );
+
+    assert_eq!(events.iter().by_protocol(ProtocolType::PumpFun).count(), 2);
+    assert_eq!(events.iter().by_event_type(EventType::PumpFunSell).count(), 1);
+    assert_eq!(
+        events.iter().by_event_types([EventType::PumpFunBuy, EventType::PumpSwapSell]).count(),
+        2
+    );
+    assert_eq!(events.iter().trades_only().count(), 3);
+
+    // Instruction-only trades carry no CPI log amounts, so they have no unified form; a replayed
+    // event with the amounts filled in does
+    assert_eq!(events.iter().unified_trades().count(), 0);
+    let replayed = DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        mint: Pubkey::new_unique(),
+        sol_amount: 1_500_000_000,
+        token_amount: 1_200_000,
+        is_buy: true,
+        ..Default::default()
+    });
+    let sol_volume: u64 = std::iter::once(replayed)
+        .chain(events)
+        .unified_trades()
+        .filter_map(|trade| trade.sol_lamports())
+        .sum();
+    assert_eq!(sol_volume, 1_500_000_000);
+
+    println!("event iterator adapters filtered the parsed events as expected");
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Snapshot the one-line `DexEvent::summary` output for fixed events.
+//!
+//! Log pipelines grep these lines, so a change in amount formatting, key shortening or field
+//! order should be deliberate. The expected strings were worked out by hand: lamports print as
+//! SOL with trailing zeros trimmed, other amounts compactly (`35.6K`, `1.2M`), and keys and
+//! signatures as their first and last four base58 characters.
+//!
+//! Run with `cargo run --example event_summary_snapshot_example --features test-fixtures`.
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_streamer_sdk::streaming::event_parser::common::{
+    EventMetadata, EventType, ProtocolType, SwapData,
+};
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::SetComputeUnitLimitEvent;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_amm_v4::RaydiumAmmV4SwapEvent;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_clmm::RaydiumClmmSwapEvent;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+
+/// `4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi`, shortened to `4vJ9...kLKi`
+const MINT: Pubkey = Pubkey::new_from_array([1; 32]);
+/// `8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR`, shortened to `8qbH...VfeR`
+const USER: Pubkey = Pubkey::new_from_array([2; 32]);
+/// `CktRuQ2mttgRGkXJtyksdKHjUdc2C4TgDzyB98oEzy8`, shortened to `CktR...Ezy8`
+const QUOTE_MINT: Pubkey = Pubkey::new_from_array([3; 32]);
+
+/// Metadata whose signature shortens to `5f5r...REX5`
+fn metadata(protocol: ProtocolType, event_type: EventType) -> EventMetadata {
+    EventMetadata {
+        signature: Signature::from([4; 64]),
+        protocol,
+        event_type,
+        ..Default::default()
+    }
+}
+
+fn main() {
+    let buy = DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        metadata: metadata(ProtocolType::PumpFun, EventType::PumpFunBuy),
+        mint: MINT,
+        sol_amount: 1_500_000_000,
+        token_amount: 1_234_567,
+        is_buy: true,
+        user: USER,
+        ..Default::default()
+    });
+    let sell = DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        metadata: metadata(ProtocolType::PumpFun, EventType::PumpFunSell),
+        mint: MINT,
+        sol_amount: 2_000_000_000,
+        token_amount: 35_600,
+        is_buy: false,
+        user: USER,
+        ..Default::default()
+    });
+    let mut swap_metadata = metadata(ProtocolType::RaydiumAmmV4, EventType::RaydiumAmmV4SwapBaseIn);
+    swap_metadata.swap_data = Some(SwapData {
+        from_mint: MINT,
+        to_mint: QUOTE_MINT,
+        from_amount: 1_000,
+        to_amount: 999,
+        ..Default::default()
+    });
+    let amm_swap = DexEvent::RaydiumAmmV4SwapEvent(RaydiumAmmV4SwapEvent {
+        metadata: swap_metadata,
+        amount_in: 1_000,
+        amount_out: 999,
+        user_source_owner: USER,
+        ..Default::default()
+    });
+    let cu_limit = DexEvent::SetComputeUnitLimitEvent(SetComputeUnitLimitEvent {
+        metadata: metadata(ProtocolType::Common, EventType::SetComputeUnitLimit),
+        units: 200_000,
+    });
+    // No dedicated format and no swap_data: protocol and event type only
+    let clmm_swap = DexEvent::RaydiumClmmSwapEvent(RaydiumClmmSwapEvent {
+        metadata: metadata(ProtocolType::RaydiumClmm, EventType::RaydiumClmmSwap),
+        ..Default::default()
+    });
+
+    let snapshots = [
+        (buy, "PumpFun BUY 1.5 SOL -> 1.2M TOKEN(4vJ9...kLKi) by 8qbH...VfeR sig 5f5r...REX5"),
+        (sell, "PumpFun SELL 35.6K TOKEN(4vJ9...kLKi) -> 2 SOL by 8qbH...VfeR sig 5f5r...REX5"),
+        (
+            amm_swap,
+            "RaydiumAmmV4 SWAP 1.0K -> 999 by 8qbH...VfeR [1.0K 4vJ9...kLKi -> 999 CktR...Ezy8] \
+             sig 5f5r...REX5",
+        ),
+        (cu_limit, "Common CU limit 200000 sig 5f5r...REX5"),
+        (clmm_swap, "RaydiumClmm RaydiumClmmSwap sig 5f5r...REX5"),
+    ];
+    for (event, expected) in &snapshots {
+        assert_eq!(event.summary(), *expected);
+        println!("{expected}");
+    }
+}
@@ -0,0 +1,74 @@
+//! Read the transaction's fee payer from `EventMetadata::fee_payer`.
+//!
+//! Every event parsed from a transaction carries its fee payer (account index 0, the first
+//! signer), which can differ from the trade's `user` when a relayer sponsors the fees. The
+//! synthetic transaction here is paid for by a relayer and carries a memo and a PumpFun buy of
+//! another user; both events report the relayer as fee payer.
+//!
+//! Run with `cargo run --example fee_payer_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::MEMO_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+/// Accounts and data of a PumpFun buy instruction with the 16 fixed accounts
+fn buy_instruction(user: Pubkey) -> (Vec<Pubkey>, Vec<u8>) {
+    let mut accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+    accounts[6] = user;
+    accounts[11] = PUMPFUN_PROGRAM_ID;
+    let mut data = discriminators::BUY_IX.to_vec();
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // amount
+    data.extend_from_slice(&50_000_000u64.to_le_bytes()); // max_sol_cost
+    (accounts, data)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let relayer = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    let (buy_accounts, buy_data) = buy_instruction(user);
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(relayer)
+        .instruction(MEMO_PROGRAM_ID, &[], b"sponsored".to_vec())
+        .instruction(PUMPFUN_PROGRAM_ID, &buy_accounts, buy_data);
+    let signature = fixture.get_signature();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+
+    assert_eq!(events.len(), 2);
+    for event in &events {
+        assert_eq!(event.metadata().fee_payer, relayer);
+    }
+    let trade = events.iter().find_map(|event| match event {
+        DexEvent::PumpFunTradeEvent(trade) => Some(trade),
+        _ => None,
+    });
+    assert_eq!(trade.map(|trade| trade.user), Some(user));
+
+    println!("buy by {user} sponsored by fee payer {relayer}");
+    Ok(())
+}
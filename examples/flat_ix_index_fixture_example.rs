@@ -0,0 +1,97 @@
+//! Reconstruct execution order across outer and inner instructions with
+//! `EventMetadata::flat_ix_index`.
+//!
+//! The synthetic transaction calls PumpFun buy both directly and through a router (as a CPI):
+//!
+//! ```text
+//! flat 0  outer 0  router
+//! flat 1             inner 0  unrelated program
+//! flat 2             inner 1  PumpFun buy
+//! flat 3             inner 2  unrelated program
+//! flat 4  outer 1  PumpFun buy
+//! flat 5  outer 2  router
+//! flat 6             inner 0  PumpFun buy
+//! ```
+//!
+//! Run with `cargo run --example flat_ix_index_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+/// Accounts and data of a PumpFun buy instruction with the 16 fixed accounts
+fn buy_instruction(user: Pubkey) -> (Vec<Pubkey>, Vec<u8>) {
+    let mut accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+    accounts[6] = user;
+    accounts[11] = PUMPFUN_PROGRAM_ID;
+    let mut data = discriminators::BUY_IX.to_vec();
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // amount
+    data.extend_from_slice(&50_000_000u64.to_le_bytes()); // max_sol_cost
+    (accounts, data)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let user = Pubkey::new_unique();
+    let router = Pubkey::new_unique();
+    let unrelated = Pubkey::new_unique();
+    let router_accounts = [user, Pubkey::new_unique()];
+    let (routed_accounts, routed_data) = buy_instruction(user);
+    let (direct_accounts, direct_data) = buy_instruction(user);
+    let (last_accounts, last_data) = buy_instruction(user);
+
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(user)
+        .instruction(router, &router_accounts, vec![1])
+        .inner_instruction(unrelated, &router_accounts, vec![2])
+        .inner_instruction(PUMPFUN_PROGRAM_ID, &routed_accounts, routed_data)
+        .inner_instruction(unrelated, &router_accounts, vec![3])
+        .instruction(PUMPFUN_PROGRAM_ID, &direct_accounts, direct_data)
+        .instruction(router, &router_accounts, vec![4])
+        .inner_instruction(PUMPFUN_PROGRAM_ID, &last_accounts, last_data);
+    let signature = fixture.get_signature();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+
+    let positions: Vec<(u32, i64, Option<i64>)> = events
+        .iter()
+        .map(|event| {
+            let metadata = event.metadata();
+            (metadata.flat_ix_index, metadata.outer_index, metadata.inner_index)
+        })
+        .collect();
+    assert_eq!(positions, vec![(2, 0, Some(1)), (4, 1, None), (6, 2, Some(0))]);
+    // Sorting by the flattened index gives the same order as (outer_index, inner_index)
+    // in execution order, where an outer instruction runs before its inner instructions
+    let mut by_nesting = positions.clone();
+    by_nesting.sort_by_key(|&(_, outer, inner)| (outer, inner.map_or(-1, |inner| inner)));
+    assert_eq!(by_nesting, positions);
+
+    for (flat, outer, inner) in positions {
+        println!("flat_ix_index {flat}: outer {outer}, inner {inner:?}");
+    }
+    Ok(())
+}
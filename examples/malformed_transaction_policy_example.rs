@@ -0,0 +1,96 @@
+//! Report transactions the parser had to skip with `ParseConfig::malformed_transaction_policy`.
+//!
+//! A transaction without a message, or whose account keys are not all 32 bytes, cannot be
+//! parsed; it is skipped and counted in `malformed_transactions_count`. `LogAndSkip` also logs
+//! it, `OnParseError` hands its signature, slot and the reason to a callback. Here one well-formed memo
+//! transaction and two broken ones are parsed with a callback collecting the skipped signatures.
+//!
+//! Run with `cargo run --example malformed_transaction_policy_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::MEMO_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::{
+    MalformedReason, MalformedTransaction, MalformedTransactionPolicy, ParseConfig,
+    ParseErrorCallback,
+};
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/// Parses one transaction and returns how many events it produced
+async fn parse(
+    grpc_tx: SubscribeUpdateTransactionInfo,
+    fixture: &TxFixtureBuilder,
+    config: &ParseConfig,
+) -> Result<usize> {
+    let events = Arc::new(Mutex::new(0usize));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        grpc_tx,
+        fixture.get_signature(),
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |_event: DexEvent| *sink.lock().unwrap() += 1),
+        config,
+    )
+    .await?;
+    let count = *events.lock().unwrap();
+    Ok(count)
+}
+
+fn memo_fixture() -> TxFixtureBuilder {
+    TxFixtureBuilder::new().instruction(MEMO_PROGRAM_ID, &[], b"hello".to_vec())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let skipped = Arc::new(Mutex::new(Vec::<MalformedTransaction>::new()));
+    let sink = skipped.clone();
+    let config = ParseConfig {
+        malformed_transaction_policy: MalformedTransactionPolicy::OnParseError(
+            ParseErrorCallback::new(move |tx| sink.lock().unwrap().push(*tx)),
+        ),
+        ..Default::default()
+    };
+
+    let valid = memo_fixture();
+    assert_eq!(parse(valid.clone().build(), &valid, &config).await?, 1);
+
+    // The update carries the transaction but not its message
+    let no_message = memo_fixture();
+    let mut grpc_tx = no_message.clone().build();
+    if let Some(transaction) = grpc_tx.transaction.as_mut() {
+        transaction.message = None;
+    }
+    assert_eq!(parse(grpc_tx, &no_message, &config).await?, 0);
+
+    // A truncated account key would shift every account index after it
+    let bad_key = memo_fixture();
+    let mut grpc_tx = bad_key.clone().build();
+    if let Some(message) = grpc_tx.transaction.as_mut().and_then(|tx| tx.message.as_mut()) {
+        message.account_keys[0].truncate(31);
+    }
+    assert_eq!(parse(grpc_tx, &bad_key, &config).await?, 0);
+
+    let skipped = std::mem::take(&mut *skipped.lock().unwrap());
+    let reasons: Vec<_> = skipped.iter().map(|tx| (tx.signature, tx.reason)).collect();
+    assert_eq!(
+        reasons,
+        vec![
+            (no_message.get_signature(), MalformedReason::MissingMessage),
+            (bad_key.get_signature(), MalformedReason::InvalidAccountKey),
+        ]
+    );
+    for tx in &skipped {
+        println!("skipped {} at slot {:?}: {}", tx.signature, tx.slot, tx.reason);
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Tag trades with bot-supplied memos (`DexEvent::MemoEvent`).
+//!
+//! SPL Memo instructions are decoded like compute budget instructions, for any protocol
+//! selection: each emits a `MemoEvent` with the memo text (invalid UTF-8 replaced with U+FFFD)
+//! and its first signer. The synthetic transaction carries an order id memo, a PumpFun buy and a
+//! memo with invalid UTF-8; the trade is correlated with its memo by signature.
+//!
+//! Run with `cargo run --example memo_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Result};
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::MEMO_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+/// Accounts and data of a PumpFun buy instruction with the 16 fixed accounts
+fn buy_instruction(user: Pubkey) -> (Vec<Pubkey>, Vec<u8>) {
+    let mut accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+    accounts[6] = user;
+    accounts[11] = PUMPFUN_PROGRAM_ID;
+    let mut data = discriminators::BUY_IX.to_vec();
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // amount
+    data.extend_from_slice(&50_000_000u64.to_le_bytes()); // max_sol_cost
+    (accounts, data)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let user = Pubkey::new_unique();
+    let (buy_accounts, buy_data) = buy_instruction(user);
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(user)
+        .instruction(MEMO_PROGRAM_ID, &[user], b"order:42".to_vec())
+        .instruction(PUMPFUN_PROGRAM_ID, &buy_accounts, buy_data)
+        .instruction(MEMO_PROGRAM_ID, &[], vec![b'o', b'k', 0xff]);
+    let signature = fixture.get_signature();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+
+    let memos: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            DexEvent::MemoEvent(memo) => Some((memo.text.as_str(), memo.signer)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(memos, vec![("order:42", Some(user)), ("ok\u{fffd}", None)]);
+
+    let trade = events
+        .iter()
+        .find(|event| matches!(event, DexEvent::PumpFunTradeEvent(_)))
+        .ok_or_else(|| anyhow!("expected a PumpFun trade"))?;
+    let order_id = events.iter().find_map(|event| match event {
+        DexEvent::MemoEvent(memo) if memo.metadata.signature == trade.metadata().signature => {
+            memo.text.strip_prefix("order:")
+        }
+        _ => None,
+    });
+    assert_eq!(order_id, Some("42"));
+
+    println!("trade {} tagged with order id {}", trade.metadata().signature, order_id.unwrap());
+    Ok(())
+}
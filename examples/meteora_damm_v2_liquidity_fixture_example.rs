@@ -0,0 +1,190 @@
+//! Run synthetic Meteora DAMM v2 add_liquidity / remove_liquidity / remove_all_liquidity
+//! instructions with their CPI logs through the parser and check the merged amounts and the
+//! account mapping.
+//!
+//! Run with `cargo run --example meteora_damm_v2_liquidity_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::meteora_damm_v2::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+const LIQUIDITY_DELTA: u128 = 18_446_744_073_709_551_616_000;
+const TOKEN_A_AMOUNT: u64 = 2_500_000_000;
+const TOKEN_B_AMOUNT: u64 = 180_000_000;
+
+/// Liquidity CPI log: pool, position, owner, liquidity_delta, the two thresholds and the
+/// token amounts, followed by the pool totals for add liquidity
+fn liquidity_log(discriminator: &[u8], accounts: &LiquidityAccounts, totals: bool) -> Vec<u8> {
+    let mut log = discriminator.to_vec();
+    log.extend_from_slice(accounts.pool.as_ref());
+    log.extend_from_slice(accounts.position.as_ref());
+    log.extend_from_slice(accounts.owner.as_ref());
+    log.extend_from_slice(&LIQUIDITY_DELTA.to_le_bytes());
+    log.extend_from_slice(&2_400_000_000u64.to_le_bytes()); // token_a_amount_threshold
+    log.extend_from_slice(&170_000_000u64.to_le_bytes()); // token_b_amount_threshold
+    log.extend_from_slice(&TOKEN_A_AMOUNT.to_le_bytes());
+    log.extend_from_slice(&TOKEN_B_AMOUNT.to_le_bytes());
+    if totals {
+        log.extend_from_slice(&52_500_000_000u64.to_le_bytes()); // total_amount_a
+        log.extend_from_slice(&3_780_000_000u64.to_le_bytes()); // total_amount_b
+    }
+    log
+}
+
+struct LiquidityAccounts {
+    pool: Pubkey,
+    position: Pubkey,
+    owner: Pubkey,
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    event_authority: Pubkey,
+}
+
+impl LiquidityAccounts {
+    fn new() -> Self {
+        Self {
+            pool: Pubkey::new_unique(),
+            position: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            token_a_mint: Pubkey::new_unique(),
+            token_b_mint: Pubkey::new_unique(),
+            event_authority: Pubkey::new_unique(),
+        }
+    }
+
+    /// add_liquidity: pool, position, token a/b accounts, token a/b vaults, token a/b mints,
+    /// position nft account, owner, token a/b programs, event authority, program
+    fn add_liquidity(&self) -> Vec<Pubkey> {
+        let mut accounts: Vec<Pubkey> = (0..14).map(|_| Pubkey::new_unique()).collect();
+        accounts[0] = self.pool;
+        accounts[1] = self.position;
+        accounts[6] = self.token_a_mint;
+        accounts[7] = self.token_b_mint;
+        accounts[9] = self.owner;
+        accounts[12] = self.event_authority;
+        accounts[13] = METEORA_DAMM_V2_PROGRAM_ID;
+        accounts
+    }
+
+    /// remove_liquidity / remove_all_liquidity: the add_liquidity accounts after the pool
+    /// authority
+    fn remove_liquidity(&self) -> Vec<Pubkey> {
+        let mut accounts = vec![Pubkey::new_unique()];
+        accounts.extend(self.add_liquidity());
+        accounts
+    }
+}
+
+async fn parse(fixture: TxFixtureBuilder) -> Result<Vec<DexEvent>> {
+    let signature = fixture.get_signature();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::MeteoraDammV2],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    Ok(events)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let liquidity = LiquidityAccounts::new();
+
+    // add_liquidity: liquidity_delta + two thresholds, amounts come from the CPI log
+    let accounts = liquidity.add_liquidity();
+    let mut data = discriminators::ADD_LIQUIDITY_IX.to_vec();
+    data.extend_from_slice(&LIQUIDITY_DELTA.to_le_bytes());
+    data.extend_from_slice(&2_400_000_000u64.to_le_bytes());
+    data.extend_from_slice(&170_000_000u64.to_le_bytes());
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(liquidity.owner)
+        .instruction(METEORA_DAMM_V2_PROGRAM_ID, &accounts, data)
+        .inner_instruction(
+            METEORA_DAMM_V2_PROGRAM_ID,
+            &[liquidity.event_authority],
+            liquidity_log(discriminators::ADD_LIQUIDITY_EVENT, &liquidity, true),
+        );
+    let events = parse(fixture).await?;
+    let [DexEvent::MeteoraDammV2AddLiquidityEvent(add)] = events.as_slice() else {
+        panic!("expected one add liquidity event, got {events:?}");
+    };
+    assert_eq!(add.liquidity_delta, LIQUIDITY_DELTA);
+    assert_eq!((add.token_a_amount, add.token_b_amount), (TOKEN_A_AMOUNT, TOKEN_B_AMOUNT));
+    assert_eq!((add.total_amount_a, add.total_amount_b), (52_500_000_000, 3_780_000_000));
+    assert_eq!(
+        (add.pool, add.position, add.owner),
+        (liquidity.pool, liquidity.position, liquidity.owner)
+    );
+    assert_eq!(
+        (add.token_a_mint, add.token_b_mint),
+        (liquidity.token_a_mint, liquidity.token_b_mint)
+    );
+    assert_eq!(add.token_a_vault, accounts[4]);
+    assert_eq!(add.position_nft_account, accounts[8]);
+
+    // remove_liquidity: same arguments, one extra leading pool authority account
+    let accounts = liquidity.remove_liquidity();
+    let mut data = discriminators::REMOVE_LIQUIDITY_IX.to_vec();
+    data.extend_from_slice(&LIQUIDITY_DELTA.to_le_bytes());
+    data.extend_from_slice(&2_400_000_000u64.to_le_bytes());
+    data.extend_from_slice(&170_000_000u64.to_le_bytes());
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(liquidity.owner)
+        .instruction(METEORA_DAMM_V2_PROGRAM_ID, &accounts, data)
+        .inner_instruction(
+            METEORA_DAMM_V2_PROGRAM_ID,
+            &[liquidity.event_authority],
+            liquidity_log(discriminators::REMOVE_LIQUIDITY_EVENT, &liquidity, false),
+        );
+    let events = parse(fixture).await?;
+    let [DexEvent::MeteoraDammV2RemoveLiquidityEvent(remove)] = events.as_slice() else {
+        panic!("expected one remove liquidity event, got {events:?}");
+    };
+    assert_eq!(remove.liquidity_delta, LIQUIDITY_DELTA);
+    assert_eq!((remove.token_a_amount, remove.token_b_amount), (TOKEN_A_AMOUNT, TOKEN_B_AMOUNT));
+    assert_eq!(remove.pool_authority, accounts[0]);
+    assert_eq!((remove.pool, remove.owner), (liquidity.pool, liquidity.owner));
+    assert_eq!(remove.token_b_vault, accounts[6]);
+    assert_eq!(remove.token_b_mint, liquidity.token_b_mint);
+
+    // remove_all_liquidity: only the two thresholds, the removed liquidity comes from the log
+    let mut data = discriminators::REMOVE_ALL_LIQUIDITY_IX.to_vec();
+    data.extend_from_slice(&2_400_000_000u64.to_le_bytes());
+    data.extend_from_slice(&170_000_000u64.to_le_bytes());
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(liquidity.owner)
+        .instruction(METEORA_DAMM_V2_PROGRAM_ID, &accounts, data)
+        .inner_instruction(
+            METEORA_DAMM_V2_PROGRAM_ID,
+            &[liquidity.event_authority],
+            liquidity_log(discriminators::REMOVE_LIQUIDITY_EVENT, &liquidity, false),
+        );
+    let events = parse(fixture).await?;
+    let [DexEvent::MeteoraDammV2RemoveLiquidityEvent(remove_all)] = events.as_slice() else {
+        panic!("expected one remove liquidity event, got {events:?}");
+    };
+    assert_eq!(remove_all.liquidity_delta, LIQUIDITY_DELTA);
+    assert_eq!(remove_all.token_a_amount, TOKEN_A_AMOUNT);
+    assert_eq!(remove_all.position, liquidity.position);
+
+    println!("DAMM v2 add / remove liquidity amounts and accounts parsed");
+    Ok(())
+}
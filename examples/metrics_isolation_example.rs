@@ -0,0 +1,35 @@
+//! Keep metrics of separate clients apart with per-instance `MetricsManager`s.
+//!
+//! Counters recorded while a future runs inside `MetricsManager::scope` go to that instance
+//! (this is how each client's subscription task reports to its own manager); everything else
+//! goes to the global instance.
+//!
+//! Run with `cargo run --example metrics_isolation_example`.
+
+use anyhow::Result;
+use solana_streamer_sdk::streaming::common::MetricsManager;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let first = MetricsManager::new();
+    let second = MetricsManager::new();
+    let global_before = MetricsManager::global().get_dropped_events_count();
+
+    first
+        .scope(async {
+            MetricsManager::current().increment_dropped_events();
+            MetricsManager::current().increment_dropped_events();
+        })
+        .await;
+    second.scope(async { MetricsManager::current().increment_dropped_events() }).await;
+
+    assert_eq!(first.get_dropped_events_count(), 2);
+    assert_eq!(second.get_dropped_events_count(), 1);
+    assert_eq!(MetricsManager::global().get_dropped_events_count(), global_before);
+
+    // With a client: YellowstoneGrpc / ShredStreamGrpc::set_metrics_manager(MetricsManager::new())
+    // or StreamClientBuilder::metrics_manager, then client.get_metrics() reports only its own stats
+    first.print_metrics();
+    second.print_metrics();
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! Keep using the object pools after a thread panicked while holding their lock.
+//!
+//! A panic while the pool mutex is held poisons it. The pools recover the inner queue instead of
+//! propagating the poison, so a single panicking task does not take down every later acquire or
+//! return. Each pool here is poisoned on purpose, then an object is acquired, modified and
+//! returned to it. The intentional panics are still printed by the default panic hook.
+//!
+//! Run with `cargo run --example poisoned_pool_example --features test-fixtures`.
+
+use solana_streamer_sdk::streaming::grpc::{
+    AccountPrettyPool, BlockMetaPrettyPool, TransactionPrettyPool,
+};
+use solana_streamer_sdk::streaming::shred::TransactionWithSlotPool;
+
+fn main() {
+    let accounts = AccountPrettyPool::new(2, 4);
+    accounts.poison();
+    assert!(accounts.is_poisoned());
+    {
+        let mut account = accounts.acquire();
+        account.slot = 7;
+        assert_eq!(accounts.memory_estimate().pooled_objects, 1);
+    }
+    assert_eq!(accounts.memory_estimate().pooled_objects, 2);
+    assert_eq!(accounts.trim(1), 1);
+
+    let block_metas = BlockMetaPrettyPool::new(2, 4);
+    block_metas.poison();
+    assert!(block_metas.is_poisoned());
+    {
+        let mut block_meta = block_metas.acquire();
+        block_meta.slot = 7;
+        assert_eq!(block_metas.memory_estimate().pooled_objects, 1);
+    }
+    assert_eq!(block_metas.memory_estimate().pooled_objects, 2);
+
+    let transactions = TransactionPrettyPool::new(2, 4);
+    transactions.poison();
+    assert!(transactions.is_poisoned());
+    {
+        let mut transaction = transactions.acquire();
+        transaction.slot = 7;
+        assert_eq!(transactions.memory_estimate().pooled_objects, 1);
+    }
+    assert_eq!(transactions.memory_estimate().pooled_objects, 2);
+
+    let shred_transactions = TransactionWithSlotPool::new(2, 4);
+    shred_transactions.poison();
+    assert!(shred_transactions.is_poisoned());
+    {
+        let mut transaction = shred_transactions.acquire();
+        transaction.slot = 7;
+        assert_eq!(shred_transactions.available(), 1);
+    }
+    assert_eq!(shred_transactions.available(), 2);
+
+    println!("all object pools kept working after their lock was poisoned");
+}
@@ -0,0 +1,69 @@
+//! Filter trades by pool age with `ParseConfig::min_pool_age_slots` / `max_pool_age_slots`.
+//!
+//! A client with either threshold set wraps its callback with `create_pool_age_callback`, which
+//! is used directly here: the first trade seen for a mint records its slot, and later trades are
+//! kept only while `slot - first_seen_slot` is within the thresholds.
+//!
+//! Run with `cargo run --example pool_age_filter_example`.
+
+use std::sync::{Arc, Mutex};
+
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::common::{
+    create_pool_age_callback, DEFAULT_POOL_AGE_CACHE_SIZE,
+};
+use solana_streamer_sdk::streaming::event_parser::common::{EventMetadata, ProtocolType};
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+
+fn pumpfun_buy(mint: Pubkey, slot: u64) -> DexEvent {
+    DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        metadata: EventMetadata { slot, protocol: ProtocolType::PumpFun, ..Default::default() },
+        mint,
+        sol_amount: 100_000_000,
+        token_amount: 1_000_000,
+        is_buy: true,
+        ..Default::default()
+    })
+}
+
+/// Slots of the trades that pass a filter with the given thresholds
+fn delivered_slots(min_age: Option<u64>, max_age: Option<u64>, trades: &[DexEvent]) -> Vec<u64> {
+    let delivered = Arc::new(Mutex::new(Vec::new()));
+    let sink = delivered.clone();
+    let callback = create_pool_age_callback(
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event.metadata().slot)),
+        min_age,
+        max_age,
+        DEFAULT_POOL_AGE_CACHE_SIZE,
+    );
+    for trade in trades {
+        callback(trade.clone());
+    }
+    drop(callback);
+    Arc::try_unwrap(delivered).unwrap().into_inner().unwrap()
+}
+
+fn main() {
+    let mint = Pubkey::new_unique();
+    // First seen at slot 1_000, then traded 2, 10, 50 and 200 slots later
+    let trades: Vec<DexEvent> = [1_000, 1_002, 1_010, 1_050, 1_200]
+        .into_iter()
+        .map(|slot| pumpfun_buy(mint, slot))
+        .collect();
+
+    // Skip the launch frenzy: only trades at least 10 slots after the mint was first seen
+    let settled = delivered_slots(Some(10), None, &trades);
+    assert_eq!(settled, vec![1_010, 1_050, 1_200]);
+
+    // Follow only fresh pools: trades at most 50 slots after the mint was first seen
+    let fresh = delivered_slots(None, Some(50), &trades);
+    assert_eq!(fresh, vec![1_000, 1_002, 1_010, 1_050]);
+
+    let window = delivered_slots(Some(10), Some(50), &trades);
+    assert_eq!(window, vec![1_010, 1_050]);
+
+    println!("min 10 slots: {settled:?}");
+    println!("max 50 slots: {fresh:?}");
+    println!("10..=50 slots: {window:?}");
+}
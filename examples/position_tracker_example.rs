@@ -0,0 +1,69 @@
+//! Track per-wallet net positions and realized P&L with `PositionTracker`.
+//!
+//! Trades are normalized with `UnifiedTrade` and accumulated per (wallet, mint): bought and sold
+//! amounts, SOL spent and received, the net balance and the realized P&L at average cost. Only
+//! SOL-quoted trades are counted, and the tracker keeps at most `max_wallets` wallets (LRU). On a
+//! client pass `tracker.event_callback()` to subscribe; here a few synthetic trades are fed directly.
+//!
+//! Run with `cargo run --example position_tracker_example`.
+
+use std::sync::Arc;
+
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::common::{Position, PositionTracker, UnifiedTrade, WSOL_MINT};
+
+fn trade(wallet: Pubkey, mint: Pubkey, is_buy: bool, tokens: u64, lamports: u64) -> UnifiedTrade {
+    UnifiedTrade {
+        trader: wallet,
+        base_mint: mint,
+        quote_mint: WSOL_MINT,
+        base_amount: tokens,
+        quote_amount: lamports,
+        is_buy,
+        is_sol_side: true,
+        ..Default::default()
+    }
+}
+
+fn main() {
+    let tracker = Arc::new(PositionTracker::new(
+        2,
+        Some(Arc::new(|position: &Position| {
+            println!(
+                "{} on {}: net {} tokens, net {} lamports spent",
+                position.wallet,
+                position.mint,
+                position.net_amount(),
+                position.net_sol_spent()
+            )
+        })),
+    ));
+
+    let (alice, bob, carol) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let mint = Pubkey::new_unique();
+
+    // Buy 1M tokens for 1 SOL, another 1M for 3 SOL, then sell 1M for 4 SOL
+    tracker.on_trade(&trade(alice, mint, true, 1_000_000, LAMPORTS_PER_SOL));
+    tracker.on_trade(&trade(alice, mint, true, 1_000_000, 3 * LAMPORTS_PER_SOL));
+    tracker.on_trade(&trade(alice, mint, false, 1_000_000, 4 * LAMPORTS_PER_SOL));
+
+    let position = tracker.position(&alice, &mint).expect("alice's position");
+    assert_eq!(position.net_amount(), 1_000_000);
+    assert_eq!(position.net_sol_spent(), 0);
+    // Average cost is 2 SOL per 1M tokens, so selling 1M for 4 SOL realized 2 SOL
+    assert_eq!(position.realized_pnl_lamports(), Some(2 * LAMPORTS_PER_SOL as i128));
+
+    // A trade quoted in another token has no SOL amount and is ignored
+    tracker.on_trade(&UnifiedTrade {
+        quote_mint: Pubkey::new_unique(),
+        ..trade(bob, mint, true, 1, 1)
+    });
+    assert!(tracker.positions(&bob).is_empty());
+
+    // With room for two wallets, a third evicts the one that traded least recently
+    tracker.on_trade(&trade(bob, mint, true, 500, LAMPORTS_PER_SOL / 10));
+    tracker.on_trade(&trade(carol, mint, true, 500, LAMPORTS_PER_SOL / 10));
+    assert_eq!(tracker.wallet_count(), 2);
+    assert!(tracker.position(&alice, &mint).is_none());
+}
@@ -0,0 +1,57 @@
+//! Compute priority fees with `compute_priority_fee` for the common compute budget setups: no
+//! limit instruction, an explicit limit, a limit above the maximum and a zero unit price.
+//!
+//! Run with `cargo run --example priority_fee_example`.
+
+use solana_streamer_sdk::streaming::common::{compute_priority_fee, ComputeBudget};
+
+fn main() {
+    // No SetComputeUnitLimit: 200,000 CU per non-compute-budget instruction
+    let single = ComputeBudget { unit_price: Some(1_000_000), ..Default::default() };
+    let fee = compute_priority_fee(&single, Some(100_000));
+    assert_eq!(fee.compute_unit_limit, 200_000);
+    assert_eq!(fee.priority_fee_lamports, 200_000);
+    assert_eq!(fee.effective_micro_lamports_per_cu, Some(2_000_000));
+
+    let three_instructions = ComputeBudget {
+        unit_price: Some(1_000_000),
+        non_compute_budget_instructions: Some(3),
+        ..Default::default()
+    };
+    let fee = compute_priority_fee(&three_instructions, None);
+    assert_eq!(fee.compute_unit_limit, 600_000);
+    assert_eq!(fee.priority_fee_lamports, 600_000);
+    assert_eq!(fee.effective_micro_lamports_per_cu, None);
+
+    // Explicit limit: charged on the requested limit, rounded up to whole lamports, whatever the
+    // instruction count
+    let explicit = ComputeBudget {
+        unit_limit: Some(150_000),
+        unit_price: Some(12_345),
+        non_compute_budget_instructions: Some(3),
+    };
+    let fee = compute_priority_fee(&explicit, Some(120_000));
+    assert_eq!(fee.compute_unit_limit, 150_000);
+    assert_eq!(fee.priority_fee_lamports, 1_852); // 1_851.75 rounded up
+    assert_eq!(fee.effective_micro_lamports_per_cu, Some(15_433));
+    assert_eq!(fee.total_fee_lamports(2), 11_852);
+
+    // Limits above 1.4M CU, requested or derived, are capped
+    let capped = ComputeBudget {
+        unit_price: Some(1_000),
+        non_compute_budget_instructions: Some(10),
+        ..Default::default()
+    };
+    assert_eq!(compute_priority_fee(&capped, None).compute_unit_limit, 1_400_000);
+
+    // Zero price (or no SetComputeUnitPrice): only the base fee is paid
+    for unit_price in [Some(0), None] {
+        let zero = ComputeBudget { unit_limit: Some(300_000), unit_price, ..Default::default() };
+        let fee = compute_priority_fee(&zero, Some(250_000));
+        assert_eq!(fee.priority_fee_lamports, 0);
+        assert_eq!(fee.effective_micro_lamports_per_cu, Some(0));
+        assert_eq!(fee.total_fee_lamports(1), 5_000);
+    }
+
+    println!("priority fees match the compute budget rules");
+}
@@ -0,0 +1,84 @@
+//! Check the conversions between `Protocol` and `ProtocolType`.
+//!
+//! Every `Protocol` converts into a `ProtocolType` and back unchanged, and the `ProtocolType`s
+//! with no matching `Protocol` (currently only `Common`) fail to convert instead of being mapped
+//! to an arbitrary protocol. The exhaustive matches below stop compiling when a variant is added
+//! to either enum, so the lists cannot silently fall out of date.
+//!
+//! Run with `cargo run --example protocol_type_conversion_example --features test-fixtures`.
+
+use solana_streamer_sdk::streaming::event_parser::common::ProtocolType;
+use solana_streamer_sdk::streaming::event_parser::core::EventDispatcher;
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+
+/// Every `ProtocolType` and the `Protocol` it should convert to, if any
+const PROTOCOL_TYPES: &[(ProtocolType, Option<Protocol>)] = &[
+    (ProtocolType::PumpSwap, Some(Protocol::PumpSwap)),
+    (ProtocolType::PumpFun, Some(Protocol::PumpFun)),
+    (ProtocolType::Bonk, Some(Protocol::Bonk)),
+    (ProtocolType::RaydiumCpmm, Some(Protocol::RaydiumCpmm)),
+    (ProtocolType::RaydiumClmm, Some(Protocol::RaydiumClmm)),
+    (ProtocolType::RaydiumAmmV4, Some(Protocol::RaydiumAmmV4)),
+    (ProtocolType::MeteoraDammV2, Some(Protocol::MeteoraDammV2)),
+    (ProtocolType::Common, None),
+];
+
+/// Fails to compile when a `ProtocolType` is added without extending `PROTOCOL_TYPES`
+fn listed_protocol_type(protocol_type: &ProtocolType) {
+    match protocol_type {
+        ProtocolType::PumpSwap
+        | ProtocolType::PumpFun
+        | ProtocolType::Bonk
+        | ProtocolType::RaydiumCpmm
+        | ProtocolType::RaydiumClmm
+        | ProtocolType::RaydiumAmmV4
+        | ProtocolType::MeteoraDammV2
+        | ProtocolType::Common => {}
+    }
+}
+
+/// Fails to compile when a `Protocol` is added; every `Protocol` must also be in the dispatcher map
+fn listed_protocol(protocol: &Protocol) {
+    match protocol {
+        Protocol::PumpSwap
+        | Protocol::PumpFun
+        | Protocol::Bonk
+        | Protocol::RaydiumCpmm
+        | Protocol::RaydiumClmm
+        | Protocol::RaydiumAmmV4
+        | Protocol::MeteoraDammV2 => {}
+    }
+}
+
+fn main() {
+    // Protocol -> ProtocolType -> Protocol
+    let protocols: Vec<Protocol> = EventDispatcher::protocol_program_map()
+        .iter()
+        .map(|(protocol, _)| protocol.clone())
+        .collect();
+    for protocol in &protocols {
+        listed_protocol(protocol);
+        let protocol_type = ProtocolType::from(protocol.clone());
+        let round_trip = Protocol::try_from(protocol_type.clone()).unwrap_or_else(|e| {
+            panic!("{protocol:?} -> {protocol_type:?} did not convert back: {e}")
+        });
+        assert_eq!(&round_trip, protocol);
+    }
+
+    // ProtocolType -> Protocol, Err for the types with no protocol
+    for (protocol_type, expected) in PROTOCOL_TYPES {
+        listed_protocol_type(protocol_type);
+        match (Protocol::try_from(protocol_type.clone()), expected) {
+            (Ok(protocol), Some(expected)) => assert_eq!(&protocol, expected),
+            (Err(_), None) => {}
+            (result, expected) => {
+                panic!("{protocol_type:?}: expected {expected:?}, got {result:?}")
+            }
+        }
+    }
+    // Every protocol appears exactly once among the convertible types
+    let convertible = PROTOCOL_TYPES.iter().filter(|(_, protocol)| protocol.is_some()).count();
+    assert_eq!(convertible, protocols.len());
+
+    println!("{} protocols round-trip through ProtocolType", protocols.len());
+}
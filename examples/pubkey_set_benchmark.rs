@@ -0,0 +1,58 @@
+//! Compare `PubkeySet` and `HashSet<Pubkey>` membership throughput for a large allowlist.
+//!
+//! Run with: `cargo run --example pubkey_set_benchmark --release`
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::common::PubkeySet;
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ALLOWLIST_SIZE: usize = 50_000;
+const LOOKUPS: usize = 2_000_000;
+
+fn main() {
+    let mut seed = 0x9E37_79B9_7F4A_7C15u64;
+    let allowlist: Vec<Pubkey> = (0..ALLOWLIST_SIZE).map(|_| random_pubkey(&mut seed)).collect();
+    // 大部分查询的账户不在白名单中，和实际交易中的账户分布一致
+    let misses: Vec<Pubkey> = (0..10_000).map(|_| random_pubkey(&mut seed)).collect();
+    let hits: Vec<Pubkey> = allowlist.iter().step_by(5).copied().collect();
+
+    let hash_set: HashSet<Pubkey> = allowlist.iter().copied().collect();
+    let pubkey_set: PubkeySet = allowlist.iter().copied().collect();
+
+    println!("Allowlist size: {}, lookups per run: {}\n", ALLOWLIST_SIZE, LOOKUPS);
+    println!("{:<24} {:>16} {:>16}", "Benchmark", "HashSet (M/s)", "PubkeySet (M/s)");
+    for (name, keys) in [("miss", &misses), ("hit", &hits)] {
+        let hash_set_time = bench(keys, |key| hash_set.contains(key));
+        let pubkey_set_time = bench(keys, |key| pubkey_set.contains(key));
+        println!("{:<24} {:>16.1} {:>16.1}", name, rate(hash_set_time), rate(pubkey_set_time));
+    }
+}
+
+/// xorshift 生成的伪随机 Pubkey，分布与真实地址一样均匀
+fn random_pubkey(seed: &mut u64) -> Pubkey {
+    let mut bytes = [0u8; 32];
+    for chunk in bytes.chunks_mut(8) {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        chunk.copy_from_slice(&seed.to_le_bytes());
+    }
+    Pubkey::new_from_array(bytes)
+}
+
+fn bench(keys: &[Pubkey], contains: impl Fn(&Pubkey) -> bool) -> Duration {
+    let mut found = 0usize;
+    let start = Instant::now();
+    for key in keys.iter().cycle().take(LOOKUPS) {
+        if contains(black_box(key)) {
+            found += 1;
+        }
+    }
+    black_box(found);
+    start.elapsed()
+}
+
+fn rate(elapsed: Duration) -> f64 {
+    LOOKUPS as f64 / elapsed.as_secs_f64() / 1_000_000.0
+}
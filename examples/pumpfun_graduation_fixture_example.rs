@@ -0,0 +1,114 @@
+//! Detect the trade that completes a PumpFun bonding curve from its CPI log, without waiting for
+//! the migrate event: a synthetic buy that takes the last tokens off the curve
+//! (`real_token_reserves` 0 afterwards) sets `completed_bonding_curve`, an ordinary buy does not.
+//!
+//! Run with `cargo run --example pumpfun_graduation_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+/// Token amount left for sale on a fresh bonding curve
+const INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+
+/// TradeEvent CPI log (current 250-byte layout) of a buy leaving `real_token_reserves` on the curve
+fn trade_log(mint: Pubkey, user: Pubkey, token_amount: u64, real_token_reserves: u64) -> Vec<u8> {
+    let mut log = discriminators::TRADE_EVENT.to_vec();
+    log.extend_from_slice(mint.as_ref());
+    log.extend_from_slice(&85_000_000_000u64.to_le_bytes()); // sol_amount
+    log.extend_from_slice(&token_amount.to_le_bytes());
+    log.push(1); // is_buy
+    log.extend_from_slice(user.as_ref());
+    log.extend_from_slice(&1_760_000_000i64.to_le_bytes()); // timestamp
+    log.extend_from_slice(&115_005_359_056u64.to_le_bytes()); // virtual_sol_reserves
+    let virtual_token_reserves = real_token_reserves + 279_900_000_000_000;
+    log.extend_from_slice(&virtual_token_reserves.to_le_bytes());
+    log.extend_from_slice(&85_005_359_056u64.to_le_bytes()); // real_sol_reserves
+    log.extend_from_slice(&real_token_reserves.to_le_bytes());
+    log.extend_from_slice(Pubkey::new_unique().as_ref()); // fee_recipient
+    log.extend_from_slice(&95u64.to_le_bytes()); // fee_basis_points
+    log.extend_from_slice(&807_500_000u64.to_le_bytes()); // fee
+    log.extend_from_slice(Pubkey::new_unique().as_ref()); // creator
+    log.extend_from_slice(&5u64.to_le_bytes()); // creator_fee_basis_points
+    log.extend_from_slice(&42_500_000u64.to_le_bytes()); // creator_fee
+    log.push(1); // track_volume
+    log.extend_from_slice(&0u64.to_le_bytes()); // total_unclaimed_tokens
+    log.extend_from_slice(&0u64.to_le_bytes()); // total_claimed_tokens
+    log.extend_from_slice(&85_000_000_000u64.to_le_bytes()); // current_sol_volume
+    log.extend_from_slice(&1_760_000_000i64.to_le_bytes()); // last_update_timestamp
+    log
+}
+
+/// Parse a buy instruction with the 16 fixed accounts followed by its TradeEvent CPI log
+async fn parse_buy(token_amount: u64, real_token_reserves: u64) -> Result<PumpFunTradeEvent> {
+    let user = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mut accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+    accounts[2] = mint;
+    accounts[6] = user;
+    accounts[11] = PUMPFUN_PROGRAM_ID;
+    let mut data = discriminators::BUY_IX.to_vec();
+    data.extend_from_slice(&token_amount.to_le_bytes());
+    data.extend_from_slice(&90_000_000_000u64.to_le_bytes()); // max_sol_cost
+
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(user)
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, data)
+        .inner_instruction(
+            PUMPFUN_PROGRAM_ID,
+            &[accounts[10]],
+            trade_log(mint, user, token_amount, real_token_reserves),
+        );
+    let signature = fixture.get_signature();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    events
+        .into_iter()
+        .find_map(|event| match event {
+            DexEvent::PumpFunTradeEvent(e) => Some(e),
+            _ => None,
+        })
+        .ok_or_else(|| anyhow::anyhow!("no PumpFun trade event"))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // The graduating buy takes everything left on the curve
+    let remaining = 2_500_000_000_000;
+    let graduating = parse_buy(remaining, 0).await?;
+    assert!(graduating.is_buy && graduating.real_token_reserves == 0);
+    assert!(graduating.completed_bonding_curve);
+
+    let ordinary = parse_buy(1_000_000_000, INITIAL_REAL_TOKEN_RESERVES - 1_000_000_000).await?;
+    assert!(!ordinary.completed_bonding_curve);
+
+    println!(
+        "graduating buy of {} tokens for mint {} completed the bonding curve",
+        graduating.token_amount, graduating.mint
+    );
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Run a synthetic PumpFun migrate instruction through the parser with and without its CPI log,
+//! under both settings of `ParseConfig::pumpfun_migrate_requires_cpi_log`.
+//!
+//! Run with `cargo run --example pumpfun_migrate_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+const SOL_AMOUNT: u64 = 84_990_359_067;
+
+/// Parse a migrate instruction with the 24 fixed accounts, optionally followed by its
+/// `COMPLETE_PUMP_AMM_MIGRATION_EVENT` CPI log
+async fn parse_migrate(with_cpi_log: bool, requires_cpi_log: bool) -> Result<Vec<DexEvent>> {
+    let user = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mut accounts: Vec<Pubkey> = (0..24).map(|_| Pubkey::new_unique()).collect();
+    accounts[2] = mint;
+    accounts[5] = user;
+    accounts[23] = PUMPFUN_PROGRAM_ID;
+
+    let mut fixture = TxFixtureBuilder::new().fee_payer(user).instruction(
+        PUMPFUN_PROGRAM_ID,
+        &accounts,
+        discriminators::MIGRATE_IX.to_vec(),
+    );
+    if with_cpi_log {
+        let mut log = discriminators::COMPLETE_PUMP_AMM_MIGRATION_EVENT.to_vec();
+        log.extend_from_slice(user.as_ref());
+        log.extend_from_slice(mint.as_ref());
+        log.extend_from_slice(&206_900_000_000_000u64.to_le_bytes()); // mint_amount
+        log.extend_from_slice(&SOL_AMOUNT.to_le_bytes());
+        log.extend_from_slice(&15_000_000u64.to_le_bytes()); // pool_migration_fee
+        log.extend_from_slice(accounts[3].as_ref()); // bonding_curve
+        log.extend_from_slice(&1_760_000_000i64.to_le_bytes()); // timestamp
+        log.extend_from_slice(accounts[9].as_ref()); // pool
+        fixture = fixture.inner_instruction(PUMPFUN_PROGRAM_ID, &[accounts[22]], log);
+    }
+    let signature = fixture.get_signature();
+
+    let parse_config =
+        ParseConfig { pumpfun_migrate_requires_cpi_log: requires_cpi_log, ..Default::default() };
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &parse_config,
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    Ok(events)
+}
+
+fn migrate_sol_amounts(events: &[DexEvent]) -> Vec<u64> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            DexEvent::PumpFunMigrateEvent(e) => Some(e.sol_amount),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Default: the migrate is emitted either way, with the log fields merged when present
+    assert_eq!(migrate_sol_amounts(&parse_migrate(true, false).await?), vec![SOL_AMOUNT]);
+    assert_eq!(migrate_sol_amounts(&parse_migrate(false, false).await?), vec![0]);
+
+    // Requiring the CPI log drops the accounts-only migrate
+    assert_eq!(migrate_sol_amounts(&parse_migrate(true, true).await?), vec![SOL_AMOUNT]);
+    assert!(migrate_sol_amounts(&parse_migrate(false, true).await?).is_empty());
+
+    println!("PumpFun migrate handled as configured with and without its CPI log");
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Run synthetic PumpSwap buy / buy_exact_quote_in / sell instructions through the parser and
+//! check which trade form each one reports.
+//!
+//! Run with `cargo run --example pumpswap_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::common::EventType;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpswap::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+/// Parse a single PumpSwap instruction with the 23 fixed swap accounts
+async fn parse_swap(discriminator: &[u8], first: u64, second: u64) -> Result<Vec<DexEvent>> {
+    let user = Pubkey::new_unique();
+    let mut accounts: Vec<Pubkey> = (0..23).map(|_| Pubkey::new_unique()).collect();
+    accounts[1] = user;
+    accounts[16] = PUMPSWAP_PROGRAM_ID;
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&first.to_le_bytes());
+    data.extend_from_slice(&second.to_le_bytes());
+
+    let fixture =
+        TxFixtureBuilder::new().fee_payer(user).instruction(PUMPSWAP_PROGRAM_ID, &accounts, data);
+    let signature = fixture.get_signature();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpSwap],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    Ok(events)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // buy: exact base out, bounded quote in
+    let events = parse_swap(discriminators::BUY_IX, 1_000_000, 50_000_000).await?;
+    assert!(events.iter().any(|event| matches!(
+        event,
+        DexEvent::PumpSwapBuyEvent(e) if e.metadata.event_type == EventType::PumpSwapBuy
+            && e.base_amount_out == 1_000_000
+            && e.max_quote_amount_in == 50_000_000
+    )));
+
+    // buy_exact_quote_in: exact quote in, bounded base out (arguments in quote/base order)
+    let events = parse_swap(discriminators::BUY_EXACT_QUOTE_IN_IX, 50_000_000, 1_000_000).await?;
+    assert!(events.iter().any(|event| matches!(
+        event,
+        DexEvent::PumpSwapBuyEvent(e) if e.metadata.event_type == EventType::PumpSwapBuyExactQuoteIn
+            && e.max_quote_amount_in == 50_000_000
+            && e.base_amount_out == 1_000_000
+    )));
+
+    // sell: exact base in, bounded quote out
+    let events = parse_swap(discriminators::SELL_IX, 1_000_000, 40_000_000).await?;
+    assert!(events.iter().any(|event| matches!(
+        event,
+        DexEvent::PumpSwapSellEvent(e) if e.metadata.event_type == EventType::PumpSwapSell
+            && e.base_amount_in == 1_000_000
+            && e.min_quote_amount_out == 40_000_000
+    )));
+
+    println!("all PumpSwap trade forms parsed");
+    Ok(())
+}
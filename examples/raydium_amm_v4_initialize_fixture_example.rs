@@ -0,0 +1,185 @@
+//! Detect a new Raydium AMM V4 pool from its `initialize2` instruction.
+//!
+//! The fixture is laid out like a mainnet pool creation for a RAY/WSOL pair: the pool accounts
+//! are the program addresses the AMM derives from the OpenBook market (amm, open orders, lp mint,
+//! vaults, target orders and config), the AMM authority and its nonce come from the
+//! `amm authority` seed, the creation fee goes to the mainnet fee account and the creator's token
+//! accounts are its associated token accounts. Only the market and the creator wallet are
+//! generated, as they are fresh keypairs in a real creation too. Because the instruction
+//! discriminator is a single byte, the parser only accepts exactly 25 data bytes and 21 accounts
+//! with the AMM authority in place; the malformed variants below are rejected.
+//!
+//! Run with `cargo run --example raydium_amm_v4_initialize_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_amm_v4::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_amm_v4::parser::{
+    RAYDIUM_AMM_V4_AUTHORITY, RAYDIUM_AMM_V4_PROGRAM_ID,
+};
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_amm_v4::RaydiumAmmV4Initialize2Event;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+const TOKEN_PROGRAM: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const ASSOCIATED_TOKEN_PROGRAM: Pubkey =
+    solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const SYSTEM_PROGRAM: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+const RENT_SYSVAR: Pubkey = solana_sdk::pubkey!("SysvarRent111111111111111111111111111111111");
+const OPENBOOK_PROGRAM: Pubkey = solana_sdk::pubkey!("srmqPvymJeFKQ4zGQed1GFppgkRHL9kaELCbyksJtPX");
+/// Mainnet account receiving the pool creation fee
+const CREATE_POOL_FEE_ADDRESS: Pubkey =
+    solana_sdk::pubkey!("7YttLkHDoNj9wyDur5pM1ejNaAvT9X4eqaYcHQqtj2G5");
+const RAY_MINT: Pubkey = solana_sdk::pubkey!("4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R");
+const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+const OPEN_TIME: u64 = 1_760_000_000;
+const INIT_PC_AMOUNT: u64 = 50_000_000_000; // 50 SOL
+const INIT_COIN_AMOUNT: u64 = 25_000_000_000; // 25,000 RAY
+
+/// Pool account the AMM derives from the market with `seed`
+fn associated_address(market: &Pubkey, seed: &[u8]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[RAYDIUM_AMM_V4_PROGRAM_ID.as_ref(), market.as_ref(), seed],
+        &RAYDIUM_AMM_V4_PROGRAM_ID,
+    )
+    .0
+}
+
+fn associated_token_account(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), TOKEN_PROGRAM.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM,
+    )
+    .0
+}
+
+/// Authority PDA and its nonce, passed as the first `initialize2` argument
+fn amm_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"amm authority"], &RAYDIUM_AMM_V4_PROGRAM_ID)
+}
+
+/// The 21 `initialize2` accounts in program order. The IDL still names 12-14 after the old
+/// withdraw queue / target orders / temp lp layout; the program now expects the target orders,
+/// the AMM config and the creation fee destination there.
+fn initialize2_accounts(creator: Pubkey, market: Pubkey) -> Vec<Pubkey> {
+    let lp_mint = associated_address(&market, b"lp_mint_associated_seed");
+    let amm_config =
+        Pubkey::find_program_address(&[b"amm_config_account_seed"], &RAYDIUM_AMM_V4_PROGRAM_ID).0;
+    vec![
+        TOKEN_PROGRAM,
+        ASSOCIATED_TOKEN_PROGRAM,
+        SYSTEM_PROGRAM,
+        RENT_SYSVAR,
+        associated_address(&market, b"amm_associated_seed"),
+        amm_authority().0,
+        associated_address(&market, b"open_order_associated_seed"),
+        lp_mint,
+        RAY_MINT,
+        WSOL_MINT,
+        associated_address(&market, b"coin_vault_associated_seed"),
+        associated_address(&market, b"pc_vault_associated_seed"),
+        associated_address(&market, b"target_associated_seed"),
+        amm_config,
+        CREATE_POOL_FEE_ADDRESS,
+        OPENBOOK_PROGRAM,
+        market,
+        creator,
+        associated_token_account(&creator, &RAY_MINT),
+        associated_token_account(&creator, &WSOL_MINT),
+        associated_token_account(&creator, &lp_mint),
+    ]
+}
+
+fn initialize2_data() -> Vec<u8> {
+    let mut data = discriminators::INITIALIZE2.to_vec();
+    data.push(amm_authority().1);
+    data.extend_from_slice(&OPEN_TIME.to_le_bytes());
+    data.extend_from_slice(&INIT_PC_AMOUNT.to_le_bytes());
+    data.extend_from_slice(&INIT_COIN_AMOUNT.to_le_bytes());
+    data
+}
+
+async fn parse(creator: Pubkey, accounts: &[Pubkey], data: Vec<u8>) -> Result<Vec<DexEvent>> {
+    let fixture = TxFixtureBuilder::new().fee_payer(creator).instruction(
+        RAYDIUM_AMM_V4_PROGRAM_ID,
+        accounts,
+        data,
+    );
+    let signature = fixture.get_signature();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::RaydiumAmmV4],
+        None,
+        fixture.build(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+    let events = std::mem::take(&mut *events.lock().unwrap());
+    Ok(events)
+}
+
+fn initialize_event(events: &[DexEvent]) -> Option<&RaydiumAmmV4Initialize2Event> {
+    events.iter().find_map(|event| match event {
+        DexEvent::RaydiumAmmV4Initialize2Event(e) => Some(e),
+        _ => None,
+    })
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    assert_eq!(amm_authority().0, RAYDIUM_AMM_V4_AUTHORITY);
+    let creator = Pubkey::new_unique();
+    let market = Pubkey::new_unique();
+    let accounts = initialize2_accounts(creator, market);
+
+    let events = parse(creator, &accounts, initialize2_data()).await?;
+    let pool = initialize_event(&events).ok_or_else(|| anyhow::anyhow!("no initialize2 event"))?;
+    assert_eq!(pool.amm, accounts[4]);
+    assert_eq!(pool.lp_mint, accounts[7]);
+    assert_eq!((pool.coin_mint, pool.pc_mint), (RAY_MINT, WSOL_MINT));
+    assert_eq!(
+        (pool.pool_coin_token_account, pool.pool_pc_token_account),
+        (accounts[10], accounts[11])
+    );
+    assert_eq!((pool.serum_program, pool.serum_market), (OPENBOOK_PROGRAM, market));
+    assert_eq!(pool.user_wallet, creator);
+    assert_eq!(pool.user_token_pc, associated_token_account(&creator, &WSOL_MINT));
+    assert_eq!((pool.nonce, pool.open_time), (amm_authority().1, OPEN_TIME));
+    assert_eq!((pool.init_pc_amount, pool.init_coin_amount), (INIT_PC_AMOUNT, INIT_COIN_AMOUNT));
+
+    // Trailing data, a missing account or a wrong authority are not a pool creation
+    let mut long_data = initialize2_data();
+    long_data.push(0);
+    assert!(initialize_event(&parse(creator, &accounts, long_data).await?).is_none());
+    let short_accounts = &accounts[..20];
+    assert!(initialize_event(&parse(creator, short_accounts, initialize2_data()).await?).is_none());
+    let mut wrong_authority = accounts.clone();
+    wrong_authority[5] = Pubkey::new_unique();
+    assert!(
+        initialize_event(&parse(creator, &wrong_authority, initialize2_data()).await?).is_none()
+    );
+
+    println!(
+        "new pool {}: {} / {}, {} coin + {} pc, opens at {}",
+        pool.amm,
+        pool.coin_mint,
+        pool.pc_mint,
+        pool.init_coin_amount,
+        pool.init_pc_amount,
+        pool.open_time
+    );
+    Ok(())
+}
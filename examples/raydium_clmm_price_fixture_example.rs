@@ -0,0 +1,64 @@
+//! Check the Raydium CLMM price helpers on `PoolState` against hand-computed values.
+//!
+//! `sqrt_price_x64` is a Q64.64 fixed-point number, so `sqrt_price()` is `sqrt_price_x64 / 2^64`,
+//! `price()` its square in base units of token1 per base unit of token0, and `price_adjusted()`
+//! that price scaled by `10^(mint_decimals0 - mint_decimals1)`. The inputs are powers of two so
+//! every expected value is exact.
+//!
+//! Run with `cargo run --example raydium_clmm_price_fixture_example --features test-fixtures`.
+
+use anyhow::{anyhow, Result};
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_clmm::types::{
+    pool_state_decode, PoolState, POOL_STATE_SIZE,
+};
+
+/// 2^64, i.e. 1.0 in Q64.64
+const Q64: u128 = 1 << 64;
+
+fn pool(sqrt_price_x64: u128, mint_decimals0: u8, mint_decimals1: u8) -> Result<PoolState> {
+    let mut pool_state = pool_state_decode(&[0u8; POOL_STATE_SIZE])
+        .ok_or_else(|| anyhow!("zeroed PoolState failed to decode"))?;
+    pool_state.sqrt_price_x64 = sqrt_price_x64;
+    pool_state.mint_decimals0 = mint_decimals0;
+    pool_state.mint_decimals1 = mint_decimals1;
+    Ok(pool_state)
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= expected.abs() * 1e-12,
+        "expected {expected}, got {actual}"
+    );
+}
+
+fn main() -> Result<()> {
+    // sqrt_price = 2 => price = 4 token1 base units per token0 base unit.
+    // SOL (9 decimals) / USDC (6 decimals): 4 * 10^(9 - 6) = 4000 USDC per SOL
+    let sol_usdc = pool(2 * Q64, 9, 6)?;
+    assert_close(sol_usdc.sqrt_price(), 2.0);
+    assert_close(sol_usdc.price(), 4.0);
+    assert_close(sol_usdc.price_adjusted(), 4_000.0);
+
+    // sqrt_price = 0.5 => price = 0.25; 6 / 9 decimals: 0.25 * 10^(6 - 9) = 0.00025
+    let usdc_sol = pool(Q64 / 2, 6, 9)?;
+    assert_close(usdc_sol.sqrt_price(), 0.5);
+    assert_close(usdc_sol.price(), 0.25);
+    assert_close(usdc_sol.price_adjusted(), 0.000_25);
+
+    // Equal decimals leave the price unchanged
+    let same_decimals = pool(3 * Q64, 6, 6)?;
+    assert_close(same_decimals.price(), 9.0);
+    assert_close(same_decimals.price_adjusted(), 9.0);
+
+    // An uninitialized pool has price 0
+    let empty = pool(0, 9, 6)?;
+    assert_eq!(empty.price(), 0.0);
+    assert_eq!(empty.price_adjusted(), 0.0);
+
+    println!(
+        "SOL/USDC sqrt_price_x64 {}: {} USDC per SOL",
+        sol_usdc.sqrt_price_x64,
+        sol_usdc.price_adjusted()
+    );
+    Ok(())
+}
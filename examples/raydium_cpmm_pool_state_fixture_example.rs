@@ -0,0 +1,99 @@
+//! Decode a Raydium CPMM `PoolState` account from a known byte buffer.
+//!
+//! The buffer is laid out by hand (8-byte discriminator followed by the 629-byte Borsh layout) so
+//! that a field shifted by a change to `PoolState` shows up as a wrong value here. It is decoded
+//! both directly with `pool_state_decode` and through `AccountEventParser` as an account update
+//! owned by the CPMM program.
+//!
+//! Run with `cargo run --example raydium_cpmm_pool_state_fixture_example`.
+
+use anyhow::{anyhow, Result};
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::account_event_parser::AccountEventParser;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_cpmm::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_cpmm::types::{
+    pool_state_decode, PoolState, POOL_STATE_SIZE,
+};
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::grpc::AccountPretty;
+
+const AMM_CONFIG: Pubkey = Pubkey::new_from_array([1; 32]);
+const TOKEN_0_VAULT: Pubkey = Pubkey::new_from_array([3; 32]);
+const TOKEN_1_VAULT: Pubkey = Pubkey::new_from_array([4; 32]);
+const TOKEN_0_MINT: Pubkey = Pubkey::new_from_array([6; 32]);
+const TOKEN_1_MINT: Pubkey = Pubkey::new_from_array([7; 32]);
+const OBSERVATION_KEY: Pubkey = Pubkey::new_from_array([10; 32]);
+const LP_SUPPLY: u64 = 1_234_567_890_123;
+
+/// Byte offsets of the fields checked below, relative to the end of the discriminator
+const AMM_CONFIG_OFFSET: usize = 0;
+const TOKEN_0_VAULT_OFFSET: usize = 64;
+const TOKEN_1_VAULT_OFFSET: usize = 96;
+const TOKEN_0_MINT_OFFSET: usize = 160;
+const TOKEN_1_MINT_OFFSET: usize = 192;
+const OBSERVATION_KEY_OFFSET: usize = 288;
+const LP_SUPPLY_OFFSET: usize = 325;
+
+/// Account data of a CPMM pool: discriminator followed by the `PoolState` layout
+fn pool_state_data() -> Vec<u8> {
+    let mut body = vec![0u8; POOL_STATE_SIZE];
+    for (offset, pubkey) in [
+        (AMM_CONFIG_OFFSET, AMM_CONFIG),
+        (TOKEN_0_VAULT_OFFSET, TOKEN_0_VAULT),
+        (TOKEN_1_VAULT_OFFSET, TOKEN_1_VAULT),
+        (TOKEN_0_MINT_OFFSET, TOKEN_0_MINT),
+        (TOKEN_1_MINT_OFFSET, TOKEN_1_MINT),
+        (OBSERVATION_KEY_OFFSET, OBSERVATION_KEY),
+    ] {
+        body[offset..offset + 32].copy_from_slice(pubkey.as_ref());
+    }
+    body[LP_SUPPLY_OFFSET..LP_SUPPLY_OFFSET + 8].copy_from_slice(&LP_SUPPLY.to_le_bytes());
+
+    let mut data = discriminators::POOL_STATE.to_vec();
+    data.extend_from_slice(&body);
+    data
+}
+
+fn assert_pool_state(pool_state: &PoolState) {
+    assert_eq!(pool_state.amm_config, AMM_CONFIG);
+    assert_eq!(pool_state.token_0_mint, TOKEN_0_MINT);
+    assert_eq!(pool_state.token_1_mint, TOKEN_1_MINT);
+    assert_eq!(pool_state.token_0_vault, TOKEN_0_VAULT);
+    assert_eq!(pool_state.token_1_vault, TOKEN_1_VAULT);
+    assert_eq!(pool_state.lp_supply, LP_SUPPLY);
+    assert_eq!(pool_state.observation_key, OBSERVATION_KEY);
+}
+
+fn main() -> Result<()> {
+    let data = pool_state_data();
+
+    // Raw layout
+    let pool_state =
+        pool_state_decode(&data[8..]).ok_or_else(|| anyhow!("PoolState failed to decode"))?;
+    assert_pool_state(&pool_state);
+    assert!(pool_state_decode(&data[8..data.len() - 1]).is_none());
+
+    // Account update owned by the CPMM program
+    let pool = Pubkey::new_unique();
+    let account = AccountPretty {
+        slot: 1,
+        pubkey: pool,
+        owner: RAYDIUM_CPMM_PROGRAM_ID,
+        data,
+        ..Default::default()
+    };
+    match AccountEventParser::parse_account_event(&[Protocol::RaydiumCpmm], account, None) {
+        Some(DexEvent::RaydiumCpmmPoolStateAccountEvent(event)) => {
+            assert_eq!(event.pubkey, pool);
+            assert_pool_state(&event.pool_state);
+        }
+        other => return Err(anyhow!("expected RaydiumCpmmPoolStateAccountEvent, got {other:?}")),
+    }
+
+    println!(
+        "pool {pool}: {} / {}, lp_supply {}",
+        pool_state.token_0_mint, pool_state.token_1_mint, pool_state.lp_supply
+    );
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! Reconstruct AMM state transitions from `pre_reserves` / `post_reserves` on trade events.
+//!
+//! Set `ParseConfig::reserve_snapshots` and the parser fills both snapshots for PumpFun, PumpSwap
+//! and Bonk trades; here `annotate_reserve_snapshots` is called directly on synthetic events with
+//! the CPI log fields set, and the price impact of each trade is computed from the snapshots.
+//!
+//! Run with `cargo run --example reserve_snapshots_example`.
+
+use solana_streamer_sdk::streaming::common::annotate_reserve_snapshots;
+use solana_streamer_sdk::streaming::event_parser::common::ReserveSnapshot;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpswap::PumpSwapSellEvent;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+
+/// Quote per base unit implied by a snapshot
+fn price(reserves: ReserveSnapshot) -> f64 {
+    reserves.quote as f64 / reserves.base as f64
+}
+
+fn snapshots(event: &DexEvent) -> (Option<ReserveSnapshot>, Option<ReserveSnapshot>) {
+    match event {
+        DexEvent::PumpFunTradeEvent(e) => (e.pre_reserves, e.post_reserves),
+        DexEvent::PumpSwapSellEvent(e) => (e.pre_reserves, e.post_reserves),
+        _ => (None, None),
+    }
+}
+
+fn main() {
+    // On a client this is all that is needed
+    let _config = ParseConfig { reserve_snapshots: true, ..Default::default() };
+
+    // PumpFun: the CPI log carries the bonding curve's virtual reserves after the trade
+    let mut pumpfun_buy = DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        sol_amount: 1_000_000_000,
+        token_amount: 34_000_000_000_000,
+        is_buy: true,
+        virtual_sol_reserves: 31_000_000_000,
+        virtual_token_reserves: 1_039_000_000_000_000,
+        ..Default::default()
+    });
+    annotate_reserve_snapshots(&mut pumpfun_buy);
+    let (pre, post) = snapshots(&pumpfun_buy);
+    let (pre, post) = (pre.expect("pre reserves"), post.expect("post reserves"));
+    assert_eq!(pre, ReserveSnapshot { base: 1_073_000_000_000_000, quote: 30_000_000_000 });
+    assert_eq!(post, ReserveSnapshot { base: 1_039_000_000_000_000, quote: 31_000_000_000 });
+    println!("PumpFun buy moved the price by {:+.2}%", (price(post) / price(pre) - 1.0) * 100.0);
+
+    // PumpSwap: the CPI log carries the pool vault balances before the trade; the LP fee stays
+    // in the pool, so only `quote_amount_out_without_lp_fee` leaves it
+    let mut pumpswap_sell = DexEvent::PumpSwapSellEvent(PumpSwapSellEvent {
+        base_amount_in: 5_000_000_000,
+        pool_base_token_reserves: 200_000_000_000,
+        pool_quote_token_reserves: 80_000_000_000,
+        quote_amount_out: 1_951_219_512,
+        lp_fee: 3_902_439,
+        quote_amount_out_without_lp_fee: 1_947_317_073,
+        ..Default::default()
+    });
+    annotate_reserve_snapshots(&mut pumpswap_sell);
+    let (pre, post) = snapshots(&pumpswap_sell);
+    let (pre, post) = (pre.expect("pre reserves"), post.expect("post reserves"));
+    assert_eq!(post, ReserveSnapshot { base: 205_000_000_000, quote: 78_052_682_927 });
+    println!("PumpSwap sell moved the price by {:+.2}%", (price(post) / price(pre) - 1.0) * 100.0);
+
+    // Without the CPI log (e.g. from ShredStream) no snapshot can be derived
+    let mut instruction_only =
+        DexEvent::PumpFunTradeEvent(PumpFunTradeEvent { is_buy: true, ..Default::default() });
+    annotate_reserve_snapshots(&mut instruction_only);
+    assert_eq!(snapshots(&instruction_only), (None, None));
+}
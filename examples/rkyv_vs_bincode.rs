@@ -0,0 +1,104 @@
+//! Compare rkyv and bincode throughput for handing a `DexEvent` across threads as bytes.
+//!
+//! Run with: `cargo run --example rkyv_vs_bincode --features rkyv --release`
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_streamer_sdk::streaming::event_parser::common::{
+    EventMetadata, EventType, ProtocolType, SwapData,
+};
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::{ArchivedDexEvent, DexEvent};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: usize = 200_000;
+
+fn main() {
+    let event = sample_event();
+
+    let bincode_bytes = bincode::serialize(&event).unwrap();
+    let rkyv_bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&event).unwrap();
+    println!("Encoded size: bincode {} bytes, rkyv {} bytes", bincode_bytes.len(), rkyv_bytes.len());
+
+    // 编码 + 完整解码
+    let bincode_round_trip = bench(|| {
+        let bytes = bincode::serialize(black_box(&event)).unwrap();
+        let decoded: DexEvent = bincode::deserialize(&bytes).unwrap();
+        black_box(decoded);
+    });
+    let rkyv_round_trip = bench(|| {
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(black_box(&event)).unwrap();
+        let archived = rkyv::access::<ArchivedDexEvent, rkyv::rancor::Error>(&bytes).unwrap();
+        let decoded: DexEvent = rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap();
+        black_box(decoded);
+    });
+
+    // 接收端只读取字段（rkyv 零拷贝访问，bincode 仍需完整解码）
+    let bincode_read = bench(|| {
+        let decoded: DexEvent = bincode::deserialize(black_box(&bincode_bytes)).unwrap();
+        black_box(decoded.metadata().slot);
+    });
+    let rkyv_checked_read = bench(|| {
+        let archived =
+            rkyv::access::<ArchivedDexEvent, rkyv::rancor::Error>(black_box(&rkyv_bytes)).unwrap();
+        black_box(archived);
+    });
+    let rkyv_unchecked_read = bench(|| {
+        // SAFETY: the bytes were produced by `rkyv::to_bytes` for a `DexEvent` above
+        let archived = unsafe { rkyv::access_unchecked::<ArchivedDexEvent>(black_box(&rkyv_bytes)) };
+        black_box(archived);
+    });
+
+    println!("\n{:<38} {:>14}", "Benchmark", "events/sec");
+    report("bincode serialize + deserialize", bincode_round_trip);
+    report("rkyv serialize + access + deserialize", rkyv_round_trip);
+    report("bincode deserialize (read)", bincode_read);
+    report("rkyv access, validated (read)", rkyv_checked_read);
+    report("rkyv access, unchecked (read)", rkyv_unchecked_read);
+}
+
+fn sample_event() -> DexEvent {
+    let mint = Pubkey::new_from_array([1; 32]);
+    DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        metadata: EventMetadata {
+            signature: Signature::from([9; 64]),
+            slot: 350_000_000,
+            tx_index: Some(42),
+            block_time: 1_750_000_000,
+            block_time_ms: 1_750_000_000_000,
+            protocol: ProtocolType::PumpFun,
+            event_type: EventType::PumpFunBuy,
+            program_id: Pubkey::new_from_array([2; 32]),
+            swap_data: Some(SwapData {
+                from_mint: Pubkey::new_from_array([3; 32]),
+                to_mint: mint,
+                from_amount: 1_500_000_000,
+                to_amount: 1_200_000_000_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        mint,
+        sol_amount: 1_500_000_000,
+        token_amount: 1_200_000_000_000,
+        is_buy: true,
+        user: Pubkey::new_from_array([4; 32]),
+        ..Default::default()
+    })
+}
+
+fn bench(mut f: impl FnMut()) -> Duration {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed()
+}
+
+fn report(name: &str, elapsed: Duration) {
+    let per_sec = ITERATIONS as f64 / elapsed.as_secs_f64();
+    println!("{:<38} {:>14.0}", name, per_sec);
+}
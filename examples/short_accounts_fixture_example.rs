@@ -0,0 +1,148 @@
+//! Feed every protocol parser instructions with too few accounts and check none of them panics.
+//!
+//! Parsers read instruction accounts through a bounds-checked accessor, so an account list that
+//! is shorter than the instruction layout (a changed layout, a truncated lookup table) yields no
+//! event instead of an index-out-of-bounds panic. Each instruction discriminator of each
+//! protocol is dispatched with zeroed data and every account list length from 0 to 40.
+//!
+//! Run with `cargo run --example short_accounts_fixture_example --features test-fixtures`.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::common::EventMetadata;
+use solana_streamer_sdk::streaming::event_parser::core::EventDispatcher;
+use solana_streamer_sdk::streaming::event_parser::protocols::{
+    bonk, meteora_damm_v2, pumpfun, pumpswap, raydium_amm_v4, raydium_clmm, raydium_cpmm,
+};
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+
+/// Longer than any instruction layout, so the accounts are the only thing missing
+const MAX_ACCOUNTS: usize = 40;
+/// Zeroed instruction arguments, long enough for every instruction's borsh layout
+const DATA_LEN: usize = 1024;
+
+/// Instruction discriminators of each protocol
+fn instructions(protocol: &Protocol) -> Vec<(&'static str, &'static [u8])> {
+    match protocol {
+        Protocol::PumpFun => {
+            use pumpfun::discriminators::*;
+            vec![
+                ("create", CREATE_TOKEN_IX),
+                ("create_v2", CREATE_V2_TOKEN_IX),
+                ("buy", BUY_IX),
+                ("buy_exact_sol_in", BUY_EXACT_SOL_IN_IX),
+                ("sell", SELL_IX),
+                ("migrate", MIGRATE_IX),
+                ("set_creator", SET_CREATOR_IX),
+                ("admin_set_creator", ADMIN_SET_CREATOR_IX),
+                ("set_params", SET_PARAMS_IX),
+                ("update_global_authority", UPDATE_GLOBAL_AUTHORITY_IX),
+                ("set_reserved_fee_recipients", SET_RESERVED_FEE_RECIPIENTS_IX),
+            ]
+        }
+        Protocol::PumpSwap => {
+            use pumpswap::discriminators::*;
+            vec![
+                ("buy", BUY_IX),
+                ("buy_exact_quote_in", BUY_EXACT_QUOTE_IN_IX),
+                ("sell", SELL_IX),
+                ("create_pool", CREATE_POOL_IX),
+                ("deposit", DEPOSIT_IX),
+                ("withdraw", WITHDRAW_IX),
+            ]
+        }
+        Protocol::Bonk => {
+            use bonk::discriminators::*;
+            vec![
+                ("buy_exact_in", BUY_EXACT_IN),
+                ("buy_exact_out", BUY_EXACT_OUT),
+                ("sell_exact_in", SELL_EXACT_IN),
+                ("sell_exact_out", SELL_EXACT_OUT),
+                ("initialize", INITIALIZE),
+                ("initialize_v2", INITIALIZE_V2),
+                ("initialize_with_token_2022", INITIALIZE_WITH_TOKEN_2022),
+                ("migrate_to_amm", MIGRATE_TO_AMM),
+                ("migrate_to_cp_swap", MIGRATE_TO_CP_SWAP),
+            ]
+        }
+        Protocol::RaydiumCpmm => {
+            use raydium_cpmm::discriminators::*;
+            vec![
+                ("swap_base_in", SWAP_BASE_IN),
+                ("swap_base_out", SWAP_BASE_OUT),
+                ("deposit", DEPOSIT),
+                ("initialize", INITIALIZE),
+                ("withdraw", WITHDRAW),
+            ]
+        }
+        Protocol::RaydiumClmm => {
+            use raydium_clmm::discriminators::*;
+            vec![
+                ("swap", SWAP),
+                ("swap_v2", SWAP_V2),
+                ("close_position", CLOSE_POSITION),
+                ("increase_liquidity_v2", INCREASE_LIQUIDITY_V2),
+                ("decrease_liquidity_v2", DECREASE_LIQUIDITY_V2),
+                ("create_pool", CREATE_POOL),
+                ("open_position_with_token_22_nft", OPEN_POSITION_WITH_TOKEN_22_NFT),
+                ("open_position_v2", OPEN_POSITION_V2),
+            ]
+        }
+        Protocol::RaydiumAmmV4 => {
+            use raydium_amm_v4::discriminators::*;
+            vec![
+                ("swap_base_in", SWAP_BASE_IN),
+                ("swap_base_out", SWAP_BASE_OUT),
+                ("deposit", DEPOSIT),
+                ("initialize2", INITIALIZE2),
+                ("withdraw", WITHDRAW),
+                ("withdraw_pnl", WITHDRAW_PNL),
+            ]
+        }
+        Protocol::MeteoraDammV2 => {
+            use meteora_damm_v2::discriminators::*;
+            vec![
+                ("swap", SWAP_IX),
+                ("swap2", SWAP2_IX),
+                ("initialize_customizable_pool", INITIALIZE_CUSTOMIZABLE_POOL_IX),
+                ("initialize_pool", INITIALIZE_POOL_IX),
+                ("initialize_pool_with_dynamic_config", INITIALIZE_POOL_WITH_DYNAMIC_CONFIG_IX),
+                ("add_liquidity", ADD_LIQUIDITY_IX),
+                ("remove_liquidity", REMOVE_LIQUIDITY_IX),
+                ("remove_all_liquidity", REMOVE_ALL_LIQUIDITY_IX),
+            ]
+        }
+    }
+}
+
+fn main() {
+    let accounts: Vec<Pubkey> = (0..MAX_ACCOUNTS).map(|_| Pubkey::new_unique()).collect();
+    let data = [0u8; DATA_LEN];
+    let mut panics = Vec::new();
+    let mut dispatched = 0;
+
+    for (protocol, program_id) in EventDispatcher::protocol_program_map() {
+        let metadata = EventMetadata { program_id: *program_id, ..Default::default() };
+        for (name, discriminator) in instructions(protocol) {
+            for len in 0..=MAX_ACCOUNTS {
+                let outcome = catch_unwind(AssertUnwindSafe(|| {
+                    EventDispatcher::dispatch_instruction(
+                        protocol.clone(),
+                        discriminator,
+                        &data,
+                        &accounts[..len],
+                        metadata.clone(),
+                    )
+                }));
+                dispatched += 1;
+                if outcome.is_err() {
+                    panics.push(format!("{protocol:?} {name} with {len} accounts"));
+                }
+            }
+        }
+    }
+
+    assert!(panics.is_empty(), "parsers panicked on short account lists: {panics:#?}");
+    println!("{dispatched} instructions with 0..={MAX_ACCOUNTS} accounts dispatched, no panics");
+}
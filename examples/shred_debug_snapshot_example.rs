@@ -0,0 +1,79 @@
+//! Inspect a ShredStream client's internal state with `ShredStreamGrpc::debug_snapshot`.
+//!
+//! A custom in-memory source delivers a single (empty) entries batch and then ends, which ends
+//! the subscription. The snapshot before, during and after shows `is_subscribed`, the last
+//! message time and the object pool occupancy; expose it on an admin endpoint the same way.
+//!
+//! Run with `cargo run --example shred_debug_snapshot_example`.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use solana_streamer_sdk::common::AnyResult;
+use solana_streamer_sdk::streaming::common::StreamClientConfig;
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+use solana_streamer_sdk::streaming::shred::{DebugSnapshot, ShredEntries, ShredSource};
+use solana_streamer_sdk::streaming::ShredStreamGrpc;
+
+/// Delivers one entries batch for slot 1, then ends
+struct OneBatchSource {
+    delivered: bool,
+}
+
+#[tonic::async_trait]
+impl ShredSource for OneBatchSource {
+    fn name(&self) -> &str {
+        "one-batch"
+    }
+
+    async fn next_entries(&mut self) -> Option<AnyResult<ShredEntries>> {
+        if self.delivered {
+            return None;
+        }
+        self.delivered = true;
+        // bincode-encoded empty `Vec<Entry>`: just the u64 length prefix
+        Some(Ok(ShredEntries { slot: 1, entries: 0u64.to_le_bytes().to_vec() }))
+    }
+}
+
+fn print_snapshot(label: &str, snapshot: &DebugSnapshot) {
+    let subscription = snapshot.subscription.as_ref();
+    println!(
+        "{label}: subscribed={} updates={:?} idle_us={:?} pool={}/{} protocols={:?}",
+        snapshot.is_subscribed,
+        subscription.map(|s| s.updates_received),
+        subscription.and_then(|s| s.idle_us()),
+        snapshot.pool_available,
+        snapshot.pool_max_size,
+        snapshot.active_protocols
+    );
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let client = ShredStreamGrpc::new_for_sources(StreamClientConfig::default());
+
+    let before = client.debug_snapshot().await;
+    assert!(!before.is_subscribed && before.subscription.is_none());
+    print_snapshot("before subscribing", &before);
+
+    client
+        .subscribe_with_sources(
+            vec![Box::new(OneBatchSource { delivered: false })],
+            vec![Protocol::PumpFun],
+            None,
+            None,
+            |_event| {},
+        )
+        .await?;
+    let during = client.debug_snapshot().await;
+    print_snapshot("after subscribing", &during);
+
+    // The source ends after its batch, which ends the subscription's stream task
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let after = client.debug_snapshot().await;
+    assert!(!after.is_subscribed);
+    assert_eq!(after.subscription.as_ref().map(|s| s.updates_received), Some(1));
+    print_snapshot("after the source ended", &after);
+    Ok(())
+}
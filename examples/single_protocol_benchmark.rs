@@ -0,0 +1,138 @@
+//! Compare `EventParser::parse_single_protocol` with the general multi-protocol path
+//! (`parse_grpc_transaction_with_config` with `&[Protocol::PumpFun]`) on a PumpFun buy
+//! transaction that also calls a few unrelated programs.
+//!
+//! Run with: `cargo run --example single_protocol_benchmark --features test-fixtures --release`
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+const ITERATIONS: usize = 200_000;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let fixture = pumpfun_buy_fixture();
+    let signature = fixture.get_signature();
+    let grpc_tx = fixture.build();
+    let config = ParseConfig::default();
+
+    let events = Arc::new(AtomicUsize::new(0));
+    let sink = events.clone();
+    let callback = Arc::new(move |event: DexEvent| {
+        black_box(event);
+        sink.fetch_add(1, Ordering::Relaxed);
+    });
+
+    // 两条路径输出的事件必须一致
+    let general_events = {
+        events.store(0, Ordering::Relaxed);
+        parse_general(&grpc_tx, signature, callback.clone(), &config).await?;
+        events.load(Ordering::Relaxed)
+    };
+    let single_events = {
+        events.store(0, Ordering::Relaxed);
+        parse_single(&grpc_tx, signature, callback.clone(), &config).await?;
+        events.load(Ordering::Relaxed)
+    };
+    assert!(general_events > 0);
+    assert_eq!(general_events, single_events);
+
+    let mut general = Duration::ZERO;
+    let mut single = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        parse_general(&grpc_tx, signature, callback.clone(), &config).await?;
+        general += start.elapsed();
+        let start = Instant::now();
+        parse_single(&grpc_tx, signature, callback.clone(), &config).await?;
+        single += start.elapsed();
+    }
+
+    println!("Transactions per run: {}, events per transaction: {}\n", ITERATIONS, single_events);
+    println!("{:<24} {:>12} {:>12}", "Path", "ns/tx", "K tx/s");
+    for (name, elapsed) in [("general", general), ("parse_single_protocol", single)] {
+        println!("{:<24} {:>12.0} {:>12.1}", name, per_tx_ns(elapsed), rate(elapsed));
+    }
+    Ok(())
+}
+
+/// PumpFun buy 指令前后各有一条无关程序的指令
+fn pumpfun_buy_fixture() -> TxFixtureBuilder {
+    let user = Pubkey::new_unique();
+    let mut accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+    accounts[6] = user;
+    accounts[11] = PUMPFUN_PROGRAM_ID;
+    let mut data = discriminators::BUY_IX.to_vec();
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    data.extend_from_slice(&50_000_000u64.to_le_bytes());
+    let unrelated_accounts: Vec<Pubkey> = (0..8).map(|_| Pubkey::new_unique()).collect();
+    TxFixtureBuilder::new()
+        .fee_payer(user)
+        .instruction(Pubkey::new_unique(), &unrelated_accounts, vec![1; 16])
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, data)
+        .instruction(Pubkey::new_unique(), &unrelated_accounts, vec![2; 16])
+}
+
+async fn parse_general(
+    grpc_tx: &SubscribeUpdateTransactionInfo,
+    signature: Signature,
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    config: &ParseConfig,
+) -> Result<()> {
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        grpc_tx.clone(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        callback,
+        config,
+    )
+    .await
+}
+
+async fn parse_single(
+    grpc_tx: &SubscribeUpdateTransactionInfo,
+    signature: Signature,
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    config: &ParseConfig,
+) -> Result<()> {
+    EventParser::parse_single_protocol(
+        Protocol::PumpFun,
+        None,
+        grpc_tx.clone(),
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        callback,
+        config,
+    )
+    .await
+}
+
+fn per_tx_ns(elapsed: Duration) -> f64 {
+    elapsed.as_nanos() as f64 / ITERATIONS as f64
+}
+
+fn rate(elapsed: Duration) -> f64 {
+    ITERATIONS as f64 / elapsed.as_secs_f64() / 1_000.0
+}
@@ -0,0 +1,76 @@
+//! Aggregate events into one `SlotSummary` per slot with `SlotSummaryAggregator`.
+//!
+//! A slot's summary is delivered when its block meta arrives, or after the timeout when it never
+//! does. On a client, set `YellowstoneGrpc::set_slot_summary_callback` (or
+//! `StreamClientBuilder::on_slot_summary`) and the aggregator is wired into the subscription.
+//!
+//! Run with `cargo run --example slot_summary_example`.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+use solana_streamer_sdk::streaming::common::{SlotSummary, SlotSummaryAggregator};
+use solana_streamer_sdk::streaming::event_parser::common::{EventMetadata, ProtocolType};
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::CommonEventParser;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+
+fn pumpfun_buy(slot: u64, sol_amount: u64) -> DexEvent {
+    DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        metadata: EventMetadata { slot, protocol: ProtocolType::PumpFun, ..Default::default() },
+        sol_amount,
+        token_amount: 1_000_000,
+        is_buy: true,
+        ..Default::default()
+    })
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let summaries: Arc<Mutex<Vec<SlotSummary>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = summaries.clone();
+    let aggregator = Arc::new(SlotSummaryAggregator::new(
+        Duration::from_millis(50),
+        Arc::new(move |summary: SlotSummary| sink.lock().unwrap().push(summary)),
+    ));
+    let flush_task = aggregator.spawn_flush_task();
+
+    // Slot 100 is closed by its block meta
+    aggregator.on_event(&pumpfun_buy(100, 500_000_000));
+    aggregator.on_event(&pumpfun_buy(100, 1_500_000_000));
+    aggregator.on_event(&CommonEventParser::generate_block_meta_event(
+        100,
+        String::new(),
+        1_700_000_000_000,
+        0,
+    ));
+    // Slot 101 never gets a block meta and is delivered after the timeout
+    aggregator.on_event(&pumpfun_buy(101, 250_000_000));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    flush_task.abort();
+
+    let summaries = summaries.lock().unwrap();
+    assert_eq!(summaries.len(), 2);
+    let closed = &summaries[0];
+    assert_eq!((closed.slot, closed.event_count, closed.trade_count), (100, 2, 2));
+    assert_eq!(closed.sol_volume_lamports, 2_000_000_000);
+    assert_eq!(closed.protocol_event_count(&ProtocolType::PumpFun), 2);
+    assert_eq!(closed.block_time_ms, Some(1_700_000_000_000));
+    assert!(!closed.timed_out);
+    let expired = &summaries[1];
+    assert_eq!((expired.slot, expired.event_count), (101, 1));
+    assert!(expired.timed_out && expired.block_time_ms.is_none());
+
+    for summary in summaries.iter() {
+        println!(
+            "slot {}: {} events, {} trades, {:.3} SOL{}",
+            summary.slot,
+            summary.event_count,
+            summary.trade_count,
+            summary.sol_volume(),
+            if summary.timed_out { " (timed out)" } else { "" }
+        );
+    }
+    Ok(())
+}
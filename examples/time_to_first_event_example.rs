@@ -0,0 +1,85 @@
+//! Measure provider warm-up with `PerformanceMetrics::time_to_first_event_us`.
+//!
+//! The time from calling subscribe to the first event handed to the callback is recorded per
+//! subscription and reset on every new one. A custom in-memory source stands in for a provider
+//! that needs 150 ms to deliver its first entries batch (one memo transaction); subscribing twice
+//! shows the value being measured again for the second subscription.
+//!
+//! Run with `cargo run --example time_to_first_event_example`.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use solana_entry::entry::Entry;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+use solana_streamer_sdk::common::AnyResult;
+use solana_streamer_sdk::streaming::common::StreamClientConfig;
+use solana_streamer_sdk::streaming::event_parser::core::common_event_parser::MEMO_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::Protocol;
+use solana_streamer_sdk::streaming::shred::{ShredEntries, ShredSource};
+use solana_streamer_sdk::streaming::ShredStreamGrpc;
+
+/// Delivers one entries batch with a memo transaction after `warm_up`, then ends
+struct SlowStartSource {
+    warm_up: Option<Duration>,
+}
+
+#[tonic::async_trait]
+impl ShredSource for SlowStartSource {
+    fn name(&self) -> &str {
+        "slow-start"
+    }
+
+    async fn next_entries(&mut self) -> Option<AnyResult<ShredEntries>> {
+        tokio::time::sleep(self.warm_up.take()?).await;
+        let payer = Pubkey::new_unique();
+        let memo = Instruction::new_with_bytes(MEMO_PROGRAM_ID, b"gm", vec![]);
+        let transaction = Transaction::new_unsigned(Message::new(&[memo], Some(&payer)));
+        let entries = vec![Entry {
+            num_hashes: 0,
+            hash: Hash::default(),
+            transactions: vec![VersionedTransaction::from(transaction)],
+        }];
+        Some(
+            bincode::serialize(&entries)
+                .map(|entries| ShredEntries { slot: 1, entries })
+                .map_err(Into::into),
+        )
+    }
+}
+
+async fn subscribe_once(client: &ShredStreamGrpc, warm_up: Duration) -> Result<i64> {
+    client
+        .subscribe_with_sources(
+            vec![Box::new(SlowStartSource { warm_up: Some(warm_up) })],
+            vec![Protocol::PumpFun],
+            None,
+            None,
+            |_event| {},
+        )
+        .await?;
+    tokio::time::sleep(warm_up + Duration::from_millis(100)).await;
+    client.get_metrics().time_to_first_event_us.ok_or_else(|| anyhow!("no event received"))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let client = ShredStreamGrpc::new_for_sources(StreamClientConfig::default());
+    assert_eq!(client.get_metrics().time_to_first_event_us, None);
+
+    let first = subscribe_once(&client, Duration::from_millis(150)).await?;
+    assert!(first >= 150_000);
+    println!("first subscription: first event after {:.1}ms", first as f64 / 1000.0);
+
+    // A new subscription measures again instead of keeping the first value
+    let second = subscribe_once(&client, Duration::from_millis(50)).await?;
+    assert!((50_000..first).contains(&second));
+    println!("second subscription: first event after {:.1}ms", second as f64 / 1000.0);
+
+    client.stop().await;
+    Ok(())
+}
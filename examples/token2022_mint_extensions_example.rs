@@ -0,0 +1,47 @@
+//! Build Token-2022 mints with `MintFixtureBuilder` and check the decoded close-authority and
+//! permanent-delegate extensions on `TokenInfoEvent`.
+//!
+//! Run with `cargo run --example token2022_mint_extensions_example --features test-fixtures`.
+
+use anyhow::{anyhow, Result};
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::account_event_parser::{
+    AccountEventParser, TokenInfoEvent,
+};
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+use solana_streamer_sdk::streaming::test_fixtures::MintFixtureBuilder;
+
+fn parse_mint(fixture: MintFixtureBuilder) -> Result<TokenInfoEvent> {
+    match AccountEventParser::parse_account_event(&[], fixture.build(), None) {
+        Some(DexEvent::TokenInfoEvent(event)) => Ok(event),
+        other => Err(anyhow!("expected TokenInfoEvent, got {other:?}")),
+    }
+}
+
+fn main() -> Result<()> {
+    // Permanent delegate only
+    let delegate = Pubkey::new_unique();
+    let event = parse_mint(
+        MintFixtureBuilder::new().supply(1_000_000).decimals(9).permanent_delegate(delegate),
+    )?;
+    assert_eq!((event.supply, event.decimals), (1_000_000, 9));
+    assert_eq!(event.permanent_delegate, Some(delegate));
+    assert_eq!(event.close_authority, None);
+    println!("permanent delegate mint: {event:?}");
+
+    // Both extensions
+    let authority = Pubkey::new_unique();
+    let event = parse_mint(
+        MintFixtureBuilder::new().close_authority(authority).permanent_delegate(delegate),
+    )?;
+    assert_eq!(event.close_authority, Some(authority));
+    assert_eq!(event.permanent_delegate, Some(delegate));
+
+    // No extensions
+    let event = parse_mint(MintFixtureBuilder::new().supply(42))?;
+    assert_eq!(event.supply, 42);
+    assert_eq!((event.close_authority, event.permanent_delegate), (None, None));
+
+    println!("all Token-2022 mint extension checks passed");
+    Ok(())
+}
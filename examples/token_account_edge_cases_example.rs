@@ -0,0 +1,81 @@
+//! Lock down how account updates that only look like token accounts are classified.
+//!
+//! Account type detection follows the owner program and the data length: only accounts owned by
+//! the Token or Token-2022 program are decoded, extended (Token-2022) layouts only for Token-2022,
+//! and anything that does not decode cleanly yields no event instead of a guessed one.
+//!
+//! Run with `cargo run --example token_account_edge_cases_example --features test-fixtures`.
+
+use anyhow::{anyhow, Result};
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::account_event_parser::AccountEventParser;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+use solana_streamer_sdk::streaming::grpc::AccountPretty;
+use solana_streamer_sdk::streaming::test_fixtures::MintFixtureBuilder;
+use spl_token::solana_program::program_pack::Pack;
+use spl_token::solana_program::pubkey::Pubkey as TokenPubkey;
+use spl_token::state::{Account, AccountState, Multisig};
+
+const SYSTEM_PROGRAM: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+const TOKEN_PROGRAM: Pubkey = Pubkey::new_from_array(spl_token::ID.to_bytes());
+const TOKEN_2022_PROGRAM: Pubkey = Pubkey::new_from_array(spl_token_2022::ID.to_bytes());
+
+fn token_account_data(amount: u64, state: AccountState) -> Vec<u8> {
+    let account = Account {
+        mint: TokenPubkey::new_from_array([7; 32]),
+        owner: TokenPubkey::new_from_array([8; 32]),
+        amount,
+        state,
+        ..Default::default()
+    };
+    let mut data = vec![0u8; Account::LEN];
+    Account::pack(account, &mut data).expect("token account fits Account::LEN");
+    data
+}
+
+fn account(owner: Pubkey, data: Vec<u8>) -> AccountPretty {
+    AccountPretty { slot: 1, pubkey: Pubkey::new_unique(), owner, data, ..Default::default() }
+}
+
+fn parse(account: AccountPretty) -> Option<DexEvent> {
+    AccountEventParser::parse_account_event(&[], account, None)
+}
+
+fn main() -> Result<()> {
+    // Valid token accounts decode for both token programs
+    for owner in [TOKEN_PROGRAM, TOKEN_2022_PROGRAM] {
+        match parse(account(owner, token_account_data(42, AccountState::Initialized))) {
+            Some(DexEvent::TokenAccountEvent(event)) => assert_eq!(event.amount, Some(42)),
+            other => return Err(anyhow!("expected TokenAccountEvent, got {other:?}")),
+        }
+    }
+
+    // Empty data
+    assert!(parse(account(TOKEN_PROGRAM, Vec::new())).is_none());
+    assert!(parse(account(TOKEN_2022_PROGRAM, Vec::new())).is_none());
+
+    // Valid token account bytes owned by another program
+    let data = token_account_data(42, AccountState::Initialized);
+    assert!(parse(account(SYSTEM_PROGRAM, data.clone())).is_none());
+    assert!(parse(account(Pubkey::new_unique(), data)).is_none());
+
+    // Uninitialized token account
+    let uninitialized = token_account_data(0, AccountState::Uninitialized);
+    assert!(parse(account(TOKEN_PROGRAM, uninitialized)).is_none());
+
+    // Multisig accounts are neither mints nor token accounts
+    assert!(parse(account(TOKEN_PROGRAM, vec![1; Multisig::LEN])).is_none());
+
+    // A Token-2022 mint with extensions decodes only under the Token-2022 program
+    let extended_mint =
+        MintFixtureBuilder::new().supply(7).permanent_delegate(Pubkey::new_unique());
+    match parse(extended_mint.clone().build()) {
+        Some(DexEvent::TokenInfoEvent(event)) => assert_eq!(event.supply, 7),
+        other => return Err(anyhow!("expected TokenInfoEvent, got {other:?}")),
+    }
+    let spoofed = AccountPretty { owner: TOKEN_PROGRAM, ..extended_mint.build() };
+    assert!(parse(spoofed).is_none());
+
+    println!("all token account edge cases classified as expected");
+    Ok(())
+}
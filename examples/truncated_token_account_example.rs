@@ -0,0 +1,138 @@
+//! Check how truncated and extension-bearing token accounts are decoded.
+//!
+//! Token account updates are only unpacked after their length and account type check out: a
+//! plain SPL account or mint has a fixed length, while a Token-2022 account with extensions is
+//! longer and carries an account type byte at `Account::LEN`. Truncated buffers, and extension
+//! layouts under the classic Token program, must not be decoded as a balance or a mint.
+//!
+//! Run with `cargo run --example truncated_token_account_example`.
+
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::account_event_parser::AccountEventParser;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+use solana_streamer_sdk::streaming::grpc::AccountPretty;
+use spl_token::solana_program::program_pack::Pack;
+use spl_token::solana_program::pubkey::Pubkey as TokenPubkey;
+use spl_token::state::{Account, AccountState, Mint};
+use spl_token_2022::extension::immutable_owner::ImmutableOwner;
+use spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+use spl_token_2022::extension::{
+    BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+};
+use spl_token_2022::state::{
+    Account as Account2022, AccountState as AccountState2022, Mint as Mint2022,
+};
+
+const TOKEN_PROGRAM: Pubkey = Pubkey::new_from_array(spl_token::ID.to_bytes());
+const TOKEN_2022_PROGRAM: Pubkey = Pubkey::new_from_array(spl_token_2022::ID.to_bytes());
+const AMOUNT: u64 = 42;
+const SUPPLY: u64 = 1_000_000;
+const DECIMALS: u8 = 9;
+
+/// A plain `Account::LEN` token account
+fn token_account_data() -> Vec<u8> {
+    let account = Account {
+        mint: TokenPubkey::new_from_array([7; 32]),
+        owner: TokenPubkey::new_from_array([8; 32]),
+        amount: AMOUNT,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+    let mut data = vec![0u8; Account::LEN];
+    Account::pack(account, &mut data).expect("token account fits Account::LEN");
+    data
+}
+
+/// A plain `Mint::LEN` mint
+fn mint_data() -> Vec<u8> {
+    let mint =
+        Mint { supply: SUPPLY, decimals: DECIMALS, is_initialized: true, ..Default::default() };
+    let mut data = vec![0u8; Mint::LEN];
+    Mint::pack(mint, &mut data).expect("mint fits Mint::LEN");
+    data
+}
+
+/// A Token-2022 token account with the `ImmutableOwner` extension
+fn token_2022_account_data() -> Vec<u8> {
+    let len =
+        ExtensionType::try_calculate_account_len::<Account2022>(&[ExtensionType::ImmutableOwner])
+            .expect("ImmutableOwner has a fixed size");
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Account2022>::unpack_uninitialized(&mut data)
+        .expect("buffer is sized for the extension");
+    state.init_extension::<ImmutableOwner>(true).expect("room for ImmutableOwner");
+    state.base = Account2022 {
+        mint: Pubkey::new_from_array([7; 32]),
+        owner: Pubkey::new_from_array([8; 32]),
+        amount: AMOUNT,
+        state: AccountState2022::Initialized,
+        ..Default::default()
+    };
+    state.pack_base();
+    state.init_account_type().expect("account type is uninitialized");
+    data
+}
+
+/// A Token-2022 mint with the `MintCloseAuthority` extension
+fn token_2022_mint_data() -> Vec<u8> {
+    let len =
+        ExtensionType::try_calculate_account_len::<Mint2022>(&[ExtensionType::MintCloseAuthority])
+            .expect("MintCloseAuthority has a fixed size");
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<Mint2022>::unpack_uninitialized(&mut data)
+        .expect("buffer is sized for the extension");
+    let extension =
+        state.init_extension::<MintCloseAuthority>(true).expect("room for MintCloseAuthority");
+    extension.close_authority.0 = Pubkey::new_from_array([9; 32]);
+    state.base =
+        Mint2022 { supply: SUPPLY, decimals: DECIMALS, is_initialized: true, ..Default::default() };
+    state.pack_base();
+    state.init_account_type().expect("account type is uninitialized");
+    data
+}
+
+fn account(owner: Pubkey, data: Vec<u8>) -> AccountPretty {
+    AccountPretty { slot: 1, pubkey: Pubkey::new_unique(), owner, data, ..Default::default() }
+}
+
+/// Decoded token account balance; None when the data was not read as a token account
+fn amount(owner: Pubkey, data: Vec<u8>) -> Option<u64> {
+    match AccountEventParser::parse_account_event(&[], account(owner, data), None) {
+        Some(DexEvent::TokenAccountEvent(event)) => event.amount,
+        Some(DexEvent::TokenInfoEvent(_)) => panic!("token account decoded as a mint"),
+        _ => None,
+    }
+}
+
+/// Decoded mint `(supply, decimals)`; None when the data was not read as a mint
+fn mint(owner: Pubkey, data: Vec<u8>) -> Option<(u64, u8)> {
+    match AccountEventParser::parse_account_event(&[], account(owner, data), None) {
+        Some(DexEvent::TokenInfoEvent(event)) => Some((event.supply, event.decimals)),
+        _ => None,
+    }
+}
+
+fn main() {
+    // Plain layouts decode under both token programs
+    for owner in [TOKEN_PROGRAM, TOKEN_2022_PROGRAM] {
+        assert_eq!(amount(owner, token_account_data()), Some(AMOUNT));
+        assert_eq!(mint(owner, mint_data()), Some((SUPPLY, DECIMALS)));
+    }
+
+    // Extension-bearing layouts decode under Token-2022 only
+    assert_eq!(amount(TOKEN_2022_PROGRAM, token_2022_account_data()), Some(AMOUNT));
+    assert_eq!(mint(TOKEN_2022_PROGRAM, token_2022_mint_data()), Some((SUPPLY, DECIMALS)));
+    assert_eq!(amount(TOKEN_PROGRAM, token_2022_account_data()), None);
+    assert_eq!(mint(TOKEN_PROGRAM, token_2022_mint_data()), None);
+
+    // Truncated buffers decode as neither
+    for owner in [TOKEN_PROGRAM, TOKEN_2022_PROGRAM] {
+        let truncated_account = token_account_data()[..Account::LEN - 1].to_vec();
+        assert_eq!(amount(owner, truncated_account), None);
+        let truncated_mint = mint_data()[..Mint::LEN - 1].to_vec();
+        assert_eq!(mint(owner, truncated_mint.clone()), None);
+        assert_eq!(amount(owner, truncated_mint), None);
+    }
+
+    println!("truncated and extension-bearing token accounts decoded as expected");
+}
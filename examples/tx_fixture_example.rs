@@ -0,0 +1,71 @@
+//! Build a synthetic PumpFun buy transaction with `TxFixtureBuilder` and run it through the parser.
+//! Also checks that events carry the message's recent blockhash.
+//!
+//! Run with `cargo run --example tx_fixture_example --features test-fixtures`.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::event_parser::core::event_parser::EventParser;
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::discriminators;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::test_fixtures::TxFixtureBuilder;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let user = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    // buy(amount, max_sol_cost) with the 16 fixed accounts of the buy instruction
+    let mut accounts: Vec<Pubkey> = (0..16).map(|_| Pubkey::new_unique()).collect();
+    accounts[2] = mint;
+    accounts[6] = user;
+    accounts[11] = PUMPFUN_PROGRAM_ID;
+    let mut data = discriminators::BUY_IX.to_vec();
+    data.extend_from_slice(&1_000_000u64.to_le_bytes());
+    data.extend_from_slice(&50_000_000u64.to_le_bytes());
+
+    // gRPC transaction updates carry no block hash, so recent_blockhash must come from the message
+    let recent_blockhash = Hash::new_unique();
+    let fixture = TxFixtureBuilder::new()
+        .fee_payer(user)
+        .recent_blockhash(recent_blockhash)
+        .instruction(PUMPFUN_PROGRAM_ID, &accounts, data);
+    let signature = fixture.get_signature();
+    let grpc_tx = fixture.build();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    EventParser::parse_grpc_transaction_with_config(
+        &[Protocol::PumpFun],
+        None,
+        grpc_tx,
+        signature,
+        Some(1),
+        None,
+        0,
+        None,
+        Some(0),
+        Arc::new(move |event: DexEvent| sink.lock().unwrap().push(event)),
+        &ParseConfig::default(),
+    )
+    .await?;
+
+    let events = events.lock().unwrap();
+    assert!(events.iter().any(|event| matches!(
+        event,
+        DexEvent::PumpFunTradeEvent(e) if e.is_buy && e.mint == mint && e.amount == 1_000_000
+    )));
+    let expected_blockhash = recent_blockhash.to_string();
+    assert!(events.iter().all(|event| {
+        event.metadata().recent_blockhash.as_deref() == Some(expected_blockhash.as_str())
+    }));
+    for event in events.iter() {
+        println!("{}", event.summary());
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//! Annotate trade events with UI-normalized amounts (`EventMetadata::ui_amounts`).
+//!
+//! Set `ParseConfig::decimals_resolver` and the parser does this for every trade; here
+//! `annotate_ui_amounts` is called directly on a synthetic PumpFun buy. The resolver reads mint
+//! decimals from your own cache. WSOL is always 9 decimals and never reaches the resolver.
+//!
+//! Run with `cargo run --example ui_amounts_example --features decimal`.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::common::{annotate_ui_amounts, DecimalsResolver};
+use solana_streamer_sdk::streaming::event_parser::common::{EventMetadata, ProtocolType};
+use solana_streamer_sdk::streaming::event_parser::core::ParseConfig;
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+
+fn pumpfun_buy(mint: Pubkey) -> DexEvent {
+    DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
+        metadata: EventMetadata { protocol: ProtocolType::PumpFun, ..Default::default() },
+        mint,
+        sol_amount: 1_500_000_000,
+        token_amount: 52_345_678_901,
+        is_buy: true,
+        ..Default::default()
+    })
+}
+
+fn main() {
+    let known_mint = Pubkey::new_unique();
+    let unknown_mint = Pubkey::new_unique();
+    let decimals_cache: Arc<HashMap<Pubkey, u8>> = Arc::new(HashMap::from([(known_mint, 6)]));
+    let cache = decimals_cache.clone();
+    let resolver = DecimalsResolver::new(move |mint| cache.get(mint).copied());
+
+    // On a client this is all that is needed
+    let _config = ParseConfig { decimals_resolver: Some(resolver.clone()), ..Default::default() };
+
+    let mut event = pumpfun_buy(known_mint);
+    annotate_ui_amounts(&mut event, &resolver);
+    let ui_amounts = event.metadata().ui_amounts.expect("decimals of both mints are known");
+    assert_eq!(ui_amounts.base_amount, Decimal::from_str("52345.678901").unwrap());
+    assert_eq!(ui_amounts.quote_amount, Decimal::from_str("1.5").unwrap());
+    assert_eq!((ui_amounts.base_decimals, ui_amounts.quote_decimals), (6, 9));
+
+    // Trades on mints the resolver does not know stay unannotated
+    let mut unknown = pumpfun_buy(unknown_mint);
+    annotate_ui_amounts(&mut unknown, &resolver);
+    assert!(unknown.metadata().ui_amounts.is_none());
+
+    println!(
+        "bought {} tokens for {} SOL",
+        ui_amounts.base_amount.normalize(),
+        ui_amounts.quote_amount.normalize()
+    );
+}
@@ -0,0 +1,110 @@
+//! Normalize swaps that pay in or receive WSOL with `UnifiedTrade::from_event` and check the
+//! direction, the `is_sol_side` flag and the SOL / token amounts.
+//!
+//! WSOL amounts are lamports: against a non-quote token a swap paying WSOL is a buy and a swap
+//! receiving WSOL is a sell, and `sol_lamports` / `sol_value` return the WSOL side either way.
+//!
+//! Run with `cargo run --example wsol_trade_example`.
+
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::common::{UnifiedTrade, USDC_MINT, WSOL_MINT};
+use solana_streamer_sdk::streaming::event_parser::common::{
+    EventMetadata, EventType, ProtocolType, SwapData,
+};
+use solana_streamer_sdk::streaming::event_parser::protocols::pumpswap::PumpSwapSellEvent;
+use solana_streamer_sdk::streaming::event_parser::protocols::raydium_cpmm::RaydiumCpmmSwapEvent;
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+
+const TOKEN_MINT: Pubkey = Pubkey::new_from_array([1; 32]);
+const USER: Pubkey = Pubkey::new_from_array([2; 32]);
+const POOL: Pubkey = Pubkey::new_from_array([3; 32]);
+
+/// Raydium CPMM swap whose amounts come from `swap_data`, as the parser fills it from the token
+/// transfers following the instruction
+fn cpmm_swap(from_mint: Pubkey, from_amount: u64, to_mint: Pubkey, to_amount: u64) -> DexEvent {
+    let metadata = EventMetadata {
+        protocol: ProtocolType::RaydiumCpmm,
+        event_type: EventType::RaydiumCpmmSwapBaseInput,
+        swap_data: Some(SwapData {
+            from_mint,
+            to_mint,
+            from_amount,
+            to_amount,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    DexEvent::RaydiumCpmmSwapEvent(RaydiumCpmmSwapEvent {
+        metadata,
+        payer: USER,
+        pool_state: POOL,
+        input_token_mint: from_mint,
+        output_token_mint: to_mint,
+        amount_in: from_amount,
+        ..Default::default()
+    })
+}
+
+fn main() {
+    // WSOL in: 1.5 SOL of WSOL buys 42,000,000 raw token units
+    let trade =
+        UnifiedTrade::from_event(&cpmm_swap(WSOL_MINT, 1_500_000_000, TOKEN_MINT, 42_000_000))
+            .expect("WSOL-in swap normalizes");
+    assert!(trade.is_buy);
+    assert!(trade.is_sol_side);
+    assert_eq!((trade.base_mint, trade.quote_mint), (TOKEN_MINT, WSOL_MINT));
+    assert_eq!((trade.base_amount, trade.quote_amount), (42_000_000, 1_500_000_000));
+    assert_eq!(trade.sol_lamports(), Some(1_500_000_000));
+    assert_eq!(trade.sol_value(), Some(1.5));
+    assert_eq!((trade.pool, trade.trader), (POOL, USER));
+
+    // WSOL out: selling 10,000,000 raw token units receives 0.25 SOL of WSOL
+    let trade =
+        UnifiedTrade::from_event(&cpmm_swap(TOKEN_MINT, 10_000_000, WSOL_MINT, 250_000_000))
+            .expect("WSOL-out swap normalizes");
+    assert!(!trade.is_buy);
+    assert!(trade.is_sol_side);
+    assert_eq!((trade.base_mint, trade.quote_mint), (TOKEN_MINT, WSOL_MINT));
+    assert_eq!((trade.base_amount, trade.quote_amount), (10_000_000, 250_000_000));
+    assert_eq!(trade.sol_lamports(), Some(250_000_000));
+    assert_eq!(trade.sol_value(), Some(0.25));
+
+    // PumpSwap sell into a WSOL quote pool: amounts come straight from the event fields
+    let sell = DexEvent::PumpSwapSellEvent(PumpSwapSellEvent {
+        metadata: EventMetadata {
+            protocol: ProtocolType::PumpSwap,
+            event_type: EventType::PumpSwapSell,
+            ..Default::default()
+        },
+        base_amount_in: 3_000_000,
+        quote_amount_out: 75_000_000,
+        base_mint: TOKEN_MINT,
+        quote_mint: WSOL_MINT,
+        pool: POOL,
+        user: USER,
+        ..Default::default()
+    });
+    let trade = UnifiedTrade::from_event(&sell).expect("PumpSwap sell normalizes");
+    assert!(!trade.is_buy);
+    assert!(trade.is_sol_side);
+    assert_eq!(trade.sol_lamports(), Some(75_000_000));
+    assert_eq!(trade.sol_value(), Some(0.075));
+
+    // WSOL paired with USDC: USDC is listed as the quote, so WSOL is the base side bought with
+    // USDC and its amount is still reported as the SOL side
+    let trade =
+        UnifiedTrade::from_event(&cpmm_swap(USDC_MINT, 180_000_000, WSOL_MINT, 1_000_000_000))
+            .expect("USDC/WSOL swap normalizes");
+    assert!(trade.is_buy);
+    assert!(trade.is_sol_side);
+    assert_eq!((trade.base_mint, trade.quote_mint), (WSOL_MINT, USDC_MINT));
+    assert_eq!(trade.sol_lamports(), Some(1_000_000_000));
+
+    // No SOL side: the flag is off and no SOL amount is reported
+    let trade = UnifiedTrade::from_event(&cpmm_swap(TOKEN_MINT, 5_000, USDC_MINT, 1_000))
+        .expect("token/USDC swap normalizes");
+    assert!(!trade.is_sol_side);
+    assert_eq!((trade.sol_lamports(), trade.sol_value()), (None, None));
+
+    println!("WSOL-in and WSOL-out swaps normalized with SOL amounts in lamports");
+}
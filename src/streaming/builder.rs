@@ -0,0 +1,192 @@
+use anyhow::anyhow;
+use yellowstone_grpc_proto::geyser::CommitmentLevel;
+
+use crate::common::AnyResult;
+#[cfg(feature = "thread-affinity")]
+use crate::streaming::common::ThreadAffinityConfig;
+use crate::streaming::common::{
+    ConnectionConfig, EventEnricher, MetricsManager, SlotSummaryCallback, StreamClientConfig,
+};
+use crate::streaming::event_parser::core::ParseConfig;
+use crate::streaming::shred::ShredErrorCallback;
+use crate::streaming::yellowstone_grpc::RawUpdateCallback;
+use crate::streaming::{ShredStreamGrpc, YellowstoneGrpc};
+
+/// 流客户端构建器
+///
+/// 在一处设置端点、认证、确认级别、连接、性能监控与解析配置，一次构建出完整配置的
+/// `YellowstoneGrpc`（`build_grpc`）或 `ShredStreamGrpc`（`build_shred`），
+/// 省去 `new_with_config` 之后的一连串 `set_*` / `update_config` 调用
+#[derive(Clone, Default)]
+pub struct StreamClientBuilder {
+    endpoints: Vec<String>,
+    auth_token: Option<String>,
+    commitment: Option<CommitmentLevel>,
+    from_slot: Option<u64>,
+    config: StreamClientConfig,
+    enricher: Option<EventEnricher>,
+    error_callback: Option<ShredErrorCallback>,
+    raw_update_callback: Option<RawUpdateCallback>,
+    metrics_manager: Option<MetricsManager>,
+    slot_summary_callback: Option<SlotSummaryCallback>,
+}
+
+impl StreamClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置唯一的端点，覆盖之前设置的端点
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoints = vec![endpoint.into()];
+        self
+    }
+
+    /// 设置多个 ShredStream 代理端点，订阅时同时消费并按签名去重
+    pub fn endpoints(mut self, endpoints: Vec<String>) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Yellowstone gRPC 的 x-token
+    pub fn auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
+    /// Yellowstone gRPC 的默认确认级别，订阅时未指定 commitment 则使用该值
+    pub fn commitment(mut self, commitment: CommitmentLevel) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Yellowstone gRPC 订阅的起始槽位，见 `YellowstoneGrpc::set_from_slot`
+    pub fn from_slot(mut self, slot: u64) -> Self {
+        self.from_slot = Some(slot);
+        self
+    }
+
+    /// 以完整的客户端配置为基础，之后调用的其它方法在其上修改
+    pub fn config(mut self, config: StreamClientConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn connection(mut self, connection: ConnectionConfig) -> Self {
+        self.config.connection = connection;
+        self
+    }
+
+    /// 首次连接 ShredStream 代理的重试策略：最多尝试 `max_attempts` 次，
+    /// 首次重试前等待 `initial_delay_ms` 毫秒，之后每次翻倍（上限 30 秒）
+    pub fn connect_retry(mut self, max_attempts: u32, initial_delay_ms: u64) -> Self {
+        self.config.connection.connect_max_attempts = max_attempts;
+        self.config.connection.connect_retry_delay_ms = initial_delay_ms;
+        self
+    }
+
+    pub fn enable_metrics(mut self, enabled: bool) -> Self {
+        self.config.enable_metrics = enabled;
+        self
+    }
+
+    pub fn max_concurrent_parses(mut self, limit: usize) -> Self {
+        self.config.max_concurrent_parses = Some(limit);
+        self
+    }
+
+    /// 在 `threads` 个专用线程上执行用户 callback，流任务只负责投递事件；
+    /// 开启 `strict_ordering`（默认）时只使用一个线程
+    pub fn callback_threads(mut self, threads: usize) -> Self {
+        self.config.callback_threads = Some(threads);
+        self
+    }
+
+    /// 单次用户 callback 执行超过 `threshold_ms` 毫秒时记录警告并计数，不会中断 callback
+    pub fn callback_warn_threshold_ms(mut self, threshold_ms: u64) -> Self {
+        self.config.callback_warn_threshold_ms = Some(threshold_ms);
+        self
+    }
+
+    /// 客户端使用的指标实例，见 `YellowstoneGrpc::set_metrics_manager`；未设置时使用全局实例
+    pub fn metrics_manager(mut self, metrics_manager: MetricsManager) -> Self {
+        self.metrics_manager = Some(metrics_manager);
+        self
+    }
+
+    /// 将流任务与解析任务绑定到指定核心，见 `ThreadAffinityConfig`
+    #[cfg(feature = "thread-affinity")]
+    pub fn thread_affinity(mut self, affinity: ThreadAffinityConfig) -> Self {
+        self.config.thread_affinity = Some(affinity);
+        self
+    }
+
+    pub fn parse_config(mut self, parse: ParseConfig) -> Self {
+        self.config.parse = parse;
+        self
+    }
+
+    /// 事件增强钩子，见 `YellowstoneGrpc::set_enricher`
+    pub fn enricher(mut self, enricher: EventEnricher) -> Self {
+        self.enricher = Some(enricher);
+        self
+    }
+
+    /// ShredStream 解码失败回调，见 `ShredStreamGrpc::set_error_callback`
+    pub fn error_callback(mut self, callback: ShredErrorCallback) -> Self {
+        self.error_callback = Some(callback);
+        self
+    }
+
+    /// Yellowstone gRPC 原始更新回调，见 `YellowstoneGrpc::set_raw_update_callback`
+    pub fn raw_update_callback(mut self, callback: RawUpdateCallback) -> Self {
+        self.raw_update_callback = Some(callback);
+        self
+    }
+
+    /// 槽位汇总回调，见 `YellowstoneGrpc::set_slot_summary_callback`
+    pub fn on_slot_summary(mut self, callback: SlotSummaryCallback) -> Self {
+        self.slot_summary_callback = Some(callback);
+        self
+    }
+
+    /// 槽位汇总等待区块元数据的超时时间（毫秒）
+    pub fn slot_summary_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.slot_summary_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// 构建 Yellowstone gRPC 客户端，需要恰好一个端点
+    pub fn build_grpc(self) -> AnyResult<YellowstoneGrpc> {
+        let [endpoint] = <[String; 1]>::try_from(self.endpoints)
+            .map_err(|_| anyhow!("Yellowstone gRPC client requires exactly one endpoint"))?;
+        let mut client = YellowstoneGrpc::new_with_config(endpoint, self.auth_token, self.config)?;
+        client.set_enricher(self.enricher);
+        client.set_raw_update_callback(self.raw_update_callback);
+        client.set_slot_summary_callback(self.slot_summary_callback);
+        client.set_commitment(self.commitment);
+        client.set_from_slot(self.from_slot);
+        if let Some(metrics_manager) = self.metrics_manager {
+            client.set_metrics_manager(metrics_manager);
+        }
+        Ok(client)
+    }
+
+    /// 构建 ShredStream 客户端并连接所有代理
+    ///
+    /// 未设置端点时构建不连接代理的客户端，只能通过 `subscribe_with_sources` 订阅自定义数据来源
+    pub async fn build_shred(self) -> AnyResult<ShredStreamGrpc> {
+        let mut client = if self.endpoints.is_empty() {
+            ShredStreamGrpc::new_for_sources(self.config)
+        } else {
+            ShredStreamGrpc::new_multi_with_config(self.endpoints, self.config).await?
+        };
+        client.set_enricher(self.enricher);
+        client.set_error_callback(self.error_callback);
+        client.set_slot_summary_callback(self.slot_summary_callback);
+        if let Some(metrics_manager) = self.metrics_manager {
+            client.set_metrics_manager(metrics_manager);
+        }
+        Ok(client)
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::{Arc, RwLock};
+
+use crate::streaming::event_parser::Protocol;
+
+/// 可在运行时替换的协议列表
+///
+/// 订阅任务每处理一条消息取一次当前快照，替换后从下一条消息开始生效，无需重新订阅。
+/// 克隆共享同一份列表
+#[derive(Clone, Debug, Default)]
+pub struct ActiveProtocols {
+    inner: Arc<RwLock<Arc<Vec<Protocol>>>>,
+}
+
+impl ActiveProtocols {
+    pub fn new(protocols: Vec<Protocol>) -> Self {
+        Self { inner: Arc::new(RwLock::new(Arc::new(protocols))) }
+    }
+
+    /// 获取当前协议列表快照
+    #[inline]
+    pub fn load(&self) -> Arc<Vec<Protocol>> {
+        self.inner.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// 替换协议列表
+    pub fn store(&self, protocols: Vec<Protocol>) {
+        *self.inner.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(protocols);
+    }
+}
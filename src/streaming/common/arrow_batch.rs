@@ -0,0 +1,198 @@
+use std::sync::{Arc, LazyLock, Mutex};
+
+use arrow_array::builder::{BooleanBuilder, Int64Builder, StringBuilder, UInt64Builder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+use super::constants::{DEFAULT_ARROW_BATCH_SIZE, LOG_TARGET};
+use super::trade::UnifiedTrade;
+use crate::streaming::event_parser::DexEvent;
+
+static UNIFIED_TRADE_SCHEMA: LazyLock<SchemaRef> = LazyLock::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("signature", DataType::Utf8, false),
+        Field::new("slot", DataType::UInt64, false),
+        Field::new("block_time_ms", DataType::Int64, false),
+        Field::new("recv_us", DataType::Int64, false),
+        Field::new("protocol", DataType::Utf8, false),
+        Field::new("pool", DataType::Utf8, false),
+        Field::new("base_mint", DataType::Utf8, false),
+        Field::new("quote_mint", DataType::Utf8, false),
+        Field::new("base_amount", DataType::UInt64, false),
+        Field::new("quote_amount", DataType::UInt64, false),
+        Field::new("is_buy", DataType::Boolean, false),
+        Field::new("trader", DataType::Utf8, false),
+        Field::new("is_sol_side", DataType::Boolean, false),
+    ]))
+});
+
+/// `UnifiedTrade` 的 Arrow schema，每个字段一列
+///
+/// 签名与公钥为 base58 字符串，`protocol` 为 `ProtocolType` 的变体名，数量为链上原始单位
+pub fn unified_trade_schema() -> SchemaRef {
+    UNIFIED_TRADE_SCHEMA.clone()
+}
+
+/// 按列累积的成交数据
+struct TradeColumns {
+    signature: StringBuilder,
+    slot: UInt64Builder,
+    block_time_ms: Int64Builder,
+    recv_us: Int64Builder,
+    protocol: StringBuilder,
+    pool: StringBuilder,
+    base_mint: StringBuilder,
+    quote_mint: StringBuilder,
+    base_amount: UInt64Builder,
+    quote_amount: UInt64Builder,
+    is_buy: BooleanBuilder,
+    trader: StringBuilder,
+    is_sol_side: BooleanBuilder,
+    len: usize,
+}
+
+impl TradeColumns {
+    fn new(capacity: usize) -> Self {
+        // base58 签名最长 88 字节，公钥最长 44 字节
+        Self {
+            signature: StringBuilder::with_capacity(capacity, capacity * 88),
+            slot: UInt64Builder::with_capacity(capacity),
+            block_time_ms: Int64Builder::with_capacity(capacity),
+            recv_us: Int64Builder::with_capacity(capacity),
+            protocol: StringBuilder::with_capacity(capacity, capacity * 16),
+            pool: StringBuilder::with_capacity(capacity, capacity * 44),
+            base_mint: StringBuilder::with_capacity(capacity, capacity * 44),
+            quote_mint: StringBuilder::with_capacity(capacity, capacity * 44),
+            base_amount: UInt64Builder::with_capacity(capacity),
+            quote_amount: UInt64Builder::with_capacity(capacity),
+            is_buy: BooleanBuilder::with_capacity(capacity),
+            trader: StringBuilder::with_capacity(capacity, capacity * 44),
+            is_sol_side: BooleanBuilder::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    fn append(&mut self, trade: &UnifiedTrade) {
+        self.signature.append_value(trade.signature.to_string());
+        self.slot.append_value(trade.slot);
+        self.block_time_ms.append_value(trade.block_time_ms);
+        self.recv_us.append_value(trade.recv_us);
+        self.protocol.append_value(format!("{:?}", trade.protocol));
+        self.pool.append_value(trade.pool.to_string());
+        self.base_mint.append_value(trade.base_mint.to_string());
+        self.quote_mint.append_value(trade.quote_mint.to_string());
+        self.base_amount.append_value(trade.base_amount);
+        self.quote_amount.append_value(trade.quote_amount);
+        self.is_buy.append_value(trade.is_buy);
+        self.trader.append_value(trade.trader.to_string());
+        self.is_sol_side.append_value(trade.is_sol_side);
+        self.len += 1;
+    }
+
+    /// 取出已累积的行组成 RecordBatch，构建器随之清空
+    fn finish(&mut self) -> Result<RecordBatch, ArrowError> {
+        self.len = 0;
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(self.signature.finish()),
+            Arc::new(self.slot.finish()),
+            Arc::new(self.block_time_ms.finish()),
+            Arc::new(self.recv_us.finish()),
+            Arc::new(self.protocol.finish()),
+            Arc::new(self.pool.finish()),
+            Arc::new(self.base_mint.finish()),
+            Arc::new(self.quote_mint.finish()),
+            Arc::new(self.base_amount.finish()),
+            Arc::new(self.quote_amount.finish()),
+            Arc::new(self.is_buy.finish()),
+            Arc::new(self.trader.finish()),
+            Arc::new(self.is_sol_side.finish()),
+        ];
+        RecordBatch::try_new(unified_trade_schema(), columns)
+    }
+}
+
+/// RecordBatch 回调
+pub type RecordBatchCallback = Arc<dyn Fn(RecordBatch) + Send + Sync>;
+
+/// 将成交事件按 [`unified_trade_schema`] 累积为 Arrow `RecordBatch`
+///
+/// 每累积 `batch_size` 笔成交通过回调输出一个 RecordBatch，适合直接写入 Parquet / DataFusion
+/// 等列式存储。非成交事件被忽略；停止订阅前调用 `flush` 输出剩余的成交
+pub struct TradeRecordBatcher {
+    batch_size: usize,
+    columns: Mutex<TradeColumns>,
+    callback: RecordBatchCallback,
+}
+
+impl TradeRecordBatcher {
+    /// 创建指定批大小的累积器（批大小至少为 1）
+    pub fn new(batch_size: usize, callback: RecordBatchCallback) -> Self {
+        let batch_size = batch_size.max(1);
+        Self { batch_size, columns: Mutex::new(TradeColumns::new(batch_size)), callback }
+    }
+
+    /// 创建默认批大小的累积器
+    pub fn with_default_batch_size(callback: RecordBatchCallback) -> Self {
+        Self::new(DEFAULT_ARROW_BATCH_SIZE, callback)
+    }
+
+    /// 批大小
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// 当前已累积、尚未输出的成交数量
+    pub fn len(&self) -> usize {
+        self.columns.lock().unwrap_or_else(|e| e.into_inner()).len
+    }
+
+    /// 是否没有待输出的成交
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 处理事件，非成交事件直接忽略
+    pub fn on_event(&self, event: &DexEvent) {
+        if let Some(trade) = UnifiedTrade::from_event(event) {
+            self.on_trade(&trade);
+        }
+    }
+
+    /// 处理一笔成交，累积满一批时输出
+    pub fn on_trade(&self, trade: &UnifiedTrade) {
+        let batch = {
+            let mut columns = self.columns.lock().unwrap_or_else(|e| e.into_inner());
+            columns.append(trade);
+            if columns.len < self.batch_size {
+                return;
+            }
+            columns.finish()
+        };
+        self.emit(batch);
+    }
+
+    /// 输出剩余的成交（例如停止订阅前），没有待输出的成交时不调用回调
+    pub fn flush(&self) {
+        let batch = {
+            let mut columns = self.columns.lock().unwrap_or_else(|e| e.into_inner());
+            if columns.len == 0 {
+                return;
+            }
+            columns.finish()
+        };
+        self.emit(batch);
+    }
+
+    /// 生成可直接传给订阅接口的事件 callback
+    pub fn event_callback(self: &Arc<Self>) -> impl Fn(DexEvent) + Send + Sync + 'static {
+        let batcher = self.clone();
+        move |event: DexEvent| batcher.on_event(&event)
+    }
+
+    fn emit(&self, batch: Result<RecordBatch, ArrowError>) {
+        match batch {
+            Ok(batch) => (self.callback)(batch),
+            Err(e) => log::warn!(target: LOG_TARGET, "Failed to build trade record batch: {e}"),
+        }
+    }
+}
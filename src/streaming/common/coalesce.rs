@@ -0,0 +1,142 @@
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+use super::metrics::MetricsManager;
+use crate::streaming::event_parser::DexEvent;
+
+/// 合并后的事件回调
+pub type CoalescedEventCallback = Arc<dyn Fn(DexEvent) + Send + Sync>;
+
+struct CoalesceSlot {
+    /// 该账户上次输出事件的时间
+    last_emit: Instant,
+    /// 周期内暂存的最新事件
+    pending: Option<DexEvent>,
+}
+
+/// 按账户限流的事件合并缓冲
+///
+/// 每个账户（`DexEvent::account_pubkey`）每个周期最多输出一个事件：距上次输出已满一个周期的事件
+/// 立即输出，否则暂存并覆盖该账户之前暂存的事件，周期结束后由 `flush_due`（或 `spawn_flush_task`
+/// 启动的定时任务）输出最新的一个。被覆盖的事件计入合并指标。非账户事件直接透传。
+/// 与去重不同，内容不同的更新同样会被合并
+pub struct AccountCoalescer {
+    interval: Duration,
+    slots: Mutex<HashMap<Pubkey, CoalesceSlot>>,
+    coalesced: AtomicU64,
+    callback: CoalescedEventCallback,
+}
+
+impl AccountCoalescer {
+    pub fn new(interval: Duration, callback: CoalescedEventCallback) -> Self {
+        Self { interval, slots: Mutex::new(HashMap::new()), coalesced: AtomicU64::new(0), callback }
+    }
+
+    /// 每个账户的最短输出间隔
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// 处理事件
+    pub fn on_event(&self, event: DexEvent) {
+        let Some(pubkey) = event.account_pubkey() else {
+            (self.callback)(event);
+            return;
+        };
+        let now = Instant::now();
+        let emit = {
+            let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            match slots.get_mut(&pubkey) {
+                Some(slot) if now.duration_since(slot.last_emit) < self.interval => {
+                    if slot.pending.replace(event).is_some() {
+                        self.record_coalesced();
+                    }
+                    None
+                }
+                Some(slot) => {
+                    slot.last_emit = now;
+                    if slot.pending.take().is_some() {
+                        self.record_coalesced();
+                    }
+                    Some(event)
+                }
+                None => {
+                    slots.insert(pubkey, CoalesceSlot { last_emit: now, pending: None });
+                    Some(event)
+                }
+            }
+        };
+        if let Some(event) = emit {
+            (self.callback)(event);
+        }
+    }
+
+    /// 输出已满一个周期的暂存事件，并清理长时间没有更新的账户
+    pub fn flush_due(&self) {
+        let now = Instant::now();
+        let mut due = Vec::new();
+        {
+            let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            slots.retain(|_, slot| {
+                if now.duration_since(slot.last_emit) < self.interval {
+                    return true;
+                }
+                match slot.pending.take() {
+                    Some(event) => {
+                        slot.last_emit = now;
+                        due.push(event);
+                        true
+                    }
+                    None => false,
+                }
+            });
+        }
+        for event in due {
+            (self.callback)(event);
+        }
+    }
+
+    /// 输出所有暂存事件并清空状态（例如停止订阅前）
+    pub fn flush(&self) {
+        let pending: Vec<DexEvent> = {
+            let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            slots.drain().filter_map(|(_, slot)| slot.pending).collect()
+        };
+        for event in pending {
+            (self.callback)(event);
+        }
+    }
+
+    /// 启动按周期调用 `flush_due` 的后台任务，abort 返回的句柄即可停止
+    pub fn spawn_flush_task(self: &Arc<Self>) -> JoinHandle<()> {
+        let coalescer = self.clone();
+        tokio::spawn(async move {
+            let period = coalescer.interval.max(Duration::from_millis(1));
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+                coalescer.flush_due();
+            }
+        })
+    }
+
+    /// 被更新的事件覆盖而未输出的事件数
+    pub fn coalesced_count(&self) -> u64 {
+        self.coalesced.load(Ordering::Relaxed)
+    }
+
+    /// 生成可直接传给订阅接口的事件 callback
+    pub fn event_callback(self: &Arc<Self>) -> impl Fn(DexEvent) + Send + Sync + 'static {
+        let coalescer = self.clone();
+        move |event: DexEvent| coalescer.on_event(event)
+    }
+
+    fn record_coalesced(&self) {
+        self.coalesced.fetch_add(1, Ordering::Relaxed);
+        MetricsManager::current().increment_coalesced_events();
+    }
+}
@@ -1,4 +1,5 @@
 use super::constants::*;
+use crate::streaming::event_parser::core::ParseConfig;
 
 /// Connection configuration
 #[derive(Debug, Clone)]
@@ -9,6 +10,12 @@ pub struct ConnectionConfig {
     pub request_timeout: u64,
     /// Maximum decoding message size in bytes (default: 10MB)
     pub max_decoding_message_size: usize,
+    /// Attempts made for the initial connection to a ShredStream proxy before giving up; values
+    /// below 1 are treated as 1 (default: 1, no retry)
+    pub connect_max_attempts: u32,
+    /// Delay before the first connection retry in milliseconds, doubled after every failed
+    /// attempt up to 30s (default: 500)
+    pub connect_retry_delay_ms: u64,
 }
 
 impl Default for ConnectionConfig {
@@ -17,10 +24,31 @@ impl Default for ConnectionConfig {
             connect_timeout: DEFAULT_CONNECT_TIMEOUT,
             request_timeout: DEFAULT_REQUEST_TIMEOUT,
             max_decoding_message_size: DEFAULT_MAX_DECODING_MESSAGE_SIZE,
+            connect_max_attempts: DEFAULT_CONNECT_MAX_ATTEMPTS,
+            connect_retry_delay_ms: DEFAULT_CONNECT_RETRY_DELAY_MS,
         }
     }
 }
 
+/// Thread affinity configuration
+///
+/// When set, a subscription's stream task runs on a dedicated multi-threaded tokio runtime with
+/// one worker thread per listed core, each pinned to its core; transactions parsed concurrently
+/// (`max_concurrent_parses`) are spawned on the same runtime. Pinning is best-effort: cores that
+/// cannot be pinned and a priority that cannot be raised are logged and ignored, and real-time
+/// priority is only applied on Linux. Keep the pinned cores out of the application's own tokio
+/// runtime (e.g. limit its `worker_threads`) so they are not shared with other work.
+#[cfg(feature = "thread-affinity")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThreadAffinityConfig {
+    /// Ids of the cores to pin worker threads to, as returned by `core_affinity::get_core_ids`;
+    /// one worker thread is started per core (default: empty, a single unpinned worker thread)
+    pub core_ids: Vec<usize>,
+    /// `SCHED_FIFO` real-time priority (1-99) for the worker threads on Linux; requires
+    /// `CAP_SYS_NICE` or a suitable `RLIMIT_RTPRIO` (default: None, normal scheduling)
+    pub realtime_priority: Option<u8>,
+}
+
 /// Common client configuration
 #[derive(Debug, Clone)]
 pub struct StreamClientConfig {
@@ -28,10 +56,81 @@ pub struct StreamClientConfig {
     pub connection: ConnectionConfig,
     /// Whether performance monitoring is enabled (default: false)
     pub enable_metrics: bool,
+    /// Whether panics raised inside the user callback are caught and logged instead of
+    /// tearing down the stream task (default: true)
+    pub catch_callback_panics: bool,
+    /// Maximum number of transactions parsed concurrently. When set, each transaction is parsed
+    /// in its own task and the stream waits for a free slot before reading further
+    /// (default: None, transactions are parsed inline)
+    pub max_concurrent_parses: Option<usize>,
+    /// Whether transaction events parsed concurrently (see `max_concurrent_parses`) are buffered
+    /// and delivered in stream order. When disabled, each transaction's events are delivered as
+    /// soon as it is parsed, possibly ahead of earlier transactions. Also limits
+    /// `callback_threads` to a single thread (default: true)
+    pub strict_ordering: bool,
+    /// Whether slot status updates are subscribed (Yellowstone gRPC only) and delivered as
+    /// `SlotStatusEvent`s, for finalizing or rolling back events received at `processed`
+    /// commitment. Updates for every commitment level are delivered regardless of the
+    /// subscription commitment; an event type filter must include `EventType::SlotStatus`
+    /// (default: false)
+    pub slot_status: bool,
+    /// Whether transaction filters with an empty `account_include` are sent with the program ids
+    /// of the subscribed protocols, so the provider only pushes transactions that touch them
+    /// (Yellowstone gRPC only). Filters that list their own `account_include` are sent unchanged;
+    /// use `TransactionFilter::for_protocols` to extend the derived set (default: false, filters
+    /// are sent as given)
+    pub auto_program_filter: bool,
+    /// Index of the preferred ShredStream proxy (in endpoint / source order) when subscribing
+    /// to several. A transaction first received from another proxy is held for up to
+    /// `primary_proxy_wait_us` waiting for the primary's copy and only processed from the backup
+    /// if the primary has not delivered it by then (default: None, the first copy to arrive is
+    /// processed)
+    pub primary_proxy: Option<usize>,
+    /// How long a backup proxy's transaction waits for the primary's copy, in microseconds
+    /// (default: 5000)
+    pub primary_proxy_wait_us: u64,
+    /// How long a slot summary waits for the slot's block meta before it is delivered anyway
+    /// (marked `timed_out`), in milliseconds. Only used when a slot summary callback is set
+    /// (default: 2000)
+    pub slot_summary_timeout_ms: u64,
+    /// Number of dedicated threads the user callback runs on. When set, the stream task only
+    /// hands parsed events off to these threads, so a slow callback no longer stalls reading the
+    /// stream until their queues fill up. Events of one transaction stay on one thread and in
+    /// order. More than one thread is only used with `strict_ordering` disabled, in which case
+    /// events of different transactions may be delivered out of order (default: None, the
+    /// callback runs on the stream task)
+    pub callback_threads: Option<usize>,
+    /// Log a warning and increment `slow_callbacks_count` when a single invocation of the user
+    /// callback runs longer than this, in milliseconds. A watchdog thread reports callbacks that
+    /// are still running, so a stuck callback is noticed before it returns; the callback itself
+    /// is never interrupted (default: None, no watchdog)
+    pub callback_warn_threshold_ms: Option<u64>,
+    /// CPU affinity and scheduling priority of the threads running the subscription's stream
+    /// and parse tasks (default: None, the tasks run on the caller's tokio runtime)
+    #[cfg(feature = "thread-affinity")]
+    pub thread_affinity: Option<ThreadAffinityConfig>,
+    /// Parser configuration
+    pub parse: ParseConfig,
 }
 
 impl Default for StreamClientConfig {
     fn default() -> Self {
-        Self { connection: ConnectionConfig::default(), enable_metrics: false }
+        Self {
+            connection: ConnectionConfig::default(),
+            enable_metrics: false,
+            catch_callback_panics: true,
+            max_concurrent_parses: None,
+            strict_ordering: true,
+            slot_status: false,
+            auto_program_filter: false,
+            primary_proxy: None,
+            primary_proxy_wait_us: DEFAULT_PRIMARY_PROXY_WAIT_US,
+            slot_summary_timeout_ms: DEFAULT_SLOT_SUMMARY_TIMEOUT_MS,
+            callback_threads: None,
+            callback_warn_threshold_ms: None,
+            #[cfg(feature = "thread-affinity")]
+            thread_affinity: None,
+            parse: ParseConfig::default(),
+        }
     }
 }
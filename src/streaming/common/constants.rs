@@ -5,6 +5,58 @@ pub const DEFAULT_CONNECT_TIMEOUT: u64 = 10;
 pub const DEFAULT_REQUEST_TIMEOUT: u64 = 60;
 pub const DEFAULT_CHANNEL_SIZE: usize = 1000;
 pub const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 1024 * 1024 * 10;
+// 初始连接的尝试次数（1 表示不重试）与首次重试间隔，重试间隔按指数退避增长到上限为止
+pub const DEFAULT_CONNECT_MAX_ATTEMPTS: u32 = 1;
+pub const DEFAULT_CONNECT_RETRY_DELAY_MS: u64 = 500;
+pub const MAX_CONNECT_RETRY_DELAY_MS: u64 = 30_000;
+// 多路 ShredStream 签名去重窗口大小
+pub const DEFAULT_DEDUP_CAPACITY: usize = 100_000;
+// 备用代理先到的交易等待主代理副本的默认时间（微秒）
+pub const DEFAULT_PRIMARY_PROXY_WAIT_US: u64 = 5_000;
+// 单条指令数据最大长度，超过则跳过解析
+pub const DEFAULT_MAX_INSTRUCTION_DATA_LEN: usize = 10 * 1024;
+// 每条指令查找 CPI log 时最多扫描的 inner instruction 数量
+pub const DEFAULT_MAX_INNER_INSTRUCTION_SCAN: usize = 256;
+// 账户 diff 模式缓存的账户数量
+pub const DEFAULT_ACCOUNT_DIFF_CACHE_SIZE: usize = 10_000;
+// 用于判断 nonce 账户变化类型而缓存的 nonce 账户数量
+pub const DEFAULT_NONCE_CACHE_SIZE: usize = 10_000;
+// 未处理程序 ID 统计保留的程序数量（需启用 `unhandled-programs` feature）
+pub const DEFAULT_UNHANDLED_PROGRAM_CACHE_SIZE: usize = 1_024;
+// 每个 mint 只输出首笔成交模式下记录的 mint 数量
+pub const DEFAULT_FIRST_TRADE_CACHE_SIZE: usize = 100_000;
+// 按池子年龄过滤成交时记录首次出现槽位的 mint 数量
+pub const DEFAULT_POOL_AGE_CACHE_SIZE: usize = 100_000;
+// 公钥 base58 字符串缓存默认容量
+pub const DEFAULT_PUBKEY_DISPLAY_CACHE_SIZE: usize = 4_096;
+// 未设置 compute unit limit 时的默认上限（每条非 compute budget 指令）
+pub const DEFAULT_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+// 单笔交易的计算单元上限
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+// 每个签名的基础手续费（lamports）
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+// 估算缺失的区块时间时每个槽位的时长（毫秒）
+pub const SLOT_DURATION_MS: i64 = 400;
+// Arrow RecordBatch 默认批大小（行）
+pub const DEFAULT_ARROW_BATCH_SIZE: usize = 8_192;
+// OHLCV 聚合默认周期（秒）：1s、1m、5m
+pub const DEFAULT_OHLCV_INTERVALS_SECS: &[u64] = &[1, 60, 300];
+// 仓位跟踪默认最多保留的钱包数
+pub const DEFAULT_POSITION_TRACKER_MAX_WALLETS: usize = 10_000;
+// 槽位汇总等待区块元数据的默认超时（毫秒）
+pub const DEFAULT_SLOT_SUMMARY_TIMEOUT_MS: u64 = 2_000;
+// 槽位汇总记录已输出槽位的数量，用于丢弃迟到的事件
+pub const SLOT_SUMMARY_FLUSHED_CACHE_SIZE: usize = 1_024;
+// 对象池 trim 后保留的单个缓冲区容量上限（字节）
+pub const POOL_TRIM_BUFFER_BASELINE: usize = 1024;
+// 绑定核心的流任务运行时的线程名
+pub const STREAM_WORKER_THREAD_NAME: &str = "solana-streamer-worker";
+// 专用 callback 线程的线程名
+pub const CALLBACK_THREAD_NAME: &str = "solana-streamer-callback";
+// 每个 callback 线程的事件队列容量，队列满时流任务等待（背压）
+pub const DEFAULT_CALLBACK_QUEUE_CAPACITY: usize = 4_096;
+// callback 执行超时看门狗的线程名
+pub const CALLBACK_WATCHDOG_THREAD_NAME: &str = "solana-streamer-callback-watchdog";
 
 // 性能监控相关常量
 pub const DEFAULT_METRICS_WINDOW_SECONDS: u64 = 5;
@@ -16,3 +68,9 @@ pub const SLOW_PROCESSING_THRESHOLD_US: f64 = 3000.0;
 pub const SOLANA_BLOCK_TIME_ADJUSTMENT_MS: i64 = 500;
 // 默认最大延迟阈值（毫秒）
 pub const MAX_LATENCY_THRESHOLD_MS: i64 = 1000;
+
+// 日志 target，可通过 `RUST_LOG=solana_streamer=warn` 等按前缀统一过滤
+// 订阅、连接与解析相关日志
+pub const LOG_TARGET: &str = "solana_streamer::stream";
+// 性能监控相关日志
+pub const METRICS_LOG_TARGET: &str = "solana_streamer::metrics";
@@ -0,0 +1,148 @@
+use solana_sdk::signature::Signature;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 基于签名的有界去重器
+///
+/// 保留最近 `capacity` 个签名，超出容量时按插入顺序淘汰最旧的签名
+pub struct Deduplicator {
+    inner: Mutex<DeduplicatorInner>,
+    capacity: usize,
+}
+
+struct DeduplicatorInner {
+    seen: HashSet<Signature>,
+    order: VecDeque<Signature>,
+}
+
+impl Deduplicator {
+    /// 创建指定容量的去重器
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            inner: Mutex::new(DeduplicatorInner {
+                seen: HashSet::with_capacity(capacity),
+                order: VecDeque::with_capacity(capacity),
+            }),
+            capacity,
+        }
+    }
+
+    /// 记录签名，首次出现返回 true，重复返回 false
+    pub fn insert(&self, signature: Signature) -> bool {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if !inner.seen.insert(signature) {
+            return false;
+        }
+        inner.order.push_back(signature);
+        if inner.order.len() > self.capacity {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.seen.remove(&oldest);
+            }
+        }
+        true
+    }
+
+    /// 当前记录的签名数量
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).order.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 清空所有记录
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.seen.clear();
+        inner.order.clear();
+    }
+}
+
+impl Default for Deduplicator {
+    fn default() -> Self {
+        Self::new(super::constants::DEFAULT_DEDUP_CAPACITY)
+    }
+}
+
+/// 多来源去重时一次送达的结果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProxyArrival {
+    /// 首次送达
+    First,
+    /// 重复送达：`first_proxy` 比本次早 `lead` 送达
+    Duplicate { first_proxy: usize, lead: Duration },
+}
+
+/// 多代理签名去重器
+///
+/// 与 `Deduplicator` 一样保留最近 `capacity` 个签名，另外记录每个签名最先送达的代理与时间，
+/// 重复送达时返回最先送达的代理领先了多久
+pub struct ProxyDeduplicator {
+    inner: Mutex<ProxyDeduplicatorInner>,
+    capacity: usize,
+}
+
+struct ProxyDeduplicatorInner {
+    first_seen: HashMap<Signature, (usize, Instant)>,
+    order: VecDeque<Signature>,
+}
+
+impl ProxyDeduplicator {
+    /// 创建指定容量的去重器
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            inner: Mutex::new(ProxyDeduplicatorInner {
+                first_seen: HashMap::with_capacity(capacity),
+                order: VecDeque::with_capacity(capacity),
+            }),
+            capacity,
+        }
+    }
+
+    /// 记录 `proxy` 在 `now` 送达的签名
+    pub fn arrive(&self, signature: Signature, proxy: usize, now: Instant) -> ProxyArrival {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(&(first_proxy, first_at)) = inner.first_seen.get(&signature) {
+            return ProxyArrival::Duplicate {
+                first_proxy,
+                lead: now.saturating_duration_since(first_at),
+            };
+        }
+        inner.first_seen.insert(signature, (proxy, now));
+        inner.order.push_back(signature);
+        if inner.order.len() > self.capacity {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.first_seen.remove(&oldest);
+            }
+        }
+        ProxyArrival::First
+    }
+
+    /// 当前记录的签名数量
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).order.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 清空所有记录
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.first_seen.clear();
+        inner.order.clear();
+    }
+}
+
+impl Default for ProxyDeduplicator {
+    fn default() -> Self {
+        Self::new(super::constants::DEFAULT_DEDUP_CAPACITY)
+    }
+}
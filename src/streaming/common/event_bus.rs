@@ -0,0 +1,324 @@
+use solana_sdk::pubkey::Pubkey;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, RwLock, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+use super::metrics::MetricsManager;
+use super::trade::UnifiedTrade;
+use crate::streaming::event_parser::common::{EventType, ProtocolType};
+use crate::streaming::event_parser::DexEvent;
+
+/// 事件总线的订阅主题
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventTopic {
+    /// 所有事件
+    All,
+    /// 指定协议的事件
+    Protocol(ProtocolType),
+    /// 指定类型的事件
+    EventType(EventType),
+    /// 涉及指定账户的事件：账户更新事件的账户地址、成交的池子 / 交易者 / mint、swap_data 中的 mint，
+    /// 以及开启 `attach_instruction_accounts` 后指令的全部账户
+    Account(Pubkey),
+}
+
+impl EventTopic {
+    /// 事件是否属于该主题
+    pub fn matches(&self, event: &DexEvent) -> bool {
+        let metadata = event.metadata();
+        match self {
+            EventTopic::All => true,
+            EventTopic::Protocol(protocol) => &metadata.protocol == protocol,
+            EventTopic::EventType(event_type) => &metadata.event_type == event_type,
+            EventTopic::Account(account) => {
+                if event.account_pubkey().as_ref() == Some(account) {
+                    return true;
+                }
+                if metadata.all_accounts.as_ref().is_some_and(|accounts| accounts.contains(account))
+                {
+                    return true;
+                }
+                if metadata
+                    .swap_data
+                    .as_ref()
+                    .is_some_and(|swap| &swap.from_mint == account || &swap.to_mint == account)
+                {
+                    return true;
+                }
+                UnifiedTrade::from_event(event).is_some_and(|trade| {
+                    [trade.pool, trade.trader, trade.base_mint, trade.quote_mint].contains(account)
+                })
+            }
+        }
+    }
+}
+
+/// 订阅者通道已满时的处理策略
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LagPolicy {
+    /// 丢弃新到达的事件
+    #[default]
+    DropNewest,
+    /// 丢弃通道中最旧的事件，为新事件腾出位置
+    DropOldest,
+    /// 阻塞发布方直到通道有空位，订阅任务会随之停顿
+    Block,
+}
+
+struct QueueState {
+    events: VecDeque<DexEvent>,
+    /// 通道从何时起持续已满，有空位后清除
+    full_since: Option<Instant>,
+    /// 已退订、订阅已释放或总线已释放
+    closed: bool,
+}
+
+/// 单个订阅者的有界事件队列
+struct SubscriberQueue {
+    state: Mutex<QueueState>,
+    /// 出队或关闭时唤醒等待空位的发布方
+    not_full: Condvar,
+    /// 入队或关闭时唤醒接收方
+    not_empty: Notify,
+    capacity: usize,
+    policy: LagPolicy,
+    lag_timeout: Duration,
+    dropped: AtomicU64,
+}
+
+impl SubscriberQueue {
+    fn new(capacity: usize, policy: LagPolicy, lag_timeout: Duration) -> Self {
+        Self {
+            state: Mutex::new(QueueState {
+                events: VecDeque::with_capacity(capacity),
+                full_since: None,
+                closed: false,
+            }),
+            not_full: Condvar::new(),
+            not_empty: Notify::new(),
+            capacity,
+            policy,
+            lag_timeout,
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// 入队，通道已满时按策略等待或丢弃；丢弃的事件计入订阅与全局丢弃指标
+    fn push(&self, event: DexEvent) {
+        let is_full = |state: &mut QueueState| state.events.len() >= self.capacity && !state.closed;
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if is_full(&mut *state) {
+            state = match self.policy {
+                LagPolicy::Block => {
+                    self.not_full.wait_while(state, is_full).unwrap_or_else(|e| e.into_inner())
+                }
+                LagPolicy::DropNewest | LagPolicy::DropOldest => {
+                    // 通道持续已满超过 lag_timeout 后不再等待，直接丢弃
+                    let now = Instant::now();
+                    let full_since = *state.full_since.get_or_insert(now);
+                    let timeout = (full_since + self.lag_timeout).saturating_duration_since(now);
+                    self.not_full
+                        .wait_timeout_while(state, timeout, is_full)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+            };
+        }
+        if state.closed {
+            return;
+        }
+        if state.events.len() < self.capacity {
+            state.full_since = None;
+        } else {
+            if self.policy == LagPolicy::DropNewest {
+                drop(state);
+                self.record_drop();
+                return;
+            }
+            state.events.pop_front();
+            self.record_drop();
+        }
+        state.events.push_back(event);
+        drop(state);
+        self.not_empty.notify_one();
+    }
+
+    fn record_drop(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+        MetricsManager::current().increment_dropped_events();
+    }
+
+    fn pop(&self) -> Option<DexEvent> {
+        let event = self.state.lock().unwrap_or_else(|e| e.into_inner()).events.pop_front();
+        if event.is_some() {
+            self.not_full.notify_one();
+        }
+        event
+    }
+
+    fn is_closed(&self) -> bool {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).closed
+    }
+
+    /// 关闭队列并唤醒双方，已缓冲的事件仍可取出
+    fn close(&self) {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).closed = true;
+        self.not_full.notify_all();
+        self.not_empty.notify_one();
+    }
+}
+
+struct Subscriber {
+    id: u64,
+    topics: Vec<EventTopic>,
+    queue: Arc<SubscriberQueue>,
+}
+
+impl Drop for Subscriber {
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
+#[derive(Default)]
+struct EventBusInner {
+    subscribers: RwLock<Vec<Subscriber>>,
+    next_id: AtomicU64,
+}
+
+impl EventBusInner {
+    fn unsubscribe(&self, id: u64) -> bool {
+        let mut subscribers = self.subscribers.write().unwrap_or_else(|e| e.into_inner());
+        let before = subscribers.len();
+        subscribers.retain(|subscriber| subscriber.id != id);
+        subscribers.len() != before
+    }
+}
+
+/// 进程内事件总线
+///
+/// 订阅回调只需把事件交给总线一次（见 `callback`），各组件按主题订阅并通过有界通道接收匹配的事件，
+/// 避免每个组件各自注册回调再重复过滤。订阅者的通道已满时按其 `LagPolicy` 等待或丢弃事件，
+/// 丢弃的事件计入丢弃指标。订阅与退订可在运行时随时进行，克隆共享同一组订阅者
+#[derive(Clone, Default)]
+pub struct EventBus {
+    inner: Arc<EventBusInner>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 订阅任一主题匹配的事件，`capacity` 为通道容量（至少为 1），通道已满时立即丢弃新事件，
+    /// 不会阻塞解析线程
+    pub fn subscribe(&self, topics: Vec<EventTopic>, capacity: usize) -> BusSubscription {
+        self.subscribe_with_lag_policy(topics, capacity, LagPolicy::DropNewest, Duration::ZERO)
+    }
+
+    /// 订阅任一主题匹配的事件，并指定通道已满时的处理策略
+    ///
+    /// 通道持续已满不超过 `lag_timeout` 时发布方等待空位，超过后按 `policy` 丢弃事件，
+    /// 直到通道重新出现空位；`LagPolicy::Block` 忽略 `lag_timeout`，一直等待。
+    /// 等待会阻塞发布事件的线程（即订阅任务），接收方需运行在其它线程上，
+    /// 例如多线程 tokio 运行时中的另一个任务
+    pub fn subscribe_with_lag_policy(
+        &self,
+        topics: Vec<EventTopic>,
+        capacity: usize,
+        policy: LagPolicy,
+        lag_timeout: Duration,
+    ) -> BusSubscription {
+        let queue = Arc::new(SubscriberQueue::new(capacity.max(1), policy, lag_timeout));
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        self.inner.subscribers.write().unwrap_or_else(|e| e.into_inner()).push(Subscriber {
+            id,
+            topics,
+            queue: queue.clone(),
+        });
+        BusSubscription { id, queue, bus: Arc::downgrade(&self.inner) }
+    }
+
+    /// 按订阅 ID 退订，订阅不存在时返回 false
+    pub fn unsubscribe(&self, id: u64) -> bool {
+        self.inner.unsubscribe(id)
+    }
+
+    /// 当前订阅者数量
+    pub fn subscriber_count(&self) -> usize {
+        self.inner.subscribers.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 将事件分发给所有匹配的订阅者
+    pub fn publish(&self, event: DexEvent) {
+        // 先释放订阅者列表的读锁，等待空位时不阻塞订阅与退订
+        let queues: Vec<Arc<SubscriberQueue>> = {
+            let subscribers = self.inner.subscribers.read().unwrap_or_else(|e| e.into_inner());
+            subscribers
+                .iter()
+                .filter(|subscriber| subscriber.topics.iter().any(|topic| topic.matches(&event)))
+                .map(|subscriber| subscriber.queue.clone())
+                .collect()
+        };
+        for queue in queues {
+            queue.push(event.clone());
+        }
+    }
+
+    /// 返回向总线发布事件的回调，可直接传给 `subscribe_events_immediate` 等订阅方法
+    pub fn callback(&self) -> impl Fn(DexEvent) + Send + Sync + 'static {
+        let bus = self.clone();
+        move |event: DexEvent| bus.publish(event)
+    }
+}
+
+/// 事件总线上的一个订阅，drop 时自动退订
+pub struct BusSubscription {
+    id: u64,
+    queue: Arc<SubscriberQueue>,
+    bus: Weak<EventBusInner>,
+}
+
+impl BusSubscription {
+    /// 订阅 ID，可用于 `EventBus::unsubscribe`
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 等待下一个事件，退订后缓冲的事件取完时返回 None
+    pub async fn recv(&mut self) -> Option<DexEvent> {
+        loop {
+            // 先读取关闭状态再出队，关闭前入队的事件不会遗漏
+            let closed = self.queue.is_closed();
+            if let Some(event) = self.queue.pop() {
+                return Some(event);
+            }
+            if closed {
+                return None;
+            }
+            self.queue.not_empty.notified().await;
+        }
+    }
+
+    /// 非阻塞获取下一个事件
+    pub fn try_recv(&mut self) -> Option<DexEvent> {
+        self.queue.pop()
+    }
+
+    /// 因通道已满而丢弃的事件数
+    pub fn dropped_count(&self) -> u64 {
+        self.queue.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for BusSubscription {
+    fn drop(&mut self) {
+        // 先关闭队列，唤醒可能正在等待空位的发布方
+        self.queue.close();
+        if let Some(bus) = self.bus.upgrade() {
+            bus.unsubscribe(self.id);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::borrow::Borrow;
+
+use super::trade::UnifiedTrade;
+use crate::streaming::event_parser::common::{EventType, ProtocolType};
+use crate::streaming::event_parser::DexEvent;
+
+/// 事件迭代器适配器，用于批量解析、回放等离线分析场景
+///
+/// 适用于任意产出 `DexEvent` 或 `&DexEvent` 的迭代器，可与标准迭代器组合子混用，例如
+/// `events.iter().by_protocol(ProtocolType::PumpFun).trades_only().count()`
+pub trait DexEventIterExt<T: Borrow<DexEvent>>: Iterator<Item = T> + Sized {
+    /// 只保留指定协议的事件
+    fn by_protocol(self, protocol: ProtocolType) -> impl Iterator<Item = T> {
+        self.filter(move |event| *as_event(event).protocol() == protocol)
+    }
+
+    /// 只保留指定类型的事件
+    fn by_event_type(self, event_type: EventType) -> impl Iterator<Item = T> {
+        self.filter(move |event| *as_event(event).event_type() == event_type)
+    }
+
+    /// 只保留类型属于 `event_types` 之一的事件
+    fn by_event_types(
+        self,
+        event_types: impl IntoIterator<Item = EventType>,
+    ) -> impl Iterator<Item = T> {
+        let event_types: Vec<EventType> = event_types.into_iter().collect();
+        self.filter(move |event| event_types.contains(as_event(event).event_type()))
+    }
+
+    /// 只保留成交事件，见 `DexEvent::is_trade`
+    fn trades_only(self) -> impl Iterator<Item = T> {
+        self.filter(|event| as_event(event).is_trade())
+    }
+
+    /// 把成交事件转换为 `UnifiedTrade`，跳过非成交事件与缺少成交数量的事件
+    fn unified_trades(self) -> impl Iterator<Item = UnifiedTrade> {
+        self.filter_map(|event| UnifiedTrade::from_event(as_event(&event)))
+    }
+}
+
+impl<I, T> DexEventIterExt<T> for I
+where
+    I: Iterator<Item = T>,
+    T: Borrow<DexEvent>,
+{
+}
+
+fn as_event<T: Borrow<DexEvent>>(event: &T) -> &DexEvent {
+    <T as Borrow<DexEvent>>::borrow(event)
+}
@@ -1,14 +1,384 @@
 use crate::common::AnyResult;
-use crate::streaming::common::MetricsEventType;
+use crate::streaming::common::{
+    CancellationToken, LruCache, MetricsEventType, PubkeySet, StreamClientConfig,
+    SubscriptionMetrics, UnifiedTrade, CALLBACK_THREAD_NAME, CALLBACK_WATCHDOG_THREAD_NAME,
+    DEFAULT_CALLBACK_QUEUE_CAPACITY, LOG_TARGET,
+};
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
-use crate::streaming::event_parser::core::account_event_parser::AccountEventParser;
-use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
+use crate::streaming::event_parser::core::account_event_parser::{
+    AccountEventParser, AccountStateCache,
+};
+use crate::streaming::event_parser::core::common_event_parser::{
+    CommonEventParser, VOTE_PROGRAM_ID,
+};
 use crate::streaming::event_parser::core::event_parser::EventParser;
+use crate::streaming::event_parser::core::{MalformedReason, ParseConfig};
 use crate::streaming::event_parser::{core::traits::DexEvent, Protocol};
 use crate::streaming::grpc::{EventPretty, MetricsManager};
 use crate::streaming::shred::TransactionWithSlot;
 use solana_sdk::pubkey::Pubkey;
-use std::sync::Arc;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::runtime::{Handle, RuntimeFlavor};
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/// 事件增强钩子
+///
+/// 在 `process_event` 之后、用户 callback 之前对事件原地修改（此时 CPI 合并和 swap_data 已完成）。
+/// 钩子运行在解析线程上，必须足够快，否则会拖慢后续所有事件的处理。
+pub type EventEnricher = Arc<dyn Fn(&mut DexEvent) + Send + Sync>;
+
+/// 按客户端配置组装用户 callback：先执行增强钩子，再调用用户 callback，整体可选 panic 保护
+///
+/// 配置了 `callback_threads` 时用户 callback 运行在专用线程上（各线程分别做 panic 保护），
+/// 增强钩子与成交过滤仍在解析侧执行；开启 `strict_ordering` 时只使用一个线程以保持数据流顺序。
+/// 执行超时告警只计量用户 callback 本身。
+/// `cancellation` 取消（订阅停止）后产生的事件以及 callback 线程中排队的事件直接丢弃
+pub fn wrap_user_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    config: &StreamClientConfig,
+    enricher: Option<EventEnricher>,
+    metrics_manager: &MetricsManager,
+    cancellation: &CancellationToken,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    let callback = match config.callback_warn_threshold_ms {
+        Some(threshold_ms) => {
+            create_watchdog_callback(callback, Duration::from_millis(threshold_ms), metrics_manager)
+        }
+        None => callback,
+    };
+    let callback = match config.callback_threads {
+        Some(threads) => {
+            // 多个线程会打乱不同交易之间的交付顺序，严格顺序模式下只用一个线程
+            let threads = if config.strict_ordering && threads > 1 {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "callback_threads = {threads} with strict_ordering enabled, \
+                     running the callback on a single thread to keep stream order"
+                );
+                1
+            } else {
+                threads
+            };
+            let callback = if config.catch_callback_panics {
+                create_panic_safe_callback(callback)
+            } else {
+                callback
+            };
+            create_threaded_callback(callback, threads, metrics_manager, cancellation)
+        }
+        None => callback,
+    };
+    let callback = match enricher {
+        Some(enricher) => create_enriched_callback(callback, enricher),
+        None => callback,
+    };
+    let callback = if config.parse.first_trade_per_mint {
+        create_first_trade_callback(callback, config.parse.first_trade_cache_size)
+    } else {
+        callback
+    };
+    // 年龄过滤在首笔成交过滤之前执行，两者同时开启时放行达到最小年龄后的首笔成交
+    let callback = match (config.parse.min_pool_age_slots, config.parse.max_pool_age_slots) {
+        (None, None) => callback,
+        (min_age, max_age) => {
+            create_pool_age_callback(callback, min_age, max_age, config.parse.pool_age_cache_size)
+        }
+    };
+    let callback =
+        if config.catch_callback_panics { create_panic_safe_callback(callback) } else { callback };
+    create_cancellable_callback(callback, cancellation.clone())
+}
+
+/// 创建在 `cancellation` 取消后丢弃事件的 callback 包装器
+///
+/// 订阅停止时仍在运行的解析任务不再执行增强钩子、过滤与用户 callback
+pub fn create_cancellable_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    cancellation: CancellationToken,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    Arc::new(move |event: DexEvent| {
+        if !cancellation.is_cancelled() {
+            callback(event);
+        }
+    })
+}
+
+/// 创建把事件交给专用线程执行 callback 的包装器
+///
+/// 调用方只把事件放入队列即返回；同一交易（按签名）的事件进入同一线程，保持原有顺序。
+/// 队列满时调用方等待，形成背压；在 tokio 多线程运行时上通过 `block_in_place` 等待，
+/// 不占用运行时的工作线程。包装器释放（订阅结束）后队列关闭，线程处理完剩余事件后退出；
+/// `cancellation` 取消时线程立即退出，队列中剩余的事件计入丢弃事件。
+/// 线程创建失败时记录警告，callback 改为在调用方直接执行
+pub fn create_threaded_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    threads: usize,
+    metrics_manager: &MetricsManager,
+    cancellation: &CancellationToken,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    let mut senders = Vec::with_capacity(threads.max(1));
+    for index in 0..threads.max(1) {
+        let (sender, receiver) = mpsc::sync_channel::<DexEvent>(DEFAULT_CALLBACK_QUEUE_CAPACITY);
+        let callback = callback.clone();
+        let metrics_manager = metrics_manager.clone();
+        let cancellation = cancellation.clone();
+        let spawned = std::thread::Builder::new()
+            .name(format!("{CALLBACK_THREAD_NAME}-{index}"))
+            .spawn(move || {
+                metrics_manager.sync_scope(|| {
+                    for event in &receiver {
+                        if cancellation.is_cancelled() {
+                            // 当前事件与队列中剩余的积压事件都不再交付
+                            let dropped = 1 + receiver.try_iter().count() as u64;
+                            metrics_manager.increment_dropped_events_by(dropped);
+                            break;
+                        }
+                        callback(event);
+                    }
+                })
+            });
+        if let Err(e) = spawned {
+            log::warn!(
+                target: LOG_TARGET,
+                "Failed to start callback thread: {e}, running the callback on the stream task"
+            );
+            return callback;
+        }
+        senders.push(sender);
+    }
+    Arc::new(move |event: DexEvent| {
+        let sender = match senders.as_slice() {
+            [sender] => sender,
+            senders => {
+                let signature = event.metadata().signature;
+                let mut prefix = [0u8; 8];
+                prefix.copy_from_slice(&signature.as_ref()[..8]);
+                &senders[(u64::from_le_bytes(prefix) % senders.len() as u64) as usize]
+            }
+        };
+        // 线程只会因 callback panic（未开启 catch_callback_panics）而提前退出
+        let sent = match sender.try_send(event) {
+            Ok(()) => Ok(()),
+            Err(mpsc::TrySendError::Full(event)) => match Handle::try_current() {
+                Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                    tokio::task::block_in_place(|| sender.send(event))
+                }
+                _ => sender.send(event),
+            },
+            Err(mpsc::TrySendError::Disconnected(event)) => Err(mpsc::SendError(event)),
+        };
+        if sent.is_err() {
+            MetricsManager::current().increment_dropped_events();
+        }
+    })
+}
+
+/// 创建先执行增强钩子再调用原始 callback 的包装器
+pub fn create_enriched_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    enricher: EventEnricher,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    Arc::new(move |mut event: DexEvent| {
+        enricher(&mut event);
+        callback(event);
+    })
+}
+
+/// 创建每个 mint 只放行首笔成交的 callback 包装器
+///
+/// 按 `UnifiedTrade` 的 base mint 判断，已见过的 mint 记录在有界 LRU 中；非成交事件直接放行
+pub fn create_first_trade_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    cache_size: usize,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    let seen_mints = Mutex::new(LruCache::new(cache_size));
+    Arc::new(move |event: DexEvent| {
+        if let Some(trade) = UnifiedTrade::from_event(&event) {
+            let mut seen_mints = seen_mints.lock().unwrap_or_else(|e| e.into_inner());
+            if seen_mints.put(trade.base_mint, ()).is_some() {
+                return;
+            }
+        }
+        callback(event);
+    })
+}
+
+/// 创建按池子年龄过滤成交的 callback 包装器
+///
+/// 按 `UnifiedTrade` 的 base mint 在有界 LRU 中记录首次见到成交的槽位，
+/// 成交槽位与其相差（年龄）不在 `[min_age_slots, max_age_slots]` 内的成交被丢弃；非成交事件直接放行
+pub fn create_pool_age_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    min_age_slots: Option<u64>,
+    max_age_slots: Option<u64>,
+    cache_size: usize,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    let first_seen_slots = Mutex::new(LruCache::new(cache_size));
+    Arc::new(move |event: DexEvent| {
+        if let Some(trade) = UnifiedTrade::from_event(&event) {
+            let age = {
+                let mut first_seen_slots =
+                    first_seen_slots.lock().unwrap_or_else(|e| e.into_inner());
+                let first_seen = match first_seen_slots.get(&trade.base_mint) {
+                    Some(&first_seen) => first_seen,
+                    None => {
+                        first_seen_slots.put(trade.base_mint, trade.slot);
+                        trade.slot
+                    }
+                };
+                // 并发解析时较早槽位的成交可能后到，年龄按 0 计
+                trade.slot.saturating_sub(first_seen)
+            };
+            if min_age_slots.is_some_and(|min| age < min)
+                || max_age_slots.is_some_and(|max| age > max)
+            {
+                return;
+            }
+        }
+        callback(event);
+    })
+}
+
+/// 创建统计订阅事件数的 callback 包装器，每个交给 callback 的事件计入订阅指标
+pub fn create_subscription_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    metrics: Arc<SubscriptionMetrics>,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    Arc::new(move |event: DexEvent| {
+        metrics.record_event();
+        callback(event);
+    })
+}
+
+// 执行中的 callback 调用
+struct InFlightCallback {
+    started: Instant,
+    signature: Signature,
+    warned: bool,
+}
+
+// 调用结束（包括 panic 展开）时移除执行记录，看门狗尚未告警的超时调用在此补报
+struct InFlightGuard<'a> {
+    in_flight: &'a Mutex<HashMap<u64, InFlightCallback>>,
+    id: u64,
+    threshold: Duration,
+    metrics_manager: &'a MetricsManager,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        let call = self.in_flight.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.id);
+        let Some(call) = call else {
+            return;
+        };
+        let elapsed = call.started.elapsed();
+        if !call.warned && elapsed >= self.threshold {
+            self.metrics_manager.increment_slow_callbacks();
+            log::warn!(
+                target: LOG_TARGET,
+                "Callback took {:?} while handling event {} (threshold {:?})",
+                elapsed,
+                call.signature,
+                self.threshold
+            );
+        }
+    }
+}
+
+/// 创建 callback 执行超时告警包装器
+///
+/// 单次调用超过 `threshold` 时记录警告（附带事件签名）并增加 `slow_callbacks_count`，
+/// 不会中断 callback。看门狗线程每 `threshold / 2` 巡检一次执行中的调用，卡住不返回的 callback 也能及时告警；
+/// 每次调用最多告警一次。包装器释放后看门狗线程退出
+pub fn create_watchdog_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    threshold: Duration,
+    metrics_manager: &MetricsManager,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    let in_flight: Arc<Mutex<HashMap<u64, InFlightCallback>>> = Arc::default();
+    let watched = Arc::downgrade(&in_flight);
+    let watchdog_metrics = metrics_manager.clone();
+    let interval = (threshold / 2).max(Duration::from_millis(1));
+    let spawned = std::thread::Builder::new()
+        .name(CALLBACK_WATCHDOG_THREAD_NAME.to_string())
+        .spawn(move || loop {
+            std::thread::sleep(interval);
+            let Some(in_flight) = watched.upgrade() else {
+                break;
+            };
+            let mut in_flight = in_flight.lock().unwrap_or_else(|e| e.into_inner());
+            for call in in_flight.values_mut() {
+                let elapsed = call.started.elapsed();
+                if !call.warned && elapsed >= threshold {
+                    call.warned = true;
+                    watchdog_metrics.increment_slow_callbacks();
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Callback still running after {:?} on event {} (threshold {:?})",
+                        elapsed,
+                        call.signature,
+                        threshold
+                    );
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        log::warn!(
+            target: LOG_TARGET,
+            "Failed to start callback watchdog thread: {e}, slow callbacks are reported on return"
+        );
+    }
+
+    let metrics_manager = metrics_manager.clone();
+    let next_id = AtomicU64::new(0);
+    Arc::new(move |event: DexEvent| {
+        let id = next_id.fetch_add(1, Ordering::Relaxed);
+        let call = InFlightCallback {
+            started: Instant::now(),
+            signature: event.metadata().signature,
+            warned: false,
+        };
+        in_flight.lock().unwrap_or_else(|e| e.into_inner()).insert(id, call);
+        let _guard = InFlightGuard {
+            in_flight: &in_flight,
+            id,
+            threshold,
+            metrics_manager: &metrics_manager,
+        };
+        callback(event);
+    })
+}
+
+/// 创建带 panic 保护的 callback 包装器
+///
+/// 用户 callback 中的 panic 会被捕获并记录（附带事件签名），不会中断订阅任务
+pub fn create_panic_safe_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    Arc::new(move |event: DexEvent| {
+        let signature = event.metadata().signature;
+        if let Err(panic) = std::panic::catch_unwind(AssertUnwindSafe(|| callback(event))) {
+            MetricsManager::current().increment_callback_panics();
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            log::warn!(
+                target: LOG_TARGET,
+                "Callback panicked while handling event {}: {}",
+                signature,
+                message
+            );
+        }
+    })
+}
 
 /// 创建带 metrics 统计的 callback 包装器
 ///
@@ -22,9 +392,14 @@ fn create_metrics_callback(
         let processing_time_us = metadata.handle_us as f64;
         let recv_us = metadata.recv_us;
         let block_time_ms = metadata.block_time_ms;
+        let server_ts_us = metadata.server_ts_us;
 
         callback(event);
 
+        if let Some(server_ts_us) = server_ts_us {
+            MetricsManager::current().record_provider_delay(recv_us, server_ts_us);
+        }
+
         update_metrics_with_latency(
             MetricsEventType::Transaction,
             1,
@@ -36,21 +411,28 @@ fn create_metrics_callback(
 }
 
 /// Process GRPC transaction events
+///
+/// `account_state` holds the subscription's last seen account states for stateful account
+/// parsing (account diff mode)
 pub async fn process_grpc_transaction(
     event_pretty: EventPretty,
     protocols: &[Protocol],
     event_type_filter: Option<&EventTypeFilter>,
     callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     bot_wallet: Option<Pubkey>,
+    parse_config: &ParseConfig,
+    account_state: &AccountStateCache,
 ) -> AnyResult<()> {
     match event_pretty {
         EventPretty::Account(account_pretty) => {
-            MetricsManager::global().add_account_process_count();
+            MetricsManager::current().add_account_process_count();
 
-            let account_event = AccountEventParser::parse_account_event(
+            let account_event = AccountEventParser::parse_account_event_with_config(
                 protocols,
                 account_pretty,
                 event_type_filter,
+                parse_config,
+                account_state,
             );
 
             if let Some(event) = account_event {
@@ -60,7 +442,19 @@ pub async fn process_grpc_transaction(
             }
         }
         EventPretty::Transaction(transaction_pretty) => {
-            MetricsManager::global().add_tx_process_count();
+            if !parse_config.slot_in_range(transaction_pretty.slot) {
+                return Ok(());
+            }
+            // 投票交易不会命中任何 DEX 协议，默认直接跳过
+            if transaction_pretty.is_vote && !parse_config.include_vote_transactions {
+                return Ok(());
+            }
+            if let Some(account_include) = &parse_config.account_include {
+                if !grpc_transaction_touches(&transaction_pretty.grpc_tx, account_include) {
+                    return Ok(());
+                }
+            }
+            MetricsManager::current().add_tx_process_count();
 
             let slot = transaction_pretty.slot;
             let signature = transaction_pretty.signature;
@@ -71,22 +465,41 @@ pub async fn process_grpc_transaction(
 
             let adapter_callback = create_metrics_callback(callback.clone());
 
-            EventParser::parse_grpc_transaction(
-                protocols,
-                event_type_filter,
-                grpc_tx,
-                signature,
-                Some(slot),
-                block_time,
-                recv_us,
-                bot_wallet,
-                tx_index,
-                adapter_callback,
-            )
-            .await?;
+            // 只订阅一个协议时走单协议快速路径，跳过逐个程序 ID 的协议匹配
+            if let [protocol] = protocols {
+                EventParser::parse_single_protocol(
+                    protocol.clone(),
+                    event_type_filter,
+                    grpc_tx,
+                    signature,
+                    Some(slot),
+                    block_time,
+                    recv_us,
+                    bot_wallet,
+                    tx_index,
+                    adapter_callback,
+                    parse_config,
+                )
+                .await?;
+            } else {
+                EventParser::parse_grpc_transaction_with_config(
+                    protocols,
+                    event_type_filter,
+                    grpc_tx,
+                    signature,
+                    Some(slot),
+                    block_time,
+                    recv_us,
+                    bot_wallet,
+                    tx_index,
+                    adapter_callback,
+                    parse_config,
+                )
+                .await?;
+            }
         }
         EventPretty::BlockMeta(block_meta_pretty) => {
-            MetricsManager::global().add_block_meta_process_count();
+            MetricsManager::current().add_block_meta_process_count();
 
             let block_time_ms = block_meta_pretty
                 .block_time
@@ -109,6 +522,15 @@ pub async fn process_grpc_transaction(
             callback(block_meta_event);
             update_metrics(MetricsEventType::BlockMeta, 1, processing_time_us);
         }
+        EventPretty::SlotStatus(slot_pretty) => {
+            callback(CommonEventParser::generate_slot_status_event(
+                slot_pretty.slot,
+                slot_pretty.parent,
+                slot_pretty.status,
+                slot_pretty.dead_error,
+                slot_pretty.recv_us,
+            ));
+        }
     }
 
     Ok(())
@@ -121,14 +543,32 @@ pub async fn process_shred_transaction(
     event_type_filter: Option<&EventTypeFilter>,
     callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     bot_wallet: Option<Pubkey>,
+    parse_config: &ParseConfig,
 ) -> AnyResult<()> {
-    MetricsManager::global().add_tx_process_count();
-
+    if !parse_config.slot_in_range(transaction_with_slot.slot) {
+        return Ok(());
+    }
     let tx = transaction_with_slot.transaction;
+    // 投票交易不会命中任何 DEX 协议，默认直接跳过
+    if !parse_config.include_vote_transactions && is_vote_transaction(&tx) {
+        return Ok(());
+    }
+    if let Some(account_include) = &parse_config.account_include {
+        if !account_include.contains_any(tx.message.static_account_keys()) {
+            return Ok(());
+        }
+    }
+    MetricsManager::current().add_tx_process_count();
+
     let slot = transaction_with_slot.slot;
     let tx_index = transaction_with_slot.tx_index;
 
     if tx.signatures.is_empty() {
+        parse_config.malformed_transaction_policy.report(
+            Signature::default(),
+            Some(slot),
+            MalformedReason::MissingSignature,
+        );
         return Ok(());
     }
 
@@ -140,7 +580,7 @@ pub async fn process_shred_transaction(
     // 若交易使用 ALT，账户可能为 default/错误；无 CPI 合并，timestamp/reserves 等多为 0。
     let accounts = tx.message.static_account_keys();
 
-    EventParser::parse_instruction_events_from_versioned_transaction(
+    EventParser::parse_instruction_events_from_versioned_transaction_with_config(
         protocols,
         event_type_filter,
         &tx,
@@ -153,16 +593,49 @@ pub async fn process_shred_transaction(
         bot_wallet,
         tx_index,
         adapter_callback,
+        parse_config,
     )
     .await?;
 
     Ok(())
 }
 
+/// 判断交易是否为投票交易（任一指令调用了 Vote 程序）
+#[inline]
+fn is_vote_transaction(tx: &VersionedTransaction) -> bool {
+    let account_keys = tx.message.static_account_keys();
+    tx.message.instructions().iter().any(|instruction| {
+        account_keys.get(instruction.program_id_index as usize) == Some(&VOTE_PROGRAM_ID)
+    })
+}
+
+/// 判断 gRPC 交易的账户（含 ALT 加载的地址）是否命中白名单
+#[inline]
+fn grpc_transaction_touches(
+    grpc_tx: &SubscribeUpdateTransactionInfo,
+    account_include: &PubkeySet,
+) -> bool {
+    let static_keys = grpc_tx
+        .transaction
+        .as_ref()
+        .and_then(|tx| tx.message.as_ref())
+        .map(|message| message.account_keys.as_slice())
+        .unwrap_or_default();
+    let loaded = grpc_tx
+        .meta
+        .as_ref()
+        .map(|meta| {
+            meta.loaded_writable_addresses.iter().chain(meta.loaded_readonly_addresses.iter())
+        })
+        .into_iter()
+        .flatten();
+    static_keys.iter().chain(loaded).any(|account| account_include.contains_bytes(account))
+}
+
 /// Update metrics for event processing (with optional latency check)
 #[inline]
 fn update_metrics(ty: MetricsEventType, count: u64, time_us: f64) {
-    MetricsManager::global().update_metrics(ty, count, time_us);
+    MetricsManager::current().update_metrics(ty, count, time_us);
 }
 
 /// Update metrics with latency check
@@ -174,5 +647,5 @@ fn update_metrics_with_latency(
     recv_us: i64,
     block_time_ms: i64,
 ) {
-    MetricsManager::global().update_metrics_with_latency(ty, count, time_us, recv_us, block_time_ms);
+    MetricsManager::current().update_metrics_with_latency(ty, count, time_us, recv_us, block_time_ms);
 }
@@ -0,0 +1,41 @@
+use std::fmt;
+use std::sync::Arc;
+
+use super::constants::LOG_TARGET;
+use super::metrics::SubscriptionMetrics;
+use super::subscription::SubscriptionId;
+
+/// 可恢复的错误（订阅继续运行）：warn 级别输出简要信息，完整的调试格式在 debug 级别输出
+pub(crate) fn log_recoverable<E: fmt::Display + fmt::Debug>(context: &str, error: &E) {
+    log::warn!(target: LOG_TARGET, "{context}: {error}");
+    log::debug!(target: LOG_TARGET, "{context}: {error:?}");
+}
+
+/// 致命错误（订阅终止）：error 级别输出简要信息，完整的调试格式在 debug 级别输出
+pub(crate) fn log_fatal<E: fmt::Display + fmt::Debug>(context: &str, error: &E) {
+    log::error!(target: LOG_TARGET, "{context}: {error}");
+    log::debug!(target: LOG_TARGET, "{context}: {error:?}");
+}
+
+/// 订阅任务的错误日志：消息前加上订阅 ID，并计入该订阅的错误数
+#[derive(Clone)]
+pub(crate) struct SubscriptionLogger {
+    id: SubscriptionId,
+    metrics: Arc<SubscriptionMetrics>,
+}
+
+impl SubscriptionLogger {
+    pub(crate) fn new(id: SubscriptionId, metrics: Arc<SubscriptionMetrics>) -> Self {
+        Self { id, metrics }
+    }
+
+    pub(crate) fn recoverable<E: fmt::Display + fmt::Debug>(&self, context: &str, error: &E) {
+        self.metrics.record_error();
+        log_recoverable(&format!("[{}] {context}", self.id), error);
+    }
+
+    pub(crate) fn fatal<E: fmt::Display + fmt::Debug>(&self, context: &str, error: &E) {
+        self.metrics.record_error();
+        log_fatal(&format!("[{}] {context}", self.id), error);
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// 有界 LRU 缓存
+///
+/// 超出容量时淘汰最久未访问的条目。非线程安全，跨线程使用时由调用方加锁。
+#[derive(Debug)]
+pub struct LruCache<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    order: BTreeMap<u64, K>,
+    tick: u64,
+    capacity: usize,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// 创建指定容量的缓存（容量至少为 1）
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// 获取值并标记为最近访问
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// 获取可变引用并标记为最近访问
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (value, last_used) = self.entries.get_mut(key)?;
+        self.order.remove(last_used);
+        *last_used = tick;
+        self.order.insert(tick, key.clone());
+        Some(value)
+    }
+
+    /// 获取值（不影响访问顺序）
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    /// 是否包含某个键（不影响访问顺序）
+    pub fn contains(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// 插入或更新值，返回旧值
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.tick += 1;
+        let tick = self.tick;
+        let previous = match self.entries.insert(key.clone(), (value, tick)) {
+            Some((previous, last_used)) => {
+                self.order.remove(&last_used);
+                Some(previous)
+            }
+            None => None,
+        };
+        self.order.insert(tick, key);
+        self.evict();
+        previous
+    }
+
+    /// 调整容量，缩小时立即淘汰多余条目
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.evict();
+    }
+
+    /// 当前容量
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 当前条目数
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 遍历所有条目（顺序不确定）
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, (value, _))| (key, value))
+    }
+
+    /// 清空缓存
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            match self.order.pop_first() {
+                Some((_, key)) => {
+                    self.entries.remove(&key);
+                }
+                None => break,
+            }
+        }
+    }
+}
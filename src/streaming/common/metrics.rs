@@ -1,6 +1,16 @@
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::task::futures::TaskLocalFuture;
 
 use super::constants::*;
+#[cfg(feature = "unhandled-programs")]
+use super::lru::LruCache;
+use super::subscription::SubscriptionId;
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+#[cfg(feature = "unhandled-programs")]
+use solana_sdk::pubkey::Pubkey;
 
 /// Event type enumeration
 #[derive(Debug, Clone, Copy)]
@@ -171,6 +181,32 @@ pub struct PerformanceMetrics {
     pub block_meta_metrics: EventMetricsSnapshot,
     pub processing_stats: ProcessingTimeStats,
     pub dropped_events_count: u64,
+    pub decode_failures_count: u64,
+    pub callback_panics_count: u64,
+    /// Callback invocations that ran longer than `StreamClientConfig::callback_warn_threshold_ms`
+    pub slow_callbacks_count: u64,
+    /// Transactions skipped because they could not be processed, see
+    /// `ParseConfig::malformed_transaction_policy`
+    pub malformed_transactions_count: u64,
+    /// Protocol parser panics caught while decoding (see `ParseConfig::catch_parser_panics`)
+    pub parser_panics_count: u64,
+    /// Account events replaced by a newer update of the same account in `AccountCoalescer`
+    pub coalesced_events_count: u64,
+    pub oversized_instructions_count: u64,
+    /// Instructions referencing account indexes beyond the transaction's account list,
+    /// padded with the default pubkey or skipped depending on `ParseConfig::missing_account_mode`
+    pub missing_account_instructions_count: u64,
+    pub inner_scan_cap_hits_count: u64,
+    /// Average provider-to-client delay (`recv_us - server_ts_us`) in microseconds
+    pub provider_delay_avg_us: f64,
+    /// Transactions currently being parsed when `max_concurrent_parses` is set
+    pub parse_in_flight: u64,
+    /// ShredStream entry deserialization time per message, reported separately from
+    /// `processing_stats` (protocol parsing)
+    pub decode_stats: ProcessingTimeStats,
+    /// Microseconds from calling subscribe to the first event handed to the callback, for the
+    /// most recent subscription; None before its first event or when no subscription is active
+    pub time_to_first_event_us: Option<i64>,
 }
 
 impl PerformanceMetrics {
@@ -190,10 +226,109 @@ impl PerformanceMetrics {
             block_meta_metrics: default_metrics,
             processing_stats: default_stats,
             dropped_events_count: 0,
+            decode_failures_count: 0,
+            callback_panics_count: 0,
+            slow_callbacks_count: 0,
+            malformed_transactions_count: 0,
+            parser_panics_count: 0,
+            coalesced_events_count: 0,
+            oversized_instructions_count: 0,
+            missing_account_instructions_count: 0,
+            inner_scan_cap_hits_count: 0,
+            provider_delay_avg_us: 0.0,
+            parse_in_flight: 0,
+            decode_stats: ProcessingTimeStats { last_us: 0.0, avg_us: 0.0 },
+            time_to_first_event_us: None,
         }
     }
 }
 
+/// Counters of a single subscription, registered under its `SubscriptionId`
+///
+/// Unlike the process-wide `MetricsManager` counters these are kept per subscription, so a
+/// stream that errors or stops receiving updates can be identified when several run at once.
+#[derive(Debug, Default)]
+pub struct SubscriptionMetrics {
+    updates_received: AtomicU64,
+    events_emitted: AtomicU64,
+    errors: AtomicU64,
+    last_update_us: AtomicI64,
+    subscribed_us: AtomicI64,
+    first_event_us: AtomicI64,
+}
+
+impl SubscriptionMetrics {
+    /// 创建订阅指标，`subscribed_us` 为调用订阅方法的时间
+    pub fn new(subscribed_us: i64) -> Self {
+        Self { subscribed_us: AtomicI64::new(subscribed_us), ..Default::default() }
+    }
+
+    /// 记录收到一条流消息
+    #[inline]
+    pub fn record_update(&self) {
+        self.updates_received.fetch_add(1, Ordering::Relaxed);
+        self.last_update_us.store(get_high_perf_clock(), Ordering::Relaxed);
+    }
+
+    /// 记录交给 callback 的事件
+    #[inline]
+    pub fn record_event(&self) {
+        // 只有第一个事件记录到达时间
+        if self.events_emitted.fetch_add(1, Ordering::Relaxed) == 0 {
+            self.first_event_us.store(get_high_perf_clock(), Ordering::Relaxed);
+        }
+    }
+
+    /// 记录订阅中的错误（可恢复或致命）
+    #[inline]
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self, id: SubscriptionId) -> SubscriptionMetricsSnapshot {
+        let last_update_us = self.last_update_us.load(Ordering::Relaxed);
+        SubscriptionMetricsSnapshot {
+            id,
+            updates_received: self.updates_received.load(Ordering::Relaxed),
+            events_emitted: self.events_emitted.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            last_update_us: (last_update_us != 0).then_some(last_update_us),
+            time_to_first_event_us: self.time_to_first_event_us(),
+        }
+    }
+
+    fn time_to_first_event_us(&self) -> Option<i64> {
+        let first_event_us = self.first_event_us.load(Ordering::Relaxed);
+        (first_event_us != 0)
+            .then(|| first_event_us.saturating_sub(self.subscribed_us.load(Ordering::Relaxed)))
+    }
+}
+
+/// Per-subscription metrics snapshot
+#[derive(Debug, Clone)]
+pub struct SubscriptionMetricsSnapshot {
+    pub id: SubscriptionId,
+    /// Stream messages received (gRPC updates or ShredStream entry batches)
+    pub updates_received: u64,
+    /// Events handed to the subscription's callback
+    pub events_emitted: u64,
+    /// Errors logged by the subscription, recoverable and fatal
+    pub errors: u64,
+    /// Receive time of the last stream message in microseconds, None before the first one
+    pub last_update_us: Option<i64>,
+    /// Microseconds from calling subscribe to the first event handed to the callback, None
+    /// before the first one
+    pub time_to_first_event_us: Option<i64>,
+}
+
+impl SubscriptionMetricsSnapshot {
+    /// Microseconds since the last stream message, None before the first one; a growing value
+    /// means the subscription stopped receiving updates
+    pub fn idle_us(&self) -> Option<i64> {
+        self.last_update_us.map(|last| get_high_perf_clock() - last)
+    }
+}
+
 /// High-performance metrics system
 #[derive(Debug)]
 pub struct HighPerformanceMetrics {
@@ -202,6 +337,40 @@ pub struct HighPerformanceMetrics {
     processing_stats: AtomicProcessingTimeStats,
     // 丢弃事件指标
     dropped_events_count: AtomicU64,
+    // 解码失败指标
+    decode_failures_count: AtomicU64,
+    // 回调 panic 指标
+    callback_panics_count: AtomicU64,
+    // 回调执行超时指标
+    slow_callbacks_count: AtomicU64,
+    // 无法处理而跳过的交易指标
+    malformed_transactions_count: AtomicU64,
+    // 协议解析器 panic 指标
+    parser_panics_count: AtomicU64,
+    // 账户事件合并指标
+    coalesced_events_count: AtomicU64,
+    // 超大指令指标
+    oversized_instructions_count: AtomicU64,
+    // 账户索引越界指令指标
+    missing_account_instructions_count: AtomicU64,
+    // inner 扫描上限命中指标
+    inner_scan_cap_hits_count: AtomicU64,
+    // gRPC 服务端到客户端延迟指标
+    provider_delay_total_us: AtomicI64,
+    provider_delay_samples: AtomicU64,
+    // 正在并发解析的交易数
+    parse_in_flight: AtomicU64,
+    // ShredStream entries 反序列化耗时
+    decode_stats: AtomicProcessingTimeStats,
+    // 是否记录指标
+    enabled: AtomicBool,
+    // 窗口指标后台任务是否已启动
+    window_task_started: AtomicBool,
+    // 各订阅的指标，订阅开始时注册，`SubscriptionHandle` drop 时移除
+    subscriptions: Mutex<BTreeMap<SubscriptionId, Arc<SubscriptionMetrics>>>,
+    // 未被任何协议处理的程序 ID 出现次数（有界 LRU，首次记录时创建）
+    #[cfg(feature = "unhandled-programs")]
+    unhandled_programs: Mutex<Option<LruCache<Pubkey, u64>>>,
 }
 
 impl HighPerformanceMetrics {
@@ -216,6 +385,24 @@ impl HighPerformanceMetrics {
             ],
             processing_stats: AtomicProcessingTimeStats::new_const(),
             dropped_events_count: AtomicU64::new(0),
+            decode_failures_count: AtomicU64::new(0),
+            callback_panics_count: AtomicU64::new(0),
+            slow_callbacks_count: AtomicU64::new(0),
+            malformed_transactions_count: AtomicU64::new(0),
+            parser_panics_count: AtomicU64::new(0),
+            coalesced_events_count: AtomicU64::new(0),
+            oversized_instructions_count: AtomicU64::new(0),
+            missing_account_instructions_count: AtomicU64::new(0),
+            inner_scan_cap_hits_count: AtomicU64::new(0),
+            provider_delay_total_us: AtomicI64::new(0),
+            provider_delay_samples: AtomicU64::new(0),
+            parse_in_flight: AtomicU64::new(0),
+            decode_stats: AtomicProcessingTimeStats::new_const(),
+            enabled: AtomicBool::new(true),
+            window_task_started: AtomicBool::new(false),
+            subscriptions: Mutex::new(BTreeMap::new()),
+            #[cfg(feature = "unhandled-programs")]
+            unhandled_programs: Mutex::new(None),
         }
     }
 
@@ -266,6 +453,82 @@ impl HighPerformanceMetrics {
         self.dropped_events_count.load(Ordering::Relaxed)
     }
 
+    /// 获取解码失败计数
+    #[inline]
+    pub fn get_decode_failures_count(&self) -> u64 {
+        self.decode_failures_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取回调 panic 计数
+    #[inline]
+    pub fn get_callback_panics_count(&self) -> u64 {
+        self.callback_panics_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取回调执行超时计数
+    #[inline]
+    pub fn get_slow_callbacks_count(&self) -> u64 {
+        self.slow_callbacks_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取无法处理而跳过的交易计数
+    #[inline]
+    pub fn get_malformed_transactions_count(&self) -> u64 {
+        self.malformed_transactions_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取协议解析器 panic 计数
+    #[inline]
+    pub fn get_parser_panics_count(&self) -> u64 {
+        self.parser_panics_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取账户事件合并计数
+    #[inline]
+    pub fn get_coalesced_events_count(&self) -> u64 {
+        self.coalesced_events_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取超大指令计数
+    #[inline]
+    pub fn get_oversized_instructions_count(&self) -> u64 {
+        self.oversized_instructions_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取账户索引越界指令计数
+    #[inline]
+    pub fn get_missing_account_instructions_count(&self) -> u64 {
+        self.missing_account_instructions_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取 inner 扫描上限命中计数
+    #[inline]
+    pub fn get_inner_scan_cap_hits_count(&self) -> u64 {
+        self.inner_scan_cap_hits_count.load(Ordering::Relaxed)
+    }
+
+    /// 获取平均 gRPC 服务端到客户端延迟（微秒）
+    #[inline]
+    pub fn get_provider_delay_avg_us(&self) -> f64 {
+        let samples = self.provider_delay_samples.load(Ordering::Relaxed);
+        if samples == 0 {
+            return 0.0;
+        }
+        self.provider_delay_total_us.load(Ordering::Relaxed) as f64 / samples as f64
+    }
+
+    /// 获取正在并发解析的交易数
+    #[inline]
+    pub fn get_parse_in_flight(&self) -> u64 {
+        self.parse_in_flight.load(Ordering::Relaxed)
+    }
+
+    /// 获取反序列化耗时统计
+    #[inline]
+    pub fn get_decode_stats(&self) -> ProcessingTimeStats {
+        self.decode_stats.get_stats()
+    }
+
     /// 更新窗口指标（后台任务调用）
     fn update_window_metrics(&self, event_type: EventType, window_duration_nanos: u64) {
         let now_nanos =
@@ -285,45 +548,99 @@ impl HighPerformanceMetrics {
 /// Global singleton instance - zero-cost static allocation
 static GLOBAL_METRICS: HighPerformanceMetrics = HighPerformanceMetrics::new_const();
 
-/// Background task initialization flag
-static BACKGROUND_TASK_STARTED: AtomicBool = AtomicBool::new(false);
-
-/// Metrics enabled flag
-static METRICS_ENABLED: AtomicBool = AtomicBool::new(true);
+tokio::task_local! {
+    /// 当前任务使用的指标管理器，由订阅任务通过 `MetricsManager::scope` 设置
+    static CURRENT_METRICS: MetricsManager;
+}
 
-/// 高性能指标管理器 (Singleton)
-#[derive(Clone, Copy)]
-pub struct MetricsManager;
+/// 高性能指标管理器
+///
+/// `global()` 返回进程级默认实例；`new()` 创建独立实例，可通过客户端的 `set_metrics_manager`
+/// 让每个客户端分别统计，或在测试中隔离指标。解析路径上的计数通过 `current()` 记录到
+/// 当前订阅任务的实例，不在订阅任务中时记录到全局实例
+#[derive(Clone, Default)]
+pub struct MetricsManager {
+    // None 表示全局实例（即 `Default`）
+    instance: Option<Arc<HighPerformanceMetrics>>,
+}
 
 impl MetricsManager {
     /// Get global singleton instance (zero-cost)
     #[inline]
     pub const fn global() -> Self {
-        Self
+        Self { instance: None }
+    }
+
+    /// 创建与全局实例及其它实例互不影响的指标实例
+    pub fn new() -> Self {
+        Self { instance: Some(Arc::new(HighPerformanceMetrics::new_const())) }
+    }
+
+    /// 当前任务的指标管理器：在订阅任务中为所属客户端的实例，否则为全局实例
+    #[inline]
+    pub fn current() -> Self {
+        CURRENT_METRICS.try_with(Self::clone).unwrap_or_else(|_| Self::global())
+    }
+
+    /// 在 `future` 执行期间把该实例设为 `current()`
+    pub fn scope<F: Future>(&self, future: F) -> TaskLocalFuture<MetricsManager, F> {
+        CURRENT_METRICS.scope(self.clone(), future)
     }
 
-    /// Initialize and start background task (call once at startup)
+    /// 在同步闭包 `f` 执行期间把该实例设为 `current()`，用于运行在专用线程上的代码
+    pub fn sync_scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        CURRENT_METRICS.sync_scope(self.clone(), f)
+    }
+
+    /// 是否为全局实例
+    pub fn is_global(&self) -> bool {
+        self.instance.is_none()
+    }
+
+    #[inline]
+    fn metrics(&self) -> &HighPerformanceMetrics {
+        self.instance.as_deref().unwrap_or(&GLOBAL_METRICS)
+    }
+
+    /// Initialize the global instance and start its background task (call once at startup)
     pub fn init(enable_metrics: bool) {
-        METRICS_ENABLED.store(enable_metrics, Ordering::Relaxed);
+        Self::global().configure(enable_metrics);
+    }
+
+    /// 启用或禁用该实例的指标记录，启用时启动窗口指标后台任务（每个实例只启动一次）
+    pub fn configure(&self, enable_metrics: bool) {
+        let metrics = self.metrics();
+        metrics.enabled.store(enable_metrics, Ordering::Relaxed);
 
         // Start background task only once
         if enable_metrics
-            && BACKGROUND_TASK_STARTED
+            && metrics
+                .window_task_started
                 .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
                 .is_ok()
         {
-            tokio::spawn(async {
+            // 只持有弱引用，独立实例被释放后任务随之退出
+            let instance = self.instance.as_ref().map(Arc::downgrade);
+            tokio::spawn(async move {
                 let mut interval = tokio::time::interval(std::time::Duration::from_millis(500));
                 loop {
                     interval.tick().await;
+                    let manager = match &instance {
+                        Some(instance) => match instance.upgrade() {
+                            Some(instance) => Self { instance: Some(instance) },
+                            None => break,
+                        },
+                        None => Self::global(),
+                    };
+                    let metrics = manager.metrics();
                     let window_duration_nanos = DEFAULT_METRICS_WINDOW_SECONDS * 1_000_000_000;
 
                     // Update window metrics for all event types
-                    GLOBAL_METRICS
-                        .update_window_metrics(EventType::Transaction, window_duration_nanos);
-                    GLOBAL_METRICS.update_window_metrics(EventType::Account, window_duration_nanos);
-                    GLOBAL_METRICS
-                        .update_window_metrics(EventType::BlockMeta, window_duration_nanos);
+                    for event_type in
+                        [EventType::Transaction, EventType::Account, EventType::BlockMeta]
+                    {
+                        metrics.update_window_metrics(event_type, window_duration_nanos);
+                    }
                 }
             });
         }
@@ -331,14 +648,14 @@ impl MetricsManager {
 
     #[inline]
     fn is_enabled(&self) -> bool {
-        METRICS_ENABLED.load(Ordering::Relaxed)
+        self.metrics().enabled.load(Ordering::Relaxed)
     }
 
     /// 记录处理次数（非阻塞）
     #[inline]
     pub fn record_process(&self, event_type: EventType) {
         if self.is_enabled() {
-            GLOBAL_METRICS.event_metrics[event_type.as_index()].add_process_count();
+            self.metrics().event_metrics[event_type.as_index()].add_process_count();
         }
     }
 
@@ -352,20 +669,25 @@ impl MetricsManager {
         let index = event_type.as_index();
 
         // 原子更新事件计数
-        GLOBAL_METRICS.event_metrics[index].add_events_processed(count);
+        self.metrics().event_metrics[index].add_events_processed(count);
 
         // 原子更新该事件类型的处理时间统计
-        GLOBAL_METRICS.event_metrics[index].update_processing_stats(processing_time_us, count);
+        self.metrics().event_metrics[index].update_processing_stats(processing_time_us, count);
 
         // 保持全局处理时间统计的兼容性
-        GLOBAL_METRICS.processing_stats.update(processing_time_us, count);
+        self.metrics().processing_stats.update(processing_time_us, count);
     }
 
     /// 记录慢处理操作
     #[inline]
     pub fn log_slow_processing(&self, processing_time_us: f64, event_count: usize) {
         if processing_time_us > SLOW_PROCESSING_THRESHOLD_US {
-            log::debug!("Slow processing: {:.2}us for {} events", processing_time_us, event_count);
+            log::debug!(
+                target: METRICS_LOG_TARGET,
+                "Slow processing: {:.2}us for {} events",
+                processing_time_us,
+                event_count
+            );
         }
     }
 
@@ -379,6 +701,7 @@ impl MetricsManager {
 
         if adjusted_latency_ms > MAX_LATENCY_THRESHOLD_MS {
             log::warn!(
+                target: METRICS_LOG_TARGET,
                 "⚠️  High gRPC latency: {}ms (threshold: {}ms, raw: recv={}ms, block={}ms)",
                 adjusted_latency_ms,
                 MAX_LATENCY_THRESHOLD_MS,
@@ -388,24 +711,145 @@ impl MetricsManager {
         }
     }
 
+    /// 记录 gRPC 服务端到客户端延迟（recv_time - server_time）
+    ///
+    /// 与 `check_and_warn_high_latency` 的链上到客户端延迟不同，只衡量数据提供方到本地的传输耗时
+    #[inline]
+    pub fn record_provider_delay(&self, recv_us: i64, server_ts_us: i64) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().provider_delay_total_us.fetch_add(recv_us - server_ts_us, Ordering::Relaxed);
+        self.metrics().provider_delay_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 记录一条消息的反序列化耗时（微秒），与协议解析耗时分开统计
+    #[inline]
+    pub fn record_decode_time(&self, decode_time_us: f64) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().decode_stats.update(decode_time_us, 1);
+    }
+
     /// 获取运行时长
     pub fn get_uptime(&self) -> std::time::Duration {
-        std::time::Duration::from_secs_f64(GLOBAL_METRICS.get_uptime_seconds())
+        std::time::Duration::from_secs_f64(self.metrics().get_uptime_seconds())
     }
 
     /// 获取事件指标
     pub fn get_event_metrics(&self, event_type: EventType) -> EventMetricsSnapshot {
-        GLOBAL_METRICS.get_event_metrics(event_type)
+        self.metrics().get_event_metrics(event_type)
     }
 
     /// 获取处理时间统计
     pub fn get_processing_stats(&self) -> ProcessingTimeStats {
-        GLOBAL_METRICS.get_processing_stats()
+        self.metrics().get_processing_stats()
     }
 
     /// 获取丢弃事件计数
     pub fn get_dropped_events_count(&self) -> u64 {
-        GLOBAL_METRICS.get_dropped_events_count()
+        self.metrics().get_dropped_events_count()
+    }
+
+    /// 获取解码失败计数
+    pub fn get_decode_failures_count(&self) -> u64 {
+        self.metrics().get_decode_failures_count()
+    }
+
+    /// 获取回调 panic 计数
+    pub fn get_callback_panics_count(&self) -> u64 {
+        self.metrics().get_callback_panics_count()
+    }
+
+    /// 获取回调执行超时计数
+    pub fn get_slow_callbacks_count(&self) -> u64 {
+        self.metrics().get_slow_callbacks_count()
+    }
+
+    /// 获取无法处理而跳过的交易计数
+    pub fn get_malformed_transactions_count(&self) -> u64 {
+        self.metrics().get_malformed_transactions_count()
+    }
+
+    /// 获取协议解析器 panic 计数
+    pub fn get_parser_panics_count(&self) -> u64 {
+        self.metrics().get_parser_panics_count()
+    }
+
+    /// 获取账户事件合并计数
+    pub fn get_coalesced_events_count(&self) -> u64 {
+        self.metrics().get_coalesced_events_count()
+    }
+
+    /// 获取超大指令计数
+    pub fn get_oversized_instructions_count(&self) -> u64 {
+        self.metrics().get_oversized_instructions_count()
+    }
+
+    /// 获取账户索引越界指令计数
+    pub fn get_missing_account_instructions_count(&self) -> u64 {
+        self.metrics().get_missing_account_instructions_count()
+    }
+
+    /// 获取 inner 扫描上限命中计数
+    pub fn get_inner_scan_cap_hits_count(&self) -> u64 {
+        self.metrics().get_inner_scan_cap_hits_count()
+    }
+
+    /// 获取平均 gRPC 服务端到客户端延迟（微秒）
+    pub fn get_provider_delay_avg_us(&self) -> f64 {
+        self.metrics().get_provider_delay_avg_us()
+    }
+
+    /// 获取正在并发解析的交易数
+    pub fn get_parse_in_flight(&self) -> u64 {
+        self.metrics().get_parse_in_flight()
+    }
+
+    /// 获取 ShredStream entries 反序列化耗时统计
+    pub fn get_decode_stats(&self) -> ProcessingTimeStats {
+        self.metrics().get_decode_stats()
+    }
+
+    /// 为新订阅注册指标，返回订阅任务用于计数的句柄；`subscribed_us` 为调用订阅方法的时间，
+    /// 用于计算首个事件的到达耗时
+    pub fn register_subscription(
+        &self,
+        id: SubscriptionId,
+        subscribed_us: i64,
+    ) -> Arc<SubscriptionMetrics> {
+        let metrics = Arc::new(SubscriptionMetrics::new(subscribed_us));
+        let mut subscriptions =
+            self.metrics().subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+        subscriptions.insert(id, metrics.clone());
+        metrics
+    }
+
+    /// 移除订阅的指标
+    pub fn unregister_subscription(&self, id: SubscriptionId) {
+        self.metrics().subscriptions.lock().unwrap_or_else(|e| e.into_inner()).remove(&id);
+    }
+
+    /// 获取单个订阅的指标，订阅已停止时返回 None
+    pub fn get_subscription_metrics(
+        &self,
+        id: SubscriptionId,
+    ) -> Option<SubscriptionMetricsSnapshot> {
+        let subscriptions = self.metrics().subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+        subscriptions.get(&id).map(|metrics| metrics.snapshot(id))
+    }
+
+    /// 获取所有活跃订阅的指标，按订阅 ID 排序
+    pub fn get_all_subscription_metrics(&self) -> Vec<SubscriptionMetricsSnapshot> {
+        let subscriptions = self.metrics().subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+        subscriptions.iter().map(|(id, metrics)| metrics.snapshot(*id)).collect()
+    }
+
+    /// 获取最近一次订阅从调用订阅方法到首个事件的耗时（微秒），每次新订阅重新计算
+    pub fn get_time_to_first_event_us(&self) -> Option<i64> {
+        let subscriptions = self.metrics().subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+        subscriptions.values().next_back().and_then(|metrics| metrics.time_to_first_event_us())
     }
 
     /// 打印性能指标（非阻塞）
@@ -419,6 +863,86 @@ impl MetricsManager {
             println!("\n⚠️  Dropped Events: {}", dropped_count);
         }
 
+        // 打印解码失败指标
+        let decode_failures = self.get_decode_failures_count();
+        if decode_failures > 0 {
+            println!("⚠️  Decode Failures: {}", decode_failures);
+        }
+
+        // 打印回调 panic 指标
+        let callback_panics = self.get_callback_panics_count();
+        if callback_panics > 0 {
+            println!("⚠️  Callback Panics: {}", callback_panics);
+        }
+
+        // 打印回调执行超时指标
+        let slow_callbacks = self.get_slow_callbacks_count();
+        if slow_callbacks > 0 {
+            println!("⚠️  Slow Callbacks: {}", slow_callbacks);
+        }
+
+        // 打印无法处理而跳过的交易指标
+        let malformed_transactions = self.get_malformed_transactions_count();
+        if malformed_transactions > 0 {
+            println!("⚠️  Malformed Transactions: {}", malformed_transactions);
+        }
+
+        // 打印协议解析器 panic 指标
+        let parser_panics = self.get_parser_panics_count();
+        if parser_panics > 0 {
+            println!("⚠️  Parser Panics: {}", parser_panics);
+        }
+
+        // 打印账户事件合并指标
+        let coalesced_events = self.get_coalesced_events_count();
+        if coalesced_events > 0 {
+            println!("⚠️  Coalesced Account Events: {}", coalesced_events);
+        }
+
+        // 打印超大指令指标
+        let oversized_instructions = self.get_oversized_instructions_count();
+        if oversized_instructions > 0 {
+            println!("⚠️  Oversized Instructions Skipped: {}", oversized_instructions);
+        }
+
+        // 打印账户索引越界指令指标
+        let missing_account_instructions = self.get_missing_account_instructions_count();
+        if missing_account_instructions > 0 {
+            println!("⚠️  Instructions With Missing Accounts: {}", missing_account_instructions);
+        }
+
+        // 打印 inner 扫描上限命中指标
+        let inner_scan_cap_hits = self.get_inner_scan_cap_hits_count();
+        if inner_scan_cap_hits > 0 {
+            println!("⚠️  Inner Scan Cap Hits: {}", inner_scan_cap_hits);
+        }
+
+        // 打印 gRPC 服务端到客户端延迟
+        let provider_delay_avg_us = self.get_provider_delay_avg_us();
+        if provider_delay_avg_us != 0.0 {
+            println!("   Provider Delay Avg: {:.2}μs", provider_delay_avg_us);
+        }
+
+        // 打印并发解析中的交易数
+        let parse_in_flight = self.get_parse_in_flight();
+        if parse_in_flight > 0 {
+            println!("   Parses In Flight: {}", parse_in_flight);
+        }
+
+        // 打印反序列化耗时
+        let decode_stats = self.get_decode_stats();
+        if decode_stats.avg_us != 0.0 {
+            println!(
+                "   Decode Time: last {:.2}μs, avg {:.2}μs",
+                decode_stats.last_us, decode_stats.avg_us
+            );
+        }
+
+        // 打印首个事件到达耗时
+        if let Some(time_to_first_event_us) = self.get_time_to_first_event_us() {
+            println!("   Time To First Event: {:.2}ms", time_to_first_event_us as f64 / 1000.0);
+        }
+
         // 打印事件指标表格（包含处理时间统计）
         println!("┌─────────────┬──────────────┬──────────────────┬─────────────┬─────────────┐");
         println!("│ Event Type  │ Process Count│ Events Processed │ Last(μs)    │ Avg(μs)     │");
@@ -437,6 +961,22 @@ impl MetricsManager {
         }
 
         println!("└─────────────┴──────────────┴──────────────────┴─────────────┴─────────────┘");
+
+        // 打印各订阅指标
+        for snapshot in self.get_all_subscription_metrics() {
+            let idle = match snapshot.idle_us() {
+                Some(idle_us) => format!("{:.1}s", idle_us as f64 / 1_000_000.0),
+                None => "-".to_string(),
+            };
+            println!(
+                "   [{}] updates: {}, events: {}, errors: {}, idle: {}",
+                snapshot.id,
+                snapshot.updates_received,
+                snapshot.events_emitted,
+                snapshot.errors,
+                idle
+            );
+        }
         println!();
     }
 
@@ -446,13 +986,14 @@ impl MetricsManager {
             return None;
         }
 
-        let handle = tokio::spawn(async {
+        let manager = self.clone();
+        let handle = tokio::spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(
                 DEFAULT_METRICS_PRINT_INTERVAL_SECONDS,
             ));
             loop {
                 interval.tick().await;
-                MetricsManager::global().print_metrics();
+                manager.print_metrics();
             }
         });
         Some(handle)
@@ -467,6 +1008,19 @@ impl MetricsManager {
             block_meta_metrics: self.get_event_metrics(EventType::BlockMeta),
             processing_stats: self.get_processing_stats(),
             dropped_events_count: self.get_dropped_events_count(),
+            decode_failures_count: self.get_decode_failures_count(),
+            callback_panics_count: self.get_callback_panics_count(),
+            slow_callbacks_count: self.get_slow_callbacks_count(),
+            malformed_transactions_count: self.get_malformed_transactions_count(),
+            parser_panics_count: self.get_parser_panics_count(),
+            coalesced_events_count: self.get_coalesced_events_count(),
+            oversized_instructions_count: self.get_oversized_instructions_count(),
+            missing_account_instructions_count: self.get_missing_account_instructions_count(),
+            inner_scan_cap_hits_count: self.get_inner_scan_cap_hits_count(),
+            provider_delay_avg_us: self.get_provider_delay_avg_us(),
+            parse_in_flight: self.get_parse_in_flight(),
+            decode_stats: self.get_decode_stats(),
+            time_to_first_event_us: self.get_time_to_first_event_us(),
         }
     }
 
@@ -522,11 +1076,11 @@ impl MetricsManager {
         }
 
         // 原子地增加丢弃事件计数
-        let new_count = GLOBAL_METRICS.dropped_events_count.fetch_add(1, Ordering::Relaxed) + 1;
+        let new_count = self.metrics().dropped_events_count.fetch_add(1, Ordering::Relaxed) + 1;
 
         // 每丢弃1000个事件记录一次警告日志
         if new_count % 1000 == 0 {
-            log::debug!("Dropped events count reached: {}", new_count);
+            log::debug!(target: METRICS_LOG_TARGET, "Dropped events count reached: {}", new_count);
         }
     }
 
@@ -539,16 +1093,154 @@ impl MetricsManager {
 
         // 原子地增加丢弃事件计数
         let new_count =
-            GLOBAL_METRICS.dropped_events_count.fetch_add(count, Ordering::Relaxed) + count;
+            self.metrics().dropped_events_count.fetch_add(count, Ordering::Relaxed) + count;
 
         // 记录批量丢弃事件的日志
         if count > 1 {
-            log::debug!("Dropped batch of {} events, total dropped: {}", count, new_count);
+            log::debug!(
+                target: METRICS_LOG_TARGET,
+                "Dropped batch of {} events, total dropped: {}",
+                count,
+                new_count
+            );
         }
 
         // 每丢弃1000个事件记录一次警告日志
         if new_count % 1000 == 0 || (new_count / 1000) != ((new_count - count) / 1000) {
-            log::debug!("Dropped events count reached: {}", new_count);
+            log::debug!(target: METRICS_LOG_TARGET, "Dropped events count reached: {}", new_count);
+        }
+    }
+
+    /// 增加解码失败计数（例如 ShredStream entries 反序列化失败）
+    #[inline]
+    pub fn increment_decode_failures(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let new_count = self.metrics().decode_failures_count.fetch_add(1, Ordering::Relaxed) + 1;
+        if new_count % 100 == 0 {
+            log::warn!(target: METRICS_LOG_TARGET, "Decode failures count reached: {}", new_count);
+        }
+    }
+
+    /// 增加回调 panic 计数
+    #[inline]
+    pub fn increment_callback_panics(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().callback_panics_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加回调执行超时计数
+    #[inline]
+    pub fn increment_slow_callbacks(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().slow_callbacks_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加无法处理而跳过的交易计数
+    #[inline]
+    pub fn increment_malformed_transactions(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().malformed_transactions_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加协议解析器 panic 计数
+    #[inline]
+    pub fn increment_parser_panics(&self) {
+        if !self.is_enabled() {
+            return;
         }
+        self.metrics().parser_panics_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加账户事件合并计数（暂存的事件被同一账户更新的事件覆盖）
+    #[inline]
+    pub fn increment_coalesced_events(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().coalesced_events_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加 inner instruction 扫描上限命中计数（超过 `max_inner_instruction_scan`）
+    #[inline]
+    pub fn increment_inner_scan_cap_hits(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().inner_scan_cap_hits_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加超大指令跳过计数（指令数据超过 `max_instruction_data_len`）
+    #[inline]
+    pub fn increment_oversized_instructions(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().oversized_instructions_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加账户索引越界指令计数（补齐默认公钥或跳过，见 `missing_account_mode`）
+    #[inline]
+    pub fn increment_missing_account_instructions(&self) {
+        if !self.is_enabled() {
+            return;
+        }
+        self.metrics().missing_account_instructions_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 增加并发解析中的交易数
+    ///
+    /// 与 `decrement_parse_in_flight` 成对调用，不受 metrics 开关影响以保持计数一致
+    #[inline]
+    pub fn increment_parse_in_flight(&self) {
+        self.metrics().parse_in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 减少并发解析中的交易数
+    #[inline]
+    pub fn decrement_parse_in_flight(&self) {
+        self.metrics().parse_in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// 记录一次未被任何协议处理的程序调用
+    #[cfg(feature = "unhandled-programs")]
+    pub fn record_unhandled_program(&self, program_id: &Pubkey) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut programs =
+            self.metrics().unhandled_programs.lock().unwrap_or_else(|e| e.into_inner());
+        let programs =
+            programs.get_or_insert_with(|| LruCache::new(DEFAULT_UNHANDLED_PROGRAM_CACHE_SIZE));
+        let count = programs.get(program_id).copied().unwrap_or(0);
+        programs.put(*program_id, count + 1);
+    }
+
+    /// 按出现次数降序返回前 `top_n` 个未处理的程序 ID
+    #[cfg(feature = "unhandled-programs")]
+    pub fn get_unhandled_programs(&self, top_n: usize) -> Vec<(Pubkey, u64)> {
+        let programs = self.metrics().unhandled_programs.lock().unwrap_or_else(|e| e.into_inner());
+        let mut top: Vec<(Pubkey, u64)> = programs
+            .iter()
+            .flat_map(|programs| programs.iter())
+            .map(|(program_id, count)| (*program_id, *count))
+            .collect();
+        top.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+        top.truncate(top_n);
+        top
+    }
+
+    /// 清空未处理程序 ID 统计
+    #[cfg(feature = "unhandled-programs")]
+    pub fn reset_unhandled_programs(&self) {
+        *self.metrics().unhandled_programs.lock().unwrap_or_else(|e| e.into_inner()) = None;
     }
 }
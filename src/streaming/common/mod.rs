@@ -1,15 +1,57 @@
 // 公用模块 - 包含流处理相关的通用功能
+pub mod active_protocols;
+#[cfg(feature = "arrow")]
+pub mod arrow_batch;
+pub mod coalesce;
 pub mod config;
 pub mod metrics;
 pub mod constants;
+pub mod dedup;
+pub mod event_bus;
+pub mod event_iter;
+pub mod logging;
+pub mod lru;
+pub mod ohlcv;
+pub mod parse_limiter;
+pub mod position;
+pub mod priority_fee;
+pub mod pubkey_display;
+pub mod pubkey_set;
+pub mod reserve_snapshot;
 pub mod subscription;
 pub mod event_processor;
 pub mod simd_utils;
+pub mod slot_summary;
+pub mod thread_affinity;
+pub mod trade;
+#[cfg(feature = "decimal")]
+pub mod ui_amounts;
 
 // 重新导出主要类型
+pub use active_protocols::*;
+#[cfg(feature = "arrow")]
+pub use arrow_batch::*;
+pub use coalesce::*;
 pub use config::*;
 pub use metrics::*;
 pub use constants::*;
+pub use dedup::*;
+pub use event_bus::*;
+pub use event_iter::*;
+pub(crate) use logging::*;
+pub use lru::*;
+pub use ohlcv::*;
+pub(crate) use parse_limiter::*;
+pub use position::*;
+pub use priority_fee::*;
+pub use pubkey_display::*;
+pub use pubkey_set::*;
+pub use reserve_snapshot::*;
 pub use subscription::*;
 pub use event_processor::*;
-pub use simd_utils::*;
\ No newline at end of file
+pub use simd_utils::*;
+pub use slot_summary::*;
+pub(crate) use thread_affinity::*;
+pub use trade::*;
+#[cfg(feature = "decimal")]
+pub use ui_amounts::*;
\ No newline at end of file
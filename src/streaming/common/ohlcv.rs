@@ -0,0 +1,145 @@
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::constants::DEFAULT_OHLCV_INTERVALS_SECS;
+use super::trade::UnifiedTrade;
+use crate::streaming::event_parser::DexEvent;
+
+/// OHLCV K 线
+///
+/// 价格为每单位 base 对应的 quote（原始单位，未按 decimals 换算）
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OhlcvBar {
+    pub pool: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub interval: Duration,
+    /// K 线起始时间（毫秒，按周期对齐）
+    pub open_time_ms: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub base_volume: u64,
+    pub quote_volume: u64,
+    pub trade_count: u64,
+}
+
+impl OhlcvBar {
+    fn new(trade: &UnifiedTrade, interval: Duration, open_time_ms: i64, price: f64) -> Self {
+        Self {
+            pool: trade.pool,
+            base_mint: trade.base_mint,
+            quote_mint: trade.quote_mint,
+            interval,
+            open_time_ms,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            base_volume: trade.base_amount,
+            quote_volume: trade.quote_amount,
+            trade_count: 1,
+        }
+    }
+
+    fn update(&mut self, trade: &UnifiedTrade, price: f64) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.base_volume = self.base_volume.saturating_add(trade.base_amount);
+        self.quote_volume = self.quote_volume.saturating_add(trade.quote_amount);
+        self.trade_count += 1;
+    }
+}
+
+/// 完成的 K 线回调
+pub type OhlcvCallback = Arc<dyn Fn(OhlcvBar) + Send + Sync>;
+
+/// 基于成交事件的多周期 OHLCV 聚合器
+///
+/// 按 (pool, 周期) 维护当前 K 线，成交时间进入下一个周期时通过回调输出已完成的 K 线。
+/// 成交时间取 `block_time_ms`，缺失时（如 ShredStream）使用接收时间。
+/// 早于当前 K 线的乱序成交会被忽略。
+pub struct OhlcvAggregator {
+    intervals: Vec<Duration>,
+    bars: Mutex<HashMap<(Pubkey, Duration), OhlcvBar>>,
+    callback: OhlcvCallback,
+}
+
+impl OhlcvAggregator {
+    /// 创建指定周期的聚合器，忽略小于 1 毫秒的周期
+    pub fn new(intervals: Vec<Duration>, callback: OhlcvCallback) -> Self {
+        let intervals = intervals.into_iter().filter(|i| i.as_millis() > 0).collect();
+        Self { intervals, bars: Mutex::new(HashMap::new()), callback }
+    }
+
+    /// 创建默认周期（1s、1m、5m）的聚合器
+    pub fn with_default_intervals(callback: OhlcvCallback) -> Self {
+        let intervals =
+            DEFAULT_OHLCV_INTERVALS_SECS.iter().map(|secs| Duration::from_secs(*secs)).collect();
+        Self::new(intervals, callback)
+    }
+
+    /// 聚合的周期
+    pub fn intervals(&self) -> &[Duration] {
+        &self.intervals
+    }
+
+    /// 处理事件，非成交事件直接忽略
+    pub fn on_event(&self, event: &DexEvent) {
+        if let Some(trade) = UnifiedTrade::from_event(event) {
+            self.on_trade(&trade);
+        }
+    }
+
+    /// 处理一笔成交
+    pub fn on_trade(&self, trade: &UnifiedTrade) {
+        let time_ms = if trade.block_time_ms > 0 { trade.block_time_ms } else { trade.recv_us / 1000 };
+        let price = trade.price();
+
+        let mut completed = Vec::new();
+        {
+            let mut bars = self.bars.lock().unwrap_or_else(|e| e.into_inner());
+            for interval in &self.intervals {
+                let interval_ms = interval.as_millis() as i64;
+                let open_time_ms = time_ms - time_ms.rem_euclid(interval_ms);
+                match bars.get_mut(&(trade.pool, *interval)) {
+                    Some(bar) if open_time_ms == bar.open_time_ms => bar.update(trade, price),
+                    Some(bar) if open_time_ms > bar.open_time_ms => {
+                        let next = OhlcvBar::new(trade, *interval, open_time_ms, price);
+                        completed.push(std::mem::replace(bar, next));
+                    }
+                    Some(_) => {}
+                    None => {
+                        let bar = OhlcvBar::new(trade, *interval, open_time_ms, price);
+                        bars.insert((trade.pool, *interval), bar);
+                    }
+                }
+            }
+        }
+
+        for bar in completed {
+            (self.callback)(bar);
+        }
+    }
+
+    /// 输出并清空所有未完成的 K 线（例如停止订阅前）
+    pub fn flush(&self) {
+        let bars: Vec<OhlcvBar> = {
+            let mut bars = self.bars.lock().unwrap_or_else(|e| e.into_inner());
+            bars.drain().map(|(_, bar)| bar).collect()
+        };
+        for bar in bars {
+            (self.callback)(bar);
+        }
+    }
+
+    /// 生成可直接传给订阅接口的事件 callback
+    pub fn event_callback(self: &Arc<Self>) -> impl Fn(DexEvent) + Send + Sync + 'static {
+        let aggregator = self.clone();
+        move |event: DexEvent| aggregator.on_event(&event)
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+
+use futures::FutureExt;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinSet;
+
+use super::metrics::MetricsManager;
+use super::subscription::CancellationToken;
+use crate::streaming::event_parser::DexEvent;
+
+type EventCallback = Arc<dyn Fn(DexEvent) + Send + Sync>;
+
+/// 交易解析并发限制器
+///
+/// 每笔交易在独立任务中解析，最多同时进行 `limit` 个；名额用尽时 `spawn` 会等待，
+/// 从而把背压传导到网络读取。任务由内部 `JoinSet` 持有，限制器随订阅任务一起被丢弃时全部中止。
+/// 开启严格顺序时，各任务的事件先缓存，再按交易提交顺序交给回调。
+/// 解析任务沿用调用方的 `MetricsManager::current()`。
+/// `cancellation` 取消后，尚未开始的解析任务直接退出，严格顺序模式下缓存的事件不再交付
+pub(crate) struct ParseLimiter {
+    semaphore: Arc<Semaphore>,
+    tasks: JoinSet<()>,
+    sequencer: Option<Arc<Sequencer>>,
+    next_seq: u64,
+    cancellation: CancellationToken,
+}
+
+impl ParseLimiter {
+    /// 创建限制器，`limit` 为 0 时按 1 处理
+    pub(crate) fn new(
+        limit: usize,
+        strict_ordering: bool,
+        cancellation: CancellationToken,
+    ) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit.max(1))),
+            tasks: JoinSet::new(),
+            sequencer: strict_ordering.then(|| Arc::new(Sequencer::default())),
+            next_seq: 0,
+            cancellation,
+        }
+    }
+
+    /// 等待空闲名额后在新任务中执行解析
+    ///
+    /// `parse` 接收本次解析应使用的回调：严格顺序模式下为事件收集器，否则即 `callback` 本身
+    pub(crate) async fn spawn<P, F>(&mut self, callback: EventCallback, parse: P)
+    where
+        P: FnOnce(EventCallback) -> F,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        // 回收已完成的任务，避免 JoinSet 无限增长
+        while self.tasks.try_join_next().is_some() {}
+
+        let permit = match self.semaphore.clone().acquire_owned().await {
+            Ok(permit) => permit,
+            // 信号量从不关闭
+            Err(_) => return,
+        };
+        let metrics = MetricsManager::current();
+        let in_flight = InFlightGuard::new(metrics.clone());
+        let cancellation = self.cancellation.clone();
+
+        let Some(sequencer) = self.sequencer.clone() else {
+            let future = parse(callback);
+            self.tasks.spawn(metrics.scope(async move {
+                if !cancellation.is_cancelled() {
+                    future.await;
+                }
+                drop(in_flight);
+                drop(permit);
+            }));
+            return;
+        };
+
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        let events: Arc<Mutex<Vec<DexEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let collector: EventCallback = {
+            let events = events.clone();
+            Arc::new(move |event: DexEvent| {
+                events.lock().unwrap_or_else(|e| e.into_inner()).push(event);
+            })
+        };
+        let future = parse(collector);
+        self.tasks.spawn(metrics.scope(async move {
+            if cancellation.is_cancelled() {
+                return;
+            }
+            // 解析 panic 时也要提交序号，否则后续交易的事件会一直被阻塞
+            let _ = AssertUnwindSafe(future).catch_unwind().await;
+            let events = std::mem::take(&mut *events.lock().unwrap_or_else(|e| e.into_inner()));
+            drop(in_flight);
+            sequencer.complete(seq, events, permit, &callback);
+        }));
+    }
+}
+
+/// 按提交顺序释放各交易的事件
+#[derive(Default)]
+struct Sequencer {
+    state: Mutex<SequencerState>,
+}
+
+#[derive(Default)]
+struct SequencerState {
+    /// 下一个应交付的序号
+    next: u64,
+    /// 已完成但前序交易尚未完成的事件；连同并发名额一起保留，使缓存的交易数不超过并发上限
+    pending: BTreeMap<u64, (Vec<DexEvent>, OwnedSemaphorePermit)>,
+}
+
+impl Sequencer {
+    /// 提交一笔交易的事件，并交付所有已连续完成的交易
+    ///
+    /// 回调在锁内调用，保证不同任务之间的交付也不会交错
+    fn complete(
+        &self,
+        seq: u64,
+        events: Vec<DexEvent>,
+        permit: OwnedSemaphorePermit,
+        callback: &EventCallback,
+    ) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.pending.insert(seq, (events, permit));
+        loop {
+            let next = state.next;
+            let Some((events, permit)) = state.pending.remove(&next) else {
+                break;
+            };
+            state.next += 1;
+            for event in events {
+                callback(event);
+            }
+            drop(permit);
+        }
+    }
+}
+
+/// 维护 in-flight 指标，任务被中止时同样会递减
+struct InFlightGuard(MetricsManager);
+
+impl InFlightGuard {
+    fn new(metrics: MetricsManager) -> Self {
+        metrics.increment_parse_in_flight();
+        Self(metrics)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.decrement_parse_in_flight();
+    }
+}
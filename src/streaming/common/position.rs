@@ -0,0 +1,160 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::constants::DEFAULT_POSITION_TRACKER_MAX_WALLETS;
+use super::lru::LruCache;
+use super::trade::{UnifiedTrade, WSOL_MINT};
+use crate::streaming::event_parser::DexEvent;
+
+/// 钱包在某个 mint 上的累计仓位
+///
+/// token 数量为链上原始单位（未按 decimals 换算），SOL 数量为 lamports
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub wallet: Pubkey,
+    pub mint: Pubkey,
+    /// 累计买入的 token 数量
+    pub bought_amount: u64,
+    /// 累计卖出的 token 数量
+    pub sold_amount: u64,
+    /// 买入花费的 SOL
+    pub sol_spent: u64,
+    /// 卖出所得的 SOL
+    pub sol_received: u64,
+    pub trade_count: u64,
+    pub last_slot: u64,
+    pub last_signature: Signature,
+}
+
+impl Position {
+    fn new(wallet: Pubkey, mint: Pubkey) -> Self {
+        Self { wallet, mint, ..Default::default() }
+    }
+
+    fn apply(&mut self, trade: &UnifiedTrade) {
+        if trade.is_buy {
+            self.bought_amount = self.bought_amount.saturating_add(trade.base_amount);
+            self.sol_spent = self.sol_spent.saturating_add(trade.quote_amount);
+        } else {
+            self.sold_amount = self.sold_amount.saturating_add(trade.base_amount);
+            self.sol_received = self.sol_received.saturating_add(trade.quote_amount);
+        }
+        self.trade_count += 1;
+        self.last_slot = self.last_slot.max(trade.slot);
+        self.last_signature = trade.signature;
+    }
+
+    /// 净持仓（买入减卖出），跟踪开始前已有持仓时可能为负
+    pub fn net_amount(&self) -> i128 {
+        self.bought_amount as i128 - self.sold_amount as i128
+    }
+
+    /// 净花费的 SOL（花费减所得），为负表示净收入
+    pub fn net_sol_spent(&self) -> i128 {
+        self.sol_spent as i128 - self.sol_received as i128
+    }
+
+    /// 按平均成本计算的已实现盈亏（lamports）
+    ///
+    /// 只计算有买入对应的卖出数量：该部分的卖出所得减去按平均买入价计算的成本。
+    /// 没有买入或卖出记录时返回 None
+    pub fn realized_pnl_lamports(&self) -> Option<i128> {
+        if self.bought_amount == 0 || self.sold_amount == 0 {
+            return None;
+        }
+        let matched = self.sold_amount.min(self.bought_amount) as u128;
+        let proceeds = self.sol_received as u128 * matched / self.sold_amount as u128;
+        let cost = self.sol_spent as u128 * matched / self.bought_amount as u128;
+        Some(proceeds as i128 - cost as i128)
+    }
+}
+
+/// 仓位更新回调，每笔计入的成交后收到该仓位的最新状态
+pub type PositionCallback = Arc<dyn Fn(&Position) + Send + Sync>;
+
+/// 基于成交事件按 (钱包, mint) 累计净仓位
+///
+/// 成交先经 [`UnifiedTrade::from_event`] 统一，钱包取 `trader`，mint 取 base 一侧。
+/// 只统计以 SOL（WSOL）计价的成交，其余交易对的 quote 单位不一致，直接忽略。
+/// 按钱包数量有界，超出 `max_wallets` 时淘汰最久没有成交的钱包及其全部仓位
+pub struct PositionTracker {
+    wallets: Mutex<LruCache<Pubkey, HashMap<Pubkey, Position>>>,
+    callback: Option<PositionCallback>,
+}
+
+impl PositionTracker {
+    /// 创建最多保留 `max_wallets` 个钱包的跟踪器（至少为 1）
+    pub fn new(max_wallets: usize, callback: Option<PositionCallback>) -> Self {
+        Self { wallets: Mutex::new(LruCache::new(max_wallets)), callback }
+    }
+
+    /// 创建默认容量（`DEFAULT_POSITION_TRACKER_MAX_WALLETS`）的跟踪器
+    pub fn with_default_max_wallets(callback: Option<PositionCallback>) -> Self {
+        Self::new(DEFAULT_POSITION_TRACKER_MAX_WALLETS, callback)
+    }
+
+    /// 处理事件，非成交事件直接忽略
+    pub fn on_event(&self, event: &DexEvent) {
+        if let Some(trade) = UnifiedTrade::from_event(event) {
+            self.on_trade(&trade);
+        }
+    }
+
+    /// 处理一笔成交，不以 SOL 计价的成交直接忽略
+    pub fn on_trade(&self, trade: &UnifiedTrade) {
+        if trade.quote_mint != WSOL_MINT || trade.trader == Pubkey::default() {
+            return;
+        }
+        let updated = {
+            let mut wallets = self.wallets.lock().unwrap_or_else(|e| e.into_inner());
+            if !wallets.contains(&trade.trader) {
+                wallets.put(trade.trader, HashMap::new());
+            }
+            let Some(positions) = wallets.get_mut(&trade.trader) else {
+                return;
+            };
+            let position = positions
+                .entry(trade.base_mint)
+                .or_insert_with(|| Position::new(trade.trader, trade.base_mint));
+            position.apply(trade);
+            self.callback.is_some().then(|| position.clone())
+        };
+
+        if let (Some(callback), Some(position)) = (&self.callback, updated) {
+            callback(&position);
+        }
+    }
+
+    /// 查询钱包在某个 mint 上的仓位（不影响淘汰顺序）
+    pub fn position(&self, wallet: &Pubkey, mint: &Pubkey) -> Option<Position> {
+        let wallets = self.wallets.lock().unwrap_or_else(|e| e.into_inner());
+        wallets.peek(wallet).and_then(|positions| positions.get(mint)).cloned()
+    }
+
+    /// 查询钱包的所有仓位，顺序不确定
+    pub fn positions(&self, wallet: &Pubkey) -> Vec<Position> {
+        let wallets = self.wallets.lock().unwrap_or_else(|e| e.into_inner());
+        wallets
+            .peek(wallet)
+            .map(|positions| positions.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// 当前跟踪的钱包数
+    pub fn wallet_count(&self) -> usize {
+        self.wallets.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 清空所有仓位
+    pub fn clear(&self) {
+        self.wallets.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    /// 生成可直接传给订阅接口的事件 callback
+    pub fn event_callback(self: &Arc<Self>) -> impl Fn(DexEvent) + Send + Sync + 'static {
+        let tracker = self.clone();
+        move |event: DexEvent| tracker.on_event(&event)
+    }
+}
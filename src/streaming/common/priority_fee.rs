@@ -0,0 +1,84 @@
+use crate::streaming::common::constants::{
+    DEFAULT_COMPUTE_UNIT_LIMIT, LAMPORTS_PER_SIGNATURE, MAX_COMPUTE_UNIT_LIMIT,
+};
+use crate::streaming::event_parser::common::EventMetadata;
+
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// 交易的 compute budget 设置，来自 `SetComputeUnitLimitEvent` / `SetComputeUnitPriceEvent`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+    /// 请求的计算单元上限，未设置时按默认值计算
+    pub unit_limit: Option<u32>,
+    /// 每个计算单元的价格 (micro-lamports)，未设置时没有优先费
+    pub unit_price: Option<u64>,
+    /// 交易中 compute budget 以外的指令数量，未设置上限时默认上限为每条指令
+    /// `DEFAULT_COMPUTE_UNIT_LIMIT`；为 None 时按一条计算
+    pub non_compute_budget_instructions: Option<u32>,
+}
+
+impl From<&EventMetadata> for ComputeBudget {
+    /// 取 `ComputeBudgetMode::Attach` / `Both` 模式下附加在事件上的 compute budget
+    fn from(metadata: &EventMetadata) -> Self {
+        Self {
+            unit_limit: metadata.compute_unit_limit,
+            unit_price: metadata.compute_unit_price,
+            non_compute_budget_instructions: None,
+        }
+    }
+}
+
+/// 交易的优先费，不含签名基础费
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PriorityFee {
+    /// 请求的每计算单元价格 (micro-lamports)
+    pub micro_lamports_per_cu: u64,
+    /// 计费使用的计算单元上限
+    pub compute_unit_limit: u32,
+    /// 优先费 (lamports)，按请求的上限而非实际消耗计费，向上取整
+    pub priority_fee_lamports: u64,
+    /// 按实际消耗折算的每计算单元价格 (micro-lamports)，未提供消耗量或消耗为 0 时为 None
+    pub effective_micro_lamports_per_cu: Option<u64>,
+}
+
+impl PriorityFee {
+    /// 交易总手续费 (lamports)：每个签名的基础费加上优先费
+    pub fn total_fee_lamports(&self, num_signatures: u64) -> u64 {
+        (num_signatures * LAMPORTS_PER_SIGNATURE).saturating_add(self.priority_fee_lamports)
+    }
+}
+
+/// 计算交易的优先费
+///
+/// 优先费 = ceil(unit_price × unit_limit / 1_000_000)，按请求的计算单元上限计费。
+/// 未设置上限时按每条非 compute budget 指令 `DEFAULT_COMPUTE_UNIT_LIMIT` 计算
+/// （指令数量见 `ComputeBudget::non_compute_budget_instructions`），
+/// 上限超过 `MAX_COMPUTE_UNIT_LIMIT` 时按最大值计算。`compute_units_consumed` 取自交易 meta，
+/// 用于折算实际每计算单元的价格
+pub fn compute_priority_fee(
+    compute_budget: &ComputeBudget,
+    compute_units_consumed: Option<u64>,
+) -> PriorityFee {
+    let micro_lamports_per_cu = compute_budget.unit_price.unwrap_or(0);
+    let compute_unit_limit = compute_budget
+        .unit_limit
+        .unwrap_or_else(|| {
+            let instructions = compute_budget.non_compute_budget_instructions.unwrap_or(1);
+            DEFAULT_COMPUTE_UNIT_LIMIT.saturating_mul(instructions)
+        })
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+    let total_micro_lamports = micro_lamports_per_cu as u128 * compute_unit_limit as u128;
+    let priority_fee_lamports =
+        total_micro_lamports.div_ceil(MICRO_LAMPORTS_PER_LAMPORT).min(u64::MAX as u128) as u64;
+    let effective_micro_lamports_per_cu =
+        compute_units_consumed.filter(|consumed| *consumed > 0).map(|consumed| {
+            (priority_fee_lamports as u128 * MICRO_LAMPORTS_PER_LAMPORT / consumed as u128)
+                .min(u64::MAX as u128) as u64
+        });
+    PriorityFee {
+        micro_lamports_per_cu,
+        compute_unit_limit,
+        priority_fee_lamports,
+        effective_micro_lamports_per_cu,
+    }
+}
@@ -0,0 +1,64 @@
+use solana_sdk::pubkey::Pubkey;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use crate::streaming::common::constants::DEFAULT_PUBKEY_DISPLAY_CACHE_SIZE;
+use crate::streaming::common::lru::LruCache;
+
+/// 公钥 base58 字符串缓存
+///
+/// 事件中反复出现的池子、程序等公钥只做一次 base58 编码，供日志等需要频繁格式化公钥的
+/// 场景按需使用，不改变事件本身的存储。超出容量时淘汰最久未访问的公钥
+pub struct PubkeyDisplayCache {
+    inner: Mutex<LruCache<Pubkey, Arc<str>>>,
+}
+
+static GLOBAL_PUBKEY_DISPLAY_CACHE: LazyLock<PubkeyDisplayCache> =
+    LazyLock::new(|| PubkeyDisplayCache::new(DEFAULT_PUBKEY_DISPLAY_CACHE_SIZE));
+
+impl PubkeyDisplayCache {
+    /// 创建指定容量的缓存
+    pub fn new(capacity: usize) -> Self {
+        Self { inner: Mutex::new(LruCache::new(capacity)) }
+    }
+
+    /// 全局共享缓存
+    pub fn global() -> &'static PubkeyDisplayCache {
+        &GLOBAL_PUBKEY_DISPLAY_CACHE
+    }
+
+    /// 获取公钥的 base58 字符串，未缓存时编码并缓存
+    pub fn get(&self, pubkey: &Pubkey) -> Arc<str> {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(display) = inner.get(pubkey) {
+            return display.clone();
+        }
+        let display: Arc<str> = pubkey.to_string().into();
+        inner.put(*pubkey, display.clone());
+        display
+    }
+
+    /// 与 `format_pubkey_short` 相同的短格式，使用缓存的 base58 字符串
+    pub fn short(&self, pubkey: &Pubkey) -> String {
+        let s = self.get(pubkey);
+        if s.len() <= 8 {
+            s.to_string()
+        } else {
+            format!("{}...{}", &s[..4], &s[s.len() - 4..])
+        }
+    }
+
+    /// 当前缓存的公钥数量
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 清空缓存
+    pub fn clear(&self) {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
@@ -0,0 +1,104 @@
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// 每个 Pubkey 在预过滤位图中占用的位数，对应约 1.4% 的误判率
+const BITS_PER_KEY: usize = 16;
+
+/// 只读 Pubkey 集合，针对大量"不在集合中"的查询优化
+///
+/// 构建时生成一个两次探测的布隆位图作为预过滤：
+/// 绝大多数不在集合中的 key 只需两次位检查即可排除，命中预过滤后再查哈希表确认。
+/// Pubkey 本身是均匀分布的哈希，直接取其字节作为探测位置。克隆只增加引用计数。
+#[derive(Clone, Default)]
+pub struct PubkeySet {
+    inner: Arc<PubkeySetInner>,
+}
+
+#[derive(Default)]
+struct PubkeySetInner {
+    keys: HashSet<Pubkey>,
+    bloom: Vec<u64>,
+    mask: u64,
+}
+
+impl PubkeySet {
+    /// 由 Pubkey 列表构建集合
+    pub fn new(pubkeys: impl IntoIterator<Item = Pubkey>) -> Self {
+        let keys: HashSet<Pubkey> = pubkeys.into_iter().collect();
+
+        let bits = (keys.len() * BITS_PER_KEY).next_power_of_two().max(64);
+        let mut bloom = vec![0u64; bits / 64];
+        let mask = bits as u64 - 1;
+        for pubkey in &keys {
+            let (first, second) = probes(pubkey.as_ref(), mask);
+            bloom[(first / 64) as usize] |= 1 << (first % 64);
+            bloom[(second / 64) as usize] |= 1 << (second % 64);
+        }
+
+        Self { inner: Arc::new(PubkeySetInner { keys, bloom, mask }) }
+    }
+
+    /// 是否包含某个 Pubkey
+    #[inline]
+    pub fn contains(&self, pubkey: &Pubkey) -> bool {
+        self.contains_bytes(pubkey.as_ref())
+    }
+
+    /// 是否包含某个以原始字节表示的 Pubkey（gRPC 消息中的账户格式），长度不为 32 时返回 false
+    #[inline]
+    pub fn contains_bytes(&self, bytes: &[u8]) -> bool {
+        let inner = &*self.inner;
+        if bytes.len() != 32 || inner.keys.is_empty() {
+            return false;
+        }
+        let (first, second) = probes(bytes, inner.mask);
+        if inner.bloom[(first / 64) as usize] & (1 << (first % 64)) == 0
+            || inner.bloom[(second / 64) as usize] & (1 << (second % 64)) == 0
+        {
+            return false;
+        }
+        inner.keys.contains(&Pubkey::new_from_array(bytes.try_into().unwrap()))
+    }
+
+    /// 是否包含任意一个给定的 Pubkey
+    pub fn contains_any<'a>(&self, pubkeys: impl IntoIterator<Item = &'a Pubkey>) -> bool {
+        pubkeys.into_iter().any(|pubkey| self.contains(pubkey))
+    }
+
+    /// 元素数量
+    pub fn len(&self) -> usize {
+        self.inner.keys.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.inner.keys.is_empty()
+    }
+
+    /// 遍历所有元素（顺序不确定）
+    pub fn iter(&self) -> impl Iterator<Item = &Pubkey> {
+        self.inner.keys.iter()
+    }
+}
+
+impl FromIterator<Pubkey> for PubkeySet {
+    fn from_iter<I: IntoIterator<Item = Pubkey>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl fmt::Debug for PubkeySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PubkeySet").field("len", &self.len()).finish()
+    }
+}
+
+/// 取 Pubkey 前 16 字节作为两个独立的探测位置
+#[inline]
+fn probes(bytes: &[u8], mask: u64) -> (u64, u64) {
+    let first = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+    let second = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+    (first & mask, second & mask)
+}
@@ -0,0 +1,102 @@
+use crate::streaming::event_parser::common::ReserveSnapshot;
+use crate::streaming::event_parser::protocols::bonk::types::TradeDirection;
+use crate::streaming::event_parser::protocols::bonk::BonkTradeEvent;
+use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
+use crate::streaming::event_parser::protocols::pumpswap::{PumpSwapBuyEvent, PumpSwapSellEvent};
+use crate::streaming::event_parser::DexEvent;
+
+/// 为成交事件写入交易前后的池子储备（`pre_reserves` / `post_reserves`）
+///
+/// 只处理能从成交数据精确推导储备变化的协议，其余事件不修改：
+/// - PumpFun：bonding curve 的虚拟储备。CPI log 给出交易后储备，交易前储备按成交数量反推
+/// - PumpSwap：池子 vault 余额。CPI log 给出交易前储备，
+///   交易后储备加减成交数量与留在池内的 LP 手续费
+/// - Bonk：曲线定价使用的储备（`virtual_base - real_base`，`virtual_quote + real_quote`），
+///   CPI log 直接给出交易前后的真实储备
+///
+/// 缺少 CPI log（如 ShredStream）或数值不一致时保持 None
+pub fn annotate_reserve_snapshots(event: &mut DexEvent) {
+    match event {
+        DexEvent::PumpFunTradeEvent(e) => {
+            (e.pre_reserves, e.post_reserves) = pumpfun_reserves(e).unzip();
+        }
+        DexEvent::PumpSwapBuyEvent(e) => {
+            (e.pre_reserves, e.post_reserves) = pumpswap_buy_reserves(e).unzip();
+        }
+        DexEvent::PumpSwapSellEvent(e) => {
+            (e.pre_reserves, e.post_reserves) = pumpswap_sell_reserves(e).unzip();
+        }
+        DexEvent::BonkTradeEvent(e) => {
+            (e.pre_reserves, e.post_reserves) = bonk_reserves(e).unzip();
+        }
+        _ => {}
+    }
+}
+
+fn pumpfun_reserves(e: &PumpFunTradeEvent) -> Option<(ReserveSnapshot, ReserveSnapshot)> {
+    if e.virtual_token_reserves == 0 || e.virtual_sol_reserves == 0 {
+        return None;
+    }
+    let post = ReserveSnapshot { base: e.virtual_token_reserves, quote: e.virtual_sol_reserves };
+    // 买入时曲线付出 token、收入 sol_amount（不含手续费）；卖出时相反
+    let pre = if e.is_buy {
+        ReserveSnapshot {
+            base: post.base.checked_add(e.token_amount)?,
+            quote: post.quote.checked_sub(e.sol_amount)?,
+        }
+    } else {
+        ReserveSnapshot {
+            base: post.base.checked_sub(e.token_amount)?,
+            quote: post.quote.checked_add(e.sol_amount)?,
+        }
+    };
+    Some((pre, post))
+}
+
+fn pumpswap_buy_reserves(e: &PumpSwapBuyEvent) -> Option<(ReserveSnapshot, ReserveSnapshot)> {
+    if e.pool_base_token_reserves == 0 || e.pool_quote_token_reserves == 0 {
+        return None;
+    }
+    let pre =
+        ReserveSnapshot { base: e.pool_base_token_reserves, quote: e.pool_quote_token_reserves };
+    // 协议费与创作者费直接转给接收方，只有成交额与 LP 手续费进入池子
+    let post = ReserveSnapshot {
+        base: pre.base.checked_sub(e.base_amount_out)?,
+        quote: pre.quote.checked_add(e.quote_amount_in_with_lp_fee)?,
+    };
+    Some((pre, post))
+}
+
+fn pumpswap_sell_reserves(e: &PumpSwapSellEvent) -> Option<(ReserveSnapshot, ReserveSnapshot)> {
+    if e.pool_base_token_reserves == 0 || e.pool_quote_token_reserves == 0 {
+        return None;
+    }
+    let pre =
+        ReserveSnapshot { base: e.pool_base_token_reserves, quote: e.pool_quote_token_reserves };
+    // LP 手续费留在池内，其余输出（用户所得、协议费、创作者费）从池子转出
+    let post = ReserveSnapshot {
+        base: pre.base.checked_add(e.base_amount_in)?,
+        quote: pre.quote.checked_sub(e.quote_amount_out_without_lp_fee)?,
+    };
+    Some((pre, post))
+}
+
+fn bonk_reserves(e: &BonkTradeEvent) -> Option<(ReserveSnapshot, ReserveSnapshot)> {
+    if e.virtual_base == 0 || e.virtual_quote == 0 {
+        return None;
+    }
+    let curve = |real_base: u64, real_quote: u64| {
+        Some(ReserveSnapshot {
+            base: e.virtual_base.checked_sub(real_base)?,
+            quote: e.virtual_quote.checked_add(real_quote)?,
+        })
+    };
+    let pre = curve(e.real_base_before, e.real_quote_before)?;
+    let post = curve(e.real_base_after, e.real_quote_after)?;
+    // 买入时曲线储备的 base 减少，卖出时增加
+    let consistent = match e.trade_direction {
+        TradeDirection::Buy => post.base <= pre.base,
+        TradeDirection::Sell => post.base >= pre.base,
+    };
+    consistent.then_some((pre, post))
+}
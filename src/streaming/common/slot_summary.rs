@@ -0,0 +1,221 @@
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+use super::constants::{DEFAULT_SLOT_SUMMARY_TIMEOUT_MS, SLOT_SUMMARY_FLUSHED_CACHE_SIZE};
+use super::lru::LruCache;
+use super::trade::UnifiedTrade;
+use crate::streaming::event_parser::common::ProtocolType;
+use crate::streaming::event_parser::DexEvent;
+
+/// 单个槽位的事件汇总
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SlotSummary {
+    pub slot: u64,
+    /// 槽位内的事件总数（不含区块元数据与槽位状态事件）
+    pub event_count: u64,
+    /// 按协议统计的事件数，按首次出现的顺序排列
+    pub events_by_protocol: Vec<(ProtocolType, u64)>,
+    /// 能转换为 `UnifiedTrade` 的成交数
+    pub trade_count: u64,
+    /// 含 SOL 一侧的成交的 SOL 成交额（lamports）
+    pub sol_volume_lamports: u64,
+    /// 区块时间（毫秒），来自区块元数据；超时输出时为 None
+    pub block_time_ms: Option<i64>,
+    /// 是否因区块元数据在超时时间内未到达而输出
+    pub timed_out: bool,
+}
+
+impl SlotSummary {
+    fn new(slot: u64) -> Self {
+        Self { slot, ..Default::default() }
+    }
+
+    /// SOL 成交额（按 `LAMPORTS_PER_SOL` 换算为 SOL）
+    pub fn sol_volume(&self) -> f64 {
+        self.sol_volume_lamports as f64 / LAMPORTS_PER_SOL as f64
+    }
+
+    /// 指定协议的事件数
+    pub fn protocol_event_count(&self, protocol: &ProtocolType) -> u64 {
+        self.events_by_protocol.iter().find(|(p, _)| p == protocol).map_or(0, |(_, count)| *count)
+    }
+
+    fn record(&mut self, event: &DexEvent) {
+        self.event_count += 1;
+        let protocol = event.protocol();
+        match self.events_by_protocol.iter_mut().find(|(p, _)| p == protocol) {
+            Some((_, count)) => *count += 1,
+            None => self.events_by_protocol.push((protocol.clone(), 1)),
+        }
+        if let Some(trade) = UnifiedTrade::from_event(event) {
+            self.trade_count += 1;
+            if let Some(lamports) = trade.sol_lamports() {
+                self.sol_volume_lamports = self.sol_volume_lamports.saturating_add(lamports);
+            }
+        }
+    }
+}
+
+/// 槽位汇总回调
+pub type SlotSummaryCallback = Arc<dyn Fn(SlotSummary) + Send + Sync>;
+
+struct PendingSlot {
+    summary: SlotSummary,
+    /// 该槽位第一个事件到达的时间
+    first_seen: Instant,
+}
+
+/// 按槽位聚合事件，每个槽位输出一次 `SlotSummary`
+///
+/// 槽位的区块元数据（`BlockMetaEvent`）到达时输出该槽位的汇总；区块元数据一直未到达
+/// （未订阅区块元数据、ShredStream 或槽位被跳过）时，第一个事件到达 `timeout` 之后由 `flush_expired`
+/// （或 `spawn_flush_task` 启动的定时任务）输出，并标记 `timed_out`。
+/// 槽位输出之后才到达的事件被忽略
+pub struct SlotSummaryAggregator {
+    timeout: Duration,
+    slots: Mutex<SlotState>,
+    callback: SlotSummaryCallback,
+}
+
+struct SlotState {
+    pending: HashMap<u64, PendingSlot>,
+    /// 最近已输出的槽位
+    flushed: LruCache<u64, ()>,
+}
+
+impl SlotSummaryAggregator {
+    pub fn new(timeout: Duration, callback: SlotSummaryCallback) -> Self {
+        Self {
+            timeout,
+            slots: Mutex::new(SlotState {
+                pending: HashMap::new(),
+                flushed: LruCache::new(SLOT_SUMMARY_FLUSHED_CACHE_SIZE),
+            }),
+            callback,
+        }
+    }
+
+    /// 使用默认超时（2 秒）创建
+    pub fn with_default_timeout(callback: SlotSummaryCallback) -> Self {
+        Self::new(Duration::from_millis(DEFAULT_SLOT_SUMMARY_TIMEOUT_MS), callback)
+    }
+
+    /// 等待区块元数据的超时时间
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// 处理事件：区块元数据输出对应槽位的汇总，槽位状态事件忽略，其它事件计入所在槽位
+    pub fn on_event(&self, event: &DexEvent) {
+        let summary = {
+            let mut state = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            match event {
+                DexEvent::BlockMetaEvent(block_meta) => {
+                    let slot = block_meta.slot;
+                    if state.flushed.put(slot, ()).is_some() {
+                        return;
+                    }
+                    let mut summary = match state.pending.remove(&slot) {
+                        Some(pending) => pending.summary,
+                        None => SlotSummary::new(slot),
+                    };
+                    summary.block_time_ms = Some(block_meta.metadata.block_time_ms);
+                    summary
+                }
+                DexEvent::SlotStatusEvent(_) => return,
+                _ => {
+                    let slot = event.slot();
+                    if state.flushed.contains(&slot) {
+                        return;
+                    }
+                    state
+                        .pending
+                        .entry(slot)
+                        .or_insert_with(|| PendingSlot {
+                            summary: SlotSummary::new(slot),
+                            first_seen: Instant::now(),
+                        })
+                        .summary
+                        .record(event);
+                    return;
+                }
+            }
+        };
+        (self.callback)(summary);
+    }
+
+    /// 输出等待区块元数据已超时的槽位，按槽位顺序
+    pub fn flush_expired(&self) {
+        let now = Instant::now();
+        let mut expired: Vec<SlotSummary> = {
+            let mut state = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            let slots: Vec<u64> = state
+                .pending
+                .iter()
+                .filter(|(_, pending)| now.duration_since(pending.first_seen) >= self.timeout)
+                .map(|(slot, _)| *slot)
+                .collect();
+            slots
+                .into_iter()
+                .filter_map(|slot| {
+                    state.flushed.put(slot, ());
+                    state.pending.remove(&slot)
+                })
+                .map(|pending| SlotSummary { timed_out: true, ..pending.summary })
+                .collect()
+        };
+        expired.sort_by_key(|summary| summary.slot);
+        for summary in expired {
+            (self.callback)(summary);
+        }
+    }
+
+    /// 输出所有未完成的槽位并清空状态（例如停止订阅前），按槽位顺序
+    pub fn flush(&self) {
+        let mut pending: Vec<SlotSummary> = {
+            let mut state = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            let pending: Vec<(u64, PendingSlot)> = state.pending.drain().collect();
+            for (slot, _) in &pending {
+                state.flushed.put(*slot, ());
+            }
+            pending.into_iter().map(|(_, pending)| pending.summary).collect()
+        };
+        pending.sort_by_key(|summary| summary.slot);
+        for summary in pending {
+            (self.callback)(summary);
+        }
+    }
+
+    /// 启动按超时周期调用 `flush_expired` 的后台任务，abort 返回的句柄即可停止
+    pub fn spawn_flush_task(self: &Arc<Self>) -> JoinHandle<()> {
+        let aggregator = self.clone();
+        tokio::spawn(async move {
+            let period = (aggregator.timeout / 4).max(Duration::from_millis(10));
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+                aggregator.flush_expired();
+            }
+        })
+    }
+
+    /// 生成可直接传给订阅接口的事件 callback
+    pub fn event_callback(self: &Arc<Self>) -> impl Fn(DexEvent) + Send + Sync + 'static {
+        let aggregator = self.clone();
+        move |event: DexEvent| aggregator.on_event(&event)
+    }
+}
+
+/// 创建先把事件计入槽位汇总、再调用原始 callback 的包装器
+pub fn create_slot_summary_callback(
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    aggregator: Arc<SlotSummaryAggregator>,
+) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+    Arc::new(move |event: DexEvent| {
+        aggregator.on_event(&event);
+        callback(event);
+    })
+}
@@ -1,42 +1,160 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
 use tokio::task::JoinHandle;
 
+use super::metrics::MetricsManager;
+
+/// Process-wide unique id of a subscription, assigned when the subscription starts
+///
+/// Included in the subscription's log messages (as `[sub-N]`) and used as the key of its
+/// per-subscription metrics, so several streams in one process can be told apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubscriptionId(u64);
+
+impl SubscriptionId {
+    /// Allocate the next unused id
+    pub fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for SubscriptionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sub-{}", self.0)
+    }
+}
+
+/// Cancellation flag shared by the parse work of a subscription
+///
+/// Cancelled when the subscription stops. Parse tasks, the entries loop of a ShredStream batch
+/// and the callback threads check it, so a backlog of already received data is dropped instead
+/// of being parsed and delivered after `stop()` returned.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all work holding a clone of this token
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
 /// Subscription handle for managing and stopping subscriptions
+///
+/// Dropping the handle aborts all related tasks, same as `stop`, cancels its
+/// `CancellationToken` and removes the subscription's metrics from its `MetricsManager`.
 pub struct SubscriptionHandle {
-    stream_handle: JoinHandle<()>,
+    id: SubscriptionId,
+    metrics_manager: MetricsManager,
+    cancellation: CancellationToken,
+    stream_handle: Option<JoinHandle<()>>,
     event_handle: Option<JoinHandle<()>>,
     metrics_handle: Option<JoinHandle<()>>,
 }
 
 impl SubscriptionHandle {
-    /// Create a new subscription handle
+    /// Create a new subscription handle with a freshly allocated id, reporting to the global
+    /// `MetricsManager`
     pub fn new(
         stream_handle: JoinHandle<()>,
         event_handle: Option<JoinHandle<()>>,
         metrics_handle: Option<JoinHandle<()>>,
     ) -> Self {
-        Self { stream_handle, event_handle, metrics_handle }
+        Self::with_id(
+            SubscriptionId::next(),
+            MetricsManager::global(),
+            stream_handle,
+            event_handle,
+            metrics_handle,
+        )
     }
 
-    /// Stop subscription and abort all related tasks
-    pub fn stop(self) {
-        self.stream_handle.abort();
-        if let Some(handle) = self.event_handle {
-            handle.abort();
-        }
-        if let Some(handle) = self.metrics_handle {
-            handle.abort();
+    /// Create a subscription handle for a subscription whose id was allocated (and registered
+    /// with `metrics_manager`) before its tasks were spawned
+    pub fn with_id(
+        id: SubscriptionId,
+        metrics_manager: MetricsManager,
+        stream_handle: JoinHandle<()>,
+        event_handle: Option<JoinHandle<()>>,
+        metrics_handle: Option<JoinHandle<()>>,
+    ) -> Self {
+        Self {
+            id,
+            metrics_manager,
+            cancellation: CancellationToken::new(),
+            stream_handle: Some(stream_handle),
+            event_handle,
+            metrics_handle,
         }
     }
 
+    /// Use `cancellation` as the token cancelled when the subscription stops, for a token that
+    /// was handed to the subscription's parse work before the handle was created
+    pub fn with_cancellation_token(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Id of this subscription
+    pub fn id(&self) -> SubscriptionId {
+        self.id
+    }
+
+    /// Token cancelled when this subscription stops
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Whether the subscription's stream task has ended, e.g. after a fatal stream error
+    pub fn is_finished(&self) -> bool {
+        self.stream_handle.as_ref().map_or(true, JoinHandle::is_finished)
+    }
+
+    /// Stop subscription, abort all related tasks and drop parse work that has not finished
+    pub fn stop(self) {
+        drop(self);
+    }
+
     /// Asynchronously wait for all tasks to complete
-    pub async fn join(self) -> Result<(), tokio::task::JoinError> {
-        let _ = self.stream_handle.await;
-        if let Some(handle) = self.event_handle {
+    pub async fn join(mut self) -> Result<(), tokio::task::JoinError> {
+        if let Some(handle) = self.stream_handle.take() {
+            let _ = handle.await;
+        }
+        if let Some(handle) = self.event_handle.take() {
             let _ = handle.await;
         }
-        if let Some(handle) = self.metrics_handle {
+        if let Some(handle) = self.metrics_handle.take() {
             let _ = handle.await;
         }
         Ok(())
     }
 }
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        // 先取消，使中止前仍在运行的解析与 callback 线程尽快丢弃剩余工作
+        self.cancellation.cancel();
+        for handle in [&self.stream_handle, &self.event_handle, &self.metrics_handle]
+            .into_iter()
+            .flatten()
+        {
+            handle.abort();
+        }
+        self.metrics_manager.unregister_subscription(self.id);
+    }
+}
@@ -0,0 +1,120 @@
+use std::future::Future;
+use tokio::task::JoinHandle;
+
+use super::config::StreamClientConfig;
+#[cfg(feature = "thread-affinity")]
+use super::{
+    config::ThreadAffinityConfig,
+    constants::{LOG_TARGET, STREAM_WORKER_THREAD_NAME},
+};
+
+/// 启动订阅的流任务
+///
+/// 配置了 `thread_affinity` 时运行在绑定核心的专用运行时上，否则（或专用运行时创建失败时）
+/// 运行在调用方的 tokio 运行时上。abort 返回的句柄即可停止任务，专用运行时随之关闭
+#[cfg_attr(not(feature = "thread-affinity"), allow(unused_variables))]
+pub(crate) fn spawn_stream_task<F>(config: &StreamClientConfig, task: F) -> JoinHandle<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    #[cfg(feature = "thread-affinity")]
+    if let Some(affinity) = &config.thread_affinity {
+        return spawn_pinned(affinity, task);
+    }
+    tokio::spawn(task)
+}
+
+#[cfg(feature = "thread-affinity")]
+fn spawn_pinned<F>(affinity: &ThreadAffinityConfig, task: F) -> JoinHandle<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc;
+
+    let core_ids = affinity.core_ids.clone();
+    let realtime_priority = affinity.realtime_priority;
+    // 运行时在专用线程上创建并由其持有，不能在异步上下文中释放
+    let (started_tx, started_rx) = mpsc::channel();
+    let owner = std::thread::Builder::new()
+        .name(format!("{STREAM_WORKER_THREAD_NAME}-owner"))
+        .spawn(move || {
+            let next_thread = AtomicUsize::new(0);
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(core_ids.len().max(1))
+                .thread_name(STREAM_WORKER_THREAD_NAME)
+                .on_thread_start(move || {
+                    // 按启动顺序轮流分配核心（blocking 线程同样绑定）
+                    let index = next_thread.fetch_add(1, Ordering::Relaxed);
+                    let core_id = (!core_ids.is_empty()).then(|| core_ids[index % core_ids.len()]);
+                    configure_current_thread(core_id, realtime_priority);
+                })
+                .enable_all()
+                .build();
+            let runtime = match runtime {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    let _ = started_tx.send(Err(e));
+                    return;
+                }
+            };
+            let (done_tx, done_rx) = tokio::sync::oneshot::channel::<()>();
+            let _ = started_tx.send(Ok((runtime.handle().clone(), done_tx)));
+            runtime.block_on(async {
+                let _ = done_rx.await;
+            });
+        });
+    // 等待运行时创建完成（只在订阅时发生一次，耗时很短）
+    let started = owner
+        .map_err(|e| e.to_string())
+        .and_then(|_| started_rx.recv().map_err(|e| e.to_string()))
+        .and_then(|started| started.map_err(|e| e.to_string()));
+    match started {
+        Ok((runtime, done_tx)) => runtime.spawn(async move {
+            // 任务结束或被 abort 时 done_tx 随之释放，持有运行时的线程随后关闭运行时
+            let _done = done_tx;
+            task.await;
+        }),
+        Err(e) => {
+            log::warn!(
+                target: LOG_TARGET,
+                "Failed to start pinned stream runtime: {e}, running on the current runtime"
+            );
+            tokio::spawn(task)
+        }
+    }
+}
+
+#[cfg(feature = "thread-affinity")]
+fn configure_current_thread(core_id: Option<usize>, realtime_priority: Option<u8>) {
+    if let Some(id) = core_id {
+        if !core_affinity::set_for_current(core_affinity::CoreId { id }) {
+            log::warn!(target: LOG_TARGET, "Failed to pin stream worker thread to core {id}");
+        }
+    }
+    if let Some(priority) = realtime_priority {
+        set_realtime_priority(priority);
+    }
+}
+
+#[cfg(all(feature = "thread-affinity", target_os = "linux"))]
+fn set_realtime_priority(priority: u8) {
+    let param = libc::sched_param { sched_priority: i32::from(priority) };
+    // SAFETY: pid 0 表示调用线程，param 在调用期间有效
+    let result = unsafe { libc::sched_setscheduler(0, libc::SCHED_FIFO, &param) };
+    if result != 0 {
+        log::warn!(
+            target: LOG_TARGET,
+            "Failed to set SCHED_FIFO priority {priority} on stream worker thread: {}",
+            std::io::Error::last_os_error()
+        );
+    }
+}
+
+#[cfg(all(feature = "thread-affinity", not(target_os = "linux")))]
+fn set_realtime_priority(priority: u8) {
+    log::warn!(
+        target: LOG_TARGET,
+        "Real-time priority {priority} is only supported on Linux, stream worker thread unchanged"
+    );
+}
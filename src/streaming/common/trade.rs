@@ -0,0 +1,200 @@
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signature};
+
+use crate::streaming::event_parser::common::{ProtocolType, SwapData};
+use crate::streaming::event_parser::protocols::bonk::types::TradeDirection;
+use crate::streaming::event_parser::DexEvent;
+
+/// Wrapped SOL mint
+pub const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+/// USDC mint
+pub const USDC_MINT: Pubkey = solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+/// USDT mint
+pub const USDT_MINT: Pubkey = solana_sdk::pubkey!("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB");
+
+/// 作为报价币的 mint，交易对中出现时总是被视为 quote 一侧
+pub const QUOTE_MINTS: &[Pubkey] = &[WSOL_MINT, USDC_MINT, USDT_MINT];
+
+/// 按 `QUOTE_MINTS` 判断交易对中的 quote 一侧，两侧都不是报价币时取字节序较小的 mint 作为 quote
+///
+/// 返回 `(base_mint, quote_mint)`
+pub fn canonical_base_quote(mint_a: Pubkey, mint_b: Pubkey) -> (Pubkey, Pubkey) {
+    let a_is_quote = if QUOTE_MINTS.contains(&mint_a) {
+        true
+    } else if QUOTE_MINTS.contains(&mint_b) {
+        false
+    } else {
+        mint_a < mint_b
+    };
+    if a_is_quote {
+        (mint_b, mint_a)
+    } else {
+        (mint_a, mint_b)
+    }
+}
+
+/// 以 base/quote 表示的成交方向与数量，`is_buy` 表示用 quote 买入 base（与 PumpFun 一致）
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TradeSide {
+    pub is_buy: bool,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+/// 跨协议统一的成交视图
+///
+/// 数量均为链上原始单位（未按 decimals 换算）；`is_buy` 表示用 quote 买入 base。
+/// PumpFun 的原生 SOL 以 `WSOL_MINT` 表示，与 WSOL 同样计入 `is_sol_side`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnifiedTrade {
+    pub signature: Signature,
+    pub slot: u64,
+    pub block_time_ms: i64,
+    pub recv_us: i64,
+    pub protocol: ProtocolType,
+    pub pool: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub is_buy: bool,
+    pub trader: Pubkey,
+    /// 交易对中是否有一侧为 SOL / WSOL，该侧数量单位为 lamports
+    pub is_sol_side: bool,
+}
+
+impl UnifiedTrade {
+    /// 从成交类事件提取统一成交，非成交事件或缺少成交数量时返回 None
+    ///
+    /// PumpFun / PumpSwap / Bonk 直接使用事件字段；Raydium 与 Meteora 使用 `swap_data`，
+    /// 按 [`canonical_base_quote`] 判断 base/quote 一侧
+    pub fn from_event(event: &DexEvent) -> Option<Self> {
+        let metadata = event.metadata();
+        let mut trade = UnifiedTrade {
+            signature: metadata.signature,
+            slot: metadata.slot,
+            block_time_ms: metadata.block_time_ms,
+            recv_us: metadata.recv_us,
+            protocol: metadata.protocol.clone(),
+            ..Default::default()
+        };
+
+        match event {
+            DexEvent::PumpFunTradeEvent(e) => {
+                trade.pool = e.bonding_curve;
+                trade.base_mint = e.mint;
+                trade.quote_mint = WSOL_MINT;
+                trade.base_amount = e.token_amount;
+                trade.quote_amount = e.sol_amount;
+                trade.is_buy = e.is_buy;
+                trade.trader = e.user;
+            }
+            DexEvent::PumpSwapBuyEvent(e) => {
+                trade.pool = e.pool;
+                trade.base_mint = e.base_mint;
+                trade.quote_mint = e.quote_mint;
+                trade.base_amount = e.base_amount_out;
+                trade.quote_amount = e.quote_amount_in;
+                trade.is_buy = true;
+                trade.trader = e.user;
+            }
+            DexEvent::PumpSwapSellEvent(e) => {
+                trade.pool = e.pool;
+                trade.base_mint = e.base_mint;
+                trade.quote_mint = e.quote_mint;
+                trade.base_amount = e.base_amount_in;
+                trade.quote_amount = e.quote_amount_out;
+                trade.is_buy = false;
+                trade.trader = e.user;
+            }
+            DexEvent::BonkTradeEvent(e) => {
+                trade.pool = e.pool_state;
+                trade.base_mint = e.base_token_mint;
+                trade.quote_mint = e.quote_token_mint;
+                trade.is_buy = e.trade_direction == TradeDirection::Buy;
+                (trade.quote_amount, trade.base_amount) = if trade.is_buy {
+                    (e.amount_in, e.amount_out)
+                } else {
+                    (e.amount_out, e.amount_in)
+                };
+                trade.trader = e.payer;
+            }
+            DexEvent::RaydiumCpmmSwapEvent(e) => {
+                trade.pool = e.pool_state;
+                trade.trader = e.payer;
+                trade.apply_swap_data(metadata.swap_data.as_ref()?);
+            }
+            DexEvent::RaydiumClmmSwapEvent(e) => {
+                trade.pool = e.pool_state;
+                trade.trader = e.payer;
+                trade.apply_swap_data(metadata.swap_data.as_ref()?);
+            }
+            DexEvent::RaydiumClmmSwapV2Event(e) => {
+                trade.pool = e.pool_state;
+                trade.trader = e.payer;
+                trade.apply_swap_data(metadata.swap_data.as_ref()?);
+            }
+            DexEvent::RaydiumAmmV4SwapEvent(e) => {
+                trade.pool = e.amm;
+                trade.trader = e.user_source_owner;
+                trade.apply_swap_data(metadata.swap_data.as_ref()?);
+            }
+            DexEvent::MeteoraDammV2SwapEvent(e) => {
+                trade.pool = e.pool;
+                trade.trader = e.payer;
+                trade.apply_swap_data(metadata.swap_data.as_ref()?);
+            }
+            DexEvent::MeteoraDammV2Swap2Event(e) => {
+                trade.pool = e.pool;
+                trade.trader = e.payer;
+                trade.apply_swap_data(metadata.swap_data.as_ref()?);
+            }
+            _ => return None,
+        }
+
+        if trade.base_amount == 0 || trade.quote_amount == 0 {
+            return None;
+        }
+        trade.is_sol_side = trade.base_mint == WSOL_MINT || trade.quote_mint == WSOL_MINT;
+        Some(trade)
+    }
+
+    /// 成交价格（每单位 base 对应的 quote，原始单位）
+    pub fn price(&self) -> f64 {
+        self.quote_amount as f64 / self.base_amount as f64
+    }
+
+    /// SOL 一侧的数量（lamports），交易对不含 SOL 时返回 None
+    ///
+    /// 优先取 quote 一侧；WSOL 只有在与 USDC/USDT 等报价币配对时才可能位于 base 一侧
+    pub fn sol_lamports(&self) -> Option<u64> {
+        if self.quote_mint == WSOL_MINT {
+            Some(self.quote_amount)
+        } else if self.base_mint == WSOL_MINT {
+            Some(self.base_amount)
+        } else {
+            None
+        }
+    }
+
+    /// SOL 一侧的数量（按 `LAMPORTS_PER_SOL` 换算为 SOL），交易对不含 SOL 时返回 None
+    pub fn sol_value(&self) -> Option<f64> {
+        self.sol_lamports().map(|lamports| lamports as f64 / LAMPORTS_PER_SOL as f64)
+    }
+
+    fn apply_swap_data(&mut self, swap_data: &SwapData) {
+        let (_, quote_mint) = canonical_base_quote(swap_data.from_mint, swap_data.to_mint);
+        let from_is_quote = quote_mint == swap_data.from_mint;
+        self.is_buy = from_is_quote;
+        if from_is_quote {
+            self.quote_mint = swap_data.from_mint;
+            self.quote_amount = swap_data.from_amount;
+            self.base_mint = swap_data.to_mint;
+            self.base_amount = swap_data.to_amount;
+        } else {
+            self.base_mint = swap_data.from_mint;
+            self.base_amount = swap_data.from_amount;
+            self.quote_mint = swap_data.to_mint;
+            self.quote_amount = swap_data.to_amount;
+        }
+    }
+}
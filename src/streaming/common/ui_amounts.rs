@@ -0,0 +1,68 @@
+use std::fmt;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::common::{UnifiedTrade, WSOL_MINT};
+use crate::streaming::event_parser::common::UiAmounts;
+use crate::streaming::event_parser::DexEvent;
+
+// WSOL / 原生 SOL 的精度，无需查询
+const WSOL_DECIMALS: u8 = 9;
+
+/// mint 精度解析器，返回 None 表示未知精度
+///
+/// 在解析线程上对每个成交事件调用，应从本地缓存读取，不要发起网络请求。
+/// WSOL（包括 PumpFun 以 WSOL 表示的原生 SOL）固定为 9 位，不会传给解析器
+#[derive(Clone)]
+pub struct DecimalsResolver(Arc<dyn Fn(&Pubkey) -> Option<u8> + Send + Sync>);
+
+impl DecimalsResolver {
+    pub fn new(resolver: impl Fn(&Pubkey) -> Option<u8> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(resolver))
+    }
+
+    /// 解析 mint 的精度
+    pub fn decimals(&self, mint: &Pubkey) -> Option<u8> {
+        if *mint == WSOL_MINT {
+            return Some(WSOL_DECIMALS);
+        }
+        (self.0)(mint)
+    }
+}
+
+impl fmt::Debug for DecimalsResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DecimalsResolver")
+    }
+}
+
+impl UiAmounts {
+    /// 按 base / quote mint 的精度换算统一成交的数量，任一精度未知或超出 `Decimal` 范围时返回 None
+    pub fn from_trade(trade: &UnifiedTrade, resolver: &DecimalsResolver) -> Option<Self> {
+        let base_decimals = resolver.decimals(&trade.base_mint)?;
+        let quote_decimals = resolver.decimals(&trade.quote_mint)?;
+        Some(Self {
+            base_amount: to_ui_amount(trade.base_amount, base_decimals)?,
+            quote_amount: to_ui_amount(trade.quote_amount, quote_decimals)?,
+            base_decimals,
+            quote_decimals,
+        })
+    }
+}
+
+/// 原始数量除以 `10^decimals`，精度超过 `Decimal` 支持的 28 位时返回 None
+pub fn to_ui_amount(amount: u64, decimals: u8) -> Option<Decimal> {
+    Decimal::try_from_i128_with_scale(i128::from(amount), u32::from(decimals)).ok()
+}
+
+/// 为成交事件写入 `EventMetadata::ui_amounts`，非成交事件或精度未知时不修改
+pub fn annotate_ui_amounts(event: &mut DexEvent, resolver: &DecimalsResolver) {
+    let Some(trade) = UnifiedTrade::from_event(event) else {
+        return;
+    };
+    if let Some(ui_amounts) = UiAmounts::from_trade(&trade, resolver) {
+        event.metadata_mut().ui_amounts = Some(ui_amounts);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::common::{
-    types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES,
+    types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES, SLOT_EVENT_TYPES,
 };
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
@@ -9,9 +9,11 @@ pub struct EventTypeFilter {
 
 impl EventTypeFilter {
     pub fn include_transaction_event(&self) -> bool {
-        self.include
-            .iter()
-            .any(|event| !ACCOUNT_EVENT_TYPES.contains(event) && !BLOCK_EVENT_TYPES.contains(event))
+        self.include.iter().any(|event| {
+            !ACCOUNT_EVENT_TYPES.contains(event)
+                && !BLOCK_EVENT_TYPES.contains(event)
+                && !SLOT_EVENT_TYPES.contains(event)
+        })
     }
 
     pub fn include_account_event(&self) -> bool {
@@ -21,4 +23,8 @@ impl EventTypeFilter {
     pub fn include_block_event(&self) -> bool {
         self.include.iter().any(|event| BLOCK_EVENT_TYPES.contains(event))
     }
+
+    pub fn include_slot_event(&self) -> bool {
+        self.include.iter().any(|event| SLOT_EVENT_TYPES.contains(event))
+    }
 }
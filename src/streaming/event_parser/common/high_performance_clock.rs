@@ -1,6 +1,17 @@
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, RwLock};
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+/// 时钟源
+///
+/// `recv_us` / `handle_us` 等时间戳都通过全局时钟源获取。生产环境使用 `HighPerformanceClock`，
+/// 测试中可通过 `set_clock` 注入 `ManualClock` 等确定性时钟，从而断言精确的延迟数值
+pub trait Clock: Send + Sync {
+    /// 当前 UTC 时间戳（微秒）
+    fn now_micros(&self) -> i64;
+}
+
 /// 高性能时钟管理器，减少系统调用开销并最小化延迟
 #[derive(Debug)]
 pub struct HighPerformanceClock {
@@ -112,13 +123,71 @@ impl Default for HighPerformanceClock {
     }
 }
 
+impl Clock for HighPerformanceClock {
+    #[inline(always)]
+    fn now_micros(&self) -> i64 {
+        HighPerformanceClock::now_micros(self)
+    }
+}
+
+/// 手动控制的时钟，时间只在调用 `set` / `advance` 时变化
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now_us: AtomicI64,
+}
+
+impl ManualClock {
+    /// 创建指向指定时间戳（微秒）的时钟
+    pub fn new(now_us: i64) -> Self {
+        Self { now_us: AtomicI64::new(now_us) }
+    }
+
+    /// 设置当前时间戳（微秒）
+    pub fn set(&self, now_us: i64) {
+        self.now_us.store(now_us, Ordering::Relaxed);
+    }
+
+    /// 时间前进指定微秒数
+    pub fn advance(&self, delta_us: i64) {
+        self.now_us.fetch_add(delta_us, Ordering::Relaxed);
+    }
+}
+
+impl Clock for ManualClock {
+    #[inline]
+    fn now_micros(&self) -> i64 {
+        self.now_us.load(Ordering::Relaxed)
+    }
+}
+
 /// 全局高性能时钟实例
 static HIGH_PERF_CLOCK: std::sync::OnceLock<HighPerformanceClock> =
     std::sync::OnceLock::new();
 
-/// 获取全局高性能时钟实例（最简单的实现）
+/// 注入的时钟源；未注入时直接走高性能时钟，热路径只多一次原子读
+static CUSTOM_CLOCK_ENABLED: AtomicBool = AtomicBool::new(false);
+static CUSTOM_CLOCK: RwLock<Option<Arc<dyn Clock>>> = RwLock::new(None);
+
+/// 替换全局时钟源（用于测试），之后所有时间戳都从该时钟获取
+pub fn set_clock(clock: Arc<dyn Clock>) {
+    *CUSTOM_CLOCK.write().unwrap_or_else(|e| e.into_inner()) = Some(clock);
+    CUSTOM_CLOCK_ENABLED.store(true, Ordering::Release);
+}
+
+/// 恢复默认的高性能时钟
+pub fn reset_clock() {
+    CUSTOM_CLOCK_ENABLED.store(false, Ordering::Release);
+    *CUSTOM_CLOCK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// 获取当前时间戳（微秒），优先使用注入的时钟源
 #[inline(always)]
 pub fn get_high_perf_clock() -> i64 {
+    if CUSTOM_CLOCK_ENABLED.load(Ordering::Acquire) {
+        if let Some(clock) = CUSTOM_CLOCK.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            return clock.now_micros();
+        }
+    }
     let clock = HIGH_PERF_CLOCK.get_or_init(HighPerformanceClock::new);
     clock.now_micros()
 }
@@ -1,5 +1,8 @@
 pub mod filter;
 pub mod high_performance_clock;
+#[cfg(feature = "rkyv")]
+pub mod rkyv_with;
+pub mod slot_clock;
 pub mod types;
 pub mod utils;
 pub use types::*;
@@ -0,0 +1,113 @@
+//! rkyv wrappers for Solana SDK (and other foreign) types that don't implement `rkyv::Archive` themselves.
+//!
+//! Used through `#[rkyv(with = ...)]` on event fields; archived values are the raw bytes.
+
+use rkyv::rancor::Fallible;
+use rkyv::with::{ArchiveWith, DeserializeWith, SerializeWith};
+use rkyv::{Archive, Place};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+/// Archives a `Pubkey` (or a fixed-size array of them) as raw 32-byte arrays
+pub struct PubkeyBytes;
+
+impl ArchiveWith<Pubkey> for PubkeyBytes {
+    type Archived = [u8; 32];
+    type Resolver = [(); 32];
+
+    fn resolve_with(field: &Pubkey, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        field.to_bytes().resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<Pubkey, S> for PubkeyBytes {
+    fn serialize_with(_: &Pubkey, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([(); 32])
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<[u8; 32], Pubkey, D> for PubkeyBytes {
+    fn deserialize_with(field: &[u8; 32], _: &mut D) -> Result<Pubkey, D::Error> {
+        Ok(Pubkey::new_from_array(*field))
+    }
+}
+
+impl<const N: usize> ArchiveWith<[Pubkey; N]> for PubkeyBytes {
+    type Archived = [[u8; 32]; N];
+    type Resolver = [[(); 32]; N];
+
+    fn resolve_with(field: &[Pubkey; N], resolver: Self::Resolver, out: Place<Self::Archived>) {
+        field.map(|pubkey| pubkey.to_bytes()).resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized, const N: usize> SerializeWith<[Pubkey; N], S> for PubkeyBytes {
+    fn serialize_with(_: &[Pubkey; N], _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([[(); 32]; N])
+    }
+}
+
+impl<D: Fallible + ?Sized, const N: usize> DeserializeWith<[[u8; 32]; N], [Pubkey; N], D>
+    for PubkeyBytes
+{
+    fn deserialize_with(field: &[[u8; 32]; N], _: &mut D) -> Result<[Pubkey; N], D::Error> {
+        Ok(field.map(Pubkey::new_from_array))
+    }
+}
+
+/// Archives a `Signature` as a raw 64-byte array
+pub struct SignatureBytes;
+
+impl ArchiveWith<Signature> for SignatureBytes {
+    type Archived = [u8; 64];
+    type Resolver = [(); 64];
+
+    fn resolve_with(field: &Signature, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(field.as_ref());
+        bytes.resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<Signature, S> for SignatureBytes {
+    fn serialize_with(_: &Signature, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([(); 64])
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<[u8; 64], Signature, D> for SignatureBytes {
+    fn deserialize_with(field: &[u8; 64], _: &mut D) -> Result<Signature, D::Error> {
+        Ok(Signature::from(*field))
+    }
+}
+
+/// Archives a `rust_decimal::Decimal` as its 16-byte serialized form
+#[cfg(feature = "decimal")]
+pub struct DecimalBytes;
+
+#[cfg(feature = "decimal")]
+impl ArchiveWith<rust_decimal::Decimal> for DecimalBytes {
+    type Archived = [u8; 16];
+    type Resolver = [(); 16];
+
+    fn resolve_with(
+        field: &rust_decimal::Decimal,
+        resolver: Self::Resolver,
+        out: Place<Self::Archived>,
+    ) {
+        field.serialize().resolve(resolver, out);
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<S: Fallible + ?Sized> SerializeWith<rust_decimal::Decimal, S> for DecimalBytes {
+    fn serialize_with(_: &rust_decimal::Decimal, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([(); 16])
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<D: Fallible + ?Sized> DeserializeWith<[u8; 16], rust_decimal::Decimal, D> for DecimalBytes {
+    fn deserialize_with(field: &[u8; 16], _: &mut D) -> Result<rust_decimal::Decimal, D::Error> {
+        Ok(rust_decimal::Decimal::deserialize(*field))
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Mutex;
+
+use crate::streaming::common::constants::SLOT_DURATION_MS;
+
+/// 槽位时间锚点
+#[derive(Clone, Copy)]
+struct SlotAnchor {
+    slot: u64,
+    unix_ms: i64,
+    /// 锚点时间是否来自数据源提供的真实区块时间
+    observed: bool,
+}
+
+static SLOT_ANCHOR: Mutex<Option<SlotAnchor>> = Mutex::new(None);
+
+/// 记录数据源提供的真实区块时间，作为后续估算的锚点
+pub fn observe_block_time(slot: u64, unix_ms: i64) {
+    let mut anchor = SLOT_ANCHOR.lock().unwrap_or_else(|e| e.into_inner());
+    if anchor.map_or(true, |anchor| !anchor.observed || slot >= anchor.slot) {
+        *anchor = Some(SlotAnchor { slot, unix_ms, observed: true });
+    }
+}
+
+/// 按槽位估算区块时间（毫秒）
+///
+/// 以锚点为基准按每个槽位 `SLOT_DURATION_MS` 推算。没有真实区块时间的数据源（如 ShredStream）
+/// 以每个新槽位首次出现时的接收时间作为锚点，因此估算值适用于实时数据流，不适用于历史交易
+pub fn estimate_block_time_ms(slot: u64, recv_us: i64) -> i64 {
+    let recv_ms = recv_us / 1000;
+    let mut anchor = SLOT_ANCHOR.lock().unwrap_or_else(|e| e.into_inner());
+    match *anchor {
+        Some(a) if a.observed || slot <= a.slot => {
+            a.unix_ms + (slot as i64 - a.slot as i64) * SLOT_DURATION_MS
+        }
+        _ => {
+            *anchor = Some(SlotAnchor { slot, unix_ms: recv_ms, observed: false });
+            recv_ms
+        }
+    }
+}
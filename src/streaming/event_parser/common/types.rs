@@ -1,10 +1,15 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use crossbeam_queue::ArrayQueue;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::{borrow::Cow, fmt, str::FromStr, sync::Arc};
 
 use crate::streaming::{common::SimdUtils, event_parser::DexEvent};
+#[cfg(all(feature = "rkyv", feature = "decimal"))]
+use crate::streaming::event_parser::common::rkyv_with::DecimalBytes;
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::{PubkeyBytes, SignatureBytes};
 
 // Object pool size configuration
 const EVENT_METADATA_POOL_SIZE: usize = 1000;
@@ -39,9 +44,9 @@ impl EventMetadataPool {
 pub static EVENT_METADATA_POOL: std::sync::LazyLock<EventMetadataPool> =
     std::sync::LazyLock::new(EventMetadataPool::new);
 
-#[derive(
-    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
-)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum ProtocolType {
     #[default]
     PumpSwap,
@@ -55,9 +60,9 @@ pub enum ProtocolType {
 }
 
 /// Event type enumeration
-#[derive(
-    Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
-)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum EventType {
     // PumpSwap events
     #[default]
@@ -73,6 +78,7 @@ pub enum EventType {
     PumpFunBuy,
     PumpFunSell,
     PumpFunMigrate,
+    PumpFunAdmin,
 
     // Bonk events
     BonkBuyExactIn,
@@ -116,6 +122,8 @@ pub enum EventType {
     MeteoraDammV2InitializePool,
     MeteoraDammV2InitializeCustomizablePool,
     MeteoraDammV2InitializePoolWithDynamicConfig,
+    MeteoraDammV2AddLiquidity,
+    MeteoraDammV2RemoveLiquidity,
 
     // Account events
     AccountRaydiumAmmV4AmmInfo,
@@ -135,12 +143,18 @@ pub enum EventType {
 
     NonceAccount,
     TokenAccount,
+    AccountChange,
 
     // Common events
     BlockMeta,
+    SlotStatus,
     SetComputeUnitLimit,
     SetComputeUnitPrice,
+    Memo,
     Unknown,
+
+    // 后续新增的事件类型追加在末尾，不改变已有变体的序号（borsh / rkyv 序列化按序号编码）
+    PumpSwapBuyExactQuoteIn,
 }
 
 pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
@@ -160,13 +174,16 @@ pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
     EventType::AccountRaydiumCpmmPoolState,
     EventType::TokenAccount,
     EventType::NonceAccount,
+    EventType::AccountChange,
 ];
 pub const BLOCK_EVENT_TYPES: &[EventType] = &[EventType::BlockMeta];
+pub const SLOT_EVENT_TYPES: &[EventType] = &[EventType::SlotStatus];
 
 impl fmt::Display for EventType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             EventType::PumpSwapBuy => write!(f, "PumpSwapBuy"),
+            EventType::PumpSwapBuyExactQuoteIn => write!(f, "PumpSwapBuyExactQuoteIn"),
             EventType::PumpSwapSell => write!(f, "PumpSwapSell"),
             EventType::PumpSwapCreatePool => write!(f, "PumpSwapCreatePool"),
             EventType::PumpSwapDeposit => write!(f, "PumpSwapDeposit"),
@@ -176,6 +193,7 @@ impl fmt::Display for EventType {
             EventType::PumpFunBuy => write!(f, "PumpFunBuy"),
             EventType::PumpFunSell => write!(f, "PumpFunSell"),
             EventType::PumpFunMigrate => write!(f, "PumpFunMigrate"),
+            EventType::PumpFunAdmin => write!(f, "PumpFunAdmin"),
             EventType::BonkBuyExactIn => write!(f, "BonkBuyExactIn"),
             EventType::BonkBuyExactOut => write!(f, "BonkBuyExactOut"),
             EventType::BonkSellExactIn => write!(f, "BonkSellExactIn"),
@@ -215,6 +233,8 @@ impl fmt::Display for EventType {
             EventType::MeteoraDammV2InitializePool => write!(f, "MeteoraDammV2InitializePool"),
             EventType::MeteoraDammV2InitializeCustomizablePool => write!(f, "MeteoraDammV2InitializeCustomizablePool"),
             EventType::MeteoraDammV2InitializePoolWithDynamicConfig => write!(f, "MeteoraDammV2InitializePoolWithDynamicConfig"),
+            EventType::MeteoraDammV2AddLiquidity => write!(f, "MeteoraDammV2AddLiquidity"),
+            EventType::MeteoraDammV2RemoveLiquidity => write!(f, "MeteoraDammV2RemoveLiquidity"),
             EventType::AccountRaydiumAmmV4AmmInfo => write!(f, "AccountRaydiumAmmV4AmmInfo"),
             EventType::AccountPumpSwapGlobalConfig => write!(f, "AccountPumpSwapGlobalConfig"),
             EventType::AccountPumpSwapPool => write!(f, "AccountPumpSwapPool"),
@@ -233,9 +253,12 @@ impl fmt::Display for EventType {
             EventType::AccountRaydiumCpmmPoolState => write!(f, "AccountRaydiumCpmmPoolState"),
             EventType::TokenAccount => write!(f, "TokenAccount"),
             EventType::NonceAccount => write!(f, "NonceAccount"),
+            EventType::AccountChange => write!(f, "AccountChange"),
             EventType::BlockMeta => write!(f, "BlockMeta"),
+            EventType::SlotStatus => write!(f, "SlotStatus"),
             EventType::SetComputeUnitLimit => write!(f, "SetComputeUnitLimit"),
             EventType::SetComputeUnitPrice => write!(f, "SetComputeUnitPrice"),
+            EventType::Memo => write!(f, "Memo"),
             EventType::Unknown => write!(f, "Unknown"),
         }
     }
@@ -268,9 +291,12 @@ impl<T> ParseResult<T> {
 }
 
 /// Protocol information
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct ProtocolInfo {
     pub name: String,
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
     pub program_ids: Vec<Pubkey>,
 }
 
@@ -284,20 +310,68 @@ impl ProtocolInfo {
     }
 }
 
-#[derive(
-    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
-)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct SwapData {
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub from_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub to_mint: Pubkey,
     pub from_amount: u64,
     pub to_amount: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<rkyv::with::AsOwned>))]
     pub description: Option<Cow<'static, str>>,
+    /// Where the amounts come from
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source: SwapDataSource,
+}
+
+/// Trade amounts in UI units (raw amount divided by `10^decimals`), see
+/// `ParseConfig::decimals_resolver`
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct UiAmounts {
+    /// Base token amount, in the units of `UnifiedTrade::base_mint`
+    #[cfg_attr(feature = "rkyv", rkyv(with = DecimalBytes))]
+    pub base_amount: rust_decimal::Decimal,
+    /// Quote token amount, in the units of `UnifiedTrade::quote_mint` (SOL for SOL pairs)
+    #[cfg_attr(feature = "rkyv", rkyv(with = DecimalBytes))]
+    pub quote_amount: rust_decimal::Decimal,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+}
+
+/// Pool reserves at one point of a trade, in raw units of the trade's base and quote mints
+/// (`UnifiedTrade::base_mint` / `quote_mint`), see `ParseConfig::reserve_snapshots`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct ReserveSnapshot {
+    pub base: u64,
+    pub quote: u64,
+}
+
+/// Provenance of `SwapData` amounts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum SwapDataSource {
+    /// Decoded directly from the instruction / CPI log
+    Direct,
+    /// Inferred from the token transfers following the instruction
+    #[default]
+    InferredFromTransfers,
 }
 
 /// Event metadata
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct EventMetadata {
+    #[cfg_attr(feature = "rkyv", rkyv(with = SignatureBytes))]
     pub signature: Signature,
     pub slot: u64,
     pub tx_index: Option<u64>, // 新增：交易在slot中的索引
@@ -307,13 +381,99 @@ pub struct EventMetadata {
     pub handle_us: i64,
     pub protocol: ProtocolType,
     pub event_type: EventType,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program_id: Pubkey,
     pub swap_data: Option<SwapData>,
     pub outer_index: i64,
     pub inner_index: Option<i64>,
+    /// Instruction invocation depth: 1 for outer instructions, 2+ for CPIs.
+    /// Taken from the inner instruction's `stack_height` when available, otherwise 1.
+    #[cfg_attr(feature = "serde", serde(default = "default_stack_height"))]
+    pub stack_height: u32,
+    /// Position of the producing instruction in the transaction's execution order, starting at 0:
+    /// each outer instruction is followed by its inner instructions, so sorting by this index
+    /// reconstructs execution order across outer and inner instructions. 0 for events not
+    /// produced by an instruction.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub flat_ix_index: u32,
+    /// Emission order of this event within its transaction, starting at 0.
+    /// `(signature, tx_event_seq)` uniquely identifies an event.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tx_event_seq: u32,
+    /// Compute unit limit requested by the transaction, attached when
+    /// `ComputeBudgetMode::Attach`/`Both` is configured.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compute_unit_limit: Option<u32>,
+    /// Compute unit price (micro-lamports) requested by the transaction, attached when
+    /// `ComputeBudgetMode::Attach`/`Both` is configured.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compute_unit_price: Option<u64>,
     /// Transaction message recent blockhash as base58 string (same encoding as signature), when available.
-    #[serde(default)]
+    /// This is the blockhash the transaction was built against, not the hash of the block it landed in.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub recent_blockhash: Option<String>,
+    /// Fee payer of the transaction (account index 0, the first signer). May differ from the
+    /// trade's `user`/`payer`, e.g. when a relayer pays the fees. Default pubkey for events not
+    /// produced by a transaction.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub fee_payer: Pubkey,
+    /// Time (microseconds since epoch) at which the gRPC provider created the update, taken from
+    /// Yellowstone's `created_at`. `recv_us - server_ts_us` is the provider-to-client delay.
+    /// `None` for sources without a server timestamp (ShredStream, RPC).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub server_ts_us: Option<i64>,
+    /// Full ordered account list of the instruction that produced this event, attached only
+    /// when `ParseConfig::attach_instruction_accounts` is set. Useful to verify account mapping.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<rkyv::with::Map<PubkeyBytes>>))]
+    pub all_accounts: Option<Vec<Pubkey>>,
+    /// Whether `block_time` / `block_time_ms` were estimated from the slot because the source
+    /// provided no block time (always the case for ShredStream), see
+    /// `ParseConfig::estimate_missing_block_time`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub block_time_estimated: bool,
+    /// UI-normalized amounts of trade events, set when `ParseConfig::decimals_resolver` is
+    /// configured and resolves the decimals of both mints; `None` for other events.
+    #[cfg(feature = "decimal")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ui_amounts: Option<UiAmounts>,
+}
+
+fn default_stack_height() -> u32 {
+    1
+}
+
+impl Default for EventMetadata {
+    fn default() -> Self {
+        Self {
+            signature: Signature::default(),
+            slot: 0,
+            tx_index: None,
+            block_time: 0,
+            block_time_ms: 0,
+            recv_us: 0,
+            handle_us: 0,
+            protocol: ProtocolType::default(),
+            event_type: EventType::default(),
+            program_id: Pubkey::default(),
+            swap_data: None,
+            outer_index: 0,
+            inner_index: None,
+            stack_height: default_stack_height(),
+            flat_ix_index: 0,
+            tx_event_seq: 0,
+            compute_unit_limit: None,
+            compute_unit_price: None,
+            recent_blockhash: None,
+            fee_payer: Pubkey::default(),
+            server_ts_us: None,
+            all_accounts: None,
+            block_time_estimated: false,
+            #[cfg(feature = "decimal")]
+            ui_amounts: None,
+        }
+    }
 }
 
 impl EventMetadata {
@@ -345,8 +505,19 @@ impl EventMetadata {
             swap_data: None,
             outer_index,
             inner_index,
+            stack_height: default_stack_height(),
+            flat_ix_index: 0,
+            tx_event_seq: 0,
+            compute_unit_limit: None,
+            compute_unit_price: None,
             tx_index,
             recent_blockhash,
+            fee_payer: Pubkey::default(),
+            server_ts_us: None,
+            all_accounts: None,
+            block_time_estimated: false,
+            #[cfg(feature = "decimal")]
+            ui_amounts: None,
         }
     }
 
@@ -381,6 +552,7 @@ pub fn parse_swap_data_from_next_instructions(
         from_amount: 0,
         to_amount: 0,
         description: None,
+        source: SwapDataSource::InferredFromTransfers,
     };
 
     // 先根据 event 取出关键信息
@@ -551,6 +723,7 @@ pub fn parse_swap_data_from_next_grpc_instructions(
         from_amount: 0,
         to_amount: 0,
         description: None,
+        source: SwapDataSource::InferredFromTransfers,
     };
 
     // 先根据 event 取出关键信息
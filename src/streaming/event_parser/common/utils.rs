@@ -108,3 +108,13 @@ pub fn format_pubkey_short(pubkey: &solana_sdk::pubkey::Pubkey) -> String {
         format!("{}...{}", &s[..4], &s[s.len() - 4..])
     }
 }
+
+/// 按索引读取指令账户，越界时让所在函数返回 `None`
+///
+/// 协议解析器用它代替 `accounts[i]`：账户布局变化或账户列表过短时不产生事件，而不是 panic
+macro_rules! acct {
+    ($accounts:expr, $index:expr) => {
+        *$accounts.get($index)?
+    };
+}
+pub(crate) use acct;
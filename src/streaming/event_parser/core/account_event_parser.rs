@@ -1,65 +1,269 @@
+use crate::streaming::common::constants::DEFAULT_NONCE_CACHE_SIZE;
+use crate::streaming::common::lru::LruCache;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::high_performance_clock::elapsed_micros_since;
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 use crate::streaming::event_parser::common::{EventMetadata, EventType, ProtocolType};
+use crate::streaming::event_parser::core::parse_config::ParseConfig;
 use crate::streaming::event_parser::core::traits::DexEvent;
 use crate::streaming::event_parser::Protocol;
+use crate::streaming::grpc::pool::factory;
 use crate::streaming::grpc::AccountPretty;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_account_decoder::parse_nonce::parse_nonce;
 use solana_sdk::pubkey::Pubkey;
 use spl_token::solana_program::program_pack::Pack;
-use spl_token::state::{Account, Mint};
+use spl_token::state::{Account, Mint, Multisig};
 use spl_token_2022::{
-    extension::StateWithExtensions,
+    extension::{
+        mint_close_authority::MintCloseAuthority, permanent_delegate::PermanentDelegate,
+        AccountType, BaseStateWithExtensions, StateWithExtensions,
+    },
     state::{Account as Account2022, Mint as Mint2022},
 };
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateAccount;
 
 /// 通用账户事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct TokenAccountEvent {
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub amount: Option<u64>,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_owner: Pubkey,
 }
 
 /// Nonce account event
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct NonceAccountEvent {
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub nonce: String,
     pub authority: String,
+    /// What changed compared to the last seen state of this nonce account; None when neither
+    /// the blockhash nor the authority changed (e.g. a withdrawal or a transfer into the account)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub changed: Option<NonceChange>,
+}
+
+/// Change reflected by a nonce account update, relative to the last cached state
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum NonceChange {
+    /// No previous state cached (first update seen for this account)
+    #[default]
+    Initialized,
+    /// The stored blockhash changed (`AdvanceNonceAccount`)
+    BlockhashAdvanced,
+    /// The authority changed (`AuthorizeNonceAccount`); takes precedence when the blockhash
+    /// changed in the same update
+    AuthorityChanged,
 }
 
 /// Nonce account event
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct TokenInfoEvent {
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub supply: u64,
     pub decimals: u8,
+    /// Token-2022 `MintCloseAuthority` extension; None when the extension is absent or unset
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
+    pub close_authority: Option<Pubkey>,
+    /// Token-2022 `PermanentDelegate` extension; None when the extension is absent or unset
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
+    pub permanent_delegate: Option<Pubkey>,
+}
+
+/// 账户变化事件（账户 diff 模式）
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct AccountChangeEvent {
+    pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub pubkey: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub owner: Pubkey,
+    pub old_lamports: u64,
+    pub new_lamports: u64,
+    pub data_changed: bool,
+}
+
+/// 账户 diff 模式下缓存的上一次账户状态
+struct AccountSnapshot {
+    lamports: u64,
+    data_hash: u64,
+}
+
+/// 有状态账户解析使用的缓存（账户 diff 快照与 nonce 账户快照）
+///
+/// 每个订阅各自创建一份，不同客户端、不同订阅之间互不共享上一次看到的账户状态
+pub struct AccountStateCache {
+    account_snapshots: Mutex<LruCache<Pubkey, AccountSnapshot>>,
+    nonce_snapshots: Mutex<LruCache<Pubkey, NonceSnapshot>>,
+}
+
+impl AccountStateCache {
+    /// 按解析配置中的 `account_diff_cache_size` 创建
+    pub fn new(parse_config: &ParseConfig) -> Self {
+        Self {
+            account_snapshots: Mutex::new(LruCache::new(parse_config.account_diff_cache_size)),
+            nonce_snapshots: Mutex::new(LruCache::new(DEFAULT_NONCE_CACHE_SIZE)),
+        }
+    }
+}
+
+impl Default for AccountStateCache {
+    fn default() -> Self {
+        Self::new(&ParseConfig::default())
+    }
+}
+
+/// 缓存的上一次 nonce 账户状态
+struct NonceSnapshot {
+    nonce: String,
+    authority: String,
 }
 
 pub struct AccountEventParser {}
 
 impl AccountEventParser {
+    /// 按解析配置解析账户事件
+    ///
+    /// 开启 `account_diff` 时不再输出完整快照，而是与 `state` 中缓存的上一次的值比较，
+    /// 仅在 lamports 或数据发生变化时输出 `AccountChangeEvent`（首次看到的账户只做缓存）。
+    pub fn parse_account_event_with_config(
+        protocols: &[Protocol],
+        account: AccountPretty,
+        event_type_filter: Option<&EventTypeFilter>,
+        parse_config: &ParseConfig,
+        state: &AccountStateCache,
+    ) -> Option<DexEvent> {
+        if !parse_config.account_diff {
+            return Self::parse_account_snapshot_event(
+                protocols,
+                account,
+                event_type_filter,
+                Some(state),
+            );
+        }
+        if let Some(filter) = event_type_filter {
+            if !filter.include.contains(&EventType::AccountChange) {
+                return None;
+            }
+        }
+        Self::parse_account_change_event(&account, state)
+    }
+
+    /// 与 `state` 中缓存的上一次账户状态比较，生成账户变化事件
+    pub fn parse_account_change_event(
+        account: &AccountPretty,
+        state: &AccountStateCache,
+    ) -> Option<DexEvent> {
+        let mut hasher = DefaultHasher::new();
+        account.data.hash(&mut hasher);
+        let data_hash = hasher.finish();
+        let snapshot = AccountSnapshot { lamports: account.lamports, data_hash };
+
+        let previous = {
+            let mut snapshots = state.account_snapshots.lock().unwrap_or_else(|e| e.into_inner());
+            snapshots.put(account.pubkey, snapshot)?
+        };
+        let data_changed = previous.data_hash != data_hash;
+        if previous.lamports == account.lamports && !data_changed {
+            return None;
+        }
+
+        let metadata = EventMetadata {
+            slot: account.slot,
+            signature: account.signature,
+            protocol: ProtocolType::Common,
+            event_type: EventType::AccountChange,
+            program_id: account.owner,
+            recv_us: account.recv_us,
+            handle_us: elapsed_micros_since(account.recv_us),
+            ..Default::default()
+        };
+        Some(DexEvent::AccountChangeEvent(AccountChangeEvent {
+            metadata,
+            pubkey: account.pubkey,
+            owner: account.owner,
+            old_lamports: previous.lamports,
+            new_lamports: account.lamports,
+            data_changed,
+        }))
+    }
+
+    /// 离线批量解析账户更新（例如落盘保存的数据）
+    ///
+    /// 通过对象池构建 `AccountPretty` 后逐个解析，输出顺序与输入一致；
+    /// 不含账户数据的更新以及未产生事件的账户被跳过
+    pub fn parse_batch(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        updates: Vec<SubscribeUpdateAccount>,
+    ) -> Vec<DexEvent> {
+        updates
+            .into_iter()
+            .filter(|update| update.account.is_some())
+            .filter_map(|update| {
+                let account = factory::create_account_pretty_pooled(update);
+                Self::parse_account_event(protocols, account, event_type_filter)
+            })
+            .collect()
+    }
+
+    /// 无状态解析账户事件
+    ///
+    /// 不缓存上一次的账户状态，nonce 账户事件的 `changed` 恒为 `Initialized`；
+    /// 需要识别 nonce 变化时使用 `parse_account_event_with_config`
     pub fn parse_account_event(
         protocols: &[Protocol],
         account: AccountPretty,
         event_type_filter: Option<&EventTypeFilter>,
+    ) -> Option<DexEvent> {
+        Self::parse_account_snapshot_event(protocols, account, event_type_filter, None)
+    }
+
+    /// 解析账户快照事件，`state` 为 None 时不与上一次的 nonce 状态比较
+    fn parse_account_snapshot_event(
+        protocols: &[Protocol],
+        account: AccountPretty,
+        event_type_filter: Option<&EventTypeFilter>,
+        state: Option<&AccountStateCache>,
     ) -> Option<DexEvent> {
         use crate::streaming::event_parser::core::dispatcher::EventDispatcher;
 
@@ -118,7 +322,7 @@ impl AccountEventParser {
         };
 
         // 尝试解析 Nonce 账户
-        if let Some(event) = Self::parse_nonce_account_event(&account, metadata.clone()) {
+        if let Some(event) = Self::parse_nonce_account(&account, metadata.clone(), state) {
             if let Some(filter) = event_type_filter {
                 if filter.include.contains(&event.metadata().event_type) {
                     return Some(event);
@@ -142,6 +346,10 @@ impl AccountEventParser {
         None
     }
 
+    /// 解析 Token / Token2022 程序拥有的 Mint 与 token 账户
+    ///
+    /// 其它程序拥有的账户、空数据以及无法按 owner 程序的布局解码的账户返回 None，见
+    /// `classify_token_account`
     pub fn parse_token_account_event(
         account: &AccountPretty,
         mut metadata: EventMetadata,
@@ -153,74 +361,75 @@ impl AccountEventParser {
         let lamports = account.lamports;
         let owner = account.owner;
         let rent_epoch = account.rent_epoch;
-        // Spl Token Mint
-        if account.data.len() >= Mint::LEN {
-            if let Ok(mint) = Mint::unpack_from_slice(&account.data) {
-                let mut event = TokenInfoEvent {
-                    metadata,
-                    pubkey,
-                    executable,
-                    lamports,
-                    owner,
-                    rent_epoch,
-                    supply: mint.supply,
-                    decimals: mint.decimals,
-                };
-                let recv_delta = elapsed_micros_since(account.recv_us);
-                event.metadata.handle_us = recv_delta;
-                return Some(DexEvent::TokenInfoEvent(event));
-            }
-        }
-        // Spl Token2022 Mint
-        if account.data.len() >= Account2022::LEN {
-            if let Ok(mint) = StateWithExtensions::<Mint2022>::unpack(&account.data) {
-                let mut event = TokenInfoEvent {
-                    metadata,
-                    pubkey,
-                    executable,
-                    lamports,
-                    owner,
-                    rent_epoch,
-                    supply: mint.base.supply,
-                    decimals: mint.base.decimals,
-                };
-                let recv_delta = elapsed_micros_since(account.recv_us);
-                event.metadata.handle_us = recv_delta;
-                return Some(DexEvent::TokenInfoEvent(event));
-            }
-        }
-        let amount = if account.owner.to_bytes() == spl_token_2022::ID.to_bytes() {
-            StateWithExtensions::<Account2022>::unpack(&account.data)
-                .ok()
-                .map(|info| info.base.amount)
-        } else {
-            Account::unpack(&account.data).ok().map(|info| info.amount)
-        };
-
-        let mut event = TokenAccountEvent {
-            metadata,
-            pubkey,
-            executable,
-            lamports,
-            owner,
-            rent_epoch,
-            amount,
-            token_owner: account.owner,
+        let mut event = match classify_token_account(&account.owner, &account.data)? {
+            // Spl Token / Token2022 Mint
+            TokenAccountData::Mint(mint) => DexEvent::TokenInfoEvent(TokenInfoEvent {
+                metadata,
+                pubkey,
+                executable,
+                lamports,
+                owner,
+                rent_epoch,
+                supply: mint.supply,
+                decimals: mint.decimals,
+                close_authority: mint.close_authority,
+                permanent_delegate: mint.permanent_delegate,
+            }),
+            TokenAccountData::Account(amount) => DexEvent::TokenAccountEvent(TokenAccountEvent {
+                metadata,
+                pubkey,
+                executable,
+                lamports,
+                owner,
+                rent_epoch,
+                amount: Some(amount),
+                token_owner: account.owner,
+            }),
         };
-        let recv_delta = elapsed_micros_since(account.recv_us);
-        event.metadata.handle_us = recv_delta;
-        Some(DexEvent::TokenAccountEvent(event))
+        event.metadata_mut().handle_us = elapsed_micros_since(account.recv_us);
+        Some(event)
     }
 
+    /// 无状态解析 nonce 账户，`changed` 恒为 `Initialized`
     pub fn parse_nonce_account_event(
+        account: &AccountPretty,
+        metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        Self::parse_nonce_account(account, metadata, None)
+    }
+
+    /// 解析 nonce 账户，并与 `state` 中缓存的上一次状态比较得出变化类型
+    fn parse_nonce_account(
         account: &AccountPretty,
         mut metadata: EventMetadata,
+        state: Option<&AccountStateCache>,
     ) -> Option<DexEvent> {
         metadata.event_type = EventType::NonceAccount;
 
         if let Ok(info) = parse_nonce(&account.data) {
             match info {
                 solana_account_decoder::parse_nonce::UiNonceState::Initialized(details) => {
+                    let snapshot = NonceSnapshot {
+                        nonce: details.blockhash.clone(),
+                        authority: details.authority.clone(),
+                    };
+                    let previous = state.and_then(|state| {
+                        state
+                            .nonce_snapshots
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .put(account.pubkey, snapshot)
+                    });
+                    let changed = match previous {
+                        None => Some(NonceChange::Initialized),
+                        Some(previous) if previous.authority != details.authority => {
+                            Some(NonceChange::AuthorityChanged)
+                        }
+                        Some(previous) if previous.nonce != details.blockhash => {
+                            Some(NonceChange::BlockhashAdvanced)
+                        }
+                        Some(_) => None,
+                    };
                     let mut event = NonceAccountEvent {
                         metadata,
                         pubkey: account.pubkey,
@@ -230,6 +439,7 @@ impl AccountEventParser {
                         rent_epoch: account.rent_epoch,
                         nonce: details.blockhash,
                         authority: details.authority,
+                        changed,
                     };
                     event.metadata.handle_us = elapsed_micros_since(account.recv_us);
                     return Some(DexEvent::NonceAccountEvent(event));
@@ -240,3 +450,92 @@ impl AccountEventParser {
         None
     }
 }
+
+/// 解码出的 Mint 信息
+struct TokenMintInfo {
+    supply: u64,
+    decimals: u8,
+    close_authority: Option<Pubkey>,
+    permanent_delegate: Option<Pubkey>,
+}
+
+/// 按 owner 程序与数据长度识别出的 token 程序账户
+enum TokenAccountData {
+    Mint(TokenMintInfo),
+    /// token 账户余额
+    Account(u64),
+}
+
+/// 识别 Token / Token2022 程序拥有的账户
+///
+/// 以 owner 程序为准：经典 Token 程序只有固定长度的 Mint 与 token 账户，带扩展的布局只在
+/// owner 为 Token2022 时解析。其它 owner、空数据、多签账户、未初始化或无法按该布局解码的
+/// 数据一律返回 None，不再尝试按另一种类型猜测
+fn classify_token_account(owner: &Pubkey, data: &[u8]) -> Option<TokenAccountData> {
+    let is_token_2022 = owner.to_bytes() == spl_token_2022::ID.to_bytes();
+    if !is_token_2022 && owner.to_bytes() != spl_token::ID.to_bytes() {
+        return None;
+    }
+    if let Some(mint) = unpack_token_mint(data, is_token_2022) {
+        return Some(TokenAccountData::Mint(mint));
+    }
+    unpack_token_account_amount(data, is_token_2022).map(TokenAccountData::Account)
+}
+
+/// 按长度和账户类型判断数据是否为 Mint 并解码
+///
+/// SPL Token 的 Mint 长度固定为 `Mint::LEN`；Token2022 的 Mint 也可能带扩展，
+/// 此时长度超过 `Account::LEN` 且第 `Account::LEN` 字节为 `AccountType::Mint`
+fn unpack_token_mint(data: &[u8], is_token_2022: bool) -> Option<TokenMintInfo> {
+    if data.len() == Mint::LEN {
+        let mint = Mint::unpack_from_slice(data).ok()?;
+        return mint.is_initialized.then_some(TokenMintInfo {
+            supply: mint.supply,
+            decimals: mint.decimals,
+            close_authority: None,
+            permanent_delegate: None,
+        });
+    }
+    if !is_token_2022 || token_2022_account_type(data)? != AccountType::Mint as u8 {
+        return None;
+    }
+    let mint = StateWithExtensions::<Mint2022>::unpack(data).ok()?;
+    // 扩展缺失或数据损坏时只丢弃该扩展，不影响 supply / decimals
+    let close_authority = mint
+        .get_extension::<MintCloseAuthority>()
+        .ok()
+        .and_then(|ext| Option::<Pubkey>::from(ext.close_authority));
+    let permanent_delegate = mint
+        .get_extension::<PermanentDelegate>()
+        .ok()
+        .and_then(|ext| Option::<Pubkey>::from(ext.delegate));
+    Some(TokenMintInfo {
+        supply: mint.base.supply,
+        decimals: mint.base.decimals,
+        close_authority,
+        permanent_delegate,
+    })
+}
+
+/// 按长度和账户类型判断数据是否为 token 账户并解码余额
+///
+/// SPL Token 账户长度固定为 `Account::LEN`；Token2022 账户带扩展时第 `Account::LEN` 字节为
+/// `AccountType::Account`，不能按普通账户只解析前 `Account::LEN` 字节
+fn unpack_token_account_amount(data: &[u8], is_token_2022: bool) -> Option<u64> {
+    if data.len() == Account::LEN {
+        return Account::unpack(data).ok().map(|account| account.amount);
+    }
+    if !is_token_2022 || token_2022_account_type(data)? != AccountType::Account as u8 {
+        return None;
+    }
+    let account = StateWithExtensions::<Account2022>::unpack(data).ok()?;
+    Some(account.base.amount)
+}
+
+/// 带扩展的 Token2022 账户的类型字节；长度不足或为多签账户时返回 None
+fn token_2022_account_type(data: &[u8]) -> Option<u8> {
+    if data.len() <= Account::LEN || data.len() == Multisig::LEN {
+        return None;
+    }
+    Some(data[Account::LEN])
+}
@@ -1,18 +1,30 @@
 use crate::streaming::event_parser::common::high_performance_clock::elapsed_micros_since;
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 use crate::streaming::event_parser::common::types::{EventType, ProtocolType};
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::streaming::event_parser::core::traits::DexEvent;
 use crate::streaming::event_parser::protocols::block::block_meta_event::BlockMetaEvent;
+use crate::streaming::event_parser::protocols::block::slot_status_event::{
+    SlotStatus, SlotStatusEvent,
+};
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 // Compute Budget Program ID
 pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("ComputeBudget111111111111111111111111111111");
+pub const VOTE_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("Vote111111111111111111111111111111111111111");
+// SPL Memo Program ID
+pub const MEMO_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
 
 /// SetComputeUnitLimit 事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct SetComputeUnitLimitEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -21,7 +33,9 @@ pub struct SetComputeUnitLimitEvent {
 }
 
 /// SetComputeUnitPrice 事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct SetComputeUnitPriceEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -29,6 +43,19 @@ pub struct SetComputeUnitPriceEvent {
     pub micro_lamports: u64,
 }
 
+/// Memo 事件
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct MemoEvent {
+    pub metadata: EventMetadata,
+    /// memo 内容，非法 UTF-8 字节替换为 U+FFFD
+    pub text: String,
+    /// 第一个签名账户，memo 未要求签名时为 None
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
+    pub signer: Option<Pubkey>,
+}
+
 pub struct CommonEventParser {}
 
 impl CommonEventParser {
@@ -43,6 +70,18 @@ impl CommonEventParser {
         DexEvent::BlockMetaEvent(block_meta_event)
     }
 
+    pub fn generate_slot_status_event(
+        slot: u64,
+        parent: Option<u64>,
+        status: SlotStatus,
+        dead_error: Option<String>,
+        recv_us: i64,
+    ) -> DexEvent {
+        let mut slot_status_event = SlotStatusEvent::new(slot, parent, status, dead_error, recv_us);
+        slot_status_event.metadata.handle_us = elapsed_micros_since(recv_us);
+        DexEvent::SlotStatusEvent(slot_status_event)
+    }
+
     /// 解析 Compute Budget 指令
     pub fn parse_compute_budget_instruction(
         instruction_data: &[u8],
@@ -80,4 +119,25 @@ impl CommonEventParser {
             _ => None,
         }
     }
+
+    /// 解析 Memo 指令
+    ///
+    /// 指令数据整体即 memo 内容（无判别器），账户为要求签名的地址
+    pub fn parse_memo_instruction(
+        instruction_data: &[u8],
+        accounts: &[Pubkey],
+        mut metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        if instruction_data.is_empty() {
+            return None;
+        }
+        metadata.protocol = ProtocolType::Common;
+        metadata.event_type = EventType::Memo;
+        let event = MemoEvent {
+            metadata,
+            text: String::from_utf8_lossy(instruction_data).into_owned(),
+            signer: accounts.first().copied(),
+        };
+        Some(DexEvent::MemoEvent(event))
+    }
 }
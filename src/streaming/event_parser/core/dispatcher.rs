@@ -9,7 +9,7 @@
 
 use crate::streaming::event_parser::{
     common::EventMetadata,
-    core::common_event_parser::{CommonEventParser, COMPUTE_BUDGET_PROGRAM_ID},
+    core::common_event_parser::{CommonEventParser, COMPUTE_BUDGET_PROGRAM_ID, MEMO_PROGRAM_ID},
     protocols::{
         bonk::parser as bonk, meteora_damm_v2::parser as meteora_damm_v2, pumpfun::parser as pumpfun,
         pumpswap::parser as pumpswap, raydium_amm_v4::parser as raydium_amm_v4,
@@ -17,7 +17,24 @@ use crate::streaming::event_parser::{
     },
     DexEvent, Protocol,
 };
+use crate::streaming::common::{MetricsManager, LOG_TARGET};
 use solana_sdk::pubkey::Pubkey;
+use std::panic::AssertUnwindSafe;
+
+/// 协议与 program_id 对照表
+const PROTOCOL_PROGRAM_MAP: &[(Protocol, Pubkey)] = &[
+    (Protocol::PumpFun, pumpfun::PUMPFUN_PROGRAM_ID),
+    (Protocol::PumpSwap, pumpswap::PUMPSWAP_PROGRAM_ID),
+    (Protocol::Bonk, bonk::BONK_PROGRAM_ID),
+    (Protocol::RaydiumCpmm, raydium_cpmm::RAYDIUM_CPMM_PROGRAM_ID),
+    (Protocol::RaydiumClmm, raydium_clmm::RAYDIUM_CLMM_PROGRAM_ID),
+    (Protocol::RaydiumAmmV4, raydium_amm_v4::RAYDIUM_AMM_V4_PROGRAM_ID),
+    (Protocol::MeteoraDammV2, meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID),
+];
+
+/// 指令 discriminator 不是 8 字节的程序，未列出的程序按 8 字节处理
+const DISCRIMINATOR_LEN_MAP: &[(Pubkey, usize)] =
+    &[(raydium_amm_v4::RAYDIUM_AMM_V4_PROGRAM_ID, 1)];
 
 /// 中心事件解析调度器
 ///
@@ -45,16 +62,7 @@ impl EventDispatcher {
         mut metadata: EventMetadata,
     ) -> Option<DexEvent> {
         // 根据协议类型设置 metadata.protocol
-        use crate::streaming::event_parser::common::ProtocolType;
-        metadata.protocol = match protocol {
-            Protocol::PumpFun => ProtocolType::PumpFun,
-            Protocol::PumpSwap => ProtocolType::PumpSwap,
-            Protocol::Bonk => ProtocolType::Bonk,
-            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
-            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
-            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
-            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
-        };
+        metadata.protocol = protocol.clone().into();
 
         match protocol {
             Protocol::PumpFun => pumpfun::parse_pumpfun_instruction_data(
@@ -120,16 +128,7 @@ impl EventDispatcher {
         mut metadata: EventMetadata,
     ) -> Option<DexEvent> {
         // 根据协议类型设置 metadata.protocol
-        use crate::streaming::event_parser::common::ProtocolType;
-        metadata.protocol = match protocol {
-            Protocol::PumpFun => ProtocolType::PumpFun,
-            Protocol::PumpSwap => ProtocolType::PumpSwap,
-            Protocol::Bonk => ProtocolType::Bonk,
-            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
-            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
-            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
-            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
-        };
+        metadata.protocol = protocol.clone().into();
 
         match protocol {
             Protocol::PumpFun => pumpfun::parse_pumpfun_inner_instruction_data(
@@ -170,26 +169,61 @@ impl EventDispatcher {
         }
     }
 
+    /// 调用协议解析器，`catch_panics` 为 true 时捕获解析器中的 panic
+    ///
+    /// panic 视为未解析出事件：记录协议与判别器、计入解析器 panic 指标，订阅任务继续运行
+    #[inline]
+    pub fn guard_parser_panic(
+        protocol: &Protocol,
+        discriminator: &[u8],
+        catch_panics: bool,
+        parse: impl FnOnce() -> Option<DexEvent>,
+    ) -> Option<DexEvent> {
+        if !catch_panics {
+            return parse();
+        }
+        match std::panic::catch_unwind(AssertUnwindSafe(parse)) {
+            Ok(event) => event,
+            Err(panic) => {
+                MetricsManager::current().increment_parser_panics();
+                let message = panic
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| panic.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
+                log::warn!(
+                    target: LOG_TARGET,
+                    "{protocol:?} parser panicked on discriminator {discriminator:02x?}: {message}"
+                );
+                None
+            }
+        }
+    }
+
+    /// 全部支持协议与其 program_id 的对应关系
+    ///
+    /// 协议匹配与预过滤都以此表为准，可用于在外部构建与本 crate 解析范围一致的订阅过滤器
+    #[inline]
+    pub fn protocol_program_map() -> &'static [(Protocol, Pubkey)] {
+        PROTOCOL_PROGRAM_MAP
+    }
+
     /// 通过 program_id 匹配协议类型
     #[inline]
     pub fn match_protocol_by_program_id(program_id: &Pubkey) -> Option<Protocol> {
-        if program_id == &pumpfun::PUMPFUN_PROGRAM_ID {
-            Some(Protocol::PumpFun)
-        } else if program_id == &pumpswap::PUMPSWAP_PROGRAM_ID {
-            Some(Protocol::PumpSwap)
-        } else if program_id == &bonk::BONK_PROGRAM_ID {
-            Some(Protocol::Bonk)
-        } else if program_id == &raydium_cpmm::RAYDIUM_CPMM_PROGRAM_ID {
-            Some(Protocol::RaydiumCpmm)
-        } else if program_id == &raydium_clmm::RAYDIUM_CLMM_PROGRAM_ID {
-            Some(Protocol::RaydiumClmm)
-        } else if program_id == &raydium_amm_v4::RAYDIUM_AMM_V4_PROGRAM_ID {
-            Some(Protocol::RaydiumAmmV4)
-        } else if program_id == &meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID {
-            Some(Protocol::MeteoraDammV2)
-        } else {
-            None
-        }
+        PROTOCOL_PROGRAM_MAP
+            .iter()
+            .find(|(_, id)| id == program_id)
+            .map(|(protocol, _)| protocol.clone())
+    }
+
+    /// 获取程序指令 discriminator 的字节数
+    #[inline]
+    pub fn discriminator_len(program_id: &Pubkey) -> usize {
+        DISCRIMINATOR_LEN_MAP
+            .iter()
+            .find(|(id, _)| id == program_id)
+            .map_or(8, |(_, len)| *len)
     }
 
     /// 检查是否为 Compute Budget Program
@@ -214,23 +248,46 @@ impl EventDispatcher {
         CommonEventParser::parse_compute_budget_instruction(instruction_data, metadata)
     }
 
+    /// 检查是否为 Memo Program
+    #[inline]
+    pub fn is_memo_program(program_id: &Pubkey) -> bool {
+        program_id == &MEMO_PROGRAM_ID
+    }
+
+    /// 解析 Memo 指令
+    ///
+    /// # 参数
+    /// - `instruction_data`: 指令数据
+    /// - `accounts`: 指令账户（要求签名的地址）
+    /// - `metadata`: 事件元数据
+    ///
+    /// # 返回
+    /// 解析成功返回 `Some(DexEvent)`，否则返回 `None`
+    #[inline]
+    pub fn dispatch_memo_instruction(
+        instruction_data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        CommonEventParser::parse_memo_instruction(instruction_data, accounts, metadata)
+    }
+
     /// 获取指定协议的 program_id
     #[inline]
     pub fn get_program_id(protocol: Protocol) -> Pubkey {
-        match protocol {
-            Protocol::PumpFun => pumpfun::PUMPFUN_PROGRAM_ID,
-            Protocol::PumpSwap => pumpswap::PUMPSWAP_PROGRAM_ID,
-            Protocol::Bonk => bonk::BONK_PROGRAM_ID,
-            Protocol::RaydiumCpmm => raydium_cpmm::RAYDIUM_CPMM_PROGRAM_ID,
-            Protocol::RaydiumClmm => raydium_clmm::RAYDIUM_CLMM_PROGRAM_ID,
-            Protocol::RaydiumAmmV4 => raydium_amm_v4::RAYDIUM_AMM_V4_PROGRAM_ID,
-            Protocol::MeteoraDammV2 => meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID,
-        }
+        Self::lookup_program_id(&protocol)
+            .unwrap_or_else(|| panic!("{protocol:?} is missing from PROTOCOL_PROGRAM_MAP"))
     }
 
     /// 批量获取 program_ids
     pub fn get_program_ids(protocols: &[Protocol]) -> Vec<Pubkey> {
-        protocols.iter().map(|p| Self::get_program_id(p.clone())).collect()
+        protocols.iter().filter_map(Self::lookup_program_id).collect()
+    }
+
+    /// 在 PROTOCOL_PROGRAM_MAP 中查找协议对应的 program_id
+    #[inline]
+    fn lookup_program_id(protocol: &Protocol) -> Option<Pubkey> {
+        PROTOCOL_PROGRAM_MAP.iter().find(|(p, _)| p == protocol).map(|(_, id)| *id)
     }
 
     /// 解析账户数据
@@ -252,16 +309,7 @@ impl EventDispatcher {
         mut metadata: crate::streaming::event_parser::common::EventMetadata,
     ) -> Option<DexEvent> {
         // 根据协议类型设置 metadata.protocol
-        use crate::streaming::event_parser::common::ProtocolType;
-        metadata.protocol = match protocol {
-            Protocol::PumpFun => ProtocolType::PumpFun,
-            Protocol::PumpSwap => ProtocolType::PumpSwap,
-            Protocol::Bonk => ProtocolType::Bonk,
-            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
-            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
-            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
-            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
-        };
+        metadata.protocol = protocol.clone().into();
 
         match protocol {
             Protocol::PumpFun => {
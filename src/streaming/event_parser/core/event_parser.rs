@@ -1,26 +1,91 @@
 use crate::streaming::event_parser::{
     DexEvent, Protocol, common::{
-        EventMetadata, filter::EventTypeFilter, high_performance_clock::elapsed_micros_since, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions
+        EventMetadata, EventType, SwapDataSource, filter::EventTypeFilter, high_performance_clock::elapsed_micros_since, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions,
+        slot_clock::{estimate_block_time_ms, observe_block_time},
     }, core::{
+        common_event_parser::{COMPUTE_BUDGET_PROGRAM_ID, MEMO_PROGRAM_ID},
         dispatcher::EventDispatcher,
         global_state::{
             add_bonk_dev_address, add_dev_address, is_bonk_dev_address_in_signature,
             is_dev_address_in_signature,
         },
         merger_event::merge,
-    }, protocols::raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID
+        parse_config::{ComputeBudgetMode, MalformedReason, MissingAccountMode, ParseConfig},
+        transaction_assembler::TransactionCpiLogs,
+    }, protocols::pumpfun,
 };
+#[cfg(feature = "decimal")]
+use crate::streaming::common::annotate_ui_amounts;
+use crate::streaming::common::{annotate_reserve_snapshots, MetricsManager};
+use base64::prelude::{Engine as _, BASE64_STANDARD};
 use prost_types::Timestamp;
 use solana_sdk::{
     message::compiled_instruction::CompiledInstruction, pubkey::Pubkey, signature::Signature,
     transaction::VersionedTransaction,
 };
 use solana_transaction_status::InnerInstructions;
-use std::sync::Arc;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
 pub struct EventParser {}
 
+/// Result of [`EventParser::detect_protocols`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DetectedProtocols {
+    /// Supported protocols whose program id appears in the transaction
+    pub protocols: HashSet<Protocol>,
+    /// Whether the compute budget program appears in the transaction
+    pub uses_compute_budget: bool,
+}
+
+/// 需要解析的协议范围
+#[derive(Clone, Copy)]
+enum ProtocolScope<'a> {
+    /// 请求的协议列表中的任意协议，逐个程序 ID 查找所属协议
+    Any(&'a [Protocol]),
+    /// 单个协议及其程序 ID，只比较程序 ID
+    Single(&'a Protocol, &'a Pubkey),
+}
+
+impl ProtocolScope<'_> {
+    /// 程序 ID 是否需要处理（Compute Budget Program 始终处理）
+    #[inline]
+    fn handles(&self, program_id: &Pubkey) -> bool {
+        match self {
+            ProtocolScope::Any(protocols) => {
+                EventParser::should_handle(protocols, None, program_id)
+            }
+            ProtocolScope::Single(_, target) => {
+                *target == program_id
+                    || EventDispatcher::is_compute_budget_program(program_id)
+                    || EventDispatcher::is_memo_program(program_id)
+            }
+        }
+    }
+
+    /// 原始 32 字节账户是否为范围内协议的程序 ID（不含 Compute Budget / Memo 程序）
+    #[inline]
+    fn handles_protocol_raw(&self, account: &[u8]) -> bool {
+        match self {
+            ProtocolScope::Any(protocols) => EventParser::should_handle_raw(protocols, account),
+            ProtocolScope::Single(_, target) => account == target.as_ref(),
+        }
+    }
+
+    /// 程序 ID 所属的协议
+    #[inline]
+    fn protocol_for(&self, program_id: &Pubkey) -> Option<Protocol> {
+        match self {
+            ProtocolScope::Any(_) => EventDispatcher::match_protocol_by_program_id(program_id),
+            ProtocolScope::Single(protocol, target) => {
+                (*target == program_id).then(|| (*protocol).clone())
+            }
+        }
+    }
+}
+
 impl EventParser {
     // ================================================================================================
     // Public API - Entry Points
@@ -30,6 +95,7 @@ impl EventParser {
     ///
     /// This is the main entry point for parsing transactions received from gRPC streams.
     /// It extracts account keys, inner instructions, and delegates to instruction parsing.
+    #[allow(clippy::too_many_arguments)]
     pub async fn parse_grpc_transaction(
         protocols: &[Protocol],
         event_type_filter: Option<&EventTypeFilter>,
@@ -42,71 +108,216 @@ impl EventParser {
         tx_index: Option<u64>,
         callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        // 创建适配器回调，将所有权回调转换为引用回调
-        let adapter_callback = Arc::new(move |event: &DexEvent| {
-            callback(event.clone());
-        });
-        if let Some(transition) = grpc_tx.transaction {
-            if let Some(message) = &transition.message {
-                let mut address_table_lookups: Vec<Vec<u8>> = vec![];
-                let mut inner_instructions: Vec<
-                    yellowstone_grpc_proto::solana::storage::confirmed_block::InnerInstructions,
-                > = vec![];
+        Self::parse_grpc_transaction_with_config(
+            protocols,
+            event_type_filter,
+            grpc_tx,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            tx_index,
+            callback,
+            &ParseConfig::default(),
+        )
+        .await
+    }
 
-                if let Some(meta) = grpc_tx.meta {
-                    inner_instructions = meta.inner_instructions;
-                    address_table_lookups.reserve(
-                        meta.loaded_writable_addresses.len() + meta.loaded_readonly_addresses.len(),
-                    );
-                    let loaded_writable_addresses = meta.loaded_writable_addresses;
-                    let loaded_readonly_addresses = meta.loaded_readonly_addresses;
-                    address_table_lookups.extend(
-                        loaded_writable_addresses.into_iter().chain(loaded_readonly_addresses),
-                    );
-                }
+    /// Parse transaction from gRPC stream with a custom parser configuration
+    #[allow(clippy::too_many_arguments)]
+    pub async fn parse_grpc_transaction_with_config(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        grpc_tx: SubscribeUpdateTransactionInfo,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+        parse_config: &ParseConfig,
+    ) -> anyhow::Result<()> {
+        Self::parse_grpc_transaction_in_scope(
+            ProtocolScope::Any(protocols),
+            event_type_filter,
+            grpc_tx,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            tx_index,
+            callback,
+            parse_config,
+        )
+        .await
+    }
 
-                let mut accounts_bytes: Vec<Vec<u8>> =
-                    Vec::with_capacity(message.account_keys.len() + address_table_lookups.len());
-                accounts_bytes.extend_from_slice(&message.account_keys);
-                accounts_bytes.extend(address_table_lookups);
-                // 转换为 Pubkey
-                let accounts: Vec<Pubkey> = accounts_bytes
-                    .iter()
-                    .filter_map(|account| {
-                        if account.len() == 32 {
-                            Some(Pubkey::try_from(account.as_slice()).unwrap_or_default())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                // 解析指令事件
-                let instructions = &message.instructions;
-                let recent_blockhash = if message.recent_blockhash.is_empty() {
-                    None
-                } else {
-                    Some(bs58::encode(&message.recent_blockhash).into_string())
-                };
-                Self::parse_instruction_events_from_grpc_transaction(
-                    protocols,
-                    event_type_filter,
-                    &instructions,
-                    signature,
-                    slot,
-                    block_time,
-                    recv_us,
-                    &accounts,
-                    &inner_instructions,
-                    bot_wallet,
-                    tx_index,
-                    recent_blockhash,
-                    adapter_callback,
+    /// Parse transaction from gRPC stream for a single protocol
+    ///
+    /// Fast path for consumers that only care about one protocol: instructions are matched
+    /// against that protocol's program id directly instead of looking up the protocol of every
+    /// program id and checking it against a protocol list. Produces the same events as
+    /// [`Self::parse_grpc_transaction_with_config`] with `&[protocol]`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn parse_single_protocol(
+        protocol: Protocol,
+        event_type_filter: Option<&EventTypeFilter>,
+        grpc_tx: SubscribeUpdateTransactionInfo,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+        parse_config: &ParseConfig,
+    ) -> anyhow::Result<()> {
+        let program_id = EventDispatcher::get_program_id(protocol.clone());
+        Self::parse_grpc_transaction_in_scope(
+            ProtocolScope::Single(&protocol, &program_id),
+            event_type_filter,
+            grpc_tx,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            tx_index,
+            callback,
+            parse_config,
+        )
+        .await
+    }
+
+    /// Parse transaction from gRPC stream, delivering all of its events at once
+    ///
+    /// Collects every event produced by the transaction (in emission order) and invokes the
+    /// callback once with `(signature, events)`. The callback is not invoked when the
+    /// transaction produced no events.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn parse_grpc_transaction_batched(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        grpc_tx: SubscribeUpdateTransactionInfo,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: Arc<dyn Fn(Signature, Vec<DexEvent>) + Send + Sync>,
+        parse_config: &ParseConfig,
+    ) -> anyhow::Result<()> {
+        let events: Arc<Mutex<Vec<DexEvent>>> = Arc::new(Mutex::new(Vec::new()));
+        let collector = {
+            let events = events.clone();
+            Arc::new(move |event: DexEvent| {
+                events.lock().unwrap_or_else(|e| e.into_inner()).push(event);
+            })
+        };
+        Self::parse_grpc_transaction_with_config(
+            protocols,
+            event_type_filter,
+            grpc_tx,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            tx_index,
+            collector,
+            parse_config,
+        )
+        .await?;
+
+        let events = std::mem::take(&mut *events.lock().unwrap_or_else(|e| e.into_inner()));
+        if !events.is_empty() {
+            callback(signature, events);
+        }
+        Ok(())
+    }
+
+    /// Detect which supported protocols a gRPC transaction touches
+    ///
+    /// Only scans account keys (including ALT-loaded addresses), without decoding instructions.
+    /// Much cheaper than a full parse, useful for routing transactions by protocol.
+    pub fn detect_protocols(grpc_tx: &SubscribeUpdateTransactionInfo) -> DetectedProtocols {
+        let mut detected = DetectedProtocols::default();
+        let static_keys = grpc_tx
+            .transaction
+            .as_ref()
+            .and_then(|tx| tx.message.as_ref())
+            .map(|message| message.account_keys.as_slice())
+            .unwrap_or_default();
+        let (loaded_writable, loaded_readonly) = grpc_tx
+            .meta
+            .as_ref()
+            .map(|meta| {
+                (
+                    meta.loaded_writable_addresses.as_slice(),
+                    meta.loaded_readonly_addresses.as_slice(),
                 )
-                .await?;
+            })
+            .unwrap_or_default();
+
+        for account in static_keys.iter().chain(loaded_writable).chain(loaded_readonly) {
+            let Ok(pubkey) = Pubkey::try_from(account.as_slice()) else {
+                continue;
+            };
+            if let Some(protocol) = EventDispatcher::match_protocol_by_program_id(&pubkey) {
+                detected.protocols.insert(protocol);
+            } else if EventDispatcher::is_compute_budget_program(&pubkey) {
+                detected.uses_compute_budget = true;
             }
         }
+        detected
+    }
 
-        Ok(())
+    /// Collect the deduplicated set of accounts referenced by a gRPC transaction's instructions
+    ///
+    /// Covers every outer and inner instruction, resolving account indexes against the static
+    /// account keys followed by ALT-loaded addresses. Program ids are included as well as data
+    /// accounts; keys listed in the message that no instruction references (such as a fee payer
+    /// only used for signing) are not. No instruction data is decoded, so this is much cheaper
+    /// than a full parse, useful for building account-interaction graphs.
+    pub fn transaction_account_set(grpc_tx: &SubscribeUpdateTransactionInfo) -> HashSet<Pubkey> {
+        let message = grpc_tx.transaction.as_ref().and_then(|tx| tx.message.as_ref());
+        let meta = grpc_tx.meta.as_ref();
+        let static_keys =
+            message.map(|message| message.account_keys.as_slice()).unwrap_or_default();
+        let loaded = meta
+            .map(|meta| {
+                meta.loaded_writable_addresses.iter().chain(meta.loaded_readonly_addresses.iter())
+            })
+            .into_iter()
+            .flatten();
+        // 保留无效公钥的位置，确保索引与账户表一致
+        let keys: Vec<Option<Pubkey>> = static_keys
+            .iter()
+            .chain(loaded)
+            .map(|account| Pubkey::try_from(account.as_slice()).ok())
+            .collect();
+
+        let outer = message
+            .into_iter()
+            .flat_map(|message| message.instructions.iter())
+            .map(|ix| (ix.program_id_index, ix.accounts.as_slice()));
+        let inner = meta
+            .into_iter()
+            .flat_map(|meta| meta.inner_instructions.iter())
+            .flat_map(|inner| inner.instructions.iter())
+            .map(|ix| (ix.program_id_index, ix.accounts.as_slice()));
+
+        let mut account_set = HashSet::with_capacity(keys.len());
+        for (program_id_index, accounts) in outer.chain(inner) {
+            let indexes = std::iter::once(program_id_index as usize)
+                .chain(accounts.iter().map(|index| *index as usize));
+            account_set.extend(indexes.filter_map(|index| keys.get(index).copied().flatten()));
+        }
+        account_set
     }
 
     /// Parse transaction from VersionedTransaction
@@ -128,10 +339,50 @@ impl EventParser {
         tx_index: Option<u64>,
         callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        // 创建适配器回调，将所有权回调转换为引用回调
-        let adapter_callback = Arc::new(move |event: &DexEvent| {
-            callback(event.clone());
-        });
+        Self::parse_instruction_events_from_versioned_transaction_with_config(
+            protocols,
+            event_type_filter,
+            transaction,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            accounts,
+            inner_instructions,
+            bot_wallet,
+            tx_index,
+            callback,
+            &ParseConfig::default(),
+        )
+        .await
+    }
+
+    /// Parse transaction from VersionedTransaction with a custom parser configuration
+    #[allow(clippy::too_many_arguments)]
+    pub async fn parse_instruction_events_from_versioned_transaction_with_config(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        transaction: &VersionedTransaction,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        accounts: &[Pubkey],
+        inner_instructions: &[InnerInstructions],
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+        parse_config: &ParseConfig,
+    ) -> anyhow::Result<()> {
+        // 槽位范围之外的交易在解析前跳过
+        if slot.is_some_and(|slot| !parse_config.slot_in_range(slot)) {
+            return Ok(());
+        }
+        let (block_time, block_time_estimated) =
+            Self::resolve_block_time(slot, block_time, recv_us, parse_config);
+        // RPC / ShredStream 交易没有服务端时间戳，block_time 是链上时间
+        let adapter_callback =
+            Self::create_adapter_callback(callback, parse_config, block_time_estimated, None);
         // 获取交易的指令和账户
         let compiled_instructions = transaction.message.instructions();
         let recent_blockhash = Some(transaction.message.recent_blockhash().to_string());
@@ -141,6 +392,18 @@ impl EventParser {
             .iter()
             .any(|account| Self::should_handle(protocols, event_type_filter, account));
         if has_program {
+            // 交易级合并：先收集整笔交易的 CPI log
+            let mut cpi_logs = (parse_config.transaction_level_merge
+                && parse_config.parse_inner_instructions)
+                .then(|| {
+                    TransactionCpiLogs::from_inner_instructions(
+                        inner_instructions,
+                        &accounts,
+                        parse_config.catch_parser_panics,
+                    )
+                });
+            // 按执行顺序为指令编号：每条外层指令之后紧接其 inner instructions
+            let mut flat_ix_index = 0u32;
             // 解析每个指令
             for (index, instruction) in compiled_instructions.iter().enumerate() {
                 if let Some(program_id) = accounts.get(instruction.program_id_index as usize) {
@@ -148,10 +411,19 @@ impl EventParser {
                     let inner_instructions = inner_instructions
                         .iter()
                         .find(|inner_instruction| inner_instruction.index == index as u8);
+                    let outer_flat_ix_index = flat_ix_index;
+                    flat_ix_index += 1 + inner_instructions.map_or(0, |inner_instructions| {
+                        inner_instructions.instructions.len() as u32
+                    });
+                    #[cfg(feature = "unhandled-programs")]
+                    Self::record_unhandled_program(&program_id);
                     if Self::should_handle(protocols, event_type_filter, &program_id) {
                         let max_idx = instruction.accounts.iter().max().unwrap_or(&0);
-                        // 补齐accounts(使用Pubkey::default())
-                        if *max_idx as usize >= accounts.len() {
+                        // 补齐accounts(使用Pubkey::default())，Skip 模式下由解析函数跳过该指令
+                        if *max_idx as usize >= accounts.len()
+                            && parse_config.missing_account_mode == MissingAccountMode::PadDefault
+                        {
+                            MetricsManager::current().increment_missing_account_instructions();
                             accounts.resize(*max_idx as usize + 1, Pubkey::default());
                         }
                         Self::parse_events_from_instruction(
@@ -165,11 +437,15 @@ impl EventParser {
                             recv_us,
                             index as i64,
                             None,
+                            None,
+                            outer_flat_ix_index,
                             bot_wallet,
                             tx_index,
                             recent_blockhash.as_deref(),
                             inner_instructions,
                             adapter_callback.clone(),
+                            cpi_logs.as_mut(),
+                            parse_config,
                         )?;
                     }
                     // Immediately process inner instructions for correct ordering
@@ -188,11 +464,15 @@ impl EventParser {
                                 recv_us,
                                 index as i64,
                                 Some(inner_index as i64),
+                                inner_instruction.stack_height,
+                                outer_flat_ix_index + 1 + inner_index as u32,
                                 bot_wallet,
                                 tx_index,
                                 recent_blockhash.as_deref(),
                                 Some(&inner_instructions),
                                 adapter_callback.clone(),
+                                cpi_logs.as_mut(),
+                                parse_config,
                             )?;
                         }
                     }
@@ -202,17 +482,195 @@ impl EventParser {
         Ok(())
     }
 
+    /// Parse a base64-encoded serialized transaction, as returned by RPC `getTransaction` with
+    /// `base64` encoding
+    ///
+    /// Decodes and bincode-deserializes the string into a `VersionedTransaction`, then parses it
+    /// like [`Self::parse_instruction_events_from_versioned_transaction_with_config`]. The
+    /// signature is the transaction's first signature.
+    ///
+    /// The encoded transaction carries no execution metadata, so pass `accounts` and
+    /// `inner_instructions` from the RPC response's `meta` when available:
+    /// - `accounts`: full account list including address lookup table addresses
+    ///   (`loadedAddresses`). When `None`, only the message's static account keys are used and
+    ///   instructions referencing lookup table accounts cannot be resolved.
+    /// - `inner_instructions`: when `None`, no CPI instructions are parsed, so events that rely
+    ///   on CPI logs (e.g. trade events) are incomplete or missing.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn parse_base64_transaction(
+        encoded: &str,
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        accounts: Option<&[Pubkey]>,
+        inner_instructions: Option<&[InnerInstructions]>,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+        parse_config: &ParseConfig,
+    ) -> anyhow::Result<()> {
+        let bytes = BASE64_STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| anyhow::anyhow!("Invalid base64 transaction: {e}"))?;
+        let transaction: VersionedTransaction = bincode::deserialize(&bytes)
+            .map_err(|e| anyhow::anyhow!("Invalid serialized transaction: {e}"))?;
+        let signature = transaction.signatures.first().copied().unwrap_or_default();
+        let accounts = accounts.unwrap_or_else(|| transaction.message.static_account_keys());
+
+        Self::parse_instruction_events_from_versioned_transaction_with_config(
+            protocols,
+            event_type_filter,
+            &transaction,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            accounts,
+            inner_instructions.unwrap_or_default(),
+            bot_wallet,
+            tx_index,
+            callback,
+            parse_config,
+        )
+        .await
+    }
+
     // ================================================================================================
     // gRPC Transaction Processing
     // ================================================================================================
 
+    /// Parse transaction from gRPC stream, handling the instructions of the protocols in `scope`
+    #[allow(clippy::too_many_arguments)]
+    async fn parse_grpc_transaction_in_scope(
+        scope: ProtocolScope<'_>,
+        event_type_filter: Option<&EventTypeFilter>,
+        grpc_tx: SubscribeUpdateTransactionInfo,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+        parse_config: &ParseConfig,
+    ) -> anyhow::Result<()> {
+        // 槽位范围之外的交易在解析前跳过
+        if slot.is_some_and(|slot| !parse_config.slot_in_range(slot)) {
+            return Ok(());
+        }
+        // gRPC 路径传入的 block_time 即 Yellowstone 更新的 created_at（服务端时间戳），
+        // 须在估算缺失的区块时间之前取出
+        let server_ts_us = block_time.map(|ts| ts.seconds * 1_000_000 + (ts.nanos as i64) / 1_000);
+        let (block_time, block_time_estimated) =
+            Self::resolve_block_time(slot, block_time, recv_us, parse_config);
+        let adapter_callback = Self::create_adapter_callback(
+            callback,
+            parse_config,
+            block_time_estimated,
+            server_ts_us,
+        );
+        if let Some(transition) = grpc_tx.transaction {
+            if let Some(message) = &transition.message {
+                let mut address_table_lookups: Vec<Vec<u8>> = vec![];
+                let mut inner_instructions: Vec<
+                    yellowstone_grpc_proto::solana::storage::confirmed_block::InnerInstructions,
+                > = vec![];
+
+                if let Some(meta) = grpc_tx.meta {
+                    inner_instructions = meta.inner_instructions;
+                    address_table_lookups.reserve(
+                        meta.loaded_writable_addresses.len() + meta.loaded_readonly_addresses.len(),
+                    );
+                    let loaded_writable_addresses = meta.loaded_writable_addresses;
+                    let loaded_readonly_addresses = meta.loaded_readonly_addresses;
+                    address_table_lookups.extend(
+                        loaded_writable_addresses.into_iter().chain(loaded_readonly_addresses),
+                    );
+                }
+
+                // 先在原始字节上检查是否包含关注的程序，不相关的交易无需转换账户列表；
+                // Compute Budget / Memo 程序只在其独立事件会被输出时计入
+                let (compute_budget_events, memo_events) =
+                    Self::standalone_common_events(event_type_filter, parse_config);
+                let has_program =
+                    message.account_keys.iter().chain(&address_table_lookups).any(|account| {
+                        scope.handles_protocol_raw(account)
+                            || (compute_budget_events
+                                && account.as_slice() == COMPUTE_BUDGET_PROGRAM_ID.as_ref())
+                            || (memo_events && account.as_slice() == MEMO_PROGRAM_ID.as_ref())
+                    });
+                if !has_program {
+                    return Ok(());
+                }
+
+                let mut accounts_bytes: Vec<Vec<u8>> =
+                    Vec::with_capacity(message.account_keys.len() + address_table_lookups.len());
+                accounts_bytes.extend_from_slice(&message.account_keys);
+                accounts_bytes.extend(address_table_lookups);
+                // 转换为 Pubkey；长度不对的账户会让后续账户索引错位，整笔交易跳过
+                let Some(accounts) = accounts_bytes
+                    .iter()
+                    .map(|account| Pubkey::try_from(account.as_slice()).ok())
+                    .collect::<Option<Vec<Pubkey>>>()
+                else {
+                    parse_config.malformed_transaction_policy.report(
+                        signature,
+                        slot,
+                        MalformedReason::InvalidAccountKey,
+                    );
+                    return Ok(());
+                };
+                // 解析指令事件
+                let instructions = &message.instructions;
+                let recent_blockhash = if message.recent_blockhash.is_empty() {
+                    None
+                } else {
+                    Some(bs58::encode(&message.recent_blockhash).into_string())
+                };
+                Self::parse_instruction_events_from_grpc_transaction(
+                    scope,
+                    event_type_filter,
+                    &instructions,
+                    signature,
+                    slot,
+                    block_time,
+                    recv_us,
+                    &accounts,
+                    &inner_instructions,
+                    bot_wallet,
+                    tx_index,
+                    recent_blockhash,
+                    adapter_callback,
+                    parse_config,
+                )
+                .await?;
+            } else {
+                parse_config.malformed_transaction_policy.report(
+                    signature,
+                    slot,
+                    MalformedReason::MissingMessage,
+                );
+            }
+        } else {
+            parse_config.malformed_transaction_policy.report(
+                signature,
+                slot,
+                MalformedReason::MissingTransaction,
+            );
+        }
+
+        Ok(())
+    }
+
     /// Parse instruction events from gRPC transaction format
     ///
     /// Iterates through all instructions in a gRPC transaction, checks if they should be handled,
     /// and delegates to instruction-level parsing for both outer and inner instructions.
     #[allow(clippy::too_many_arguments)]
     async fn parse_instruction_events_from_grpc_transaction(
-        protocols: &[Protocol],
+        scope: ProtocolScope<'_>,
         event_type_filter: Option<&EventTypeFilter>,
         compiled_instructions: &[yellowstone_grpc_proto::prelude::CompiledInstruction],
         signature: Signature,
@@ -225,14 +683,25 @@ impl EventParser {
         tx_index: Option<u64>,
         recent_blockhash: Option<String>,
         callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
+        parse_config: &ParseConfig,
     ) -> anyhow::Result<()> {
         // 获取交易的指令和账户
         let mut accounts = accounts.to_vec();
         // 检查交易中是否包含程序
-        let has_program = accounts
-            .iter()
-            .any(|account| Self::should_handle(protocols, event_type_filter, account));
+        let has_program = accounts.iter().any(|account| scope.handles(account));
         if has_program {
+            // 交易级合并：先收集整笔交易的 CPI log
+            let mut cpi_logs = (parse_config.transaction_level_merge
+                && parse_config.parse_inner_instructions)
+                .then(|| {
+                    TransactionCpiLogs::from_grpc(
+                        inner_instructions,
+                        &accounts,
+                        parse_config.catch_parser_panics,
+                    )
+                });
+            // 按执行顺序为指令编号：每条外层指令之后紧接其 inner instructions
+            let mut flat_ix_index = 0u32;
             // 解析每个指令
             for (index, instruction) in compiled_instructions.iter().enumerate() {
                 if let Some(program_id) = accounts.get(instruction.program_id_index as usize) {
@@ -240,14 +709,26 @@ impl EventParser {
                     let inner_instructions = inner_instructions
                         .iter()
                         .find(|inner_instruction| inner_instruction.index == index as u32);
+                    let outer_flat_ix_index = flat_ix_index;
+                    flat_ix_index += 1 + inner_instructions.map_or(0, |inner_instructions| {
+                        inner_instructions.instructions.len() as u32
+                    });
+                    #[cfg(feature = "unhandled-programs")]
+                    Self::record_unhandled_program(&program_id);
                     let max_idx = instruction.accounts.iter().max().unwrap_or(&0);
-                    // 补齐accounts(使用Pubkey::default())
-                    if *max_idx as usize >= accounts.len() {
+                    // 补齐accounts(使用Pubkey::default())，Skip 模式下由解析函数跳过该指令
+                    let padded = *max_idx as usize >= accounts.len()
+                        && parse_config.missing_account_mode == MissingAccountMode::PadDefault;
+                    if padded {
                         accounts.resize(*max_idx as usize + 1, Pubkey::default());
                     }
-                    if Self::should_handle(protocols, event_type_filter, &program_id) {
+                    if scope.handles(&program_id) {
+                        // 与 shred 路径一致，只统计已处理协议的指令
+                        if padded {
+                            MetricsManager::current().increment_missing_account_instructions();
+                        }
                         Self::parse_events_from_grpc_instruction(
-                            protocols,
+                            scope,
                             event_type_filter,
                             instruction,
                             &accounts,
@@ -257,11 +738,15 @@ impl EventParser {
                             recv_us,
                             index as i64,
                             None,
+                            None,
+                            outer_flat_ix_index,
                             bot_wallet,
                             tx_index,
                             recent_blockhash.as_deref(),
                             inner_instructions,
                             callback.clone(),
+                            cpi_logs.as_mut(),
+                            parse_config,
                         )?;
                     }
                     // Immediately process inner instructions for correct ordering
@@ -278,7 +763,7 @@ impl EventParser {
                                     data: data.to_vec(),
                                 };
                             Self::parse_events_from_grpc_instruction(
-                                protocols,
+                                scope,
                                 event_type_filter,
                                 &instruction,
                                 &accounts,
@@ -288,11 +773,15 @@ impl EventParser {
                                 recv_us,
                                 inner_instructions.index as i64,
                                 Some(inner_index as i64),
+                                inner_instruction.stack_height,
+                                outer_flat_ix_index + 1 + inner_index as u32,
                                 bot_wallet,
                                 tx_index,
                                 recent_blockhash.as_deref(),
                                 Some(&inner_instructions),
                                 callback.clone(),
+                                cpi_logs.as_mut(),
+                                parse_config,
                             )?;
                         }
                     }
@@ -308,8 +797,8 @@ impl EventParser {
     /// to protocol-specific parsers, handles inner instructions, and processes swap data.
     #[allow(clippy::too_many_arguments)]
     fn parse_events_from_grpc_instruction(
-        protocols: &[Protocol],
-        event_type_filter: Option<&EventTypeFilter>,
+        scope: ProtocolScope<'_>,
+        _event_type_filter: Option<&EventTypeFilter>,
         instruction: &yellowstone_grpc_proto::prelude::CompiledInstruction,
         accounts: &[Pubkey],
         signature: Signature,
@@ -318,11 +807,15 @@ impl EventParser {
         recv_us: i64,
         outer_index: i64,
         inner_index: Option<i64>,
+        stack_height: Option<u32>,
+        flat_ix_index: u32,
         bot_wallet: Option<Pubkey>,
         tx_index: Option<u64>,
         recent_blockhash: Option<&str>,
         inner_instructions: Option<&yellowstone_grpc_proto::prelude::InnerInstructions>,
         callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
+        cpi_logs: Option<&mut TransactionCpiLogs>,
+        parse_config: &ParseConfig,
     ) -> anyhow::Result<()> {
         // 添加边界检查以防止越界访问
         let program_id_index = instruction.program_id_index as usize;
@@ -330,25 +823,36 @@ impl EventParser {
             return Ok(());
         }
         let program_id = accounts[program_id_index];
-        if !Self::should_handle(protocols, event_type_filter, &program_id) {
+        if !scope.handles(&program_id) {
+            return Ok(());
+        }
+
+        // 超大指令数据直接跳过，避免在异常交易上浪费 CPU
+        if instruction.data.len() > parse_config.max_instruction_data_len {
+            MetricsManager::current().increment_oversized_instructions();
             return Ok(());
         }
 
         let is_cu_program = EventDispatcher::is_compute_budget_program(&program_id);
+        let is_memo_program = EventDispatcher::is_memo_program(&program_id);
 
-        let disc_len = match program_id {
-            RAYDIUM_AMM_V4_PROGRAM_ID => 1,
-            _ => 8,
-        };
+        let disc_len = EventDispatcher::discriminator_len(&program_id);
 
         // 检查指令数据长度（至少需要 disc_len 字节的 discriminator）
-        if !is_cu_program && instruction.data.len() < disc_len {
+        if !is_cu_program && !is_memo_program && instruction.data.len() < disc_len {
+            return Ok(());
+        }
+        // Skip 模式下账户索引越界的指令不解析，避免输出全零公钥
+        if parse_config.missing_account_mode == MissingAccountMode::Skip
+            && instruction.accounts.iter().any(|&index| index as usize >= accounts.len())
+        {
+            MetricsManager::current().increment_missing_account_instructions();
             return Ok(());
         }
         // 创建元数据
         let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
         let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
-        let metadata = EventMetadata::new(
+        let mut metadata = EventMetadata::new(
             signature,
             slot,
             timestamp.seconds,
@@ -362,7 +866,20 @@ impl EventParser {
             tx_index,
             recent_blockhash.map(|s| s.to_string()),
         );
-
+        // 外层指令及缺少 stack_height 的旧格式数据均按 1 处理
+        metadata.stack_height = stack_height.unwrap_or(1);
+        metadata.flat_ix_index = flat_ix_index;
+        // 账户列表的第一个账户即手续费支付者（第一个签名者）
+        metadata.fee_payer = accounts.first().copied().unwrap_or_default();
+        if parse_config.attach_instruction_accounts {
+            metadata.all_accounts = Some(
+                instruction
+                    .accounts
+                    .iter()
+                    .map(|index| accounts.get(*index as usize).copied().unwrap_or_default())
+                    .collect(),
+            );
+        }
         if is_cu_program {
             if let Some(event) = EventDispatcher::dispatch_compute_budget_instruction(
                 &instruction.data,
@@ -373,8 +890,22 @@ impl EventParser {
             return Ok(());
         }
 
-        // 使用 EventDispatcher 匹配协议
-        let protocol = match EventDispatcher::match_protocol_by_program_id(&program_id) {
+        if is_memo_program {
+            let signers: Vec<Pubkey> = instruction
+                .accounts
+                .iter()
+                .filter_map(|&idx| accounts.get(idx as usize).copied())
+                .collect();
+            if let Some(event) =
+                EventDispatcher::dispatch_memo_instruction(&instruction.data, &signers, metadata)
+            {
+                callback(&event);
+            }
+            return Ok(());
+        }
+
+        // 按解析范围匹配协议
+        let protocol = match scope.protocol_for(&program_id) {
             Some(p) => p,
             None => return Ok(()),
         };
@@ -391,21 +922,37 @@ impl EventParser {
             .collect();
 
         // 使用 EventDispatcher 解析 instruction 事件
-        let mut event = match EventDispatcher::dispatch_instruction(
-            protocol.clone(),
+        let event = EventDispatcher::guard_parser_panic(
+            &protocol,
             instruction_discriminator,
-            instruction_data,
-            &account_pubkeys,
-            metadata.clone(),
-        ) {
+            parse_config.catch_parser_panics,
+            || {
+                EventDispatcher::dispatch_instruction(
+                    protocol.clone(),
+                    instruction_discriminator,
+                    instruction_data,
+                    &account_pubkeys,
+                    metadata.clone(),
+                )
+            },
+        );
+        let mut event = match event {
             Some(e) => e,
             None => return Ok(()),
         };
 
+        // 关闭 inner instruction 解析时直接发出指令事件，跳过 CPI log 查找、合并与 swap_data 提取
+        let (inner_instructions, cpi_logs) = if parse_config.parse_inner_instructions {
+            (inner_instructions, cpi_logs)
+        } else {
+            (None, None)
+        };
+
         // 处理 inner instructions - 查找对应的 CPI log 进行 merge
         // 当 inner_index 有值时，只查找索引大于当前 inner_index 的 CPI log
         // 超低延迟：顺序执行，避免 thread::scope 的 spawn/join 开销
         let mut inner_instruction_event: Option<DexEvent> = None;
+        let mut merged_cpi_position: Option<(i64, i64)> = None;
         if let Some(inner_instructions_ref) = inner_instructions {
             let current_inner_idx = inner_index.unwrap_or(-1) as i32;
 
@@ -413,19 +960,40 @@ impl EventParser {
                 if (idx as i32) <= current_inner_idx {
                     continue;
                 }
+                // 扫描数量达到上限时停止，限制病态交易的尾延迟
+                if (idx as i64 - current_inner_idx as i64) as usize
+                    > parse_config.max_inner_instruction_scan
+                {
+                    MetricsManager::current().increment_inner_scan_cap_hits();
+                    break;
+                }
+                // 已被前面的指令事件跨指令合并的 CPI log 不再重复合并
+                if cpi_logs.as_deref().is_some_and(|cpi_logs| {
+                    cpi_logs.is_consumed(inner_instructions_ref.index as i64, idx as i64)
+                }) {
+                    continue;
+                }
                 let inner_data = &inner_instruction.data;
                 if inner_data.len() < 16 {
                     continue;
                 }
                 let inner_discriminator = &inner_data[..16];
                 let inner_instruction_data = &inner_data[16..];
-                if let Some(inner_event) = EventDispatcher::dispatch_inner_instruction(
-                    protocol.clone(),
+                if let Some(inner_event) = EventDispatcher::guard_parser_panic(
+                    &protocol,
                     inner_discriminator,
-                    inner_instruction_data,
-                    metadata.clone(),
+                    parse_config.catch_parser_panics,
+                    || {
+                        EventDispatcher::dispatch_inner_instruction(
+                            protocol.clone(),
+                            inner_discriminator,
+                            inner_instruction_data,
+                            metadata.clone(),
+                        )
+                    },
                 ) {
                     inner_instruction_event = Some(inner_event);
+                    merged_cpi_position = Some((inner_instructions_ref.index as i64, idx as i64));
                     break;
                 }
             }
@@ -442,7 +1010,24 @@ impl EventParser {
             }
         }
 
-        // PumpFun MIGRATE: 有 CPI 时合并 log；无 CPI 时仍发出仅含指令数据的事件。
+        // 交易级合并：本地 inner 集合中找不到 CPI log 时，跨指令查找
+        if let Some(cpi_logs) = cpi_logs {
+            match merged_cpi_position {
+                Some((cpi_outer_index, cpi_inner_index)) => {
+                    cpi_logs.mark_consumed(cpi_outer_index, cpi_inner_index)
+                }
+                None => {
+                    inner_instruction_event = cpi_logs.take_for(&event, outer_index, inner_index)
+                }
+            }
+        }
+
+        // 找不到 CPI log 时按协议规则决定是否仍发出仅含指令数据的事件
+        if inner_instruction_event.is_none()
+            && pumpfun::parser::requires_cpi_log(&event, parse_config)
+        {
+            return Ok(());
+        }
 
         // 合并事件
         if let Some(inner_instruction_event) = inner_instruction_event {
@@ -477,11 +1062,15 @@ impl EventParser {
         recv_us: i64,
         outer_index: i64,
         inner_index: Option<i64>,
+        stack_height: Option<u32>,
+        flat_ix_index: u32,
         bot_wallet: Option<Pubkey>,
         tx_index: Option<u64>,
         recent_blockhash: Option<&str>,
         inner_instructions: Option<&InnerInstructions>,
         callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
+        cpi_logs: Option<&mut TransactionCpiLogs>,
+        parse_config: &ParseConfig,
     ) -> anyhow::Result<()> {
         // 添加边界检查以防止越界访问
         let program_id_index = instruction.program_id_index as usize;
@@ -493,22 +1082,33 @@ impl EventParser {
             return Ok(());
         }
 
+        // 超大指令数据直接跳过，避免在异常交易上浪费 CPU
+        if instruction.data.len() > parse_config.max_instruction_data_len {
+            MetricsManager::current().increment_oversized_instructions();
+            return Ok(());
+        }
+
         let is_cu_program = EventDispatcher::is_compute_budget_program(&program_id);
+        let is_memo_program = EventDispatcher::is_memo_program(&program_id);
 
-        let disc_len = match program_id {
-            RAYDIUM_AMM_V4_PROGRAM_ID => 1,
-            _ => 8,
-        };
+        let disc_len = EventDispatcher::discriminator_len(&program_id);
 
-        // 检查指令数据长度（至少需要 8 字节的 discriminator）
-        if !is_cu_program && instruction.data.len() < disc_len {
+        // 检查指令数据长度（至少需要 disc_len 字节的 discriminator）
+        if !is_cu_program && !is_memo_program && instruction.data.len() < disc_len {
+            return Ok(());
+        }
+        // Skip 模式下账户索引越界的指令不解析，避免输出全零公钥
+        if parse_config.missing_account_mode == MissingAccountMode::Skip
+            && instruction.accounts.iter().any(|&index| index as usize >= accounts.len())
+        {
+            MetricsManager::current().increment_missing_account_instructions();
             return Ok(());
         }
 
         // 创建元数据
         let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
         let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
-        let metadata = EventMetadata::new(
+        let mut metadata = EventMetadata::new(
             signature,
             slot,
             timestamp.seconds,
@@ -522,6 +1122,20 @@ impl EventParser {
             tx_index,
             recent_blockhash.map(|s| s.to_string()),
         );
+        // 外层指令及缺少 stack_height 的旧格式数据均按 1 处理
+        metadata.stack_height = stack_height.unwrap_or(1);
+        metadata.flat_ix_index = flat_ix_index;
+        // 账户列表的第一个账户即手续费支付者（第一个签名者）
+        metadata.fee_payer = accounts.first().copied().unwrap_or_default();
+        if parse_config.attach_instruction_accounts {
+            metadata.all_accounts = Some(
+                instruction
+                    .accounts
+                    .iter()
+                    .map(|index| accounts.get(*index as usize).copied().unwrap_or_default())
+                    .collect(),
+            );
+        }
 
         if is_cu_program {
             if let Some(event) = EventDispatcher::dispatch_compute_budget_instruction(
@@ -533,6 +1147,20 @@ impl EventParser {
             return Ok(());
         }
 
+        if is_memo_program {
+            let signers: Vec<Pubkey> = instruction
+                .accounts
+                .iter()
+                .filter_map(|&idx| accounts.get(idx as usize).copied())
+                .collect();
+            if let Some(event) =
+                EventDispatcher::dispatch_memo_instruction(&instruction.data, &signers, metadata)
+            {
+                callback(&event);
+            }
+            return Ok(());
+        }
+
         // 使用 EventDispatcher 匹配协议
         let protocol = match EventDispatcher::match_protocol_by_program_id(&program_id) {
             Some(p) => p,
@@ -551,22 +1179,39 @@ impl EventParser {
             .collect();
 
         // 使用 EventDispatcher 解析 instruction 事件
-        let mut event = match EventDispatcher::dispatch_instruction(
-            protocol.clone(),
+        let event = EventDispatcher::guard_parser_panic(
+            &protocol,
             instruction_discriminator,
-            instruction_data,
-            &account_pubkeys,
-            metadata.clone(),
-        ) {
+            parse_config.catch_parser_panics,
+            || {
+                EventDispatcher::dispatch_instruction(
+                    protocol.clone(),
+                    instruction_discriminator,
+                    instruction_data,
+                    &account_pubkeys,
+                    metadata.clone(),
+                )
+            },
+        );
+        let mut event = match event {
             Some(e) => e,
             None => return Ok(()),
         };
 
+        // 关闭 inner instruction 解析时直接发出指令事件，跳过 CPI log 查找、合并与 swap_data 提取
+        let (inner_instructions, cpi_logs) = if parse_config.parse_inner_instructions {
+            (inner_instructions, cpi_logs)
+        } else {
+            (None, None)
+        };
+
         // 处理 inner instructions - 查找对应的 CPI log 进行 merge
         // 当 inner_index 有值时，只查找索引大于当前 inner_index 的 CPI log
         let mut inner_instruction_event: Option<DexEvent> = None;
+        let mut merged_cpi_position: Option<(i64, i64)> = None;
         if let Some(inner_instructions_ref) = inner_instructions {
             let current_inner_idx = inner_index.unwrap_or(-1) as i32;
+            let consumed_logs = cpi_logs.as_deref();
             
             // 并行执行两个任务: 解析 inner event 和提取 swap_data
             let (inner_event_result, swap_data_result) = std::thread::scope(|s| {
@@ -576,6 +1221,19 @@ impl EventParser {
                         if (idx as i32) <= current_inner_idx {
                             continue;
                         }
+                        // 扫描数量达到上限时停止，限制病态交易的尾延迟
+                        if (idx as i64 - current_inner_idx as i64) as usize
+                            > parse_config.max_inner_instruction_scan
+                        {
+                            MetricsManager::current().increment_inner_scan_cap_hits();
+                            return None;
+                        }
+                        // 已被前面的指令事件跨指令合并的 CPI log 不再重复合并
+                        if consumed_logs.is_some_and(|cpi_logs| {
+                            cpi_logs.is_consumed(inner_instructions_ref.index as i64, idx as i64)
+                        }) {
+                            continue;
+                        }
                         
                         let inner_data = &inner_instruction.instruction.data;
                         // 检查长度（需要 16 字节的 discriminator）
@@ -585,13 +1243,20 @@ impl EventParser {
                         let inner_discriminator = &inner_data[..16];
                         let inner_instruction_data = &inner_data[16..];
 
-                        if let Some(inner_event) = EventDispatcher::dispatch_inner_instruction(
-                            protocol.clone(),
+                        if let Some(inner_event) = EventDispatcher::guard_parser_panic(
+                            &protocol,
                             inner_discriminator,
-                            inner_instruction_data,
-                            metadata.clone(),
+                            parse_config.catch_parser_panics,
+                            || {
+                                EventDispatcher::dispatch_inner_instruction(
+                                    protocol.clone(),
+                                    inner_discriminator,
+                                    inner_instruction_data,
+                                    metadata.clone(),
+                                )
+                            },
                         ) {
-                            return Some(inner_event);
+                            return Some((idx, inner_event));
                         }
                     }
                     None
@@ -614,13 +1279,33 @@ impl EventParser {
                 (inner_event_handle.join().unwrap(), swap_data_handle.join().unwrap())
             });
 
-            inner_instruction_event = inner_event_result;
+            if let Some((idx, inner_event)) = inner_event_result {
+                inner_instruction_event = Some(inner_event);
+                merged_cpi_position = Some((inner_instructions_ref.index as i64, idx as i64));
+            }
             if let Some(swap_data) = swap_data_result {
                 event.metadata_mut().set_swap_data(swap_data);
             }
         }
 
-        // PumpFun MIGRATE: 有 CPI 时合并 log；无 CPI（如 shred）仍发出仅含指令数据的事件。
+        // 交易级合并：本地 inner 集合中找不到 CPI log 时，跨指令查找
+        if let Some(cpi_logs) = cpi_logs {
+            match merged_cpi_position {
+                Some((cpi_outer_index, cpi_inner_index)) => {
+                    cpi_logs.mark_consumed(cpi_outer_index, cpi_inner_index)
+                }
+                None => {
+                    inner_instruction_event = cpi_logs.take_for(&event, outer_index, inner_index)
+                }
+            }
+        }
+
+        // 找不到 CPI log 时按协议规则决定是否仍发出仅含指令数据的事件
+        if inner_instruction_event.is_none()
+            && pumpfun::parser::requires_cpi_log(&event, parse_config)
+        {
+            return Ok(());
+        }
 
         // 合并事件
         if let Some(inner_instruction_event) = inner_instruction_event {
@@ -639,6 +1324,99 @@ impl EventParser {
     // Helper Functions
     // ================================================================================================
 
+    /// Create the per-transaction adapter callback
+    ///
+    /// Converts the owned-event callback into a reference callback, numbers events in emission
+    /// order (starting at 0 for each transaction), sets the server timestamp, applies the
+    /// compute budget mode and, with a decimals resolver configured, annotates trades with
+    /// UI-normalized amounts.
+    fn create_adapter_callback(
+        callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+        parse_config: &ParseConfig,
+        block_time_estimated: bool,
+        server_ts_us: Option<i64>,
+    ) -> Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync> {
+        let compute_budget_mode = parse_config.compute_budget_mode;
+        let reserve_snapshots = parse_config.reserve_snapshots;
+        #[cfg(feature = "decimal")]
+        let decimals_resolver = parse_config.decimals_resolver.clone();
+        let tx_event_seq = AtomicU32::new(0);
+        // 本交易已解析到的 (unit limit, unit price)
+        let compute_budget: Mutex<(Option<u32>, Option<u64>)> = Mutex::new((None, None));
+        Arc::new(move |event: &DexEvent| {
+            let mut event = event.clone();
+            if compute_budget_mode != ComputeBudgetMode::Standalone {
+                let (unit_limit, unit_price) = {
+                    let mut compute_budget =
+                        compute_budget.lock().unwrap_or_else(|e| e.into_inner());
+                    match &event {
+                        DexEvent::SetComputeUnitLimitEvent(e) => compute_budget.0 = Some(e.units),
+                        DexEvent::SetComputeUnitPriceEvent(e) => {
+                            compute_budget.1 = Some(e.micro_lamports)
+                        }
+                        _ => {}
+                    }
+                    *compute_budget
+                };
+                let is_compute_budget_event = matches!(
+                    event,
+                    DexEvent::SetComputeUnitLimitEvent(_) | DexEvent::SetComputeUnitPriceEvent(_)
+                );
+                if is_compute_budget_event && compute_budget_mode == ComputeBudgetMode::Attach {
+                    return;
+                }
+                let metadata = event.metadata_mut();
+                metadata.compute_unit_limit = unit_limit;
+                metadata.compute_unit_price = unit_price;
+            }
+            let metadata = event.metadata_mut();
+            metadata.tx_event_seq = tx_event_seq.fetch_add(1, Ordering::Relaxed);
+            metadata.server_ts_us = server_ts_us;
+            if block_time_estimated {
+                metadata.block_time_estimated = true;
+            }
+            if reserve_snapshots {
+                annotate_reserve_snapshots(&mut event);
+            }
+            #[cfg(feature = "decimal")]
+            if let Some(resolver) = &decimals_resolver {
+                annotate_ui_amounts(&mut event, resolver);
+            }
+            callback(event);
+        })
+    }
+
+    /// Resolve the block time used for a transaction's events
+    ///
+    /// Real block times are recorded as the slot clock anchor. A missing block time is
+    /// estimated from the slot when `estimate_missing_block_time` is enabled; the returned flag
+    /// tells whether the block time was estimated.
+    fn resolve_block_time(
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        parse_config: &ParseConfig,
+    ) -> (Option<Timestamp>, bool) {
+        if !parse_config.estimate_missing_block_time {
+            return (block_time, false);
+        }
+        match (block_time, slot) {
+            (Some(ts), Some(slot)) => {
+                observe_block_time(slot, ts.seconds * 1000 + (ts.nanos as i64) / 1_000_000);
+                (Some(ts), false)
+            }
+            (None, Some(slot)) => {
+                let unix_ms = estimate_block_time_ms(slot, recv_us);
+                let timestamp = Timestamp {
+                    seconds: unix_ms.div_euclid(1000),
+                    nanos: (unix_ms.rem_euclid(1000) * 1_000_000) as i32,
+                };
+                (Some(timestamp), true)
+            }
+            _ => (block_time, false),
+        }
+    }
+
     /// Check if instruction should be processed based on protocol filter
     ///
     /// Determines whether a program_id matches any of the protocols we're interested in.
@@ -650,13 +1428,59 @@ impl EventParser {
         // 使用 EventDispatcher 来匹配协议
         if let Some(protocol) = EventDispatcher::match_protocol_by_program_id(program_id) {
             protocols.contains(&protocol)
-        } else if EventDispatcher::is_compute_budget_program(program_id) {
+        } else if EventDispatcher::is_compute_budget_program(program_id)
+            || EventDispatcher::is_memo_program(program_id)
+        {
             return true;
         } else {
             false
         }
     }
 
+    /// Count top-level program ids that no supported protocol handles
+    #[cfg(feature = "unhandled-programs")]
+    fn record_unhandled_program(program_id: &Pubkey) {
+        if EventDispatcher::match_protocol_by_program_id(program_id).is_none()
+            && !EventDispatcher::is_compute_budget_program(program_id)
+            && !EventDispatcher::is_memo_program(program_id)
+        {
+            MetricsManager::current().record_unhandled_program(program_id);
+        }
+    }
+
+    /// Byte-level counterpart of [`Self::should_handle`] for raw account keys
+    ///
+    /// Compares the 32-byte key against the requested protocols' program ids without
+    /// constructing a `Pubkey`; keys of any other length never match. The compute budget and
+    /// memo programs are not matched here, see [`Self::standalone_common_events`].
+    #[inline]
+    fn should_handle_raw(protocols: &[Protocol], account: &[u8]) -> bool {
+        account.len() == 32
+            && protocols.iter().any(|protocol| {
+                EventDispatcher::get_program_id(protocol.clone()).as_ref() == account
+            })
+    }
+
+    /// Whether compute budget and memo instructions produce events of their own, as
+    /// `(compute_budget, memo)`
+    ///
+    /// In `ComputeBudgetMode::Attach` compute budget instructions only annotate other events,
+    /// and event types left out of the event type filter are never delivered. A transaction
+    /// whose only relevant programs are these can then be skipped before parsing.
+    fn standalone_common_events(
+        event_type_filter: Option<&EventTypeFilter>,
+        parse_config: &ParseConfig,
+    ) -> (bool, bool) {
+        let includes = |event_type: EventType| match event_type_filter {
+            Some(filter) => filter.include.contains(&event_type),
+            None => true,
+        };
+        let compute_budget = parse_config.compute_budget_mode != ComputeBudgetMode::Attach
+            && (includes(EventType::SetComputeUnitLimit)
+                || includes(EventType::SetComputeUnitPrice));
+        (compute_budget, includes(EventType::Memo))
+    }
+
     // ================================================================================================
     // Event Post-Processing
     // ================================================================================================
@@ -704,6 +1528,7 @@ impl EventParser {
                     } else {
                         trade_info.sol_amount
                     };
+                    swap_data.source = SwapDataSource::Direct;
                 }
                 DexEvent::PumpFunTradeEvent(trade_info)
             }
@@ -711,6 +1536,7 @@ impl EventParser {
                 if let Some(swap_data) = trade_info.metadata.swap_data.as_mut() {
                     swap_data.from_amount = trade_info.user_quote_amount_in;
                     swap_data.to_amount = trade_info.base_amount_out;
+                    swap_data.source = SwapDataSource::Direct;
                 }
                 DexEvent::PumpSwapBuyEvent(trade_info)
             }
@@ -718,6 +1544,7 @@ impl EventParser {
                 if let Some(swap_data) = trade_info.metadata.swap_data.as_mut() {
                     swap_data.from_amount = trade_info.base_amount_in;
                     swap_data.to_amount = trade_info.user_quote_amount_out;
+                    swap_data.source = SwapDataSource::Direct;
                 }
                 DexEvent::PumpSwapSellEvent(trade_info)
             }
@@ -31,6 +31,8 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
                 e.cashback_fee_basis_points = cpie.cashback_fee_basis_points;
                 e.cashback = cpie.cashback;
                 e.is_cashback_coin = cpie.is_cashback_coin;
+                e.has_fee_fields = cpie.has_fee_fields;
+                e.completed_bonding_curve = cpie.completed_bonding_curve;
                 if cpie.account.is_some() {
                     e.account = cpie.account;
                 }
@@ -428,7 +430,77 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
             }
             _ => {}
         },
+        DexEvent::MeteoraDammV2AddLiquidityEvent(e) => match cpi_log_event {
+            DexEvent::MeteoraDammV2AddLiquidityEvent(cpie) => {
+                e.pool = cpie.pool;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+                e.liquidity_delta = cpie.liquidity_delta;
+                e.token_a_amount_threshold = cpie.token_a_amount_threshold;
+                e.token_b_amount_threshold = cpie.token_b_amount_threshold;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+                e.total_amount_a = cpie.total_amount_a;
+                e.total_amount_b = cpie.total_amount_b;
+            }
+            _ => {}
+        },
+        DexEvent::MeteoraDammV2RemoveLiquidityEvent(e) => match cpi_log_event {
+            DexEvent::MeteoraDammV2RemoveLiquidityEvent(cpie) => {
+                e.pool = cpie.pool;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+                e.liquidity_delta = cpie.liquidity_delta;
+                e.token_a_amount_threshold = cpie.token_a_amount_threshold;
+                e.token_b_amount_threshold = cpie.token_b_amount_threshold;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+            }
+            _ => {}
+        },
 
         _ => {}
     }
 }
+
+
+/// 判断 CPI log 事件能否合并到指令事件（与 `merge` 中的配对保持一致）
+pub fn can_merge(instruction_event: &DexEvent, cpi_log_event: &DexEvent) -> bool {
+    matches!(
+        (instruction_event, cpi_log_event),
+        (DexEvent::PumpFunTradeEvent(_), DexEvent::PumpFunTradeEvent(_))
+            | (DexEvent::PumpFunCreateTokenEvent(_), DexEvent::PumpFunCreateV2TokenEvent(_))
+            | (DexEvent::PumpFunCreateV2TokenEvent(_), DexEvent::PumpFunCreateV2TokenEvent(_))
+            | (DexEvent::PumpFunMigrateEvent(_), DexEvent::PumpFunMigrateEvent(_))
+            | (DexEvent::BonkTradeEvent(_), DexEvent::BonkTradeEvent(_))
+            | (DexEvent::BonkPoolCreateEvent(_), DexEvent::BonkPoolCreateEvent(_))
+            | (DexEvent::BonkMigrateToAmmEvent(_), DexEvent::BonkMigrateToAmmEvent(_))
+            | (DexEvent::PumpSwapBuyEvent(_), DexEvent::PumpSwapBuyEvent(_))
+            | (DexEvent::PumpSwapSellEvent(_), DexEvent::PumpSwapSellEvent(_))
+            | (DexEvent::PumpSwapCreatePoolEvent(_), DexEvent::PumpSwapCreatePoolEvent(_))
+            | (DexEvent::PumpSwapDepositEvent(_), DexEvent::PumpSwapDepositEvent(_))
+            | (DexEvent::PumpSwapWithdrawEvent(_), DexEvent::PumpSwapWithdrawEvent(_))
+            | (DexEvent::MeteoraDammV2SwapEvent(_), DexEvent::MeteoraDammV2SwapEvent(_))
+            | (DexEvent::MeteoraDammV2Swap2Event(_), DexEvent::MeteoraDammV2SwapEvent(_))
+            | (
+                DexEvent::MeteoraDammV2InitializePoolEvent(_),
+                DexEvent::MeteoraDammV2InitializePoolEvent(_)
+            )
+            | (
+                DexEvent::MeteoraDammV2InitializeCustomizablePoolEvent(_),
+                DexEvent::MeteoraDammV2InitializePoolEvent(_)
+            )
+            | (
+                DexEvent::MeteoraDammV2InitializePoolWithDynamicConfigEvent(_),
+                DexEvent::MeteoraDammV2InitializePoolEvent(_)
+            )
+            | (
+                DexEvent::MeteoraDammV2AddLiquidityEvent(_),
+                DexEvent::MeteoraDammV2AddLiquidityEvent(_)
+            )
+            | (
+                DexEvent::MeteoraDammV2RemoveLiquidityEvent(_),
+                DexEvent::MeteoraDammV2RemoveLiquidityEvent(_)
+            )
+    )
+}
@@ -2,11 +2,18 @@ pub mod account_event_parser;
 pub mod common_event_parser;
 pub mod dispatcher;
 pub mod global_state;
+pub mod parse_config;
 pub mod parser_cache;
+pub mod selftest;
 pub mod traits;
+pub mod transaction_assembler;
 
 pub use traits::DexEvent;
 pub use dispatcher::EventDispatcher;
+pub use parse_config::{
+    ComputeBudgetMode, MalformedReason, MalformedTransaction, MalformedTransactionPolicy,
+    MissingAccountMode, ParseConfig, ParseErrorCallback,
+};
 
 pub mod event_parser;
 pub mod merger_event;
\ No newline at end of file
@@ -0,0 +1,264 @@
+use crate::streaming::common::constants::{
+    DEFAULT_ACCOUNT_DIFF_CACHE_SIZE, DEFAULT_FIRST_TRADE_CACHE_SIZE,
+    DEFAULT_MAX_INNER_INSTRUCTION_SCAN, DEFAULT_MAX_INSTRUCTION_DATA_LEN,
+    DEFAULT_POOL_AGE_CACHE_SIZE,
+};
+use crate::streaming::common::pubkey_set::PubkeySet;
+#[cfg(feature = "decimal")]
+use crate::streaming::common::ui_amounts::DecimalsResolver;
+use crate::streaming::common::{MetricsManager, LOG_TARGET};
+use solana_sdk::signature::Signature;
+use std::fmt;
+use std::sync::Arc;
+
+/// Parser configuration
+#[derive(Debug, Clone)]
+pub struct ParseConfig {
+    /// Whether CPI logs are collected across the whole transaction before merging, so an
+    /// instruction event whose CPI log landed under a different outer instruction still gets
+    /// merged with the first unused log after it (default: false)
+    pub transaction_level_merge: bool,
+    /// Instructions whose data exceeds this many bytes are skipped without decoding
+    /// (default: 10KB)
+    pub max_instruction_data_len: usize,
+    /// Whether vote transactions are parsed instead of being skipped up front (default: false)
+    pub include_vote_transactions: bool,
+    /// How compute budget instructions are surfaced (default: standalone events)
+    pub compute_budget_mode: ComputeBudgetMode,
+    /// Whether inner instructions are searched for each instruction event's CPI log and token
+    /// transfers. When false the decoded instruction is emitted as-is, so fields only carried by
+    /// the CPI log stay at their defaults: PumpFun trade amounts / reserves / fees / timestamp and
+    /// create / migrate log fields, PumpSwap buy / sell amounts, fees and pool reserves plus
+    /// create-pool / deposit / withdraw results, Bonk trade amounts and pool state plus
+    /// pool-create / migrate log fields, and Meteora DAMM v2 swap and pool-initialize results.
+    /// `EventMetadata::swap_data` is never filled either, so `UnifiedTrade::from_event` yields nothing
+    /// for Raydium and Meteora swaps. Instructions invoked via CPI are still parsed as events
+    /// of their own (default: true)
+    pub parse_inner_instructions: bool,
+    /// Maximum number of inner instructions scanned per instruction when looking for its
+    /// CPI log; the search stops (and a metric is recorded) once reached (default: 256)
+    pub max_inner_instruction_scan: usize,
+    /// Whether account updates are delivered as `AccountChangeEvent` deltas against the
+    /// previously seen value instead of full snapshots (default: false)
+    pub account_diff: bool,
+    /// Number of accounts whose last seen value is cached in account diff mode, per subscription
+    /// (default: 10000)
+    pub account_diff_cache_size: usize,
+    /// Client-side account allowlist: transactions referencing none of these accounts are
+    /// skipped before parsing. ShredStream only sees static account keys (default: None)
+    pub account_include: Option<PubkeySet>,
+    /// First slot (inclusive) whose transactions are parsed; transactions in earlier slots are
+    /// skipped before parsing, e.g. to replay or backfill only part of a capture. Account
+    /// updates are not affected (default: None, no lower bound)
+    pub start_slot: Option<u64>,
+    /// Last slot (inclusive) whose transactions are parsed; see `start_slot`
+    /// (default: None, no upper bound)
+    pub end_slot: Option<u64>,
+    /// Whether only the first trade event seen for each base mint is delivered; later trades
+    /// on the same mint are dropped, other events pass through (default: false)
+    pub first_trade_per_mint: bool,
+    /// Number of mints remembered in first-trade mode; a mint evicted from this LRU counts as
+    /// new again (default: 100000)
+    pub first_trade_cache_size: usize,
+    /// Trades on a base mint first seen fewer than this many slots before the trade are dropped,
+    /// e.g. to skip the snipes right after launch. Age counts from the first trade this stream
+    /// saw for the mint, so mints already trading when the subscription starts look new;
+    /// non-trade events pass through (default: None, no minimum)
+    pub min_pool_age_slots: Option<u64>,
+    /// Trades on a base mint first seen more than this many slots before the trade are dropped,
+    /// e.g. to follow only fresh launches; measured like `min_pool_age_slots`
+    /// (default: None, no maximum)
+    pub max_pool_age_slots: Option<u64>,
+    /// Number of mints whose first-seen slot is remembered for the pool age filter; a mint
+    /// evicted from this LRU counts as new again (default: 100000)
+    pub pool_age_cache_size: usize,
+    /// Whether the full ordered account list of the instruction that produced each event is
+    /// attached as `EventMetadata::all_accounts`, for debugging account mapping. Allocates per
+    /// event (default: false)
+    pub attach_instruction_accounts: bool,
+    /// Whether a missing block time (always the case for ShredStream) is estimated from the slot,
+    /// anchored on the last real block time seen or on the receive time of each new slot, at
+    /// 400ms per slot. The anchor is shared process-wide and follows the live stream, so only
+    /// enable this for live subscriptions, not for parsing historical transactions. Estimated
+    /// events are flagged with `EventMetadata::block_time_estimated`; when false the block time
+    /// stays 0 (default: false)
+    pub estimate_missing_block_time: bool,
+    /// How instructions referencing account indexes beyond the transaction's account list are
+    /// handled; every occurrence is counted in metrics (default: pad with the default pubkey)
+    pub missing_account_mode: MissingAccountMode,
+    /// Whether a panic inside a protocol parser (e.g. indexing into malformed data) is caught,
+    /// logged with the protocol and discriminator and counted in metrics, with the instruction
+    /// treated as producing no event. When false the panic propagates to the parse task
+    /// (default: true)
+    pub catch_parser_panics: bool,
+    /// What happens to a transaction that cannot be processed (no transaction or message in the
+    /// update, an account key that is not 32 bytes, no signature). Such transactions are always
+    /// skipped and counted in `malformed_transactions_count`; the policy decides whether they
+    /// are also logged or reported to a callback (default: skip silently)
+    pub malformed_transaction_policy: MalformedTransactionPolicy,
+    /// Whether a PumpFun migrate instruction is only emitted once its CPI log (amounts, pool,
+    /// timestamp) has been merged. When false a migrate without a CPI log, e.g. from ShredStream
+    /// or with `parse_inner_instructions` disabled, is emitted with its accounts only
+    /// (default: false)
+    pub pumpfun_migrate_requires_cpi_log: bool,
+    /// Whether trade events carry the pool reserves before and after the trade
+    /// (`pre_reserves` / `post_reserves`), derived from the trade's CPI log. Supported for
+    /// PumpFun (bonding curve virtual reserves), PumpSwap (pool vault balances) and Bonk (curve
+    /// reserves); other protocols and trades without a CPI log leave both None (default: false)
+    pub reserve_snapshots: bool,
+    /// Mint decimals lookup used to annotate trade events with UI-normalized amounts
+    /// (`EventMetadata::ui_amounts`), so amounts compare across protocols without per-protocol
+    /// conversions. Called on the parse path for every trade, so it should read from a local
+    /// cache; trades whose base or quote decimals are unknown are left unannotated
+    /// (default: None, no normalization)
+    #[cfg(feature = "decimal")]
+    pub decimals_resolver: Option<DecimalsResolver>,
+}
+
+/// How decoded compute budget instructions (unit limit / unit price) are delivered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ComputeBudgetMode {
+    /// Emit standalone `SetComputeUnitLimitEvent` / `SetComputeUnitPriceEvent`
+    #[default]
+    Standalone,
+    /// Attach unit limit/price to the metadata of the following events in the same transaction,
+    /// without emitting the standalone events
+    Attach,
+    /// Attach to following events and also emit the standalone events
+    Both,
+}
+
+/// How a transaction that cannot be processed is surfaced, see
+/// `ParseConfig::malformed_transaction_policy`
+#[derive(Debug, Clone, Default)]
+pub enum MalformedTransactionPolicy {
+    /// Skip the transaction, only counting it in metrics
+    #[default]
+    Skip,
+    /// Skip the transaction and log a warning with its signature and the reason
+    LogAndSkip,
+    /// Skip the transaction and pass its signature and the reason to a callback
+    OnParseError(ParseErrorCallback),
+}
+
+impl MalformedTransactionPolicy {
+    /// Count a skipped transaction and surface it according to the policy
+    pub(crate) fn report(&self, signature: Signature, slot: Option<u64>, reason: MalformedReason) {
+        MetricsManager::current().increment_malformed_transactions();
+        match self {
+            MalformedTransactionPolicy::Skip => {}
+            MalformedTransactionPolicy::LogAndSkip => log::warn!(
+                target: LOG_TARGET,
+                "Skipping malformed transaction {signature} (slot {slot:?}): {reason}"
+            ),
+            MalformedTransactionPolicy::OnParseError(callback) => {
+                (callback.0)(&MalformedTransaction { signature, slot, reason })
+            }
+        }
+    }
+}
+
+/// Callback receiving the transactions skipped under `MalformedTransactionPolicy::OnParseError`
+///
+/// Called on the parse path, so it should be quick (e.g. increment a counter or send on a
+/// channel).
+#[derive(Clone)]
+pub struct ParseErrorCallback(Arc<dyn Fn(&MalformedTransaction) + Send + Sync>);
+
+impl ParseErrorCallback {
+    pub fn new(callback: impl Fn(&MalformedTransaction) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for ParseErrorCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ParseErrorCallback")
+    }
+}
+
+/// A transaction skipped because it could not be processed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MalformedTransaction {
+    /// Signature of the transaction, `Signature::default()` when it has none
+    pub signature: Signature,
+    pub slot: Option<u64>,
+    pub reason: MalformedReason,
+}
+
+/// Why a transaction could not be processed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MalformedReason {
+    /// The gRPC update carries no transaction
+    MissingTransaction,
+    /// The transaction carries no message
+    MissingMessage,
+    /// A static or ALT-loaded account key is not 32 bytes, so account indexes cannot be resolved
+    InvalidAccountKey,
+    /// The transaction has no signature
+    MissingSignature,
+}
+
+impl fmt::Display for MalformedReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MalformedReason::MissingTransaction => write!(f, "missing transaction"),
+            MalformedReason::MissingMessage => write!(f, "missing message"),
+            MalformedReason::InvalidAccountKey => write!(f, "invalid account key"),
+            MalformedReason::MissingSignature => write!(f, "missing signature"),
+        }
+    }
+}
+
+/// How an instruction referencing account indexes beyond the transaction's account list is
+/// handled, e.g. lookup table accounts missing from the source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingAccountMode {
+    /// Substitute `Pubkey::default()` for the missing accounts and parse the instruction; the
+    /// event may then report all-zero pubkeys (such as a vault) as real accounts
+    #[default]
+    PadDefault,
+    /// Skip the instruction without emitting events
+    Skip,
+}
+
+impl ParseConfig {
+    /// Whether transactions in `slot` fall within `[start_slot, end_slot]`
+    #[inline]
+    pub fn slot_in_range(&self, slot: u64) -> bool {
+        self.start_slot.map_or(true, |start| slot >= start)
+            && self.end_slot.map_or(true, |end| slot <= end)
+    }
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        Self {
+            transaction_level_merge: false,
+            max_instruction_data_len: DEFAULT_MAX_INSTRUCTION_DATA_LEN,
+            include_vote_transactions: false,
+            compute_budget_mode: ComputeBudgetMode::Standalone,
+            parse_inner_instructions: true,
+            max_inner_instruction_scan: DEFAULT_MAX_INNER_INSTRUCTION_SCAN,
+            account_diff: false,
+            account_diff_cache_size: DEFAULT_ACCOUNT_DIFF_CACHE_SIZE,
+            account_include: None,
+            start_slot: None,
+            end_slot: None,
+            first_trade_per_mint: false,
+            first_trade_cache_size: DEFAULT_FIRST_TRADE_CACHE_SIZE,
+            min_pool_age_slots: None,
+            max_pool_age_slots: None,
+            pool_age_cache_size: DEFAULT_POOL_AGE_CACHE_SIZE,
+            attach_instruction_accounts: false,
+            estimate_missing_block_time: false,
+            missing_account_mode: MissingAccountMode::PadDefault,
+            catch_parser_panics: true,
+            malformed_transaction_policy: MalformedTransactionPolicy::Skip,
+            pumpfun_migrate_requires_cpi_log: false,
+            reserve_snapshots: false,
+            #[cfg(feature = "decimal")]
+            decimals_resolver: None,
+        }
+    }
+}
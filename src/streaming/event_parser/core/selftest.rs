@@ -0,0 +1,560 @@
+//! 解析器自检
+//!
+//! 用内置的参考指令（判别器、参数与账户布局均与链上一致）与 CPI log 逐个调用 `EventDispatcher`，
+//! 核对解析出的事件类型、数量与账户映射，并确认账户列表被截短时解析器不会 panic，
+//! 用于在启动时或 CI 中发现判别器或布局回归
+
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{EventMetadata, EventType},
+    core::dispatcher::EventDispatcher,
+    protocols::{
+        bonk::{self, types::TradeDirection},
+        meteora_damm_v2, pumpfun, pumpswap, raydium_amm_v4, raydium_clmm, raydium_cpmm,
+    },
+    DexEvent, Protocol,
+};
+
+/// CPI log 判别器长度（event 前缀 8 字节 + 事件判别器 8 字节）
+const CPI_LOG_DISCRIMINATOR_LEN: usize = 16;
+
+/// 参考指令
+struct Fixture {
+    name: &'static str,
+    protocol: Protocol,
+    /// 完整指令数据（含判别器）
+    data: Vec<u8>,
+    /// 数据为 CPI log（inner instruction），按 16 字节判别器分发
+    cpi_log: bool,
+    account_count: usize,
+    /// 核对解析结果，`accounts` 为传入的账户列表
+    check: fn(&DexEvent, &[Pubkey]) -> bool,
+}
+
+/// 单个参考指令的自检结果
+#[derive(Clone, Debug)]
+pub struct SelfTestResult {
+    pub protocol: Protocol,
+    pub name: &'static str,
+    pub passed: bool,
+    /// 未通过的原因
+    pub error: Option<String>,
+}
+
+/// 自检报告
+#[derive(Clone, Debug, Default)]
+pub struct SelfTestReport {
+    pub results: Vec<SelfTestResult>,
+}
+
+impl SelfTestReport {
+    /// 是否全部通过
+    pub fn all_passed(&self) -> bool {
+        self.results.iter().all(|result| result.passed)
+    }
+
+    /// 未通过的结果
+    pub fn failures(&self) -> impl Iterator<Item = &SelfTestResult> {
+        self.results.iter().filter(|result| !result.passed)
+    }
+
+    /// 按协议汇总 `(协议, 通过数, 总数)`，顺序与参考指令一致
+    pub fn protocol_summary(&self) -> Vec<(Protocol, usize, usize)> {
+        let mut summary: Vec<(Protocol, usize, usize)> = Vec::new();
+        for result in &self.results {
+            let position = summary.iter().position(|(protocol, _, _)| *protocol == result.protocol);
+            let index = match position {
+                Some(index) => index,
+                None => {
+                    summary.push((result.protocol.clone(), 0, 0));
+                    summary.len() - 1
+                }
+            };
+            summary[index].1 += result.passed as usize;
+            summary[index].2 += 1;
+        }
+        summary
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (protocol, passed, total) in self.protocol_summary() {
+            let status = if passed == total { "PASS" } else { "FAIL" };
+            writeln!(f, "{status} {protocol:?}: {passed}/{total}")?;
+        }
+        for failure in self.failures() {
+            let error = failure.error.as_deref().unwrap_or_default();
+            writeln!(f, "  {:?} {}: {}", failure.protocol, failure.name, error)?;
+        }
+        Ok(())
+    }
+}
+
+/// 运行全部内置参考指令，返回按协议分组的通过情况
+pub fn parser_selftest() -> SelfTestReport {
+    let results = fixtures().iter().map(run_fixture).collect();
+    SelfTestReport { results }
+}
+
+fn run_fixture(fixture: &Fixture) -> SelfTestResult {
+    let accounts: Vec<Pubkey> =
+        (0..fixture.account_count).map(|i| Pubkey::new_from_array([i as u8 + 1; 32])).collect();
+    let program_id = EventDispatcher::get_program_id(fixture.protocol.clone());
+    let disc_len = EventDispatcher::discriminator_len(&program_id);
+    let metadata = EventMetadata { program_id, ..Default::default() };
+
+    let dispatch = |accounts: &[Pubkey]| {
+        catch_unwind(AssertUnwindSafe(|| {
+            if fixture.cpi_log {
+                let (discriminator, data) = fixture.data.split_at(CPI_LOG_DISCRIMINATOR_LEN);
+                return EventDispatcher::dispatch_inner_instruction(
+                    fixture.protocol.clone(),
+                    discriminator,
+                    data,
+                    metadata.clone(),
+                );
+            }
+            let (discriminator, data) = fixture.data.split_at(disc_len);
+            EventDispatcher::dispatch_instruction(
+                fixture.protocol.clone(),
+                discriminator,
+                data,
+                accounts,
+                metadata.clone(),
+            )
+        }))
+    };
+    let mut error = match dispatch(&accounts) {
+        Err(_) => Some("parser panicked".to_string()),
+        Ok(None) => Some("no event decoded".to_string()),
+        Ok(Some(event)) if !(fixture.check)(&event, &accounts) => {
+            Some(format!("unexpected event: {event:?}"))
+        }
+        Ok(Some(_)) => None,
+    };
+    // 账户列表被截短时解析器应返回 None（或忽略缺失的可选账户），不能 panic
+    if error.is_none() {
+        error = (0..accounts.len())
+            .find(|len| dispatch(&accounts[..*len]).is_err())
+            .map(|len| format!("parser panicked with only {len} accounts"));
+    }
+    SelfTestResult {
+        protocol: fixture.protocol.clone(),
+        name: fixture.name,
+        passed: error.is_none(),
+        error,
+    }
+}
+
+/// 判别器后依次拼接小端 u64 参数
+fn ix_data(discriminator: &[u8], args: &[u64]) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    for arg in args {
+        data.extend_from_slice(&arg.to_le_bytes());
+    }
+    data
+}
+
+/// PumpFun TradeEvent CPI log（当前布局），指定 `len` 时只保留事件前 `len` 字节以模拟旧版布局：
+/// 121 字节为加入费用字段之前，217 字节为加入交易量统计之前
+fn pumpfun_trade_log(len: Option<usize>) -> Vec<u8> {
+    let mut log = pumpfun::discriminators::TRADE_EVENT.to_vec();
+    log.extend_from_slice(&[1; 32]); // mint
+    log.extend_from_slice(&1_000_000_000u64.to_le_bytes()); // sol_amount
+    log.extend_from_slice(&35_000_000_000u64.to_le_bytes()); // token_amount
+    log.push(1); // is_buy
+    log.extend_from_slice(&[2; 32]); // user
+    log.extend_from_slice(&1_760_000_000i64.to_le_bytes()); // timestamp
+    for reserve in [40_000_000_000u64, 800_000_000_000_000, 10_000_000_000, 500_000_000_000_000] {
+        log.extend_from_slice(&reserve.to_le_bytes());
+    }
+    log.extend_from_slice(&[3; 32]); // fee_recipient
+    log.extend_from_slice(&95u64.to_le_bytes()); // fee_basis_points
+    log.extend_from_slice(&9_500_000u64.to_le_bytes()); // fee
+    log.extend_from_slice(&[4; 32]); // creator
+    log.extend_from_slice(&5u64.to_le_bytes()); // creator_fee_basis_points
+    log.extend_from_slice(&500_000u64.to_le_bytes()); // creator_fee
+    log.push(1); // track_volume
+    log.extend_from_slice(&[0; 32]); // 交易量统计字段
+    log.extend_from_slice(&3u32.to_le_bytes());
+    log.extend_from_slice(b"buy"); // ix_name
+    if let Some(len) = len {
+        log.truncate(CPI_LOG_DISCRIMINATOR_LEN + len);
+    }
+    log
+}
+
+/// 判别器后接一个 Pubkey 参数
+fn pubkey_arg_data(discriminator: &[u8], pubkey: [u8; 32]) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&pubkey);
+    data
+}
+
+/// PumpFun `set_params` 指令数据，参数取主网 Global 账户当前的取值
+fn pumpfun_set_params_data() -> Vec<u8> {
+    let mut data = ix_data(
+        pumpfun::discriminators::SET_PARAMS_IX,
+        &[1_073_000_000_000_000, 30_000_000_000, 793_100_000_000_000, 1_000_000_000_000_000, 95],
+    );
+    data.extend_from_slice(&[5; 32]); // withdraw_authority
+    data.push(1); // enable_migrate
+    data.extend_from_slice(&15_000_001u64.to_le_bytes()); // pool_migration_fee
+    data.extend_from_slice(&5u64.to_le_bytes()); // creator_fee_basis_points
+    data.extend_from_slice(&[6; 32]); // set_creator_authority
+    data.extend_from_slice(&[7; 32]); // admin_set_creator_authority
+    data
+}
+
+fn fixtures() -> Vec<Fixture> {
+    vec![
+        Fixture {
+            name: "buy",
+            protocol: Protocol::PumpFun,
+            data: ix_data(pumpfun::discriminators::BUY_IX, &[1_000_000, 50_000_000]),
+            cpi_log: false,
+            account_count: 16,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpFunTradeEvent(e) if e.is_buy
+                    && e.amount == 1_000_000
+                    && e.max_sol_cost == 50_000_000
+                    && e.mint == a[2]
+                    && e.user == a[6])
+            },
+        },
+        Fixture {
+            name: "sell",
+            protocol: Protocol::PumpFun,
+            data: ix_data(pumpfun::discriminators::SELL_IX, &[1_000_000, 40_000_000]),
+            cpi_log: false,
+            account_count: 14,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpFunTradeEvent(e) if !e.is_buy
+                    && e.amount == 1_000_000
+                    && e.min_sol_output == 40_000_000
+                    && e.mint == a[2]
+                    && e.user == a[6])
+            },
+        },
+        Fixture {
+            name: "trade log",
+            protocol: Protocol::PumpFun,
+            data: pumpfun_trade_log(None),
+            cpi_log: true,
+            account_count: 0,
+            check: |event, _| {
+                matches!(event, DexEvent::PumpFunTradeEvent(e) if e.ix_name == "buy"
+                    && e.fees().is_some_and(|fees| fees.fee == 9_500_000
+                        && fees.creator_fee == 500_000
+                        && fees.creator == Pubkey::new_from_array([4; 32])))
+            },
+        },
+        Fixture {
+            name: "trade log without volume fields",
+            protocol: Protocol::PumpFun,
+            data: pumpfun_trade_log(Some(pumpfun::PUMPFUN_TRADE_EVENT_LOG_SIZE_WITH_FEES)),
+            cpi_log: true,
+            account_count: 0,
+            check: |event, _| {
+                matches!(event, DexEvent::PumpFunTradeEvent(e) if !e.track_volume
+                    && e.fees().is_some_and(|fees| fees.total_fee() == 10_000_000
+                        && fees.fee_recipient == Pubkey::new_from_array([3; 32])))
+            },
+        },
+        Fixture {
+            name: "trade log without fee fields",
+            protocol: Protocol::PumpFun,
+            data: pumpfun_trade_log(Some(pumpfun::PUMPFUN_TRADE_EVENT_LOG_SIZE_BASE)),
+            cpi_log: true,
+            account_count: 0,
+            check: |event, _| {
+                matches!(event, DexEvent::PumpFunTradeEvent(e) if e.is_buy
+                    && e.sol_amount == 1_000_000_000
+                    && e.fees().is_none())
+            },
+        },
+        Fixture {
+            name: "set_creator",
+            protocol: Protocol::PumpFun,
+            data: pubkey_arg_data(pumpfun::discriminators::SET_CREATOR_IX, [8; 32]),
+            cpi_log: false,
+            account_count: 7,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpFunAdminEvent(e)
+                    if e.action == pumpfun::PumpFunAdminAction::SetCreator
+                        && e.creator == Pubkey::new_from_array([8; 32])
+                        && e.authority == a[0]
+                        && e.mint == a[2]
+                        && e.bonding_curve == a[4])
+            },
+        },
+        Fixture {
+            name: "admin_set_creator",
+            protocol: Protocol::PumpFun,
+            data: pubkey_arg_data(pumpfun::discriminators::ADMIN_SET_CREATOR_IX, [8; 32]),
+            cpi_log: false,
+            account_count: 6,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpFunAdminEvent(e)
+                    if e.action == pumpfun::PumpFunAdminAction::AdminSetCreator
+                        && e.creator == Pubkey::new_from_array([8; 32])
+                        && e.authority == a[0]
+                        && e.mint == a[2]
+                        && e.bonding_curve == a[3])
+            },
+        },
+        Fixture {
+            name: "set_params",
+            protocol: Protocol::PumpFun,
+            data: pumpfun_set_params_data(),
+            cpi_log: false,
+            account_count: 4,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpFunAdminEvent(e)
+                    if e.action == pumpfun::PumpFunAdminAction::SetParams
+                        && e.authority == a[1]
+                        && e.global == a[0]
+                        && e.params.as_ref().is_some_and(|params| params.fee_basis_points == 95
+                            && params.enable_migrate
+                            && params.pool_migration_fee == 15_000_001
+                            && params.creator_fee_basis_points == 5
+                            && params.admin_set_creator_authority
+                                == Pubkey::new_from_array([7; 32])))
+            },
+        },
+        Fixture {
+            name: "update_global_authority",
+            protocol: Protocol::PumpFun,
+            data: pumpfun::discriminators::UPDATE_GLOBAL_AUTHORITY_IX.to_vec(),
+            cpi_log: false,
+            account_count: 5,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpFunAdminEvent(e)
+                    if e.action == pumpfun::PumpFunAdminAction::UpdateGlobalAuthority
+                        && e.authority == a[1]
+                        && e.new_authority == a[2])
+            },
+        },
+        Fixture {
+            name: "set_reserved_fee_recipients",
+            protocol: Protocol::PumpFun,
+            data: pubkey_arg_data(pumpfun::discriminators::SET_RESERVED_FEE_RECIPIENTS_IX, [9; 32]),
+            cpi_log: false,
+            account_count: 4,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpFunAdminEvent(e)
+                    if e.action == pumpfun::PumpFunAdminAction::SetReservedFeeRecipients
+                        && e.authority == a[1]
+                        && e.whitelist_pda == Pubkey::new_from_array([9; 32]))
+            },
+        },
+        Fixture {
+            name: "buy",
+            protocol: Protocol::PumpSwap,
+            data: ix_data(pumpswap::discriminators::BUY_IX, &[1_000_000, 50_000_000]),
+            cpi_log: false,
+            account_count: 23,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpSwapBuyEvent(e)
+                    if e.metadata.event_type == EventType::PumpSwapBuy
+                        && e.base_amount_out == 1_000_000
+                        && e.max_quote_amount_in == 50_000_000
+                        && e.pool == a[0]
+                        && e.base_mint == a[3])
+            },
+        },
+        Fixture {
+            name: "buy_exact_quote_in",
+            protocol: Protocol::PumpSwap,
+            data: ix_data(
+                pumpswap::discriminators::BUY_EXACT_QUOTE_IN_IX,
+                &[50_000_000, 1_000_000],
+            ),
+            cpi_log: false,
+            account_count: 23,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpSwapBuyEvent(e)
+                    if e.metadata.event_type == EventType::PumpSwapBuyExactQuoteIn
+                        && e.max_quote_amount_in == 50_000_000
+                        && e.base_amount_out == 1_000_000
+                        && e.pool == a[0])
+            },
+        },
+        Fixture {
+            name: "sell",
+            protocol: Protocol::PumpSwap,
+            data: ix_data(pumpswap::discriminators::SELL_IX, &[1_000_000, 40_000_000]),
+            cpi_log: false,
+            account_count: 21,
+            check: |event, a| {
+                matches!(event, DexEvent::PumpSwapSellEvent(e) if e.base_amount_in == 1_000_000
+                    && e.min_quote_amount_out == 40_000_000
+                    && e.pool == a[0]
+                    && e.quote_mint == a[4])
+            },
+        },
+        Fixture {
+            name: "buy_exact_in",
+            protocol: Protocol::Bonk,
+            data: ix_data(bonk::discriminators::BUY_EXACT_IN, &[50_000_000, 1_000_000, 0]),
+            cpi_log: false,
+            account_count: 18,
+            check: |event, a| {
+                matches!(event, DexEvent::BonkTradeEvent(e)
+                    if e.trade_direction == TradeDirection::Buy
+                        && e.amount_in == 50_000_000
+                        && e.minimum_amount_out == 1_000_000
+                        && e.pool_state == a[4]
+                        && e.base_token_mint == a[9])
+            },
+        },
+        Fixture {
+            name: "sell_exact_in",
+            protocol: Protocol::Bonk,
+            data: ix_data(bonk::discriminators::SELL_EXACT_IN, &[1_000_000, 40_000_000, 0]),
+            cpi_log: false,
+            account_count: 18,
+            check: |event, a| {
+                matches!(event, DexEvent::BonkTradeEvent(e)
+                    if e.trade_direction == TradeDirection::Sell
+                        && e.amount_in == 1_000_000
+                        && e.minimum_amount_out == 40_000_000
+                        && e.pool_state == a[4])
+            },
+        },
+        Fixture {
+            name: "buy_exact_out",
+            protocol: Protocol::Bonk,
+            data: ix_data(bonk::discriminators::BUY_EXACT_OUT, &[1_000_000, 50_000_000, 0]),
+            cpi_log: false,
+            account_count: 18,
+            check: |event, a| {
+                matches!(event, DexEvent::BonkTradeEvent(e)
+                    if e.metadata.event_type == EventType::BonkBuyExactOut
+                        && e.trade_direction == TradeDirection::Buy
+                        && e.amount_out == 1_000_000
+                        && e.maximum_amount_in == 50_000_000
+                        && e.pool_state == a[4])
+            },
+        },
+        Fixture {
+            name: "sell_exact_out",
+            protocol: Protocol::Bonk,
+            data: ix_data(bonk::discriminators::SELL_EXACT_OUT, &[40_000_000, 1_000_000, 0]),
+            cpi_log: false,
+            account_count: 18,
+            check: |event, a| {
+                matches!(event, DexEvent::BonkTradeEvent(e)
+                    if e.metadata.event_type == EventType::BonkSellExactOut
+                        && e.trade_direction == TradeDirection::Sell
+                        && e.amount_out == 40_000_000
+                        && e.maximum_amount_in == 1_000_000
+                        && e.pool_state == a[4])
+            },
+        },
+        Fixture {
+            name: "swap_base_input",
+            protocol: Protocol::RaydiumCpmm,
+            data: ix_data(raydium_cpmm::discriminators::SWAP_BASE_IN, &[1_000_000, 900_000]),
+            cpi_log: false,
+            account_count: 13,
+            check: |event, a| {
+                matches!(event, DexEvent::RaydiumCpmmSwapEvent(e) if e.amount_in == 1_000_000
+                    && e.minimum_amount_out == 900_000
+                    && e.pool_state == a[3]
+                    && e.input_token_mint == a[10])
+            },
+        },
+        Fixture {
+            name: "swap_base_output",
+            protocol: Protocol::RaydiumCpmm,
+            data: ix_data(raydium_cpmm::discriminators::SWAP_BASE_OUT, &[1_100_000, 1_000_000]),
+            cpi_log: false,
+            account_count: 13,
+            check: |event, a| {
+                matches!(event, DexEvent::RaydiumCpmmSwapEvent(e) if e.max_amount_in == 1_100_000
+                    && e.amount_out == 1_000_000
+                    && e.pool_state == a[3])
+            },
+        },
+        Fixture {
+            name: "swap",
+            protocol: Protocol::RaydiumClmm,
+            data: {
+                let mut data = ix_data(raydium_clmm::discriminators::SWAP, &[1_000_000, 900_000]);
+                data.extend_from_slice(&0u128.to_le_bytes());
+                data.push(1);
+                data
+            },
+            cpi_log: false,
+            account_count: 10,
+            check: |event, a| {
+                matches!(event, DexEvent::RaydiumClmmSwapEvent(e) if e.amount == 1_000_000
+                    && e.other_amount_threshold == 900_000
+                    && e.is_base_input
+                    && e.pool_state == a[2])
+            },
+        },
+        Fixture {
+            name: "swap_base_in",
+            protocol: Protocol::RaydiumAmmV4,
+            data: ix_data(raydium_amm_v4::discriminators::SWAP_BASE_IN, &[1_000_000, 900_000]),
+            cpi_log: false,
+            account_count: 18,
+            check: |event, a| {
+                matches!(event, DexEvent::RaydiumAmmV4SwapEvent(e) if e.amount_in == 1_000_000
+                    && e.minimum_amount_out == 900_000
+                    && e.amm == a[1]
+                    && e.amm_target_orders == Some(a[4]))
+            },
+        },
+        Fixture {
+            name: "swap_base_in without target orders",
+            protocol: Protocol::RaydiumAmmV4,
+            data: ix_data(raydium_amm_v4::discriminators::SWAP_BASE_IN, &[1_000_000, 900_000]),
+            cpi_log: false,
+            account_count: 17,
+            check: |event, a| {
+                matches!(event, DexEvent::RaydiumAmmV4SwapEvent(e) if e.amount_in == 1_000_000
+                    && e.amm == a[1]
+                    && e.pool_coin_token_account == a[4])
+            },
+        },
+        Fixture {
+            name: "swap",
+            protocol: Protocol::MeteoraDammV2,
+            data: ix_data(meteora_damm_v2::discriminators::SWAP_IX, &[1_000_000, 900_000]),
+            cpi_log: false,
+            account_count: 14,
+            check: |event, a| {
+                matches!(event, DexEvent::MeteoraDammV2SwapEvent(e) if e.amount_0 == 1_000_000
+                    && e.amount_1 == 900_000
+                    && e.pool == a[1]
+                    && e.token_a_mint == a[6])
+            },
+        },
+        Fixture {
+            name: "swap2",
+            protocol: Protocol::MeteoraDammV2,
+            data: {
+                let mut data =
+                    ix_data(meteora_damm_v2::discriminators::SWAP2_IX, &[1_000_000, 900_000]);
+                data.push(0);
+                data
+            },
+            cpi_log: false,
+            account_count: 14,
+            check: |event, a| {
+                matches!(event, DexEvent::MeteoraDammV2Swap2Event(e) if e.amount_0 == 1_000_000
+                    && e.amount_1 == 900_000
+                    && e.swap_mode == 0
+                    && e.pool == a[1])
+            },
+        },
+    ]
+}
@@ -1,11 +1,14 @@
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{
+    format_pubkey_short, EventMetadata, EventType, ProtocolType,
+};
 use crate::streaming::event_parser::core::account_event_parser::{
-    NonceAccountEvent, TokenAccountEvent, TokenInfoEvent,
+    AccountChangeEvent, NonceAccountEvent, TokenAccountEvent, TokenInfoEvent,
 };
 use crate::streaming::event_parser::core::common_event_parser::{
-    SetComputeUnitLimitEvent, SetComputeUnitPriceEvent,
+    MemoEvent, SetComputeUnitLimitEvent, SetComputeUnitPriceEvent,
 };
 use crate::streaming::event_parser::protocols::block::block_meta_event::BlockMetaEvent;
+use crate::streaming::event_parser::protocols::block::slot_status_event::SlotStatusEvent;
 use crate::streaming::event_parser::protocols::bonk::events::*;
 use crate::streaming::event_parser::protocols::meteora_damm_v2::events::*;
 use crate::streaming::event_parser::protocols::pumpfun::events::*;
@@ -13,11 +16,16 @@ use crate::streaming::event_parser::protocols::pumpswap::events::*;
 use crate::streaming::event_parser::protocols::raydium_amm_v4::events::*;
 use crate::streaming::event_parser::protocols::raydium_clmm::events::*;
 use crate::streaming::event_parser::protocols::raydium_cpmm::events::*;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 use std::fmt::Debug;
 
 /// Unified Event Enum - Replaces the trait-based approach with a type-safe enum
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum DexEvent {
     // Bonk events
     BonkTradeEvent(BonkTradeEvent),
@@ -33,6 +41,7 @@ pub enum DexEvent {
     PumpFunCreateV2TokenEvent(PumpFunCreateV2TokenEvent),
     PumpFunTradeEvent(PumpFunTradeEvent),
     PumpFunMigrateEvent(PumpFunMigrateEvent),
+    PumpFunAdminEvent(PumpFunAdminEvent),
     PumpFunBondingCurveAccountEvent(PumpFunBondingCurveAccountEvent),
     PumpFunGlobalAccountEvent(PumpFunGlobalAccountEvent),
 
@@ -80,14 +89,19 @@ pub enum DexEvent {
     MeteoraDammV2InitializePoolEvent(MeteoraDammV2InitializePoolEvent),
     MeteoraDammV2InitializeCustomizablePoolEvent(MeteoraDammV2InitializeCustomizablePoolEvent),
     MeteoraDammV2InitializePoolWithDynamicConfigEvent(MeteoraDammV2InitializePoolWithDynamicConfigEvent),
+    MeteoraDammV2AddLiquidityEvent(MeteoraDammV2AddLiquidityEvent),
+    MeteoraDammV2RemoveLiquidityEvent(MeteoraDammV2RemoveLiquidityEvent),
 
     // Common events
     TokenAccountEvent(TokenAccountEvent),
     NonceAccountEvent(NonceAccountEvent),
     TokenInfoEvent(TokenInfoEvent),
+    AccountChangeEvent(AccountChangeEvent),
     BlockMetaEvent(BlockMetaEvent),
+    SlotStatusEvent(SlotStatusEvent),
     SetComputeUnitLimitEvent(SetComputeUnitLimitEvent),
     SetComputeUnitPriceEvent(SetComputeUnitPriceEvent),
+    MemoEvent(MemoEvent),
 }
 
 impl DexEvent {
@@ -104,6 +118,7 @@ impl DexEvent {
             DexEvent::PumpFunCreateV2TokenEvent(e) => &e.metadata,
             DexEvent::PumpFunTradeEvent(e) => &e.metadata,
             DexEvent::PumpFunMigrateEvent(e) => &e.metadata,
+            DexEvent::PumpFunAdminEvent(e) => &e.metadata,
             DexEvent::PumpFunBondingCurveAccountEvent(e) => &e.metadata,
             DexEvent::PumpFunGlobalAccountEvent(e) => &e.metadata,
             DexEvent::PumpSwapBuyEvent(e) => &e.metadata,
@@ -141,12 +156,17 @@ impl DexEvent {
             DexEvent::MeteoraDammV2InitializePoolEvent(e) => &e.metadata,
             DexEvent::MeteoraDammV2InitializeCustomizablePoolEvent(e) => &e.metadata,
             DexEvent::MeteoraDammV2InitializePoolWithDynamicConfigEvent(e) => &e.metadata,
+            DexEvent::MeteoraDammV2AddLiquidityEvent(e) => &e.metadata,
+            DexEvent::MeteoraDammV2RemoveLiquidityEvent(e) => &e.metadata,
             DexEvent::TokenAccountEvent(e) => &e.metadata,
             DexEvent::NonceAccountEvent(e) => &e.metadata,
             DexEvent::TokenInfoEvent(e) => &e.metadata,
+            DexEvent::AccountChangeEvent(e) => &e.metadata,
             DexEvent::BlockMetaEvent(e) => &e.metadata,
+            DexEvent::SlotStatusEvent(e) => &e.metadata,
             DexEvent::SetComputeUnitLimitEvent(e) => &e.metadata,
             DexEvent::SetComputeUnitPriceEvent(e) => &e.metadata,
+            DexEvent::MemoEvent(e) => &e.metadata,
         }
     }
 
@@ -163,6 +183,7 @@ impl DexEvent {
             DexEvent::PumpFunCreateV2TokenEvent(e) => &mut e.metadata,
             DexEvent::PumpFunTradeEvent(e) => &mut e.metadata,
             DexEvent::PumpFunMigrateEvent(e) => &mut e.metadata,
+            DexEvent::PumpFunAdminEvent(e) => &mut e.metadata,
             DexEvent::PumpFunBondingCurveAccountEvent(e) => &mut e.metadata,
             DexEvent::PumpFunGlobalAccountEvent(e) => &mut e.metadata,
             DexEvent::PumpSwapBuyEvent(e) => &mut e.metadata,
@@ -200,12 +221,214 @@ impl DexEvent {
             DexEvent::MeteoraDammV2InitializePoolEvent(e) => &mut e.metadata,
             DexEvent::MeteoraDammV2InitializeCustomizablePoolEvent(e) => &mut e.metadata,
             DexEvent::MeteoraDammV2InitializePoolWithDynamicConfigEvent(e) => &mut e.metadata,
+            DexEvent::MeteoraDammV2AddLiquidityEvent(e) => &mut e.metadata,
+            DexEvent::MeteoraDammV2RemoveLiquidityEvent(e) => &mut e.metadata,
             DexEvent::TokenAccountEvent(e) => &mut e.metadata,
             DexEvent::NonceAccountEvent(e) => &mut e.metadata,
             DexEvent::TokenInfoEvent(e) => &mut e.metadata,
+            DexEvent::AccountChangeEvent(e) => &mut e.metadata,
             DexEvent::BlockMetaEvent(e) => &mut e.metadata,
+            DexEvent::SlotStatusEvent(e) => &mut e.metadata,
             DexEvent::SetComputeUnitLimitEvent(e) => &mut e.metadata,
             DexEvent::SetComputeUnitPriceEvent(e) => &mut e.metadata,
+            DexEvent::MemoEvent(e) => &mut e.metadata,
+        }
+    }
+
+    /// 事件所属协议，账户、区块等通用事件为 `ProtocolType::Common`
+    pub fn protocol(&self) -> &ProtocolType {
+        &self.metadata().protocol
+    }
+
+    /// 交易签名；账户事件为最近写入该账户的交易签名（可能为默认值），区块事件为默认值
+    pub fn signature(&self) -> Signature {
+        self.metadata().signature
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.metadata().slot
+    }
+
+    pub fn event_type(&self) -> &EventType {
+        &self.metadata().event_type
+    }
+
+    /// 收到原始更新的时间（微秒）
+    pub fn recv_us(&self) -> i64 {
+        self.metadata().recv_us
+    }
+
+    /// 是否为成交事件（各协议的 buy / sell / swap）
+    pub fn is_trade(&self) -> bool {
+        matches!(
+            self,
+            DexEvent::PumpFunTradeEvent(_)
+                | DexEvent::PumpSwapBuyEvent(_)
+                | DexEvent::PumpSwapSellEvent(_)
+                | DexEvent::BonkTradeEvent(_)
+                | DexEvent::RaydiumCpmmSwapEvent(_)
+                | DexEvent::RaydiumClmmSwapEvent(_)
+                | DexEvent::RaydiumClmmSwapV2Event(_)
+                | DexEvent::RaydiumAmmV4SwapEvent(_)
+                | DexEvent::MeteoraDammV2SwapEvent(_)
+                | DexEvent::MeteoraDammV2Swap2Event(_)
+        )
+    }
+
+    /// 账户更新事件对应的账户地址，交易与区块事件返回 None
+    pub fn account_pubkey(&self) -> Option<Pubkey> {
+        match self {
+            DexEvent::BonkPoolStateAccountEvent(e) => Some(e.pubkey),
+            DexEvent::BonkGlobalConfigAccountEvent(e) => Some(e.pubkey),
+            DexEvent::BonkPlatformConfigAccountEvent(e) => Some(e.pubkey),
+            DexEvent::PumpFunBondingCurveAccountEvent(e) => Some(e.pubkey),
+            DexEvent::PumpFunGlobalAccountEvent(e) => Some(e.pubkey),
+            DexEvent::PumpSwapGlobalConfigAccountEvent(e) => Some(e.pubkey),
+            DexEvent::PumpSwapPoolAccountEvent(e) => Some(e.pubkey),
+            DexEvent::RaydiumAmmV4AmmInfoAccountEvent(e) => Some(e.pubkey),
+            DexEvent::RaydiumClmmAmmConfigAccountEvent(e) => Some(e.pubkey),
+            DexEvent::RaydiumClmmPoolStateAccountEvent(e) => Some(e.pubkey),
+            DexEvent::RaydiumClmmTickArrayStateAccountEvent(e) => Some(e.pubkey),
+            DexEvent::RaydiumCpmmAmmConfigAccountEvent(e) => Some(e.pubkey),
+            DexEvent::RaydiumCpmmPoolStateAccountEvent(e) => Some(e.pubkey),
+            DexEvent::TokenAccountEvent(e) => Some(e.pubkey),
+            DexEvent::NonceAccountEvent(e) => Some(e.pubkey),
+            DexEvent::TokenInfoEvent(e) => Some(e.pubkey),
+            DexEvent::AccountChangeEvent(e) => Some(e.pubkey),
+            _ => None,
         }
     }
+
+    /// 单行可读摘要，用于日志和调试
+    ///
+    /// 例如 `PumpFun BUY 1.5 SOL -> 1.2M TOKEN(Mint...abcd) by 7xKX...9abc sig 4abc...wxyz`。
+    /// 未专门处理的事件输出协议、事件类型以及 swap_data（如有）。
+    pub fn summary(&self) -> String {
+        let metadata = self.metadata();
+        let detail = match self {
+            DexEvent::PumpFunTradeEvent(e) => {
+                let sol = format!("{} SOL", format_sol(e.sol_amount));
+                let token =
+                    format!("{} TOKEN({})", format_amount(e.token_amount), format_pubkey_short(&e.mint));
+                if e.is_buy {
+                    format!("BUY {} -> {} by {}", sol, token, format_pubkey_short(&e.user))
+                } else {
+                    format!("SELL {} -> {} by {}", token, sol, format_pubkey_short(&e.user))
+                }
+            }
+            DexEvent::PumpSwapBuyEvent(e) => format!(
+                "BUY {} {} -> {} {} by {}",
+                format_amount(e.user_quote_amount_in),
+                format_pubkey_short(&e.quote_mint),
+                format_amount(e.base_amount_out),
+                format_pubkey_short(&e.base_mint),
+                format_pubkey_short(&e.user)
+            ),
+            DexEvent::PumpSwapSellEvent(e) => format!(
+                "SELL {} {} -> {} {} by {}",
+                format_amount(e.base_amount_in),
+                format_pubkey_short(&e.base_mint),
+                format_amount(e.user_quote_amount_out),
+                format_pubkey_short(&e.quote_mint),
+                format_pubkey_short(&e.user)
+            ),
+            DexEvent::BonkTradeEvent(e) => format!(
+                "{:?} {} -> {} base {} by {}",
+                e.trade_direction,
+                format_amount(e.amount_in),
+                format_amount(e.amount_out),
+                format_pubkey_short(&e.base_token_mint),
+                format_pubkey_short(&e.payer)
+            ),
+            DexEvent::RaydiumCpmmSwapEvent(e) => format!(
+                "SWAP {} {} -> {} {} by {}",
+                format_amount(e.amount_in),
+                format_pubkey_short(&e.input_token_mint),
+                format_amount(e.amount_out),
+                format_pubkey_short(&e.output_token_mint),
+                format_pubkey_short(&e.payer)
+            ),
+            DexEvent::RaydiumAmmV4SwapEvent(e) => format!(
+                "SWAP {} -> {} by {}",
+                format_amount(e.amount_in),
+                format_amount(e.amount_out),
+                format_pubkey_short(&e.user_source_owner)
+            ),
+            DexEvent::PumpFunCreateTokenEvent(e) => format!(
+                "CREATE {} ({}) by {}",
+                e.symbol,
+                format_pubkey_short(&e.mint),
+                format_pubkey_short(&e.user)
+            ),
+            DexEvent::PumpFunCreateV2TokenEvent(e) => format!(
+                "CREATE {} ({}) by {}",
+                e.symbol,
+                format_pubkey_short(&e.mint),
+                format_pubkey_short(&e.user)
+            ),
+            DexEvent::SetComputeUnitLimitEvent(e) => format!("CU limit {}", e.units),
+            DexEvent::SetComputeUnitPriceEvent(e) => format!("CU price {}", e.micro_lamports),
+            DexEvent::MemoEvent(e) => format!("MEMO {:?}", e.text),
+            _ => format!("{:?}", metadata.event_type),
+        };
+
+        let swap = match (&metadata.swap_data, self) {
+            (
+                Some(swap_data),
+                DexEvent::RaydiumAmmV4SwapEvent(_)
+                | DexEvent::RaydiumClmmSwapEvent(_)
+                | DexEvent::RaydiumClmmSwapV2Event(_)
+                | DexEvent::MeteoraDammV2SwapEvent(_)
+                | DexEvent::MeteoraDammV2Swap2Event(_),
+            ) => format!(
+                " [{} {} -> {} {}]",
+                format_amount(swap_data.from_amount),
+                format_pubkey_short(&swap_data.from_mint),
+                format_amount(swap_data.to_amount),
+                format_pubkey_short(&swap_data.to_mint)
+            ),
+            _ => String::new(),
+        };
+
+        format!(
+            "{:?} {}{} sig {}",
+            metadata.protocol,
+            detail,
+            swap,
+            format_signature_short(&metadata.signature)
+        )
+    }
+}
+
+/// 将 lamports 格式化为 SOL（最多 4 位小数）
+fn format_sol(lamports: u64) -> String {
+    let sol = format!("{:.4}", lamports as f64 / 1_000_000_000.0);
+    sol.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// 将数量格式化为紧凑形式，例如 1.2M、35.6K
+fn format_amount(amount: u64) -> String {
+    let value = amount as f64;
+    let (scaled, suffix) = if amount >= 1_000_000_000_000 {
+        (value / 1e12, "T")
+    } else if amount >= 1_000_000_000 {
+        (value / 1e9, "B")
+    } else if amount >= 1_000_000 {
+        (value / 1e6, "M")
+    } else if amount >= 1_000 {
+        (value / 1e3, "K")
+    } else {
+        return amount.to_string();
+    };
+    format!("{:.1}{}", scaled, suffix)
+}
+
+/// 格式化签名为短字符串
+fn format_signature_short(signature: &solana_sdk::signature::Signature) -> String {
+    let s = signature.to_string();
+    if s.len() <= 8 {
+        s
+    } else {
+        format!("{}...{}", &s[..4], &s[s.len() - 4..])
+    }
 }
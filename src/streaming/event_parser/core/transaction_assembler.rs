@@ -0,0 +1,154 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::InnerInstructions;
+
+use crate::streaming::event_parser::{
+    common::EventMetadata,
+    core::{dispatcher::EventDispatcher, merger_event::can_merge},
+    DexEvent,
+};
+
+/// 交易内的一条 CPI log 候选
+struct CpiLogCandidate {
+    outer_index: i64,
+    inner_index: i64,
+    event: DexEvent,
+    consumed: bool,
+}
+
+/// 交易级 CPI log 收集器
+///
+/// 先解码整笔交易所有 inner instructions 中的 CPI log，指令事件在本地 inner 集合里找不到
+/// 对应 log 时，再从这里查找位于该指令之后的 log。每条 log 最多合并一次。
+#[derive(Default)]
+pub struct TransactionCpiLogs {
+    candidates: Vec<CpiLogCandidate>,
+}
+
+impl TransactionCpiLogs {
+    /// 从 gRPC 格式的 inner instructions 收集 CPI log，`catch_parser_panics` 见 `ParseConfig`
+    pub fn from_grpc(
+        inner_instructions: &[yellowstone_grpc_proto::prelude::InnerInstructions],
+        accounts: &[Pubkey],
+        catch_parser_panics: bool,
+    ) -> Self {
+        let mut logs = Self::default();
+        for inner_instructions_ref in inner_instructions {
+            for (inner_index, inner_instruction) in
+                inner_instructions_ref.instructions.iter().enumerate()
+            {
+                logs.push(
+                    inner_instructions_ref.index as i64,
+                    inner_index as i64,
+                    accounts.get(inner_instruction.program_id_index as usize),
+                    &inner_instruction.data,
+                    catch_parser_panics,
+                );
+            }
+        }
+        logs
+    }
+
+    /// 从标准格式的 inner instructions 收集 CPI log，`catch_parser_panics` 见 `ParseConfig`
+    pub fn from_inner_instructions(
+        inner_instructions: &[InnerInstructions],
+        accounts: &[Pubkey],
+        catch_parser_panics: bool,
+    ) -> Self {
+        let mut logs = Self::default();
+        for inner_instructions_ref in inner_instructions {
+            for (inner_index, inner_instruction) in
+                inner_instructions_ref.instructions.iter().enumerate()
+            {
+                logs.push(
+                    inner_instructions_ref.index as i64,
+                    inner_index as i64,
+                    accounts.get(inner_instruction.instruction.program_id_index as usize),
+                    &inner_instruction.instruction.data,
+                    catch_parser_panics,
+                );
+            }
+        }
+        logs
+    }
+
+    fn push(
+        &mut self,
+        outer_index: i64,
+        inner_index: i64,
+        program_id: Option<&Pubkey>,
+        data: &[u8],
+        catch_parser_panics: bool,
+    ) {
+        // CPI log 需要 16 字节的 discriminator
+        if data.len() < 16 {
+            return;
+        }
+        let protocol = match program_id.and_then(EventDispatcher::match_protocol_by_program_id) {
+            Some(p) => p,
+            None => return,
+        };
+        let event = EventDispatcher::guard_parser_panic(
+            &protocol,
+            &data[..16],
+            catch_parser_panics,
+            || {
+                EventDispatcher::dispatch_inner_instruction(
+                    protocol.clone(),
+                    &data[..16],
+                    &data[16..],
+                    EventMetadata::default(),
+                )
+            },
+        );
+        if let Some(event) = event {
+            self.candidates.push(CpiLogCandidate {
+                outer_index,
+                inner_index,
+                event,
+                consumed: false,
+            });
+        }
+    }
+
+    /// 是否没有收集到任何 CPI log
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+
+    /// 某位置的 CPI log 是否已被其它指令事件合并，本地查找时需跳过
+    pub fn is_consumed(&self, outer_index: i64, inner_index: i64) -> bool {
+        self.candidates
+            .iter()
+            .any(|c| c.consumed && c.outer_index == outer_index && c.inner_index == inner_index)
+    }
+
+    /// 标记某位置的 CPI log 已被本地查找合并，避免被其它指令事件重复使用
+    pub fn mark_consumed(&mut self, outer_index: i64, inner_index: i64) {
+        if let Some(candidate) = self
+            .candidates
+            .iter_mut()
+            .find(|c| c.outer_index == outer_index && c.inner_index == inner_index)
+        {
+            candidate.consumed = true;
+        }
+    }
+
+    /// 为指令事件取出一条可合并的 CPI log
+    ///
+    /// 选择位于该指令之后的第一条未使用的 log；CPI log 总是在触发它的指令之后产生，
+    /// 不会取该指令之前的 log
+    pub fn take_for(
+        &mut self,
+        event: &DexEvent,
+        outer_index: i64,
+        inner_index: Option<i64>,
+    ) -> Option<DexEvent> {
+        let position = (outer_index, inner_index.unwrap_or(-1));
+        let idx = self.candidates.iter().position(|c| {
+            !c.consumed && (c.outer_index, c.inner_index) > position && can_merge(event, &c.event)
+        })?;
+        let candidate = &mut self.candidates[idx];
+        candidate.consumed = true;
+        Some(candidate.event.clone())
+    }
+}
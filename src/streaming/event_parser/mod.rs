@@ -3,4 +3,6 @@ pub mod core;
 pub mod protocols;
 
 pub use core::traits::DexEvent;
+#[cfg(feature = "rkyv")]
+pub use core::traits::ArchivedDexEvent;
 pub use protocols::types::Protocol;
\ No newline at end of file
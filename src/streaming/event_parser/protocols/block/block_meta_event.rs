@@ -1,10 +1,13 @@
 use crate::streaming::event_parser::common::{types::EventType, EventMetadata};
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::signature::Signature;
 
 /// Block元数据事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BlockMetaEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
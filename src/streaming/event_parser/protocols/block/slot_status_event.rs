@@ -0,0 +1,65 @@
+use crate::streaming::event_parser::common::{types::EventType, EventMetadata};
+use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use solana_sdk::signature::Signature;
+
+/// 槽位状态
+///
+/// 同一槽位依次经历 Processed → Confirmed → Finalized；被分叉丢弃的槽位报告为 Dead，
+/// 此前在该槽位收到的事件应当回滚
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum SlotStatus {
+    /// 槽位已被当前节点处理，仍可能被分叉丢弃
+    #[default]
+    Processed,
+    /// 槽位已获得超级多数投票确认
+    Confirmed,
+    /// 槽位已最终确认，不会再回滚
+    Finalized,
+    /// 槽位被丢弃（分叉或执行失败），其中的交易不会上链
+    Dead,
+}
+
+/// 槽位状态事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct SlotStatusEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub slot: u64,
+    /// 父槽位，服务端未提供时为 None
+    pub parent: Option<u64>,
+    pub status: SlotStatus,
+    /// Dead 状态下的错误信息
+    pub dead_error: Option<String>,
+}
+
+impl SlotStatusEvent {
+    pub fn new(
+        slot: u64,
+        parent: Option<u64>,
+        status: SlotStatus,
+        dead_error: Option<String>,
+        recv_us: i64,
+    ) -> Self {
+        let metadata = EventMetadata::new(
+            Signature::default(),
+            slot,
+            0,
+            0,
+            crate::streaming::event_parser::common::types::ProtocolType::Common,
+            EventType::SlotStatus,
+            solana_sdk::pubkey::Pubkey::default(),
+            0,
+            None,
+            recv_us,
+            None,
+            None, // recent_blockhash not applicable for slot status
+        );
+        Self { metadata, slot, parent, status, dead_error }
+    }
+}
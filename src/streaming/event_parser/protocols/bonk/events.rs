@@ -1,19 +1,25 @@
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{EventMetadata, ReserveSnapshot};
 use crate::streaming::event_parser::protocols::bonk::types::{
     CurveParams, MintParams, PoolStatus, TradeDirection, VestingParams,
 };
 use crate::streaming::event_parser::protocols::bonk::{
     AmmFeeOn, GlobalConfig, PlatformConfig, PoolState,
 };
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 /// Trade event
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BonkTradeEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_state: Pubkey,
     pub total_base_sell: u64,
     pub virtual_base: u64,
@@ -38,37 +44,59 @@ pub struct BonkTradeEvent {
     #[borsh(skip)]
     pub share_fee_rate: u64,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_base_token: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_quote_token: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_token_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_token_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_token_program: Pubkey,
     #[borsh(skip)]
     pub is_dev_create_token_trade: bool,
     #[borsh(skip)]
     pub is_bot: bool,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_associated_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator_associated_account: Pubkey,
+    /// Pool reserves before the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pre_reserves: Option<ReserveSnapshot>,
+    /// Pool reserves after the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub post_reserves: Option<ReserveSnapshot>,
 }
 
 pub const BONK_TRADE_EVENT_LOG_SIZE: usize = 32 + 8 * 13 + 1 + 1 + 1;
@@ -81,30 +109,42 @@ pub fn bonk_trade_event_log_decode(data: &[u8]) -> Option<BonkTradeEvent> {
 }
 
 /// Create pool event
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BonkPoolCreateEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_state: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub config: Pubkey,
     pub base_mint_param: MintParams,
     pub curve_param: CurveParams,
     pub vesting_param: VestingParams,
     pub amm_fee_on: Option<AmmFeeOn>,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_config: Pubkey,
 }
 
@@ -118,7 +158,9 @@ pub fn bonk_pool_create_event_log_decode(data: &[u8]) -> Option<BonkPoolCreateEv
 }
 
 /// Create pool event
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BonkMigrateToAmmEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -126,138 +168,213 @@ pub struct BonkMigrateToAmmEvent {
     pub quote_lot_size: u64,
     pub market_vault_signer_nonce: u8,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub openbook_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub market: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub request_queue: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_queue: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub bids: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub asks: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub market_vault_signer: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub market_base_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub market_quote_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_pool: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_open_orders: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_lp_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_base_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_quote_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_target_orders: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_create_fee_destination: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_state: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_lp_token: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub spl_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub rent_program: Pubkey,
 }
 
 // Migrate to CP Swap event
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BonkMigrateToCpswapEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_config: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_lp_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_base_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_quote_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_config: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_create_pool_fee: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_observation: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub lock_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub lock_authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub lock_lp_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_state: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_config: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_lp_token: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_token_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_token_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_token_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub rent_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub metadata_program: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
 /// 池状态
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BonkPoolStateAccountEvent {
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub pool_state: PoolState,
 }
 
 /// 全局配置
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BonkGlobalConfigAccountEvent {
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub global_config: GlobalConfig,
 }
 
 /// 平台配置
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BonkPlatformConfigAccountEvent {
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub platform_config: PlatformConfig,
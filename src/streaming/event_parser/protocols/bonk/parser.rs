@@ -153,21 +153,21 @@ fn parse_buy_exact_in_instruction(
         amount_in,
         minimum_amount_out,
         share_fee_rate,
-        payer: accounts[0],
-        global_config: accounts[2],
-        platform_config: accounts[3],
-        pool_state: accounts[4],
-        user_base_token: accounts[5],
-        user_quote_token: accounts[6],
-        base_vault: accounts[7],
-        quote_vault: accounts[8],
-        base_token_mint: accounts[9],
-        quote_token_mint: accounts[10],
-        base_token_program: accounts[11],
-        quote_token_program: accounts[12],
-        system_program: accounts[15],
-        platform_associated_account: accounts[16],
-        creator_associated_account: accounts[17],
+        payer: acct!(accounts, 0),
+        global_config: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        pool_state: acct!(accounts, 4),
+        user_base_token: acct!(accounts, 5),
+        user_quote_token: acct!(accounts, 6),
+        base_vault: acct!(accounts, 7),
+        quote_vault: acct!(accounts, 8),
+        base_token_mint: acct!(accounts, 9),
+        quote_token_mint: acct!(accounts, 10),
+        base_token_program: acct!(accounts, 11),
+        quote_token_program: acct!(accounts, 12),
+        system_program: acct!(accounts, 15),
+        platform_associated_account: acct!(accounts, 16),
+        creator_associated_account: acct!(accounts, 17),
         trade_direction: TradeDirection::Buy,
         ..Default::default()
     }))
@@ -193,21 +193,21 @@ fn parse_buy_exact_out_instruction(
         amount_out,
         maximum_amount_in,
         share_fee_rate,
-        payer: accounts[0],
-        global_config: accounts[2],
-        platform_config: accounts[3],
-        pool_state: accounts[4],
-        user_base_token: accounts[5],
-        user_quote_token: accounts[6],
-        base_vault: accounts[7],
-        quote_vault: accounts[8],
-        base_token_mint: accounts[9],
-        quote_token_mint: accounts[10],
-        base_token_program: accounts[11],
-        quote_token_program: accounts[12],
-        system_program: accounts[15],
-        platform_associated_account: accounts[16],
-        creator_associated_account: accounts[17],
+        payer: acct!(accounts, 0),
+        global_config: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        pool_state: acct!(accounts, 4),
+        user_base_token: acct!(accounts, 5),
+        user_quote_token: acct!(accounts, 6),
+        base_vault: acct!(accounts, 7),
+        quote_vault: acct!(accounts, 8),
+        base_token_mint: acct!(accounts, 9),
+        quote_token_mint: acct!(accounts, 10),
+        base_token_program: acct!(accounts, 11),
+        quote_token_program: acct!(accounts, 12),
+        system_program: acct!(accounts, 15),
+        platform_associated_account: acct!(accounts, 16),
+        creator_associated_account: acct!(accounts, 17),
         trade_direction: TradeDirection::Buy,
         ..Default::default()
     }))
@@ -233,21 +233,21 @@ fn parse_sell_exact_in_instruction(
         amount_in,
         minimum_amount_out,
         share_fee_rate,
-        payer: accounts[0],
-        global_config: accounts[2],
-        platform_config: accounts[3],
-        pool_state: accounts[4],
-        user_base_token: accounts[5],
-        user_quote_token: accounts[6],
-        base_vault: accounts[7],
-        quote_vault: accounts[8],
-        base_token_mint: accounts[9],
-        quote_token_mint: accounts[10],
-        base_token_program: accounts[11],
-        quote_token_program: accounts[12],
-        system_program: accounts[15],
-        platform_associated_account: accounts[16],
-        creator_associated_account: accounts[17],
+        payer: acct!(accounts, 0),
+        global_config: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        pool_state: acct!(accounts, 4),
+        user_base_token: acct!(accounts, 5),
+        user_quote_token: acct!(accounts, 6),
+        base_vault: acct!(accounts, 7),
+        quote_vault: acct!(accounts, 8),
+        base_token_mint: acct!(accounts, 9),
+        quote_token_mint: acct!(accounts, 10),
+        base_token_program: acct!(accounts, 11),
+        quote_token_program: acct!(accounts, 12),
+        system_program: acct!(accounts, 15),
+        platform_associated_account: acct!(accounts, 16),
+        creator_associated_account: acct!(accounts, 17),
         trade_direction: TradeDirection::Sell,
         ..Default::default()
     }))
@@ -273,21 +273,21 @@ fn parse_sell_exact_out_instruction(
         amount_out,
         maximum_amount_in,
         share_fee_rate,
-        payer: accounts[0],
-        global_config: accounts[2],
-        platform_config: accounts[3],
-        pool_state: accounts[4],
-        user_base_token: accounts[5],
-        user_quote_token: accounts[6],
-        base_vault: accounts[7],
-        quote_vault: accounts[8],
-        base_token_mint: accounts[9],
-        quote_token_mint: accounts[10],
-        base_token_program: accounts[11],
-        quote_token_program: accounts[12],
-        system_program: accounts[15],
-        platform_associated_account: accounts[16],
-        creator_associated_account: accounts[17],
+        payer: acct!(accounts, 0),
+        global_config: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        pool_state: acct!(accounts, 4),
+        user_base_token: acct!(accounts, 5),
+        user_quote_token: acct!(accounts, 6),
+        base_vault: acct!(accounts, 7),
+        quote_vault: acct!(accounts, 8),
+        base_token_mint: acct!(accounts, 9),
+        quote_token_mint: acct!(accounts, 10),
+        base_token_program: acct!(accounts, 11),
+        quote_token_program: acct!(accounts, 12),
+        system_program: acct!(accounts, 15),
+        platform_associated_account: acct!(accounts, 16),
+        creator_associated_account: acct!(accounts, 17),
         trade_direction: TradeDirection::Sell,
         ..Default::default()
     }))
@@ -312,15 +312,15 @@ fn parse_initialize_instruction(
 
     Some(DexEvent::BonkPoolCreateEvent(BonkPoolCreateEvent {
         metadata,
-        payer: accounts[0],
-        creator: accounts[1],
-        global_config: accounts[2],
-        platform_config: accounts[3],
-        pool_state: accounts[5],
-        base_mint: accounts[6],
-        quote_mint: accounts[7],
-        base_vault: accounts[8],
-        quote_vault: accounts[9],
+        payer: acct!(accounts, 0),
+        creator: acct!(accounts, 1),
+        global_config: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        pool_state: acct!(accounts, 5),
+        base_mint: acct!(accounts, 6),
+        quote_mint: acct!(accounts, 7),
+        base_vault: acct!(accounts, 8),
+        quote_vault: acct!(accounts, 9),
         base_mint_param,
         curve_param,
         vesting_param,
@@ -348,15 +348,15 @@ fn parse_initialize_v2_instruction(
 
     Some(DexEvent::BonkPoolCreateEvent(BonkPoolCreateEvent {
         metadata,
-        payer: accounts[0],
-        creator: accounts[1],
-        global_config: accounts[2],
-        platform_config: accounts[3],
-        pool_state: accounts[5],
-        base_mint: accounts[6],
-        quote_mint: accounts[7],
-        base_vault: accounts[8],
-        quote_vault: accounts[9],
+        payer: acct!(accounts, 0),
+        creator: acct!(accounts, 1),
+        global_config: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        pool_state: acct!(accounts, 5),
+        base_mint: acct!(accounts, 6),
+        quote_mint: acct!(accounts, 7),
+        base_vault: acct!(accounts, 8),
+        quote_vault: acct!(accounts, 9),
         base_mint_param,
         curve_param,
         vesting_param,
@@ -389,15 +389,15 @@ fn parse_initialize_with_token_2022_instruction(
 
     Some(DexEvent::BonkPoolCreateEvent(BonkPoolCreateEvent {
         metadata,
-        payer: accounts[0],
-        creator: accounts[1],
-        global_config: accounts[2],
-        platform_config: accounts[3],
-        pool_state: accounts[5],
-        base_mint: accounts[6],
-        quote_mint: accounts[7],
-        base_vault: accounts[8],
-        quote_vault: accounts[9],
+        payer: acct!(accounts, 0),
+        creator: acct!(accounts, 1),
+        global_config: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        pool_state: acct!(accounts, 5),
+        base_mint: acct!(accounts, 6),
+        quote_mint: acct!(accounts, 7),
+        base_vault: acct!(accounts, 8),
+        quote_vault: acct!(accounts, 9),
         base_mint_param,
         curve_param,
         vesting_param,
@@ -536,38 +536,38 @@ fn parse_migrate_to_amm_instruction(
         base_lot_size,
         quote_lot_size,
         market_vault_signer_nonce,
-        payer: accounts[0],
-        base_mint: accounts[1],
-        quote_mint: accounts[2],
-        openbook_program: accounts[3],
-        market: accounts[4],
-        request_queue: accounts[5],
-        event_queue: accounts[6],
-        bids: accounts[7],
-        asks: accounts[8],
-        market_vault_signer: accounts[9],
-        market_base_vault: accounts[10],
-        market_quote_vault: accounts[11],
-        amm_program: accounts[12],
-        amm_pool: accounts[13],
-        amm_authority: accounts[14],
-        amm_open_orders: accounts[15],
-        amm_lp_mint: accounts[16],
-        amm_base_vault: accounts[17],
-        amm_quote_vault: accounts[18],
-        amm_target_orders: accounts[19],
-        amm_config: accounts[20],
-        amm_create_fee_destination: accounts[21],
-        authority: accounts[22],
-        pool_state: accounts[23],
-        global_config: accounts[24],
-        base_vault: accounts[25],
-        quote_vault: accounts[26],
-        pool_lp_token: accounts[27],
-        spl_token_program: accounts[28],
-        associated_token_program: accounts[29],
-        system_program: accounts[30],
-        rent_program: accounts[31],
+        payer: acct!(accounts, 0),
+        base_mint: acct!(accounts, 1),
+        quote_mint: acct!(accounts, 2),
+        openbook_program: acct!(accounts, 3),
+        market: acct!(accounts, 4),
+        request_queue: acct!(accounts, 5),
+        event_queue: acct!(accounts, 6),
+        bids: acct!(accounts, 7),
+        asks: acct!(accounts, 8),
+        market_vault_signer: acct!(accounts, 9),
+        market_base_vault: acct!(accounts, 10),
+        market_quote_vault: acct!(accounts, 11),
+        amm_program: acct!(accounts, 12),
+        amm_pool: acct!(accounts, 13),
+        amm_authority: acct!(accounts, 14),
+        amm_open_orders: acct!(accounts, 15),
+        amm_lp_mint: acct!(accounts, 16),
+        amm_base_vault: acct!(accounts, 17),
+        amm_quote_vault: acct!(accounts, 18),
+        amm_target_orders: acct!(accounts, 19),
+        amm_config: acct!(accounts, 20),
+        amm_create_fee_destination: acct!(accounts, 21),
+        authority: acct!(accounts, 22),
+        pool_state: acct!(accounts, 23),
+        global_config: acct!(accounts, 24),
+        base_vault: acct!(accounts, 25),
+        quote_vault: acct!(accounts, 26),
+        pool_lp_token: acct!(accounts, 27),
+        spl_token_program: acct!(accounts, 28),
+        associated_token_program: acct!(accounts, 29),
+        system_program: acct!(accounts, 30),
+        rent_program: acct!(accounts, 31),
         ..Default::default()
     }))
 }
@@ -582,35 +582,35 @@ fn parse_migrate_to_cpswap_instruction(
 
     Some(DexEvent::BonkMigrateToCpswapEvent(BonkMigrateToCpswapEvent {
         metadata,
-        payer: accounts[0],
-        base_mint: accounts[1],
-        quote_mint: accounts[2],
-        platform_config: accounts[3],
-        cpswap_program: accounts[4],
-        cpswap_pool: accounts[5],
-        cpswap_authority: accounts[6],
-        cpswap_lp_mint: accounts[7],
-        cpswap_base_vault: accounts[8],
-        cpswap_quote_vault: accounts[9],
-        cpswap_config: accounts[10],
-        cpswap_create_pool_fee: accounts[11],
-        cpswap_observation: accounts[12],
-        lock_program: accounts[13],
-        lock_authority: accounts[14],
-        lock_lp_vault: accounts[15],
-        authority: accounts[16],
-        pool_state: accounts[17],
-        global_config: accounts[18],
-        base_vault: accounts[19],
-        quote_vault: accounts[20],
-        pool_lp_token: accounts[21],
-        base_token_program: accounts[22],
-        quote_token_program: accounts[23],
-        associated_token_program: accounts[24],
-        system_program: accounts[25],
-        rent_program: accounts[26],
-        metadata_program: accounts[27],
-        remaining_accounts: accounts[28..].to_vec(),
+        payer: acct!(accounts, 0),
+        base_mint: acct!(accounts, 1),
+        quote_mint: acct!(accounts, 2),
+        platform_config: acct!(accounts, 3),
+        cpswap_program: acct!(accounts, 4),
+        cpswap_pool: acct!(accounts, 5),
+        cpswap_authority: acct!(accounts, 6),
+        cpswap_lp_mint: acct!(accounts, 7),
+        cpswap_base_vault: acct!(accounts, 8),
+        cpswap_quote_vault: acct!(accounts, 9),
+        cpswap_config: acct!(accounts, 10),
+        cpswap_create_pool_fee: acct!(accounts, 11),
+        cpswap_observation: acct!(accounts, 12),
+        lock_program: acct!(accounts, 13),
+        lock_authority: acct!(accounts, 14),
+        lock_lp_vault: acct!(accounts, 15),
+        authority: acct!(accounts, 16),
+        pool_state: acct!(accounts, 17),
+        global_config: acct!(accounts, 18),
+        base_vault: acct!(accounts, 19),
+        quote_vault: acct!(accounts, 20),
+        pool_lp_token: acct!(accounts, 21),
+        base_token_program: acct!(accounts, 22),
+        quote_token_program: acct!(accounts, 23),
+        associated_token_program: acct!(accounts, 24),
+        system_program: acct!(accounts, 25),
+        rent_program: acct!(accounts, 26),
+        metadata_program: acct!(accounts, 27),
+        remaining_accounts: accounts.get(28..)?.to_vec(),
         ..Default::default()
     }))
 }
@@ -1,4 +1,5 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
@@ -12,15 +13,21 @@ use crate::streaming::{
     },
     grpc::AccountPretty,
 };
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum TradeDirection {
     #[default]
     Buy,
     Sell,
 }
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum PoolStatus {
     #[default]
     Fund,
@@ -28,7 +35,9 @@ pub enum PoolStatus {
     Trade,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct MintParams {
     pub decimals: u8,
     pub name: String,
@@ -36,14 +45,18 @@ pub struct MintParams {
     pub uri: String,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct VestingParams {
     pub total_locked_amount: u64,
     pub cliff_period: u64,
     pub unlock_period: u64,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum AmmFeeOn {
     QuoteToken,
     BothToken,
@@ -55,7 +68,9 @@ impl Default for AmmFeeOn {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 #[borsh(use_discriminant = true)]
 #[repr(u8)]
 pub enum AmmCreatorFeeOn {
@@ -69,7 +84,9 @@ impl Default for AmmCreatorFeeOn {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct ConstantCurve {
     pub supply: u64,
     pub total_base_sell: u64,
@@ -77,21 +94,27 @@ pub struct ConstantCurve {
     pub migrate_type: u8,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct FixedCurve {
     pub supply: u64,
     pub total_quote_fund_raising: u64,
     pub migrate_type: u8,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct LinearCurve {
     pub supply: u64,
     pub total_quote_fund_raising: u64,
     pub migrate_type: u8,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum CurveParams {
     Constant { data: ConstantCurve },
     Fixed { data: FixedCurve },
@@ -104,7 +127,9 @@ impl Default for CurveParams {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct VestingSchedule {
     pub total_locked_amount: u64,
     pub cliff_period: u64,
@@ -113,7 +138,9 @@ pub struct VestingSchedule {
     pub allocated_share_amount: u64,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PoolState {
     pub epoch: u64,
     pub auth_bump: u8,
@@ -132,17 +159,24 @@ pub struct PoolState {
     pub platform_fee: u64,
     pub migrate_fee: u64,
     pub vesting_schedule: VestingSchedule,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_config: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_config: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
     pub token_program_flag: u8,
     pub amm_creator_fee_on: AmmCreatorFeeOn,
     pub platform_vesting_share: u64,
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub padding: [u8; 54],
 }
 
@@ -210,7 +244,9 @@ pub fn pool_state_parser(account: &AccountPretty, mut metadata: EventMetadata) -
         None
     }
 }
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct GlobalConfig {
     pub epoch: u64,
     pub curve_type: u8,
@@ -223,10 +259,15 @@ pub struct GlobalConfig {
     pub min_base_sell_rate: u64,
     pub min_base_migrate_rate: u64,
     pub min_quote_fund_raising: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub protocol_fee_owner: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub migrate_fee_owner: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub migrate_to_amm_wallet: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub migrate_to_cpswap_wallet: Pubkey,
     pub padding: [u64; 16],
 }
@@ -264,7 +305,9 @@ pub fn global_config_parser(
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BondingCurveParam {
     pub migrate_type: u8,
     pub migrate_cpmm_fee_on: u8,
@@ -276,13 +319,16 @@ pub struct BondingCurveParam {
     pub unlock_period: u64,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PlatformCurveParam {
     pub epoch: u64,
     pub index: u8,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_config: Pubkey,
     pub bonding_curve_param: BondingCurveParam,
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub padding: [u64; 50],
 }
 
@@ -298,28 +344,36 @@ impl Default for PlatformCurveParam {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PlatformConfig {
     pub epoch: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_fee_wallet: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_nft_wallet: Pubkey,
     pub platform_scale: u64,
     pub creator_scale: u64,
     pub burn_scale: u64,
     pub fee_rate: u64,
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub name: [u8; 64],
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub web: [u8; 256],
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub img: [u8; 256],
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub cpswap_config: Pubkey,
     pub creator_fee_rate: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub transfer_fee_extension_auth: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_vesting_wallet: Pubkey,
     pub platform_vesting_scale: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub platform_cp_creator: Pubkey,
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub padding: [u8; 108],
     pub curve_params: Vec<PlatformCurveParam>,
 }
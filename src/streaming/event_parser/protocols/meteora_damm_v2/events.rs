@@ -1,11 +1,16 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::common::EventMetadata;
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 
 /// Base fee parameters
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BaseFeeParameters {
     pub cliff_fee_numerator: u64,
     pub first_factor: u16,
@@ -15,7 +20,9 @@ pub struct BaseFeeParameters {
 }
 
 /// Dynamic fee parameters
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct DynamicFeeParameters {
     pub bin_step: u16,
     pub bin_step_u128: u128,
@@ -27,7 +34,9 @@ pub struct DynamicFeeParameters {
 }
 
 /// Pool fee parameters
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PoolFeeParameters {
     pub base_fee: BaseFeeParameters,
     pub padding: [u8; 3],
@@ -35,12 +44,15 @@ pub struct PoolFeeParameters {
 }
 
 /// Meteora DAMM v2 Swap Event (对应 swap 指令)
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct MeteoraDammV2SwapEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
     pub trade_direction: u8, // 0 or 1
     pub collect_fee_mode: u8,
@@ -74,40 +86,56 @@ pub struct MeteoraDammV2SwapEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub input_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub output_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
     pub referral_token_account: Option<Pubkey>,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
 }
 
 /// Meteora DAMM v2 Swap2 Event (对应 swap2 指令)
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct MeteoraDammV2Swap2Event {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
     pub trade_direction: u8, // 0 or 1
     pub collect_fee_mode: u8,
@@ -141,47 +169,69 @@ pub struct MeteoraDammV2Swap2Event {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub input_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub output_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
     pub referral_token_account: Option<Pubkey>,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub sysvar: Pubkey,
 }
 
 /// Meteora DAMM v2 Initialize Pool Event (对应 initialize_pool 指令)
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct MeteoraDammV2InitializePoolEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub alpha_vault: Pubkey,
 
     // Pool fees
@@ -207,47 +257,69 @@ pub struct MeteoraDammV2InitializePoolEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position_nft_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position_nft_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer_token_a: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer_token_b: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
 /// Meteora DAMM v2 Initialize Customizable Pool Event (对应 initialize_customizable_pool 指令)
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct MeteoraDammV2InitializeCustomizablePoolEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub alpha_vault: Pubkey,
 
     // Pool fees
@@ -273,49 +345,72 @@ pub struct MeteoraDammV2InitializeCustomizablePoolEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position_nft_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position_nft_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer_token_a: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer_token_b: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_2022_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
     pub remaining_accounts: Vec<Pubkey>,
 }
 
 /// Meteora DAMM v2 Initialize Pool With Dynamic Config Event (对应 initialize_pool_with_dynamic_config 指令)
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct MeteoraDammV2InitializePoolWithDynamicConfigEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
     // 来自 CPI Log Event 的数据
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub alpha_vault: Pubkey,
 
     // Pool fees
@@ -341,39 +436,182 @@ pub struct MeteoraDammV2InitializePoolWithDynamicConfigEvent {
 
     // 来自 Input Accounts 的数据
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position_nft_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position_nft_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_creator_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub position: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer_token_a: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub payer_token_b: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_a_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_b_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_2022_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub config: Pubkey,
 }
 
+/// Meteora DAMM v2 Add Liquidity Event (对应 add_liquidity 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct MeteoraDammV2AddLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub position: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub owner: Pubkey,
+
+    // Add liquidity parameters
+    pub liquidity_delta: u128, // 增加的流动性
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+
+    // Add liquidity result
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub total_amount_a: u64,
+    pub total_amount_b: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_program: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Remove Liquidity Event (对应 remove_liquidity / remove_all_liquidity 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct MeteoraDammV2RemoveLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub position: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub owner: Pubkey,
+
+    // Remove liquidity parameters
+    pub liquidity_delta: u128, // 移除的流动性，remove_all_liquidity 时由 CPI log 提供
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+
+    // Remove liquidity result
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub token_b_program: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub program: Pubkey,
+}
+
 /// Event discriminators
 pub mod discriminators {
     // Instruction discriminators
@@ -385,6 +623,10 @@ pub mod discriminators {
     pub const INITIALIZE_POOL_IX: &[u8] = &[0x5f, 0xb4, 0x0a, 0xac, 0x54, 0xae, 0xe8, 0x28]; // initialize_pool
     pub const INITIALIZE_POOL_WITH_DYNAMIC_CONFIG_IX: &[u8] =
         &[0x95, 0x52, 0x48, 0xc5, 0xfd, 0xfc, 0x44, 0x0f]; // initialize_pool_with_dynamic_config
+    pub const ADD_LIQUIDITY_IX: &[u8] = &[0xb5, 0x9d, 0x59, 0x43, 0x8f, 0xb6, 0x34, 0x48]; // add_liquidity
+    pub const REMOVE_LIQUIDITY_IX: &[u8] = &[0x50, 0x55, 0xd1, 0x48, 0x18, 0xce, 0xb1, 0x6c]; // remove_liquidity
+    pub const REMOVE_ALL_LIQUIDITY_IX: &[u8] =
+        &[0x0a, 0x33, 0x3d, 0x23, 0x70, 0x69, 0x18, 0x55]; // remove_all_liquidity
 
     // Event discriminators (CPI Log Event)
     // e445a52e51cb9a1d 是 Meteora 的事件前缀
@@ -397,6 +639,14 @@ pub mod discriminators {
         0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d, 0xe4, 0x32, 0xf6, 0x55, 0xcb, 0x42, 0x86,
         0x25,
     ]; // initialize pool event
+    pub const ADD_LIQUIDITY_EVENT: &[u8] = &[
+        0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d, 0xaf, 0xf2, 0x08, 0x9d, 0x1e, 0xf7, 0xb9,
+        0xa9,
+    ]; // add liquidity event
+    pub const REMOVE_LIQUIDITY_EVENT: &[u8] = &[
+        0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d, 0x57, 0x2e, 0x58, 0x62, 0xaf, 0x60, 0x22,
+        0x5b,
+    ]; // remove liquidity event
 }
 
 /// Decode swap event from CPI log
@@ -415,3 +665,33 @@ pub fn meteora_damm_v2_initialize_pool_event_decode(
 ) -> Option<MeteoraDammV2InitializePoolEvent> {
     borsh::from_slice::<MeteoraDammV2InitializePoolEvent>(&data).ok()
 }
+
+/// Decode add liquidity event from CPI log
+/// Note: discriminator (16 bytes) is already removed by the caller
+pub const METEORA_DAMM_V2_ADD_LIQUIDITY_EVENT_LOG_SIZE: usize = 160;
+pub fn meteora_damm_v2_add_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2AddLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V2_ADD_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2AddLiquidityEvent>(
+        &data[..METEORA_DAMM_V2_ADD_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode remove liquidity event from CPI log
+/// Note: discriminator (16 bytes) is already removed by the caller
+pub const METEORA_DAMM_V2_REMOVE_LIQUIDITY_EVENT_LOG_SIZE: usize = 144;
+pub fn meteora_damm_v2_remove_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2RemoveLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V2_REMOVE_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2RemoveLiquidityEvent>(
+        &data[..METEORA_DAMM_V2_REMOVE_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
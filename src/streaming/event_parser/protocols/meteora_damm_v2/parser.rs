@@ -1,10 +1,12 @@
 use crate::streaming::event_parser::{
-    common::{EventMetadata, EventType},
+    common::{utils::acct, EventMetadata, EventType},
     protocols::meteora_damm_v2::{
-        discriminators, meteora_damm_v2_initialize_pool_event_decode,
-        meteora_damm_v2_swap_event_decode, MeteoraDammV2InitializeCustomizablePoolEvent,
+        discriminators, meteora_damm_v2_add_liquidity_event_decode,
+        meteora_damm_v2_initialize_pool_event_decode,
+        meteora_damm_v2_remove_liquidity_event_decode, meteora_damm_v2_swap_event_decode,
+        MeteoraDammV2AddLiquidityEvent, MeteoraDammV2InitializeCustomizablePoolEvent,
         MeteoraDammV2InitializePoolEvent, MeteoraDammV2InitializePoolWithDynamicConfigEvent,
-        MeteoraDammV2Swap2Event, MeteoraDammV2SwapEvent,
+        MeteoraDammV2RemoveLiquidityEvent, MeteoraDammV2Swap2Event, MeteoraDammV2SwapEvent,
     },
     DexEvent,
 };
@@ -35,6 +37,15 @@ pub fn parse_meteora_damm_v2_instruction_data(
         discriminators::INITIALIZE_POOL_WITH_DYNAMIC_CONFIG_IX => {
             parse_initialize_pool_with_dynamic_config_instruction(data, accounts, metadata)
         }
+        discriminators::ADD_LIQUIDITY_IX => {
+            parse_add_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_IX => {
+            parse_remove_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::REMOVE_ALL_LIQUIDITY_IX => {
+            parse_remove_all_liquidity_instruction(data, accounts, metadata)
+        }
         _ => None,
     }
 }
@@ -52,6 +63,12 @@ pub fn parse_meteora_damm_v2_inner_instruction_data(
         discriminators::INITIALIZE_POOL_EVENT => {
             parse_initialize_pool_inner_instruction(data, metadata)
         }
+        discriminators::ADD_LIQUIDITY_EVENT => {
+            parse_add_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_EVENT => {
+            parse_remove_liquidity_inner_instruction(data, metadata)
+        }
         _ => None,
     }
 }
@@ -74,20 +91,20 @@ fn parse_swap_instruction(
 
     Some(DexEvent::MeteoraDammV2SwapEvent(MeteoraDammV2SwapEvent {
         metadata,
-        pool_authority: accounts[0],
-        pool: accounts[1],
-        input_token_account: accounts[2],
-        output_token_account: accounts[3],
-        token_a_vault: accounts[4],
-        token_b_vault: accounts[5],
-        token_a_mint: accounts[6],
-        token_b_mint: accounts[7],
-        payer: accounts[8],
-        token_a_program: accounts[9],
-        token_b_program: accounts[10],
-        referral_token_account: Some(accounts[11]),
-        event_authority: accounts[12],
-        program: accounts[13],
+        pool_authority: acct!(accounts, 0),
+        pool: acct!(accounts, 1),
+        input_token_account: acct!(accounts, 2),
+        output_token_account: acct!(accounts, 3),
+        token_a_vault: acct!(accounts, 4),
+        token_b_vault: acct!(accounts, 5),
+        token_a_mint: acct!(accounts, 6),
+        token_b_mint: acct!(accounts, 7),
+        payer: acct!(accounts, 8),
+        token_a_program: acct!(accounts, 9),
+        token_b_program: acct!(accounts, 10),
+        referral_token_account: Some(acct!(accounts, 11)),
+        event_authority: acct!(accounts, 12),
+        program: acct!(accounts, 13),
         amount_0: amount_in,
         amount_1: minimum_amount_out,
         ..Default::default()
@@ -116,25 +133,25 @@ fn parse_swap2_instruction(
 
     Some(DexEvent::MeteoraDammV2Swap2Event(MeteoraDammV2Swap2Event {
         metadata,
-        pool_authority: accounts[0],
-        pool: accounts[1],
-        input_token_account: accounts[2],
-        output_token_account: accounts[3],
-        token_a_vault: accounts[4],
-        token_b_vault: accounts[5],
-        token_a_mint: accounts[6],
-        token_b_mint: accounts[7],
-        payer: accounts[8],
-        token_a_program: accounts[9],
-        token_b_program: accounts[10],
+        pool_authority: acct!(accounts, 0),
+        pool: acct!(accounts, 1),
+        input_token_account: acct!(accounts, 2),
+        output_token_account: acct!(accounts, 3),
+        token_a_vault: acct!(accounts, 4),
+        token_b_vault: acct!(accounts, 5),
+        token_a_mint: acct!(accounts, 6),
+        token_b_mint: acct!(accounts, 7),
+        payer: acct!(accounts, 8),
+        token_a_program: acct!(accounts, 9),
+        token_b_program: acct!(accounts, 10),
         referral_token_account: if has_referral && accounts.len() > 11 {
-            Some(accounts[11])
+            Some(acct!(accounts, 11))
         } else {
             None
         },
-        event_authority: accounts[if has_referral { 12 } else { 11 }],
-        program: accounts[if has_referral { 13 } else { 12 }],
-        sysvar: accounts[if has_referral { 14 } else { 13 }],
+        event_authority: acct!(accounts, if has_referral { 12 } else { 11 }),
+        program: acct!(accounts, if has_referral { 13 } else { 12 }),
+        sysvar: acct!(accounts, if has_referral { 14 } else { 13 }),
         amount_0,
         amount_1,
         swap_mode,
@@ -182,25 +199,25 @@ fn parse_initialize_pool_instruction(
 
     Some(DexEvent::MeteoraDammV2InitializePoolEvent(MeteoraDammV2InitializePoolEvent {
         metadata,
-        creator: accounts[0],
-        position_nft_mint: accounts[1],
-        position_nft_account: accounts[2],
-        payer: accounts[3],
-        config: accounts[4],
-        pool_authority: accounts[5],
-        pool: accounts[6],
-        position: accounts[7],
-        token_a_mint: accounts[8],
-        token_b_mint: accounts[9],
-        token_a_vault: accounts[10],
-        token_b_vault: accounts[11],
-        payer_token_a: accounts[12],
-        payer_token_b: accounts[13],
-        token_a_program: accounts[14],
-        token_b_program: accounts[15],
-        event_authority: accounts[18],
-        program: accounts[19],
-        remaining_accounts: accounts[20..].to_vec(),
+        creator: acct!(accounts, 0),
+        position_nft_mint: acct!(accounts, 1),
+        position_nft_account: acct!(accounts, 2),
+        payer: acct!(accounts, 3),
+        config: acct!(accounts, 4),
+        pool_authority: acct!(accounts, 5),
+        pool: acct!(accounts, 6),
+        position: acct!(accounts, 7),
+        token_a_mint: acct!(accounts, 8),
+        token_b_mint: acct!(accounts, 9),
+        token_a_vault: acct!(accounts, 10),
+        token_b_vault: acct!(accounts, 11),
+        payer_token_a: acct!(accounts, 12),
+        payer_token_b: acct!(accounts, 13),
+        token_a_program: acct!(accounts, 14),
+        token_b_program: acct!(accounts, 15),
+        event_authority: acct!(accounts, 18),
+        program: acct!(accounts, 19),
+        remaining_accounts: accounts.get(20..)?.to_vec(),
         liquidity,
         sqrt_price,
         ..Default::default()
@@ -282,26 +299,26 @@ fn parse_initialize_customizable_pool_instruction(
     Some(DexEvent::MeteoraDammV2InitializeCustomizablePoolEvent(
         MeteoraDammV2InitializeCustomizablePoolEvent {
             metadata,
-            creator: accounts[0],
-            position_nft_mint: accounts[1],
-            position_nft_account: accounts[2],
-            payer: accounts[3],
-            pool_authority: accounts[4],
-            pool: accounts[5],
-            position: accounts[6],
-            token_a_mint: accounts[7],
-            token_b_mint: accounts[8],
-            token_a_vault: accounts[9],
-            token_b_vault: accounts[10],
-            payer_token_a: accounts[11],
-            payer_token_b: accounts[12],
-            token_a_program: accounts[13],
-            token_b_program: accounts[14],
-            token_2022_program: accounts[15],
-            system_program: accounts[16],
-            event_authority: accounts[17],
-            program: accounts[18],
-            remaining_accounts: accounts[19..].to_vec(),
+            creator: acct!(accounts, 0),
+            position_nft_mint: acct!(accounts, 1),
+            position_nft_account: acct!(accounts, 2),
+            payer: acct!(accounts, 3),
+            pool_authority: acct!(accounts, 4),
+            pool: acct!(accounts, 5),
+            position: acct!(accounts, 6),
+            token_a_mint: acct!(accounts, 7),
+            token_b_mint: acct!(accounts, 8),
+            token_a_vault: acct!(accounts, 9),
+            token_b_vault: acct!(accounts, 10),
+            payer_token_a: acct!(accounts, 11),
+            payer_token_b: acct!(accounts, 12),
+            token_a_program: acct!(accounts, 13),
+            token_b_program: acct!(accounts, 14),
+            token_2022_program: acct!(accounts, 15),
+            system_program: acct!(accounts, 16),
+            event_authority: acct!(accounts, 17),
+            program: acct!(accounts, 18),
+            remaining_accounts: accounts.get(19..)?.to_vec(),
             pool_fees,
             sqrt_min_price,
             sqrt_max_price,
@@ -385,27 +402,27 @@ fn parse_initialize_pool_with_dynamic_config_instruction(
     Some(DexEvent::MeteoraDammV2InitializePoolWithDynamicConfigEvent(
         MeteoraDammV2InitializePoolWithDynamicConfigEvent {
             metadata,
-            creator: accounts[0],
-            position_nft_mint: accounts[1],
-            position_nft_account: accounts[2],
-            payer: accounts[3],
-            pool_creator_authority: accounts[4],
-            pool_authority: accounts[6],
-            pool: accounts[7],
-            position: accounts[8],
-            token_a_mint: accounts[9],
-            token_b_mint: accounts[10],
-            token_a_vault: accounts[11],
-            token_b_vault: accounts[12],
-            payer_token_a: accounts[13],
-            payer_token_b: accounts[14],
-            token_a_program: accounts[15],
-            token_b_program: accounts[16],
-            token_2022_program: accounts[17],
-            system_program: accounts[18],
-            event_authority: accounts[19],
-            program: accounts[20],
-            config: accounts[5],
+            creator: acct!(accounts, 0),
+            position_nft_mint: acct!(accounts, 1),
+            position_nft_account: acct!(accounts, 2),
+            payer: acct!(accounts, 3),
+            pool_creator_authority: acct!(accounts, 4),
+            pool_authority: acct!(accounts, 6),
+            pool: acct!(accounts, 7),
+            position: acct!(accounts, 8),
+            token_a_mint: acct!(accounts, 9),
+            token_b_mint: acct!(accounts, 10),
+            token_a_vault: acct!(accounts, 11),
+            token_b_vault: acct!(accounts, 12),
+            payer_token_a: acct!(accounts, 13),
+            payer_token_b: acct!(accounts, 14),
+            token_a_program: acct!(accounts, 15),
+            token_b_program: acct!(accounts, 16),
+            token_2022_program: acct!(accounts, 17),
+            system_program: acct!(accounts, 18),
+            event_authority: acct!(accounts, 19),
+            program: acct!(accounts, 20),
+            config: acct!(accounts, 5),
             pool_fees,
             sqrt_min_price,
             sqrt_max_price,
@@ -418,6 +435,133 @@ fn parse_initialize_pool_with_dynamic_config_instruction(
     ))
 }
 
+/// 解析 add_liquidity 指令
+fn parse_add_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2AddLiquidity;
+
+    // 结构: liquidity_delta (u128) + token_a_amount_threshold (u64) + token_b_amount_threshold (u64)
+    if data.len() < 32 || accounts.len() < 14 {
+        return None;
+    }
+
+    let liquidity_delta = u128::from_le_bytes(data[0..16].try_into().ok()?);
+    let token_a_amount_threshold = u64::from_le_bytes(data[16..24].try_into().ok()?);
+    let token_b_amount_threshold = u64::from_le_bytes(data[24..32].try_into().ok()?);
+
+    Some(DexEvent::MeteoraDammV2AddLiquidityEvent(MeteoraDammV2AddLiquidityEvent {
+        metadata,
+        pool: acct!(accounts, 0),
+        position: acct!(accounts, 1),
+        token_a_account: acct!(accounts, 2),
+        token_b_account: acct!(accounts, 3),
+        token_a_vault: acct!(accounts, 4),
+        token_b_vault: acct!(accounts, 5),
+        token_a_mint: acct!(accounts, 6),
+        token_b_mint: acct!(accounts, 7),
+        position_nft_account: acct!(accounts, 8),
+        owner: acct!(accounts, 9),
+        token_a_program: acct!(accounts, 10),
+        token_b_program: acct!(accounts, 11),
+        event_authority: acct!(accounts, 12),
+        program: acct!(accounts, 13),
+        liquidity_delta,
+        token_a_amount_threshold,
+        token_b_amount_threshold,
+        ..Default::default()
+    }))
+}
+
+/// 解析 remove_liquidity 指令
+fn parse_remove_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    // 结构: liquidity_delta (u128) + token_a_amount_threshold (u64) + token_b_amount_threshold (u64)
+    if data.len() < 32 {
+        return None;
+    }
+
+    let liquidity_delta = u128::from_le_bytes(data[0..16].try_into().ok()?);
+    let token_a_amount_threshold = u64::from_le_bytes(data[16..24].try_into().ok()?);
+    let token_b_amount_threshold = u64::from_le_bytes(data[24..32].try_into().ok()?);
+
+    build_remove_liquidity_event(
+        accounts,
+        metadata,
+        liquidity_delta,
+        token_a_amount_threshold,
+        token_b_amount_threshold,
+    )
+}
+
+/// 解析 remove_all_liquidity 指令
+///
+/// 指令参数只有两个最小数量，移除的流动性需由 CPI log 合并后得到
+fn parse_remove_all_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    // 结构: token_a_amount_threshold (u64) + token_b_amount_threshold (u64)
+    if data.len() < 16 {
+        return None;
+    }
+
+    let token_a_amount_threshold = u64::from_le_bytes(data[0..8].try_into().ok()?);
+    let token_b_amount_threshold = u64::from_le_bytes(data[8..16].try_into().ok()?);
+
+    build_remove_liquidity_event(
+        accounts,
+        metadata,
+        0,
+        token_a_amount_threshold,
+        token_b_amount_threshold,
+    )
+}
+
+/// remove_liquidity 与 remove_all_liquidity 的账户布局相同
+fn build_remove_liquidity_event(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+    liquidity_delta: u128,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2RemoveLiquidity;
+
+    if accounts.len() < 15 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2RemoveLiquidityEvent(MeteoraDammV2RemoveLiquidityEvent {
+        metadata,
+        pool_authority: acct!(accounts, 0),
+        pool: acct!(accounts, 1),
+        position: acct!(accounts, 2),
+        token_a_account: acct!(accounts, 3),
+        token_b_account: acct!(accounts, 4),
+        token_a_vault: acct!(accounts, 5),
+        token_b_vault: acct!(accounts, 6),
+        token_a_mint: acct!(accounts, 7),
+        token_b_mint: acct!(accounts, 8),
+        position_nft_account: acct!(accounts, 9),
+        owner: acct!(accounts, 10),
+        token_a_program: acct!(accounts, 11),
+        token_b_program: acct!(accounts, 12),
+        event_authority: acct!(accounts, 13),
+        program: acct!(accounts, 14),
+        liquidity_delta,
+        token_a_amount_threshold,
+        token_b_amount_threshold,
+        ..Default::default()
+    }))
+}
+
 /// 解析 swap inner instruction (CPI event)
 fn parse_swap_inner_instruction(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
     if let Some(event) = meteora_damm_v2_swap_event_decode(data) {
@@ -442,3 +586,31 @@ fn parse_initialize_pool_inner_instruction(
         None
     }
 }
+
+/// 解析 add liquidity inner instruction (CPI event)
+fn parse_add_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2AddLiquidity;
+    meteora_damm_v2_add_liquidity_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV2AddLiquidityEvent(MeteoraDammV2AddLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 remove liquidity inner instruction (CPI event)
+fn parse_remove_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2RemoveLiquidity;
+    meteora_damm_v2_remove_liquidity_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV2RemoveLiquidityEvent(MeteoraDammV2RemoveLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
@@ -8,4 +8,5 @@ pub mod raydium_clmm;
 pub mod raydium_cpmm;
 pub mod types;
 pub use block::block_meta_event::BlockMetaEvent;
+pub use block::slot_status_event::{SlotStatus, SlotStatusEvent};
 pub use types::Protocol;
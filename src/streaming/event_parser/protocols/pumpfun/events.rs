@@ -1,20 +1,29 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{EventMetadata, ReserveSnapshot};
 use crate::streaming::event_parser::protocols::pumpfun::types::{BondingCurve, Global};
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpFunCreateTokenEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub bonding_curve: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
     pub timestamp: i64,
     pub virtual_token_reserves: u64,
@@ -22,6 +31,7 @@ pub struct PumpFunCreateTokenEvent {
     pub real_token_reserves: u64,
     pub token_total_supply: u64,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_program: Pubkey,
     #[borsh(skip)]
     pub is_mayhem_mode: bool,
@@ -29,71 +39,100 @@ pub struct PumpFunCreateTokenEvent {
     #[borsh(skip)]
     pub is_cashback_enabled: bool,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mint_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mpl_token_metadata: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub metadata_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub rent: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
 }
 
 /// CreateV2 事件：与 create_v2 指令 16 个账户一致（见 parser 注释）。
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpFunCreateV2TokenEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub bonding_curve: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
     pub timestamp: i64,
     pub virtual_token_reserves: u64,
     pub virtual_sol_reserves: u64,
     pub real_token_reserves: u64,
     pub token_total_supply: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_program: Pubkey,
     pub is_mayhem_mode: bool,
     /// Whether cashback is enabled (IDL CreateEvent.is_cashback_enabled)
     pub is_cashback_enabled: bool,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mint_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mayhem_program_id: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_params: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub sol_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mayhem_state: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mayhem_token_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
 }
 
@@ -227,23 +266,29 @@ pub fn pumpfun_create_v2_token_event_log_decode(data: &[u8]) -> Option<PumpFunCr
     })
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpFunTradeEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mint: Pubkey,
     pub sol_amount: u64,
     pub token_amount: u64,
     pub is_buy: bool,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
     pub timestamp: i64,
     pub virtual_sol_reserves: u64,
     pub virtual_token_reserves: u64,
     pub real_sol_reserves: u64,
     pub real_token_reserves: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub fee_recipient: Pubkey,
     pub fee_basis_points: u64,
     pub fee: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
     pub creator_fee_basis_points: u64,
     pub creator_fee: u64,
@@ -265,39 +310,53 @@ pub struct PumpFunTradeEvent {
     pub is_dev_create_token_trade: bool, // Whether this is a dev-created token trade
 
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global: Pubkey,
     // #[borsh(skip)]
     // pub fee_recipient: Pubkey,
     // #[borsh(skip)]
     // pub mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_user: Pubkey,
     // #[borsh(skip)]
     // pub user: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator_vault: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global_volume_accumulator: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_volume_accumulator: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub fee_config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub fee_program: Pubkey,
     /// 17th instruction account (index 16), shown as "Account" on block explorers; present on some buy/sell.
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<PubkeyBytes>))]
     pub account: Option<Pubkey>,
 
     // === IDL TradeEvent extension fields (aligned with sol-parser-sdk / pumpfun IDL) ===
@@ -312,18 +371,89 @@ pub struct PumpFunTradeEvent {
     /// Whether this is a cashback coin (cashback_fee_basis_points > 0)
     #[borsh(skip)]
     pub is_cashback_coin: bool,
+    /// Whether the CPI log carried the fee fields (fee_recipient .. creator_fee); false for
+    /// trades logged before PumpFun introduced fees in TradeEvent, or when no CPI log was parsed
+    #[borsh(skip)]
+    pub has_fee_fields: bool,
+    /// Whether this trade completed the bonding curve and triggers graduation (a buy that left
+    /// `real_token_reserves` at 0). Known as soon as the trade lands, before the migrate event;
+    /// false when no CPI log was parsed
+    #[borsh(skip)]
+    pub completed_bonding_curve: bool,
+    /// Pool reserves before the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pre_reserves: Option<ReserveSnapshot>,
+    /// Pool reserves after the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub post_reserves: Option<ReserveSnapshot>,
+}
+
+/// PumpFun 单笔交易的协议费与创作者费，见 `PumpFunTradeEvent::fees`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PumpFunTradeFees {
+    pub fee_recipient: Pubkey,
+    pub fee_basis_points: u64,
+    /// 协议费 (lamports)
+    pub fee: u64,
+    pub creator: Pubkey,
+    pub creator_fee_basis_points: u64,
+    /// 创作者费 (lamports)
+    pub creator_fee: u64,
+}
+
+impl PumpFunTradeFees {
+    /// 协议费与创作者费之和 (lamports)
+    pub fn total_fee(&self) -> u64 {
+        self.fee.saturating_add(self.creator_fee)
+    }
+}
+
+impl PumpFunTradeEvent {
+    /// 交易的协议费与创作者费，CPI log 不含费用字段（早期交易或未解析 CPI log）时为 None
+    ///
+    /// `sol_amount` 不含费用：买入实际支付 `sol_amount + total_fee()`，
+    /// 卖出实际收到 `sol_amount - total_fee()`
+    pub fn fees(&self) -> Option<PumpFunTradeFees> {
+        self.has_fee_fields.then_some(PumpFunTradeFees {
+            fee_recipient: self.fee_recipient,
+            fee_basis_points: self.fee_basis_points,
+            fee: self.fee,
+            creator: self.creator,
+            creator_fee_basis_points: self.creator_fee_basis_points,
+            creator_fee: self.creator_fee,
+        })
+    }
 }
 
 /// Borsh byte length of TradeEvent fixed fields (IDL order; excludes ix_name and following variable part).
 /// Layout: mint(32)+sol_amount(8)+token_amount(8)+is_buy(1)+user(32)+timestamp(8)+virtual_sol(8)+virtual_token(8)+real_sol(8)+real_token(8)+fee_recipient(32)+fee_basis_points(8)+fee(8)+creator(32)+creator_fee_bps(8)+creator_fee(8)+track_volume(1)+total_unclaimed(8)+total_claimed(8)+current_sol_volume(8)+last_update_timestamp(8) = 250
 pub const PUMPFUN_TRADE_EVENT_LOG_SIZE: usize = 250;
+/// Length of older TradeEvent logs that end at creator_fee (before volume tracking fields).
+pub const PUMPFUN_TRADE_EVENT_LOG_SIZE_WITH_FEES: usize = 217;
+/// Length of the oldest TradeEvent logs that end at real_token_reserves (before fee fields).
+pub const PUMPFUN_TRADE_EVENT_LOG_SIZE_BASE: usize = 121;
 
 /// Decode TradeEvent log; if data.len() > 250 then parse ix_name, mayhem_mode, cashback (IDL-aligned).
+/// Older, shorter layouts are decoded with the missing trailing fields left at their defaults;
+/// `has_fee_fields` tells whether the fee fields were present.
 pub fn pumpfun_trade_event_log_decode(data: &[u8]) -> Option<PumpFunTradeEvent> {
     if data.len() < PUMPFUN_TRADE_EVENT_LOG_SIZE {
-        return None;
+        if data.len() < PUMPFUN_TRADE_EVENT_LOG_SIZE_BASE {
+            return None;
+        }
+        // 旧版布局：按当前布局补零后解码，缺失的字段保持默认值
+        let mut padded = [0u8; PUMPFUN_TRADE_EVENT_LOG_SIZE];
+        padded[..data.len()].copy_from_slice(data);
+        let mut event = borsh::from_slice::<PumpFunTradeEvent>(&padded).ok()?;
+        event.has_fee_fields = data.len() >= PUMPFUN_TRADE_EVENT_LOG_SIZE_WITH_FEES;
+        event.completed_bonding_curve = completes_bonding_curve(&event);
+        return Some(event);
     }
     let mut event = borsh::from_slice::<PumpFunTradeEvent>(&data[..PUMPFUN_TRADE_EVENT_LOG_SIZE]).ok()?;
+    event.has_fee_fields = true;
+    event.completed_bonding_curve = completes_bonding_curve(&event);
     let mut offset = PUMPFUN_TRADE_EVENT_LOG_SIZE;
     if offset < data.len() {
         let (ix_name, inc) = read_borsh_string(data, offset).unwrap_or((String::new(), 0));
@@ -345,6 +475,12 @@ pub fn pumpfun_trade_event_log_decode(data: &[u8]) -> Option<PumpFunTradeEvent>
     Some(event)
 }
 
+/// TradeEvent 不带完成标志：买入后曲线中剩余的可售代币为 0 即完成，程序随即将曲线标记为 complete
+#[inline]
+fn completes_bonding_curve(event: &PumpFunTradeEvent) -> bool {
+    event.is_buy && event.real_token_reserves == 0
+}
+
 #[inline]
 fn read_borsh_string(data: &[u8], start: usize) -> Option<(String, usize)> {
     if start + 4 > data.len() {
@@ -359,59 +495,85 @@ fn read_borsh_string(data: &[u8], start: usize) -> Option<(String, usize)> {
     Some((s, 4 + len))
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpFunMigrateEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
 
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub mint: Pubkey,
     pub mint_amount: u64,
     pub sol_amount: u64,
     pub pool_migration_fee: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub bonding_curve: Pubkey,
     pub timestamp: i64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
 
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub global: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub withdraw_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_bonding_curve: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub system_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pump_amm: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority_mint_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_authority_wsol_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_global_config: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub wsol_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub lp_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_quote_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_2022_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub associated_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pump_amm_event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub event_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub program: Pubkey,
 }
 
@@ -425,31 +587,108 @@ pub fn pumpfun_migrate_event_log_decode(data: &[u8]) -> Option<PumpFunMigrateEve
 }
 
 /// Bonding curve
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpFunBondingCurveAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub bonding_curve: BondingCurve,
 }
 
 /// Global config
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpFunGlobalAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub global: Global,
 }
 
+/// PumpFun 管理类指令
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum PumpFunAdminAction {
+    /// `set_creator`：设置代币的 creator
+    #[default]
+    SetCreator,
+    /// `admin_set_creator`：管理员覆盖代币的 creator
+    AdminSetCreator,
+    /// `set_params`：修改全局参数
+    SetParams,
+    /// `update_global_authority`：转移全局管理权限
+    UpdateGlobalAuthority,
+    /// `set_reserved_fee_recipients`：设置保留的手续费接收地址白名单
+    SetReservedFeeRecipients,
+}
+
+/// `set_params` 指令参数（IDL 顺序）
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct PumpFunSetParams {
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub withdraw_authority: Pubkey,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: u64,
+    pub creator_fee_basis_points: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub set_creator_authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub admin_set_creator_authority: Pubkey,
+}
+
+pub const PUMPFUN_SET_PARAMS_SIZE: usize = 8 * 5 + 32 + 1 + 8 * 2 + 32 * 2;
+
+/// PumpFun 管理 / 配置变更事件
+///
+/// 由指令解析得到，`authority` 为签名的管理账户。只有与 `action` 相关的字段会被填充：
+/// creator 变更填 `mint` / `bonding_curve` / `creator`，`UpdateGlobalAuthority` 填
+/// `new_authority`，`SetReservedFeeRecipients` 填 `whitelist_pda`，`SetParams` 填 `params`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct PumpFunAdminEvent {
+    pub metadata: EventMetadata,
+    pub action: PumpFunAdminAction,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub global: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub bonding_curve: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub creator: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub new_authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
+    pub whitelist_pda: Pubkey,
+    pub params: Option<PumpFunSetParams>,
+}
+
 /// Event discriminator constants
 pub mod discriminators {
     // Event discriminators
@@ -470,6 +709,11 @@ pub mod discriminators {
     pub const BUY_EXACT_SOL_IN_IX: &[u8] = &[56, 252, 116, 8, 158, 223, 205, 95];
     pub const SELL_IX: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
     pub const MIGRATE_IX: &[u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
+    pub const SET_CREATOR_IX: &[u8] = &[254, 148, 255, 112, 207, 142, 170, 165];
+    pub const ADMIN_SET_CREATOR_IX: &[u8] = &[69, 25, 171, 142, 57, 239, 13, 4];
+    pub const SET_PARAMS_IX: &[u8] = &[27, 234, 178, 52, 147, 2, 187, 141];
+    pub const UPDATE_GLOBAL_AUTHORITY_IX: &[u8] = &[227, 181, 74, 196, 208, 21, 97, 213];
+    pub const SET_RESERVED_FEE_RECIPIENTS_IX: &[u8] = &[111, 172, 162, 232, 114, 89, 213, 142];
 
     // Account discriminators
     pub const BONDING_CURVE_ACCOUNT: &[u8] = &[23, 183, 248, 55, 96, 216, 172, 96];
@@ -1,9 +1,11 @@
 use crate::streaming::event_parser::{
-    common::{EventMetadata, EventType},
+    common::{utils::acct, EventMetadata, EventType},
+    core::ParseConfig,
     protocols::pumpfun::{
         discriminators, pumpfun_create_v2_token_event_log_decode, pumpfun_migrate_event_log_decode,
-        pumpfun_trade_event_log_decode, PumpFunCreateTokenEvent, PumpFunCreateV2TokenEvent,
-        PumpFunMigrateEvent, PumpFunTradeEvent,
+        pumpfun_trade_event_log_decode, PumpFunAdminAction, PumpFunAdminEvent,
+        PumpFunCreateTokenEvent, PumpFunCreateV2TokenEvent, PumpFunMigrateEvent, PumpFunSetParams,
+        PumpFunTradeEvent, PUMPFUN_SET_PARAMS_SIZE,
     },
     DexEvent,
 };
@@ -31,6 +33,17 @@ pub fn parse_pumpfun_instruction_data(
         discriminators::BUY_EXACT_SOL_IN_IX => parse_buy_exact_sol_in_instruction(data, accounts, metadata),
         discriminators::SELL_IX => parse_sell_instruction(data, accounts, metadata),
         discriminators::MIGRATE_IX => parse_migrate_instruction(data, accounts, metadata),
+        discriminators::SET_CREATOR_IX => parse_set_creator_instruction(data, accounts, metadata),
+        discriminators::ADMIN_SET_CREATOR_IX => {
+            parse_admin_set_creator_instruction(data, accounts, metadata)
+        }
+        discriminators::SET_PARAMS_IX => parse_set_params_instruction(data, accounts, metadata),
+        discriminators::UPDATE_GLOBAL_AUTHORITY_IX => {
+            parse_update_global_authority_instruction(accounts, metadata)
+        }
+        discriminators::SET_RESERVED_FEE_RECIPIENTS_IX => {
+            parse_set_reserved_fee_recipients_instruction(data, accounts, metadata)
+        }
         _ => None,
     }
 }
@@ -169,20 +182,20 @@ fn parse_create_token_instruction(
         symbol: symbol.to_string(),
         uri: uri.to_string(),
         creator,
-        mint: accounts[0],
-        mint_authority: accounts[1],
-        bonding_curve: accounts[2],
-        associated_bonding_curve: accounts[3],
-        global: accounts[4],
-        mpl_token_metadata: accounts[5],
-        metadata_account: accounts[6],
-        user: accounts[7],
-        system_program: accounts[8],
-        token_program: accounts[9],
-        associated_token_program: accounts[10],
-        rent: accounts[11],
-        event_authority: accounts[12],
-        program: accounts[13],
+        mint: acct!(accounts, 0),
+        mint_authority: acct!(accounts, 1),
+        bonding_curve: acct!(accounts, 2),
+        associated_bonding_curve: acct!(accounts, 3),
+        global: acct!(accounts, 4),
+        mpl_token_metadata: acct!(accounts, 5),
+        metadata_account: acct!(accounts, 6),
+        user: acct!(accounts, 7),
+        system_program: acct!(accounts, 8),
+        token_program: acct!(accounts, 9),
+        associated_token_program: acct!(accounts, 10),
+        rent: acct!(accounts, 11),
+        event_authority: acct!(accounts, 12),
+        program: acct!(accounts, 13),
         ..Default::default()
     }))
 }
@@ -245,29 +258,29 @@ fn parse_create_v2_token_instruction(
     };
 
     // Safe slice: already guaranteed accounts.len() >= 16 above; avoid any index panic (issue #63).
-    let acc = &accounts[0..CREATE_V2_MIN_ACCOUNTS];
+    let acc = accounts.get(..CREATE_V2_MIN_ACCOUNTS)?;
     Some(DexEvent::PumpFunCreateV2TokenEvent(PumpFunCreateV2TokenEvent {
         metadata,
         name: name.to_string(),
         symbol: symbol.to_string(),
         uri: uri.to_string(),
         creator,
-        mint: acc[0],
-        mint_authority: acc[1],
-        bonding_curve: acc[2],
-        associated_bonding_curve: acc[3],
-        global: acc[4],
-        user: acc[5],
-        system_program: acc[6],
-        token_program: acc[7],
-        associated_token_program: acc[8],
-        mayhem_program_id: acc[9],
-        global_params: acc[10],
-        sol_vault: acc[11],
-        mayhem_state: acc[12],
-        mayhem_token_vault: acc[13],
-        event_authority: acc[14],
-        program: acc[15],
+        mint: acct!(acc, 0),
+        mint_authority: acct!(acc, 1),
+        bonding_curve: acct!(acc, 2),
+        associated_bonding_curve: acct!(acc, 3),
+        global: acct!(acc, 4),
+        user: acct!(acc, 5),
+        system_program: acct!(acc, 6),
+        token_program: acct!(acc, 7),
+        associated_token_program: acct!(acc, 8),
+        mayhem_program_id: acct!(acc, 9),
+        global_params: acct!(acc, 10),
+        sol_vault: acct!(acc, 11),
+        mayhem_state: acct!(acc, 12),
+        mayhem_token_vault: acct!(acc, 13),
+        event_authority: acct!(acc, 14),
+        program: acct!(acc, 15),
         ..Default::default()
     }))
 }
@@ -292,22 +305,22 @@ fn parse_buy_instruction(
     let max_sol_cost = u64::from_le_bytes(data[8..16].try_into().unwrap());
     Some(DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
         metadata,
-        global: accounts[0],
-        fee_recipient: accounts[1],
-        mint: accounts[2],
-        bonding_curve: accounts[3],
-        associated_bonding_curve: accounts[4],
-        associated_user: accounts[5],
-        user: accounts[6],
-        system_program: accounts[7],
-        token_program: accounts[8],
-        creator_vault: accounts[9],
-        event_authority: accounts[10],
-        program: accounts[11],
-        global_volume_accumulator: accounts[12],
-        user_volume_accumulator: accounts[13],
-        fee_config: accounts[14],
-        fee_program: accounts[15],
+        global: acct!(accounts, 0),
+        fee_recipient: acct!(accounts, 1),
+        mint: acct!(accounts, 2),
+        bonding_curve: acct!(accounts, 3),
+        associated_bonding_curve: acct!(accounts, 4),
+        associated_user: acct!(accounts, 5),
+        user: acct!(accounts, 6),
+        system_program: acct!(accounts, 7),
+        token_program: acct!(accounts, 8),
+        creator_vault: acct!(accounts, 9),
+        event_authority: acct!(accounts, 10),
+        program: acct!(accounts, 11),
+        global_volume_accumulator: acct!(accounts, 12),
+        user_volume_accumulator: acct!(accounts, 13),
+        fee_config: acct!(accounts, 14),
+        fee_program: acct!(accounts, 15),
         account: accounts.get(16).copied(),
         max_sol_cost,
         amount,
@@ -334,22 +347,22 @@ fn parse_buy_exact_sol_in_instruction(
 
     Some(DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
         metadata,
-        global: accounts[0],
-        fee_recipient: accounts[1],
-        mint: accounts[2],
-        bonding_curve: accounts[3],
-        associated_bonding_curve: accounts[4],
-        associated_user: accounts[5],
-        user: accounts[6],
-        system_program: accounts[7],
-        token_program: accounts[8],
-        creator_vault: accounts[9],
-        event_authority: accounts[10],
-        program: accounts[11],
-        global_volume_accumulator: accounts[12],
-        user_volume_accumulator: accounts[13],
-        fee_config: accounts[14],
-        fee_program: accounts[15],
+        global: acct!(accounts, 0),
+        fee_recipient: acct!(accounts, 1),
+        mint: acct!(accounts, 2),
+        bonding_curve: acct!(accounts, 3),
+        associated_bonding_curve: acct!(accounts, 4),
+        associated_user: acct!(accounts, 5),
+        user: acct!(accounts, 6),
+        system_program: acct!(accounts, 7),
+        token_program: acct!(accounts, 8),
+        creator_vault: acct!(accounts, 9),
+        event_authority: acct!(accounts, 10),
+        program: acct!(accounts, 11),
+        global_volume_accumulator: acct!(accounts, 12),
+        user_volume_accumulator: acct!(accounts, 13),
+        fee_config: acct!(accounts, 14),
+        fee_program: acct!(accounts, 15),
         account: accounts.get(16).copied(),
         max_sol_cost: spendable_sol_in,
         amount: min_tokens_out,
@@ -374,22 +387,22 @@ fn parse_sell_instruction(
     let min_sol_output = u64::from_le_bytes(data[8..16].try_into().unwrap());
     Some(DexEvent::PumpFunTradeEvent(PumpFunTradeEvent {
         metadata,
-        global: accounts[0],
-        fee_recipient: accounts[1],
-        mint: accounts[2],
-        bonding_curve: accounts[3],
-        associated_bonding_curve: accounts[4],
-        associated_user: accounts[5],
-        user: accounts[6],
-        system_program: accounts[7],
-        creator_vault: accounts[8],
-        token_program: accounts[9],
-        event_authority: accounts[10],
-        program: accounts[11],
+        global: acct!(accounts, 0),
+        fee_recipient: acct!(accounts, 1),
+        mint: acct!(accounts, 2),
+        bonding_curve: acct!(accounts, 3),
+        associated_bonding_curve: acct!(accounts, 4),
+        associated_user: acct!(accounts, 5),
+        user: acct!(accounts, 6),
+        system_program: acct!(accounts, 7),
+        creator_vault: acct!(accounts, 8),
+        token_program: acct!(accounts, 9),
+        event_authority: acct!(accounts, 10),
+        program: acct!(accounts, 11),
         global_volume_accumulator: Pubkey::default(),
         user_volume_accumulator: Pubkey::default(),
-        fee_config: accounts[12],
-        fee_program: accounts[13],
+        fee_config: acct!(accounts, 12),
+        fee_program: acct!(accounts, 13),
         account: accounts.get(16).copied(),
         min_sol_output,
         amount,
@@ -398,6 +411,17 @@ fn parse_sell_instruction(
     }))
 }
 
+/// 找不到对应的 CPI log 时是否丢弃该指令事件
+///
+/// 迁移指令（`discriminators::MIGRATE_IX`）没有参数，数量、池子与时间戳只在
+/// `COMPLETE_PUMP_AMM_MIGRATION_EVENT` CPI log 中。默认仍发出只含账户的迁移事件（ShredStream 没有
+/// inner instructions，关闭 `parse_inner_instructions` 时同样如此）；开启
+/// `ParseConfig::pumpfun_migrate_requires_cpi_log` 后只发出合并了 CPI log 的迁移事件
+pub fn requires_cpi_log(event: &DexEvent, parse_config: &ParseConfig) -> bool {
+    parse_config.pumpfun_migrate_requires_cpi_log
+        && matches!(event, DexEvent::PumpFunMigrateEvent(_))
+}
+
 /// 解析迁移指令事件
 /// 共 24 个固定账户: 0: global, 1: withdraw_authority, 2: mint, 3: bonding_curve, 4: associated_bonding_curve,
 /// 5: user, 6: system_program, 7: token_program, 8: pump_amm, 9: pool, 10: pool_authority,
@@ -416,30 +440,151 @@ fn parse_migrate_instruction(
     }
     Some(DexEvent::PumpFunMigrateEvent(PumpFunMigrateEvent {
         metadata,
-        global: accounts[0],
-        withdraw_authority: accounts[1],
-        mint: accounts[2],
-        bonding_curve: accounts[3],
-        associated_bonding_curve: accounts[4],
-        user: accounts[5],
-        system_program: accounts[6],
-        token_program: accounts[7],
-        pump_amm: accounts[8],
-        pool: accounts[9],
-        pool_authority: accounts[10],
-        pool_authority_mint_account: accounts[11],
-        pool_authority_wsol_account: accounts[12],
-        amm_global_config: accounts[13],
-        wsol_mint: accounts[14],
-        lp_mint: accounts[15],
-        user_pool_token_account: accounts[16],
-        pool_base_token_account: accounts[17],
-        pool_quote_token_account: accounts[18],
-        token_2022_program: accounts[19],
-        associated_token_program: accounts[20],
-        pump_amm_event_authority: accounts[21],
-        event_authority: accounts[22],
-        program: accounts[23],
+        global: acct!(accounts, 0),
+        withdraw_authority: acct!(accounts, 1),
+        mint: acct!(accounts, 2),
+        bonding_curve: acct!(accounts, 3),
+        associated_bonding_curve: acct!(accounts, 4),
+        user: acct!(accounts, 5),
+        system_program: acct!(accounts, 6),
+        token_program: acct!(accounts, 7),
+        pump_amm: acct!(accounts, 8),
+        pool: acct!(accounts, 9),
+        pool_authority: acct!(accounts, 10),
+        pool_authority_mint_account: acct!(accounts, 11),
+        pool_authority_wsol_account: acct!(accounts, 12),
+        amm_global_config: acct!(accounts, 13),
+        wsol_mint: acct!(accounts, 14),
+        lp_mint: acct!(accounts, 15),
+        user_pool_token_account: acct!(accounts, 16),
+        pool_base_token_account: acct!(accounts, 17),
+        pool_quote_token_account: acct!(accounts, 18),
+        token_2022_program: acct!(accounts, 19),
+        associated_token_program: acct!(accounts, 20),
+        pump_amm_event_authority: acct!(accounts, 21),
+        event_authority: acct!(accounts, 22),
+        program: acct!(accounts, 23),
+        ..Default::default()
+    }))
+}
+
+/// 读取指令参数开头的 Pubkey
+fn read_pubkey_arg(data: &[u8]) -> Option<Pubkey> {
+    data.get(..32).and_then(|bytes| Pubkey::try_from(bytes).ok())
+}
+
+/// 解析设置 creator 指令
+/// 账户: 0: set_creator_authority, 1: global, 2: mint, 3: metadata, 4: bonding_curve,
+/// 5: event_authority, 6: program
+fn parse_set_creator_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunAdmin;
+
+    if accounts.len() < 7 {
+        return None;
+    }
+    Some(DexEvent::PumpFunAdminEvent(PumpFunAdminEvent {
+        metadata,
+        action: PumpFunAdminAction::SetCreator,
+        authority: acct!(accounts, 0),
+        global: acct!(accounts, 1),
+        mint: acct!(accounts, 2),
+        bonding_curve: acct!(accounts, 4),
+        creator: read_pubkey_arg(data)?,
+        ..Default::default()
+    }))
+}
+
+/// 解析管理员设置 creator 指令
+/// 账户: 0: admin_set_creator_authority, 1: global, 2: mint, 3: bonding_curve,
+/// 4: event_authority, 5: program
+fn parse_admin_set_creator_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunAdmin;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+    Some(DexEvent::PumpFunAdminEvent(PumpFunAdminEvent {
+        metadata,
+        action: PumpFunAdminAction::AdminSetCreator,
+        authority: acct!(accounts, 0),
+        global: acct!(accounts, 1),
+        mint: acct!(accounts, 2),
+        bonding_curve: acct!(accounts, 3),
+        creator: read_pubkey_arg(data)?,
+        ..Default::default()
+    }))
+}
+
+/// 解析修改全局参数指令
+/// 账户: 0: global, 1: authority, 2: event_authority, 3: program
+fn parse_set_params_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunAdmin;
+
+    if data.len() < PUMPFUN_SET_PARAMS_SIZE || accounts.len() < 4 {
+        return None;
+    }
+    let params = borsh::from_slice::<PumpFunSetParams>(&data[..PUMPFUN_SET_PARAMS_SIZE]).ok()?;
+    Some(DexEvent::PumpFunAdminEvent(PumpFunAdminEvent {
+        metadata,
+        action: PumpFunAdminAction::SetParams,
+        authority: acct!(accounts, 1),
+        global: acct!(accounts, 0),
+        params: Some(params),
+        ..Default::default()
+    }))
+}
+
+/// 解析转移全局管理权限指令
+/// 账户: 0: global, 1: authority, 2: new_authority, 3: event_authority, 4: program
+fn parse_update_global_authority_instruction(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunAdmin;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+    Some(DexEvent::PumpFunAdminEvent(PumpFunAdminEvent {
+        metadata,
+        action: PumpFunAdminAction::UpdateGlobalAuthority,
+        authority: acct!(accounts, 1),
+        global: acct!(accounts, 0),
+        new_authority: acct!(accounts, 2),
+        ..Default::default()
+    }))
+}
+
+/// 解析设置保留手续费接收地址指令
+/// 账户: 0: global, 1: authority, 2: event_authority, 3: program
+fn parse_set_reserved_fee_recipients_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunAdmin;
+
+    if accounts.len() < 4 {
+        return None;
+    }
+    Some(DexEvent::PumpFunAdminEvent(PumpFunAdminEvent {
+        metadata,
+        action: PumpFunAdminAction::SetReservedFeeRecipients,
+        authority: acct!(accounts, 1),
+        global: acct!(accounts, 0),
+        whitelist_pda: read_pubkey_arg(data)?,
         ..Default::default()
     }))
 }
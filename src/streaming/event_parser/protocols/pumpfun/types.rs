@@ -1,4 +1,5 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
@@ -10,8 +11,12 @@ use crate::streaming::{
     },
     grpc::AccountPretty,
 };
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct BondingCurve {
     pub virtual_token_reserves: u64,
     pub virtual_sol_reserves: u64,
@@ -19,6 +24,7 @@ pub struct BondingCurve {
     pub real_sol_reserves: u64,
     pub token_total_supply: u64,
     pub complete: bool,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
     pub is_mayhem_mode: bool,
     pub is_cashback_coin: bool,
@@ -57,27 +63,38 @@ pub fn bonding_curve_parser(
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct Global {
     pub initialized: bool,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub fee_recipient: Pubkey,
     pub initial_virtual_token_reserves: u64,
     pub initial_virtual_sol_reserves: u64,
     pub initial_real_token_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub withdraw_authority: Pubkey,
     pub enable_migrate: bool,
     pub pool_migration_fee: u64,
     pub creator_fee_basis_points: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub fee_recipients: [Pubkey; 7],
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub set_creator_authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub admin_set_creator_authority: Pubkey,
     pub create_v2_enabled: bool,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub whitelist_pda: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub reserved_fee_recipient: Pubkey,
     pub mayhem_mode_enabled: bool,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub reserved_fee_recipients: [Pubkey; 7],
     pub is_cashback_enabled: bool,
 }
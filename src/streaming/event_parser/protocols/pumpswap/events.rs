@@ -1,12 +1,17 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{EventMetadata, ReserveSnapshot};
 use crate::streaming::event_parser::protocols::pumpswap::types::{GlobalConfig, Pool};
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 
 /// 买入事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpSwapBuyEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -24,12 +29,19 @@ pub struct PumpSwapBuyEvent {
     pub protocol_fee: u64,
     pub quote_amount_in_with_lp_fee: u64,
     pub user_quote_amount_in: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_base_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_quote_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub protocol_fee_recipient: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub protocol_fee_recipient_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator: Pubkey,
     pub coin_creator_fee_basis_points: u64,
     pub coin_creator_fee: u64,
@@ -39,21 +51,37 @@ pub struct PumpSwapBuyEvent {
     pub current_sol_volume: u64,
     pub last_update_timestamp: i64,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_quote_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator_vault_ata: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator_vault_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_token_program: Pubkey,
+    /// Pool reserves before the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pre_reserves: Option<ReserveSnapshot>,
+    /// Pool reserves after the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub post_reserves: Option<ReserveSnapshot>,
 }
 
 pub const PUMP_SWAP_BUY_EVENT_LOG_SIZE: usize = 385;
@@ -66,7 +94,9 @@ pub fn pump_swap_buy_event_log_decode(data: &[u8]) -> Option<PumpSwapBuyEvent> {
 }
 
 /// 卖出事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpSwapSellEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -84,31 +114,54 @@ pub struct PumpSwapSellEvent {
     pub protocol_fee: u64,
     pub quote_amount_out_without_lp_fee: u64,
     pub user_quote_amount_out: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_base_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_quote_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub protocol_fee_recipient: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub protocol_fee_recipient_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator: Pubkey,
     pub coin_creator_fee_basis_points: u64,
     pub coin_creator_fee: u64,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_quote_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator_vault_ata: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator_vault_authority: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_token_program: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_token_program: Pubkey,
+    /// Pool reserves before the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pre_reserves: Option<ReserveSnapshot>,
+    /// Pool reserves after the trade, set when `ParseConfig::reserve_snapshots` is enabled
+    #[borsh(skip)]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub post_reserves: Option<ReserveSnapshot>,
 }
 
 pub const PUMP_SWAP_SELL_EVENT_LOG_SIZE: usize = 352;
@@ -121,14 +174,19 @@ pub fn pump_swap_sell_event_log_decode(data: &[u8]) -> Option<PumpSwapSellEvent>
 }
 
 /// 创建池子事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpSwapCreatePoolEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
     pub timestamp: i64,
     pub index: u16,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
     pub base_mint_decimals: u8,
     pub quote_mint_decimals: u8,
@@ -140,16 +198,24 @@ pub struct PumpSwapCreatePoolEvent {
     pub initial_liquidity: u64,
     pub lp_token_amount_out: u64,
     pub pool_bump: u8,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub lp_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_base_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_quote_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_quote_token_account: Pubkey,
 }
 
@@ -163,7 +229,9 @@ pub fn pump_swap_create_pool_event_log_decode(data: &[u8]) -> Option<PumpSwapCre
 }
 
 /// 存款事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpSwapDepositEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -178,18 +246,27 @@ pub struct PumpSwapDepositEvent {
     pub base_amount_in: u64,
     pub quote_amount_in: u64,
     pub lp_mint_supply: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_base_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_quote_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_quote_token_account: Pubkey,
 }
 
@@ -203,7 +280,9 @@ pub fn pump_swap_deposit_event_log_decode(data: &[u8]) -> Option<PumpSwapDeposit
 }
 
 /// 提款事件
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpSwapWithdrawEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
@@ -218,18 +297,27 @@ pub struct PumpSwapWithdrawEvent {
     pub base_amount_out: u64,
     pub quote_amount_out: u64,
     pub lp_mint_supply: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_base_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_quote_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub user_pool_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_base_token_account: Pubkey,
     #[borsh(skip)]
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_quote_token_account: Pubkey,
 }
 
@@ -243,26 +331,34 @@ pub fn pump_swap_withdraw_event_log_decode(data: &[u8]) -> Option<PumpSwapWithdr
 }
 
 /// 全局配置
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpSwapGlobalConfigAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub global_config: GlobalConfig,
 }
 
 /// 池
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PumpSwapPoolAccountEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pubkey: Pubkey,
     pub executable: bool,
     pub lamports: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub rent_epoch: u64,
     pub pool: Pool,
@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{read_u64_le, utils::acct, EventMetadata, EventType},
     protocols::pumpswap::{
         discriminators, pump_swap_buy_event_log_decode, pump_swap_create_pool_event_log_decode,
         pump_swap_deposit_event_log_decode, pump_swap_sell_event_log_decode,
@@ -155,18 +155,18 @@ fn parse_buy_instruction(
         metadata,
         base_amount_out,
         max_quote_amount_in,
-        pool: accounts[0],
-        user: accounts[1],
-        base_mint: accounts[3],
-        quote_mint: accounts[4],
-        user_base_token_account: accounts[5],
-        user_quote_token_account: accounts[6],
-        pool_base_token_account: accounts[7],
-        pool_quote_token_account: accounts[8],
-        protocol_fee_recipient: accounts[9],
-        protocol_fee_recipient_token_account: accounts[10],
-        base_token_program: accounts[11],
-        quote_token_program: accounts[12],
+        pool: acct!(accounts, 0),
+        user: acct!(accounts, 1),
+        base_mint: acct!(accounts, 3),
+        quote_mint: acct!(accounts, 4),
+        user_base_token_account: acct!(accounts, 5),
+        user_quote_token_account: acct!(accounts, 6),
+        pool_base_token_account: acct!(accounts, 7),
+        pool_quote_token_account: acct!(accounts, 8),
+        protocol_fee_recipient: acct!(accounts, 9),
+        protocol_fee_recipient_token_account: acct!(accounts, 10),
+        base_token_program: acct!(accounts, 11),
+        quote_token_program: acct!(accounts, 12),
         coin_creator_vault_ata: accounts.get(17).copied().unwrap_or_default(),
         coin_creator_vault_authority: accounts.get(18).copied().unwrap_or_default(),
         ..Default::default()
@@ -176,12 +176,14 @@ fn parse_buy_instruction(
 /// 解析 buy_exact_quote_in 指令事件
 /// 账户布局与 buy 相同，共 23 个固定账户（0–22，17/18 为 coin_creator_vault_ata / coin_creator_vault_authority）。
 /// 参数顺序与 buy 不同: spendable_quote_in (SOL), min_base_amount_out (token).
+/// 与 buy 共用 `PumpSwapBuyEvent`，以事件类型 `PumpSwapBuyExactQuoteIn` 区分（精确输入 quote）；
+/// buy 为精确输出 base，sell 只有精确输入 base 一种形式
 fn parse_buy_exact_quote_in_instruction(
     data: &[u8],
     accounts: &[Pubkey],
     mut metadata: EventMetadata,
 ) -> Option<DexEvent> {
-    metadata.event_type = EventType::PumpSwapBuy;
+    metadata.event_type = EventType::PumpSwapBuyExactQuoteIn;
 
     if data.len() < 16 || accounts.len() < 13 {
         return None;
@@ -195,18 +197,18 @@ fn parse_buy_exact_quote_in_instruction(
         metadata,
         base_amount_out: min_base_amount_out,
         max_quote_amount_in: spendable_quote_in,
-        pool: accounts[0],
-        user: accounts[1],
-        base_mint: accounts[3],
-        quote_mint: accounts[4],
-        user_base_token_account: accounts[5],
-        user_quote_token_account: accounts[6],
-        pool_base_token_account: accounts[7],
-        pool_quote_token_account: accounts[8],
-        protocol_fee_recipient: accounts[9],
-        protocol_fee_recipient_token_account: accounts[10],
-        base_token_program: accounts[11],
-        quote_token_program: accounts[12],
+        pool: acct!(accounts, 0),
+        user: acct!(accounts, 1),
+        base_mint: acct!(accounts, 3),
+        quote_mint: acct!(accounts, 4),
+        user_base_token_account: acct!(accounts, 5),
+        user_quote_token_account: acct!(accounts, 6),
+        pool_base_token_account: acct!(accounts, 7),
+        pool_quote_token_account: acct!(accounts, 8),
+        protocol_fee_recipient: acct!(accounts, 9),
+        protocol_fee_recipient_token_account: acct!(accounts, 10),
+        base_token_program: acct!(accounts, 11),
+        quote_token_program: acct!(accounts, 12),
         coin_creator_vault_ata: accounts.get(17).copied().unwrap_or_default(),
         coin_creator_vault_authority: accounts.get(18).copied().unwrap_or_default(),
         ..Default::default()
@@ -238,18 +240,18 @@ fn parse_sell_instruction(
         metadata,
         base_amount_in,
         min_quote_amount_out,
-        pool: accounts[0],
-        user: accounts[1],
-        base_mint: accounts[3],
-        quote_mint: accounts[4],
-        user_base_token_account: accounts[5],
-        user_quote_token_account: accounts[6],
-        pool_base_token_account: accounts[7],
-        pool_quote_token_account: accounts[8],
-        protocol_fee_recipient: accounts[9],
-        protocol_fee_recipient_token_account: accounts[10],
-        base_token_program: accounts[11],
-        quote_token_program: accounts[12],
+        pool: acct!(accounts, 0),
+        user: acct!(accounts, 1),
+        base_mint: acct!(accounts, 3),
+        quote_mint: acct!(accounts, 4),
+        user_base_token_account: acct!(accounts, 5),
+        user_quote_token_account: acct!(accounts, 6),
+        pool_base_token_account: acct!(accounts, 7),
+        pool_quote_token_account: acct!(accounts, 8),
+        protocol_fee_recipient: acct!(accounts, 9),
+        protocol_fee_recipient_token_account: acct!(accounts, 10),
+        base_token_program: acct!(accounts, 11),
+        quote_token_program: acct!(accounts, 12),
         coin_creator_vault_ata: accounts.get(17).copied().unwrap_or_default(),
         coin_creator_vault_authority: accounts.get(18).copied().unwrap_or_default(),
         ..Default::default()
@@ -282,16 +284,16 @@ fn parse_create_pool_instruction(
         index,
         base_amount_in,
         quote_amount_in,
-        pool: accounts[0],
-        creator: accounts[2],
-        base_mint: accounts[3],
-        quote_mint: accounts[4],
-        lp_mint: accounts[5],
-        user_base_token_account: accounts[6],
-        user_quote_token_account: accounts[7],
-        user_pool_token_account: accounts[8],
-        pool_base_token_account: accounts[9],
-        pool_quote_token_account: accounts[10],
+        pool: acct!(accounts, 0),
+        creator: acct!(accounts, 2),
+        base_mint: acct!(accounts, 3),
+        quote_mint: acct!(accounts, 4),
+        lp_mint: acct!(accounts, 5),
+        user_base_token_account: acct!(accounts, 6),
+        user_quote_token_account: acct!(accounts, 7),
+        user_pool_token_account: acct!(accounts, 8),
+        pool_base_token_account: acct!(accounts, 9),
+        pool_quote_token_account: acct!(accounts, 10),
         coin_creator,
         ..Default::default()
     }))
@@ -318,15 +320,15 @@ fn parse_deposit_instruction(
         lp_token_amount_out,
         max_base_amount_in,
         max_quote_amount_in,
-        pool: accounts[0],
-        user: accounts[2],
-        base_mint: accounts[3],
-        quote_mint: accounts[4],
-        user_base_token_account: accounts[6],
-        user_quote_token_account: accounts[7],
-        user_pool_token_account: accounts[8],
-        pool_base_token_account: accounts[9],
-        pool_quote_token_account: accounts[10],
+        pool: acct!(accounts, 0),
+        user: acct!(accounts, 2),
+        base_mint: acct!(accounts, 3),
+        quote_mint: acct!(accounts, 4),
+        user_base_token_account: acct!(accounts, 6),
+        user_quote_token_account: acct!(accounts, 7),
+        user_pool_token_account: acct!(accounts, 8),
+        pool_base_token_account: acct!(accounts, 9),
+        pool_quote_token_account: acct!(accounts, 10),
         ..Default::default()
     }))
 }
@@ -352,15 +354,15 @@ fn parse_withdraw_instruction(
         lp_token_amount_in,
         min_base_amount_out,
         min_quote_amount_out,
-        pool: accounts[0],
-        user: accounts[2],
-        base_mint: accounts[3],
-        quote_mint: accounts[4],
-        user_base_token_account: accounts[6],
-        user_quote_token_account: accounts[7],
-        user_pool_token_account: accounts[8],
-        pool_base_token_account: accounts[9],
-        pool_quote_token_account: accounts[10],
+        pool: acct!(accounts, 0),
+        user: acct!(accounts, 2),
+        base_mint: acct!(accounts, 3),
+        quote_mint: acct!(accounts, 4),
+        user_base_token_account: acct!(accounts, 6),
+        user_quote_token_account: acct!(accounts, 7),
+        user_pool_token_account: acct!(accounts, 8),
+        pool_base_token_account: acct!(accounts, 9),
+        pool_quote_token_account: acct!(accounts, 10),
         ..Default::default()
     }))
 }
@@ -1,4 +1,5 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
@@ -10,19 +11,29 @@ use crate::streaming::{
     },
     grpc::AccountPretty,
 };
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct GlobalConfig {
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub admin: Pubkey,
     pub lp_fee_basis_points: u64,
     pub protocol_fee_basis_points: u64,
     pub disable_flags: u8,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub protocol_fee_recipients: [Pubkey; 8],
     pub coin_creator_fee_basis_points: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub admin_set_coin_creator_authority: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub whitelist_pda: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub reserved_fee_recipient: Pubkey,
     pub mayhem_mode_enabled: bool,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub reserved_fee_recipients: [Pubkey; 7],
 }
 
@@ -59,17 +70,26 @@ pub fn global_config_parser(
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct Pool {
     pub pool_bump: u8,
     pub index: u16,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub creator: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub base_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub quote_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub lp_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_base_token_account: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_quote_token_account: Pubkey,
     pub lp_supply: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub coin_creator: Pubkey,
     pub is_mayhem_mode: bool,
 }
@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{read_u64_le, utils::acct, EventMetadata, EventType},
     protocols::raydium_amm_v4::{
         discriminators, RaydiumAmmV4DepositEvent, RaydiumAmmV4Initialize2Event,
         RaydiumAmmV4SwapEvent, RaydiumAmmV4WithdrawEvent, RaydiumAmmV4WithdrawPnlEvent,
@@ -12,6 +12,10 @@ use solana_sdk::pubkey::Pubkey;
 pub const RAYDIUM_AMM_V4_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
 
+/// Raydium AMM V4 池子的权限 PDA，所有池子共用
+pub const RAYDIUM_AMM_V4_AUTHORITY: Pubkey =
+    solana_sdk::pubkey!("5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1");
+
 /// 解析 Raydium AMM V4 instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
@@ -81,23 +85,23 @@ fn parse_withdraw_pnl_instruction(
 
     Some(DexEvent::RaydiumAmmV4WithdrawPnlEvent(RaydiumAmmV4WithdrawPnlEvent {
         metadata,
-        token_program: accounts[0],
-        amm: accounts[1],
-        amm_config: accounts[2],
-        amm_authority: accounts[3],
-        amm_open_orders: accounts[4],
-        pool_coin_token_account: accounts[5],
-        pool_pc_token_account: accounts[6],
-        coin_pnl_token_account: accounts[7],
-        pc_pnl_token_account: accounts[8],
-        pnl_owner_account: accounts[9],
-        amm_target_orders: accounts[10],
-        serum_program: accounts[11],
-        serum_market: accounts[12],
-        serum_event_queue: accounts[13],
-        serum_coin_vault_account: accounts[14],
-        serum_pc_vault_account: accounts[15],
-        serum_vault_signer: accounts[16],
+        token_program: acct!(accounts, 0),
+        amm: acct!(accounts, 1),
+        amm_config: acct!(accounts, 2),
+        amm_authority: acct!(accounts, 3),
+        amm_open_orders: acct!(accounts, 4),
+        pool_coin_token_account: acct!(accounts, 5),
+        pool_pc_token_account: acct!(accounts, 6),
+        coin_pnl_token_account: acct!(accounts, 7),
+        pc_pnl_token_account: acct!(accounts, 8),
+        pnl_owner_account: acct!(accounts, 9),
+        amm_target_orders: acct!(accounts, 10),
+        serum_program: acct!(accounts, 11),
+        serum_market: acct!(accounts, 12),
+        serum_event_queue: acct!(accounts, 13),
+        serum_coin_vault_account: acct!(accounts, 14),
+        serum_pc_vault_account: acct!(accounts, 15),
+        serum_vault_signer: acct!(accounts, 16),
     }))
 }
 
@@ -118,32 +122,36 @@ fn parse_withdraw_instruction(
         metadata,
         amount,
 
-        token_program: accounts[0],
-        amm: accounts[1],
-        amm_authority: accounts[2],
-        amm_open_orders: accounts[3],
-        amm_target_orders: accounts[4],
-        lp_mint_address: accounts[5],
-        pool_coin_token_account: accounts[6],
-        pool_pc_token_account: accounts[7],
-        pool_withdraw_queue: accounts[8],
-        pool_temp_lp_token_account: accounts[9],
-        serum_program: accounts[10],
-        serum_market: accounts[11],
-        serum_coin_vault_account: accounts[12],
-        serum_pc_vault_account: accounts[13],
-        serum_vault_signer: accounts[14],
-        user_lp_token_account: accounts[15],
-        user_coin_token_account: accounts[16],
-        user_pc_token_account: accounts[17],
-        user_owner: accounts[18],
-        serum_event_queue: accounts[19],
-        serum_bids: accounts[20],
-        serum_asks: accounts[21],
+        token_program: acct!(accounts, 0),
+        amm: acct!(accounts, 1),
+        amm_authority: acct!(accounts, 2),
+        amm_open_orders: acct!(accounts, 3),
+        amm_target_orders: acct!(accounts, 4),
+        lp_mint_address: acct!(accounts, 5),
+        pool_coin_token_account: acct!(accounts, 6),
+        pool_pc_token_account: acct!(accounts, 7),
+        pool_withdraw_queue: acct!(accounts, 8),
+        pool_temp_lp_token_account: acct!(accounts, 9),
+        serum_program: acct!(accounts, 10),
+        serum_market: acct!(accounts, 11),
+        serum_coin_vault_account: acct!(accounts, 12),
+        serum_pc_vault_account: acct!(accounts, 13),
+        serum_vault_signer: acct!(accounts, 14),
+        user_lp_token_account: acct!(accounts, 15),
+        user_coin_token_account: acct!(accounts, 16),
+        user_pc_token_account: acct!(accounts, 17),
+        user_owner: acct!(accounts, 18),
+        serum_event_queue: acct!(accounts, 19),
+        serum_bids: acct!(accounts, 20),
+        serum_asks: acct!(accounts, 21),
     }))
 }
 
-/// 解析初始化指令事件
+/// 解析初始化（创建池子）指令事件
+///
+/// 判别器只有 1 字节，容易与其它数据误匹配，因此严格校验：数据必须恰好为
+/// nonce + open_time + init_pc_amount + init_coin_amount（25 字节），账户恰好 21 个，
+/// 且权限账户为 AMM V4 的权限 PDA、两个 mint 不同
 fn parse_initialize2_instruction(
     data: &[u8],
     accounts: &[Pubkey],
@@ -151,7 +159,10 @@ fn parse_initialize2_instruction(
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::RaydiumAmmV4Initialize2;
 
-    if data.len() < 25 || accounts.len() < 21 {
+    if data.len() != 25 || accounts.len() != 21 {
+        return None;
+    }
+    if acct!(accounts, 5) != RAYDIUM_AMM_V4_AUTHORITY || acct!(accounts, 8) == acct!(accounts, 9) {
         return None;
     }
     let nonce = data[0];
@@ -166,27 +177,27 @@ fn parse_initialize2_instruction(
         init_pc_amount,
         init_coin_amount,
 
-        token_program: accounts[0],
-        spl_associated_token_account: accounts[1],
-        system_program: accounts[2],
-        rent: accounts[3],
-        amm: accounts[4],
-        amm_authority: accounts[5],
-        amm_open_orders: accounts[6],
-        lp_mint: accounts[7],
-        coin_mint: accounts[8],
-        pc_mint: accounts[9],
-        pool_coin_token_account: accounts[10],
-        pool_pc_token_account: accounts[11],
-        pool_withdraw_queue: accounts[12],
-        amm_target_orders: accounts[13],
-        pool_temp_lp: accounts[14],
-        serum_program: accounts[15],
-        serum_market: accounts[16],
-        user_wallet: accounts[17],
-        user_token_coin: accounts[18],
-        user_token_pc: accounts[19],
-        user_lp_token_account: accounts[20],
+        token_program: acct!(accounts, 0),
+        spl_associated_token_account: acct!(accounts, 1),
+        system_program: acct!(accounts, 2),
+        rent: acct!(accounts, 3),
+        amm: acct!(accounts, 4),
+        amm_authority: acct!(accounts, 5),
+        amm_open_orders: acct!(accounts, 6),
+        lp_mint: acct!(accounts, 7),
+        coin_mint: acct!(accounts, 8),
+        pc_mint: acct!(accounts, 9),
+        pool_coin_token_account: acct!(accounts, 10),
+        pool_pc_token_account: acct!(accounts, 11),
+        pool_withdraw_queue: acct!(accounts, 12),
+        amm_target_orders: acct!(accounts, 13),
+        pool_temp_lp: acct!(accounts, 14),
+        serum_program: acct!(accounts, 15),
+        serum_market: acct!(accounts, 16),
+        user_wallet: acct!(accounts, 17),
+        user_token_coin: acct!(accounts, 18),
+        user_token_pc: acct!(accounts, 19),
+        user_lp_token_account: acct!(accounts, 20),
     }))
 }
 
@@ -211,20 +222,20 @@ fn parse_deposit_instruction(
         max_pc_amount,
         base_side,
 
-        token_program: accounts[0],
-        amm: accounts[1],
-        amm_authority: accounts[2],
-        amm_open_orders: accounts[3],
-        amm_target_orders: accounts[4],
-        lp_mint_address: accounts[5],
-        pool_coin_token_account: accounts[6],
-        pool_pc_token_account: accounts[7],
-        serum_market: accounts[8],
-        user_coin_token_account: accounts[9],
-        user_pc_token_account: accounts[10],
-        user_lp_token_account: accounts[11],
-        user_owner: accounts[12],
-        serum_event_queue: accounts[13],
+        token_program: acct!(accounts, 0),
+        amm: acct!(accounts, 1),
+        amm_authority: acct!(accounts, 2),
+        amm_open_orders: acct!(accounts, 3),
+        amm_target_orders: acct!(accounts, 4),
+        lp_mint_address: acct!(accounts, 5),
+        pool_coin_token_account: acct!(accounts, 6),
+        pool_pc_token_account: acct!(accounts, 7),
+        serum_market: acct!(accounts, 8),
+        user_coin_token_account: acct!(accounts, 9),
+        user_pc_token_account: acct!(accounts, 10),
+        user_lp_token_account: acct!(accounts, 11),
+        user_owner: acct!(accounts, 12),
+        serum_event_queue: acct!(accounts, 13),
     }))
 }
 
@@ -254,24 +265,24 @@ fn parse_swap_base_output_instruction(
         max_amount_in,
         amount_out,
 
-        token_program: accounts[0],
-        amm: accounts[1],
-        amm_authority: accounts[2],
-        amm_open_orders: accounts[3],
-        amm_target_orders: Some(accounts[4]),
-        pool_coin_token_account: accounts[5],
-        pool_pc_token_account: accounts[6],
-        serum_program: accounts[7],
-        serum_market: accounts[8],
-        serum_bids: accounts[9],
-        serum_asks: accounts[10],
-        serum_event_queue: accounts[11],
-        serum_coin_vault_account: accounts[12],
-        serum_pc_vault_account: accounts[13],
-        serum_vault_signer: accounts[14],
-        user_source_token_account: accounts[15],
-        user_destination_token_account: accounts[16],
-        user_source_owner: accounts[17],
+        token_program: acct!(accounts, 0),
+        amm: acct!(accounts, 1),
+        amm_authority: acct!(accounts, 2),
+        amm_open_orders: acct!(accounts, 3),
+        amm_target_orders: Some(acct!(accounts, 4)),
+        pool_coin_token_account: acct!(accounts, 5),
+        pool_pc_token_account: acct!(accounts, 6),
+        serum_program: acct!(accounts, 7),
+        serum_market: acct!(accounts, 8),
+        serum_bids: acct!(accounts, 9),
+        serum_asks: acct!(accounts, 10),
+        serum_event_queue: acct!(accounts, 11),
+        serum_coin_vault_account: acct!(accounts, 12),
+        serum_pc_vault_account: acct!(accounts, 13),
+        serum_vault_signer: acct!(accounts, 14),
+        user_source_token_account: acct!(accounts, 15),
+        user_destination_token_account: acct!(accounts, 16),
+        user_source_owner: acct!(accounts, 17),
 
         ..Default::default()
     }))
@@ -303,24 +314,24 @@ fn parse_swap_base_input_instruction(
         amount_in,
         minimum_amount_out,
 
-        token_program: accounts[0],
-        amm: accounts[1],
-        amm_authority: accounts[2],
-        amm_open_orders: accounts[3],
-        amm_target_orders: Some(accounts[4]),
-        pool_coin_token_account: accounts[5],
-        pool_pc_token_account: accounts[6],
-        serum_program: accounts[7],
-        serum_market: accounts[8],
-        serum_bids: accounts[9],
-        serum_asks: accounts[10],
-        serum_event_queue: accounts[11],
-        serum_coin_vault_account: accounts[12],
-        serum_pc_vault_account: accounts[13],
-        serum_vault_signer: accounts[14],
-        user_source_token_account: accounts[15],
-        user_destination_token_account: accounts[16],
-        user_source_owner: accounts[17],
+        token_program: acct!(accounts, 0),
+        amm: acct!(accounts, 1),
+        amm_authority: acct!(accounts, 2),
+        amm_open_orders: acct!(accounts, 3),
+        amm_target_orders: Some(acct!(accounts, 4)),
+        pool_coin_token_account: acct!(accounts, 5),
+        pool_pc_token_account: acct!(accounts, 6),
+        serum_program: acct!(accounts, 7),
+        serum_market: acct!(accounts, 8),
+        serum_bids: acct!(accounts, 9),
+        serum_asks: acct!(accounts, 10),
+        serum_event_queue: acct!(accounts, 11),
+        serum_coin_vault_account: acct!(accounts, 12),
+        serum_pc_vault_account: acct!(accounts, 13),
+        serum_vault_signer: acct!(accounts, 14),
+        user_source_token_account: acct!(accounts, 15),
+        user_destination_token_account: acct!(accounts, 16),
+        user_source_owner: acct!(accounts, 17),
 
         ..Default::default()
     }))
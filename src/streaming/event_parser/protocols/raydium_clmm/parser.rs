@@ -1,7 +1,7 @@
 use crate::streaming::event_parser::{
     common::{
-        read_i32_le, read_option_bool, read_u128_le, read_u64_le, read_u8_le, EventMetadata,
-        EventType,
+        read_i32_le, read_option_bool, read_u128_le, read_u64_le, read_u8_le, utils::acct,
+        EventMetadata, EventType,
     },
     protocols::raydium_clmm::{
         discriminators, RaydiumClmmClosePositionEvent, RaydiumClmmCreatePoolEvent,
@@ -105,29 +105,29 @@ fn parse_open_position_v2_instruction(
         amount1_max: read_u64_le(data, 40)?,
         with_metadata: read_u8_le(data, 48)? == 1,
         base_flag: read_option_bool(data, &mut 49)?,
-        payer: accounts[0],
-        position_nft_owner: accounts[1],
-        position_nft_mint: accounts[2],
-        position_nft_account: accounts[3],
-        metadata_account: accounts[4],
-        pool_state: accounts[5],
-        protocol_position: accounts[6],
-        tick_array_lower: accounts[7],
-        tick_array_upper: accounts[8],
-        personal_position: accounts[9],
-        token_account0: accounts[10],
-        token_account1: accounts[11],
-        token_vault0: accounts[12],
-        token_vault1: accounts[13],
-        rent: accounts[14],
-        system_program: accounts[15],
-        token_program: accounts[16],
-        associated_token_program: accounts[17],
-        metadata_program: accounts[18],
-        token_program2022: accounts[19],
-        vault0_mint: accounts[20],
-        vault1_mint: accounts[21],
-        remaining_accounts: accounts[22..].to_vec(),
+        payer: acct!(accounts, 0),
+        position_nft_owner: acct!(accounts, 1),
+        position_nft_mint: acct!(accounts, 2),
+        position_nft_account: acct!(accounts, 3),
+        metadata_account: acct!(accounts, 4),
+        pool_state: acct!(accounts, 5),
+        protocol_position: acct!(accounts, 6),
+        tick_array_lower: acct!(accounts, 7),
+        tick_array_upper: acct!(accounts, 8),
+        personal_position: acct!(accounts, 9),
+        token_account0: acct!(accounts, 10),
+        token_account1: acct!(accounts, 11),
+        token_vault0: acct!(accounts, 12),
+        token_vault1: acct!(accounts, 13),
+        rent: acct!(accounts, 14),
+        system_program: acct!(accounts, 15),
+        token_program: acct!(accounts, 16),
+        associated_token_program: acct!(accounts, 17),
+        metadata_program: acct!(accounts, 18),
+        token_program2022: acct!(accounts, 19),
+        vault0_mint: acct!(accounts, 20),
+        vault1_mint: acct!(accounts, 21),
+        remaining_accounts: accounts.get(22..)?.to_vec(),
     }))
 }
 
@@ -154,26 +154,26 @@ fn parse_open_position_with_token_22_nft_instruction(
             amount1_max: read_u64_le(data, 40)?,
             with_metadata: read_u8_le(data, 48)? == 1,
             base_flag: read_option_bool(data, &mut 49)?,
-            payer: accounts[0],
-            position_nft_owner: accounts[1],
-            position_nft_mint: accounts[2],
-            position_nft_account: accounts[3],
-            pool_state: accounts[4],
-            protocol_position: accounts[5],
-            tick_array_lower: accounts[6],
-            tick_array_upper: accounts[7],
-            personal_position: accounts[8],
-            token_account0: accounts[9],
-            token_account1: accounts[10],
-            token_vault0: accounts[11],
-            token_vault1: accounts[12],
-            rent: accounts[13],
-            system_program: accounts[14],
-            token_program: accounts[15],
-            associated_token_program: accounts[16],
-            token_program2022: accounts[17],
-            vault0_mint: accounts[18],
-            vault1_mint: accounts[19],
+            payer: acct!(accounts, 0),
+            position_nft_owner: acct!(accounts, 1),
+            position_nft_mint: acct!(accounts, 2),
+            position_nft_account: acct!(accounts, 3),
+            pool_state: acct!(accounts, 4),
+            protocol_position: acct!(accounts, 5),
+            tick_array_lower: acct!(accounts, 6),
+            tick_array_upper: acct!(accounts, 7),
+            personal_position: acct!(accounts, 8),
+            token_account0: acct!(accounts, 9),
+            token_account1: acct!(accounts, 10),
+            token_vault0: acct!(accounts, 11),
+            token_vault1: acct!(accounts, 12),
+            rent: acct!(accounts, 13),
+            system_program: acct!(accounts, 14),
+            token_program: acct!(accounts, 15),
+            associated_token_program: acct!(accounts, 16),
+            token_program2022: acct!(accounts, 17),
+            vault0_mint: acct!(accounts, 18),
+            vault1_mint: acct!(accounts, 19),
         },
     ))
 }
@@ -195,21 +195,21 @@ fn parse_increase_liquidity_v2_instruction(
         amount0_max: read_u64_le(data, 16)?,
         amount1_max: read_u64_le(data, 24)?,
         base_flag: read_option_bool(data, &mut 32)?,
-        nft_owner: accounts[0],
-        nft_account: accounts[1],
-        pool_state: accounts[2],
-        protocol_position: accounts[3],
-        personal_position: accounts[4],
-        tick_array_lower: accounts[5],
-        tick_array_upper: accounts[6],
-        token_account0: accounts[7],
-        token_account1: accounts[8],
-        token_vault0: accounts[9],
-        token_vault1: accounts[10],
-        token_program: accounts[11],
-        token_program2022: accounts[12],
-        vault0_mint: accounts[13],
-        vault1_mint: accounts[14],
+        nft_owner: acct!(accounts, 0),
+        nft_account: acct!(accounts, 1),
+        pool_state: acct!(accounts, 2),
+        protocol_position: acct!(accounts, 3),
+        personal_position: acct!(accounts, 4),
+        tick_array_lower: acct!(accounts, 5),
+        tick_array_upper: acct!(accounts, 6),
+        token_account0: acct!(accounts, 7),
+        token_account1: acct!(accounts, 8),
+        token_vault0: acct!(accounts, 9),
+        token_vault1: acct!(accounts, 10),
+        token_program: acct!(accounts, 11),
+        token_program2022: acct!(accounts, 12),
+        vault0_mint: acct!(accounts, 13),
+        vault1_mint: acct!(accounts, 14),
     }))
 }
 
@@ -228,19 +228,19 @@ fn parse_create_pool_instruction(
         metadata,
         sqrt_price_x64: read_u128_le(data, 0)?,
         open_time: read_u64_le(data, 16)?,
-        pool_creator: accounts[0],
-        amm_config: accounts[1],
-        pool_state: accounts[2],
-        token_mint0: accounts[3],
-        token_mint1: accounts[4],
-        token_vault0: accounts[5],
-        token_vault1: accounts[6],
-        observation_state: accounts[7],
-        tick_array_bitmap: accounts[8],
-        token_program0: accounts[9],
-        token_program1: accounts[10],
-        system_program: accounts[11],
-        rent: accounts[12],
+        pool_creator: acct!(accounts, 0),
+        amm_config: acct!(accounts, 1),
+        pool_state: acct!(accounts, 2),
+        token_mint0: acct!(accounts, 3),
+        token_mint1: acct!(accounts, 4),
+        token_vault0: acct!(accounts, 5),
+        token_vault1: acct!(accounts, 6),
+        observation_state: acct!(accounts, 7),
+        tick_array_bitmap: acct!(accounts, 8),
+        token_program0: acct!(accounts, 9),
+        token_program1: acct!(accounts, 10),
+        system_program: acct!(accounts, 11),
+        rent: acct!(accounts, 12),
     }))
 }
 
@@ -260,23 +260,23 @@ fn parse_decrease_liquidity_v2_instruction(
         liquidity: read_u128_le(data, 0)?,
         amount0_min: read_u64_le(data, 16)?,
         amount1_min: read_u64_le(data, 24)?,
-        nft_owner: accounts[0],
-        nft_account: accounts[1],
-        personal_position: accounts[2],
-        pool_state: accounts[3],
-        protocol_position: accounts[4],
-        token_vault0: accounts[5],
-        token_vault1: accounts[6],
-        tick_array_lower: accounts[7],
-        tick_array_upper: accounts[8],
-        recipient_token_account0: accounts[9],
-        recipient_token_account1: accounts[10],
-        token_program: accounts[11],
-        token_program2022: accounts[12],
-        memo_program: accounts[13],
-        vault0_mint: accounts[14],
-        vault1_mint: accounts[15],
-        remaining_accounts: accounts[16..].to_vec(),
+        nft_owner: acct!(accounts, 0),
+        nft_account: acct!(accounts, 1),
+        personal_position: acct!(accounts, 2),
+        pool_state: acct!(accounts, 3),
+        protocol_position: acct!(accounts, 4),
+        token_vault0: acct!(accounts, 5),
+        token_vault1: acct!(accounts, 6),
+        tick_array_lower: acct!(accounts, 7),
+        tick_array_upper: acct!(accounts, 8),
+        recipient_token_account0: acct!(accounts, 9),
+        recipient_token_account1: acct!(accounts, 10),
+        token_program: acct!(accounts, 11),
+        token_program2022: acct!(accounts, 12),
+        memo_program: acct!(accounts, 13),
+        vault0_mint: acct!(accounts, 14),
+        vault1_mint: acct!(accounts, 15),
+        remaining_accounts: accounts.get(16..)?.to_vec(),
     }))
 }
 
@@ -293,12 +293,12 @@ fn parse_close_position_instruction(
     }
     Some(DexEvent::RaydiumClmmClosePositionEvent(RaydiumClmmClosePositionEvent {
         metadata,
-        nft_owner: accounts[0],
-        position_nft_mint: accounts[1],
-        position_nft_account: accounts[2],
-        personal_position: accounts[3],
-        system_program: accounts[4],
-        token_program: accounts[5],
+        nft_owner: acct!(accounts, 0),
+        position_nft_mint: acct!(accounts, 1),
+        position_nft_account: acct!(accounts, 2),
+        personal_position: acct!(accounts, 3),
+        system_program: acct!(accounts, 4),
+        token_program: acct!(accounts, 5),
     }))
 }
 
@@ -325,17 +325,17 @@ fn parse_swap_instruction(
         other_amount_threshold,
         sqrt_price_limit_x64,
         is_base_input: is_base_input == 1,
-        payer: accounts[0],
-        amm_config: accounts[1],
-        pool_state: accounts[2],
-        input_token_account: accounts[3],
-        output_token_account: accounts[4],
-        input_vault: accounts[5],
-        output_vault: accounts[6],
-        observation_state: accounts[7],
-        token_program: accounts[8],
-        tick_array: accounts[9],
-        remaining_accounts: accounts[10..].to_vec(),
+        payer: acct!(accounts, 0),
+        amm_config: acct!(accounts, 1),
+        pool_state: acct!(accounts, 2),
+        input_token_account: acct!(accounts, 3),
+        output_token_account: acct!(accounts, 4),
+        input_vault: acct!(accounts, 5),
+        output_vault: acct!(accounts, 6),
+        observation_state: acct!(accounts, 7),
+        token_program: acct!(accounts, 8),
+        tick_array: acct!(accounts, 9),
+        remaining_accounts: accounts.get(10..)?.to_vec(),
     }))
 }
 
@@ -361,19 +361,19 @@ fn parse_swap_v2_instruction(
         other_amount_threshold,
         sqrt_price_limit_x64,
         is_base_input: is_base_input == 1,
-        payer: accounts[0],
-        amm_config: accounts[1],
-        pool_state: accounts[2],
-        input_token_account: accounts[3],
-        output_token_account: accounts[4],
-        input_vault: accounts[5],
-        output_vault: accounts[6],
-        observation_state: accounts[7],
-        token_program: accounts[8],
-        token_program2022: accounts[9],
-        memo_program: accounts[10],
-        input_vault_mint: accounts[11],
-        output_vault_mint: accounts[12],
-        remaining_accounts: accounts[13..].to_vec(),
+        payer: acct!(accounts, 0),
+        amm_config: acct!(accounts, 1),
+        pool_state: acct!(accounts, 2),
+        input_token_account: acct!(accounts, 3),
+        output_token_account: acct!(accounts, 4),
+        input_vault: acct!(accounts, 5),
+        output_vault: acct!(accounts, 6),
+        observation_state: acct!(accounts, 7),
+        token_program: acct!(accounts, 8),
+        token_program2022: acct!(accounts, 9),
+        memo_program: acct!(accounts, 10),
+        input_vault_mint: acct!(accounts, 11),
+        output_vault_mint: acct!(accounts, 12),
+        remaining_accounts: accounts.get(13..)?.to_vec(),
     }))
 }
@@ -1,4 +1,5 @@
 use borsh::BorshDeserialize;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
@@ -13,17 +14,23 @@ use crate::streaming::{
     },
     grpc::AccountPretty,
 };
+#[cfg(feature = "rkyv")]
+use crate::streaming::event_parser::common::rkyv_with::PubkeyBytes;
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct AmmConfig {
     pub bump: u8,
     pub index: u16,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
     pub protocol_fee_rate: u32,
     pub trade_fee_rate: u32,
     pub tick_spacing: u16,
     pub fund_fee_rate: u32,
     pub padding_u32: u32,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub fund_owner: Pubkey,
     pub padding: [u64; 3],
 }
@@ -58,7 +65,9 @@ pub fn amm_config_parser(account: &AccountPretty, mut metadata: EventMetadata) -
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct RewardInfo {
     pub reward_state: u8,
     pub open_time: u64,
@@ -67,21 +76,33 @@ pub struct RewardInfo {
     pub emissions_per_second_x64: u128,
     pub reward_total_emissioned: u64,
     pub reward_claimed: u64,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_mint: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_vault: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub authority: Pubkey,
     pub reward_growth_global_x64: u128,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct PoolState {
     pub bump: [u8; 1],
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub amm_config: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub owner: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_mint0: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_mint1: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_vault0: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub token_vault1: Pubkey,
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub observation_key: Pubkey,
     pub mint_decimals0: u8,
     pub mint_decimals1: u8,
@@ -115,6 +136,28 @@ pub struct PoolState {
     pub padding2: [u64; 32],
 }
 
+impl PoolState {
+    /// sqrt(price) 的浮点值（Q64.64 定点数 `sqrt_price_x64 / 2^64`）
+    pub fn sqrt_price(&self) -> f64 {
+        self.sqrt_price_x64 as f64 / Q64
+    }
+
+    /// 原始价格：每单位 token0 可兑换的 token1 数量（最小单位，未按 decimals 调整）
+    pub fn price(&self) -> f64 {
+        let sqrt_price = self.sqrt_price();
+        sqrt_price * sqrt_price
+    }
+
+    /// 按 mint decimals 调整后的价格：每个 token0 可兑换的 token1 数量
+    pub fn price_adjusted(&self) -> f64 {
+        let decimals_diff = self.mint_decimals0 as i32 - self.mint_decimals1 as i32;
+        self.price() * 10f64.powi(decimals_diff)
+    }
+}
+
+/// 2^64，用于 Q64.64 定点数转换
+const Q64: f64 = 18_446_744_073_709_551_616.0;
+
 pub const POOL_STATE_SIZE: usize = 1536;
 
 pub fn pool_state_decode(data: &[u8]) -> Option<PoolState> {
@@ -145,7 +188,9 @@ pub fn pool_state_parser(account: &AccountPretty, mut metadata: EventMetadata) -
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct TickState {
     pub tick: i32,
     pub liquidity_net: i128,
@@ -170,15 +215,18 @@ impl Default for TickState {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct TickArrayState {
+    #[cfg_attr(feature = "rkyv", rkyv(with = PubkeyBytes))]
     pub pool_id: Pubkey,
     pub start_tick_index: i32,
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub ticks: [TickState; 60],
     pub initialized_tick_count: u8,
     pub recent_epoch: u64,
-    #[serde(with = "serde_big_array::BigArray")]
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub padding: [u8; 107],
 }
 
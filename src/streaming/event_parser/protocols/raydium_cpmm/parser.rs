@@ -1,7 +1,7 @@
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{read_u64_le, utils::acct, EventMetadata, EventType},
     protocols::raydium_cpmm::{
         discriminators, RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent, RaydiumCpmmSwapEvent,
         RaydiumCpmmWithdrawEvent,
@@ -84,20 +84,20 @@ fn parse_withdraw_instruction(
         lp_token_amount: read_u64_le(data, 0)?,
         minimum_token0_amount: read_u64_le(data, 8)?,
         minimum_token1_amount: read_u64_le(data, 16)?,
-        owner: accounts[0],
-        authority: accounts[1],
-        pool_state: accounts[2],
-        owner_lp_token: accounts[3],
-        token_0_account: accounts[4],
-        token_1_account: accounts[5],
-        token_0_vault: accounts[6],
-        token_1_vault: accounts[7],
-        token_program: accounts[8],
-        token_program2022: accounts[9],
-        vault_0_mint: accounts[10],
-        vault_1_mint: accounts[11],
-        lp_mint: accounts[12],
-        memo_program: accounts[13],
+        owner: acct!(accounts, 0),
+        authority: acct!(accounts, 1),
+        pool_state: acct!(accounts, 2),
+        owner_lp_token: acct!(accounts, 3),
+        token_0_account: acct!(accounts, 4),
+        token_1_account: acct!(accounts, 5),
+        token_0_vault: acct!(accounts, 6),
+        token_1_vault: acct!(accounts, 7),
+        token_program: acct!(accounts, 8),
+        token_program2022: acct!(accounts, 9),
+        vault_0_mint: acct!(accounts, 10),
+        vault_1_mint: acct!(accounts, 11),
+        lp_mint: acct!(accounts, 12),
+        memo_program: acct!(accounts, 13),
     }))
 }
 
@@ -117,26 +117,26 @@ fn parse_initialize_instruction(
         init_amount0: read_u64_le(data, 0)?,
         init_amount1: read_u64_le(data, 8)?,
         open_time: read_u64_le(data, 16)?,
-        creator: accounts[0],
-        amm_config: accounts[1],
-        authority: accounts[2],
-        pool_state: accounts[3],
-        token_0_mint: accounts[4],
-        token_1_mint: accounts[5],
-        lp_mint: accounts[6],
-        creator_token_0: accounts[7],
-        creator_token_1: accounts[8],
-        creator_lp_token: accounts[9],
-        token_0_vault: accounts[10],
-        token_1_vault: accounts[11],
-        create_pool_fee: accounts[12],
-        observation_state: accounts[13],
-        token_program: accounts[14],
-        token_0_program: accounts[15],
-        token_1_program: accounts[16],
-        associated_token_program: accounts[17],
-        system_program: accounts[18],
-        rent: accounts[19],
+        creator: acct!(accounts, 0),
+        amm_config: acct!(accounts, 1),
+        authority: acct!(accounts, 2),
+        pool_state: acct!(accounts, 3),
+        token_0_mint: acct!(accounts, 4),
+        token_1_mint: acct!(accounts, 5),
+        lp_mint: acct!(accounts, 6),
+        creator_token_0: acct!(accounts, 7),
+        creator_token_1: acct!(accounts, 8),
+        creator_lp_token: acct!(accounts, 9),
+        token_0_vault: acct!(accounts, 10),
+        token_1_vault: acct!(accounts, 11),
+        create_pool_fee: acct!(accounts, 12),
+        observation_state: acct!(accounts, 13),
+        token_program: acct!(accounts, 14),
+        token_0_program: acct!(accounts, 15),
+        token_1_program: acct!(accounts, 16),
+        associated_token_program: acct!(accounts, 17),
+        system_program: acct!(accounts, 18),
+        rent: acct!(accounts, 19),
     }))
 }
 
@@ -156,19 +156,19 @@ fn parse_deposit_instruction(
         lp_token_amount: read_u64_le(data, 0)?,
         maximum_token0_amount: read_u64_le(data, 8)?,
         maximum_token1_amount: read_u64_le(data, 16)?,
-        owner: accounts[0],
-        authority: accounts[1],
-        pool_state: accounts[2],
-        owner_lp_token: accounts[3],
-        token_0_account: accounts[4],
-        token_1_account: accounts[5],
-        token_0_vault: accounts[6],
-        token_1_vault: accounts[7],
-        token_program: accounts[8],
-        token_program2022: accounts[9],
-        vault_0_mint: accounts[10],
-        vault_1_mint: accounts[11],
-        lp_mint: accounts[12],
+        owner: acct!(accounts, 0),
+        authority: acct!(accounts, 1),
+        pool_state: acct!(accounts, 2),
+        owner_lp_token: acct!(accounts, 3),
+        token_0_account: acct!(accounts, 4),
+        token_1_account: acct!(accounts, 5),
+        token_0_vault: acct!(accounts, 6),
+        token_1_vault: acct!(accounts, 7),
+        token_program: acct!(accounts, 8),
+        token_program2022: acct!(accounts, 9),
+        vault_0_mint: acct!(accounts, 10),
+        vault_1_mint: acct!(accounts, 11),
+        lp_mint: acct!(accounts, 12),
     }))
 }
 
@@ -191,19 +191,19 @@ fn parse_swap_base_input_instruction(
         metadata,
         amount_in,
         minimum_amount_out,
-        payer: accounts[0],
-        authority: accounts[1],
-        amm_config: accounts[2],
-        pool_state: accounts[3],
-        input_token_account: accounts[4],
-        output_token_account: accounts[5],
-        input_vault: accounts[6],
-        output_vault: accounts[7],
-        input_token_program: accounts[8],
-        output_token_program: accounts[9],
-        input_token_mint: accounts[10],
-        output_token_mint: accounts[11],
-        observation_state: accounts[12],
+        payer: acct!(accounts, 0),
+        authority: acct!(accounts, 1),
+        amm_config: acct!(accounts, 2),
+        pool_state: acct!(accounts, 3),
+        input_token_account: acct!(accounts, 4),
+        output_token_account: acct!(accounts, 5),
+        input_vault: acct!(accounts, 6),
+        output_vault: acct!(accounts, 7),
+        input_token_program: acct!(accounts, 8),
+        output_token_program: acct!(accounts, 9),
+        input_token_mint: acct!(accounts, 10),
+        output_token_mint: acct!(accounts, 11),
+        observation_state: acct!(accounts, 12),
         ..Default::default()
     }))
 }
@@ -226,19 +226,19 @@ fn parse_swap_base_output_instruction(
         metadata,
         max_amount_in,
         amount_out,
-        payer: accounts[0],
-        authority: accounts[1],
-        amm_config: accounts[2],
-        pool_state: accounts[3],
-        input_token_account: accounts[4],
-        output_token_account: accounts[5],
-        input_vault: accounts[6],
-        output_vault: accounts[7],
-        input_token_program: accounts[8],
-        output_token_program: accounts[9],
-        input_token_mint: accounts[10],
-        output_token_mint: accounts[11],
-        observation_state: accounts[12],
+        payer: acct!(accounts, 0),
+        authority: acct!(accounts, 1),
+        amm_config: acct!(accounts, 2),
+        pool_state: acct!(accounts, 3),
+        input_token_account: acct!(accounts, 4),
+        output_token_account: acct!(accounts, 5),
+        input_vault: acct!(accounts, 6),
+        output_vault: acct!(accounts, 7),
+        input_token_program: acct!(accounts, 8),
+        output_token_program: acct!(accounts, 9),
+        input_token_mint: acct!(accounts, 10),
+        output_token_mint: acct!(accounts, 11),
+        observation_state: acct!(accounts, 12),
         ..Default::default()
     }))
 }
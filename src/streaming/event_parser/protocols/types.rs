@@ -4,6 +4,7 @@ use crate::streaming::event_parser::protocols::{
     raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID, raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
     raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID,
 };
+use crate::streaming::event_parser::common::ProtocolType;
 use anyhow::{anyhow, Result};
 use solana_sdk::pubkey::Pubkey;
 
@@ -33,6 +34,38 @@ impl Protocol {
     }
 }
 
+impl From<Protocol> for ProtocolType {
+    fn from(protocol: Protocol) -> Self {
+        match protocol {
+            Protocol::PumpSwap => ProtocolType::PumpSwap,
+            Protocol::PumpFun => ProtocolType::PumpFun,
+            Protocol::Bonk => ProtocolType::Bonk,
+            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
+            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
+            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
+            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+        }
+    }
+}
+
+impl TryFrom<ProtocolType> for Protocol {
+    type Error = anyhow::Error;
+
+    /// `ProtocolType::Common` 不对应任何具体协议，转换失败
+    fn try_from(protocol_type: ProtocolType) -> Result<Self, Self::Error> {
+        match protocol_type {
+            ProtocolType::PumpSwap => Ok(Protocol::PumpSwap),
+            ProtocolType::PumpFun => Ok(Protocol::PumpFun),
+            ProtocolType::Bonk => Ok(Protocol::Bonk),
+            ProtocolType::RaydiumCpmm => Ok(Protocol::RaydiumCpmm),
+            ProtocolType::RaydiumClmm => Ok(Protocol::RaydiumClmm),
+            ProtocolType::RaydiumAmmV4 => Ok(Protocol::RaydiumAmmV4),
+            ProtocolType::MeteoraDammV2 => Ok(Protocol::MeteoraDammV2),
+            ProtocolType::Common => Err(anyhow!("ProtocolType::Common has no matching Protocol")),
+        }
+    }
+}
+
 impl std::fmt::Display for Protocol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1,4 +1,5 @@
 use super::types::{AccountPretty, BlockMetaPretty, TransactionPretty};
+use crate::streaming::common::constants::POOL_TRIM_BUFFER_BASELINE;
 use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::collections::VecDeque;
@@ -15,6 +16,80 @@ pub trait ObjectPool<T> {
     fn return_object(&self, obj: Box<T>);
 }
 
+/// 单个对象池的内存占用估算
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// 池中空闲对象数量
+    pub pooled_objects: usize,
+    /// 池的最大容量
+    pub max_size: usize,
+    /// 估算字节数：对象本身大小 + 对象持有的堆内存容量 + 队列自身容量
+    pub estimated_bytes: usize,
+}
+
+/// 所有对象池的内存占用估算
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolMemoryStats {
+    pub account: PoolStats,
+    pub block_meta: PoolStats,
+    pub transaction: PoolStats,
+}
+
+impl PoolMemoryStats {
+    /// 所有对象池的估算字节数之和
+    pub fn total_bytes(&self) -> usize {
+        self.account.estimated_bytes
+            + self.block_meta.estimated_bytes
+            + self.transaction.estimated_bytes
+    }
+}
+
+/// 遍历池中对象估算内存占用，`held_bytes` 返回单个对象持有的堆内存容量
+fn estimate_pool<T>(
+    pool: &Mutex<VecDeque<Box<T>>>,
+    max_size: usize,
+    held_bytes: impl Fn(&T) -> usize,
+) -> PoolStats {
+    let pool = pool.lock().unwrap_or_else(|e| e.into_inner());
+    let objects_bytes: usize =
+        pool.iter().map(|object| std::mem::size_of::<T>() + held_bytes(object)).sum();
+    PoolStats {
+        pooled_objects: pool.len(),
+        max_size,
+        estimated_bytes: pool.capacity() * std::mem::size_of::<Box<T>>() + objects_bytes,
+    }
+}
+
+/// 丢弃超过 `target_size` 的空闲对象并收缩剩余对象的缓冲区，返回丢弃的对象数量
+///
+/// 全程持有池锁，与并发的 acquire / 归还互斥；被丢弃的对象在释放锁之后再析构
+fn trim_pool<T>(
+    pool: &Mutex<VecDeque<Box<T>>>,
+    target_size: usize,
+    shrink: impl Fn(&mut T),
+) -> usize {
+    let excess = {
+        let mut pool = pool.lock().unwrap_or_else(|e| e.into_inner());
+        let at = target_size.min(pool.len());
+        let excess = pool.split_off(at);
+        for object in pool.iter_mut() {
+            shrink(object);
+        }
+        pool.shrink_to(target_size);
+        excess
+    };
+    excess.len()
+}
+
+/// 在持有池锁时 panic，使锁进入中毒状态
+#[cfg(feature = "test-fixtures")]
+fn poison_pool<T>(pool: &Mutex<VecDeque<Box<T>>>) {
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _pool = pool.lock().unwrap_or_else(|e| e.into_inner());
+        panic!("object pool poisoned on purpose");
+    }));
+}
+
 /// 带自动归还的智能指针
 pub struct PooledObject<T> {
     object: Option<Box<T>>,
@@ -32,7 +107,8 @@ impl<T> PooledObject<T> {
 impl<T> Drop for PooledObject<T> {
     fn drop(&mut self) {
         if let Some(obj) = self.object.take() {
-            let mut pool = self.pool.lock().unwrap();
+            // 锁中毒（持锁线程 panic）时直接复用内部数据，避免单次 panic 级联到所有后续操作
+            let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
             if pool.len() < self.max_size {
                 pool.push_back(obj);
             }
@@ -74,7 +150,7 @@ impl AccountPrettyPool {
     }
 
     pub fn acquire(&self) -> PooledAccountPretty {
-        let mut pool = self.pool.lock().unwrap();
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         let account = match pool.pop_front() {
             Some(reused) => reused,
             None => Box::new(AccountPretty::default()),
@@ -82,6 +158,30 @@ impl AccountPrettyPool {
 
         PooledAccountPretty { account, pool: Arc::clone(&self.pool), max_size: self.max_size }
     }
+
+    /// 估算内存占用（按 data 的实际容量累加）
+    pub fn memory_estimate(&self) -> PoolStats {
+        estimate_pool(&self.pool, self.max_size, |account| account.data.capacity())
+    }
+
+    /// 将空闲对象裁剪到 `target_size` 个，并把 data 容量收缩到基线，返回丢弃的对象数量
+    pub fn trim(&self, target_size: usize) -> usize {
+        trim_pool(&self.pool, target_size, |account| {
+            account.data.shrink_to(POOL_TRIM_BUFFER_BASELINE)
+        })
+    }
+
+    /// 在持有池锁时 panic 使锁中毒，用于验证中毒后仍能正常取出与归还对象
+    #[cfg(feature = "test-fixtures")]
+    pub fn poison(&self) {
+        poison_pool(&self.pool);
+    }
+
+    /// 池锁是否已中毒
+    #[cfg(feature = "test-fixtures")]
+    pub fn is_poisoned(&self) -> bool {
+        self.pool.is_poisoned()
+    }
 }
 
 /// 带自动归还的 AccountPretty
@@ -124,7 +224,7 @@ impl PooledAccountPretty {
 
 impl Drop for PooledAccountPretty {
     fn drop(&mut self) {
-        let mut pool = self.pool.lock().unwrap();
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         if pool.len() < self.max_size {
             // 清理敏感数据
             self.account.data.clear();
@@ -169,7 +269,7 @@ impl BlockMetaPrettyPool {
     }
 
     pub fn acquire(&self) -> PooledBlockMetaPretty {
-        let mut pool = self.pool.lock().unwrap();
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         let block_meta = match pool.pop_front() {
             Some(reused) => reused,
             None => Box::new(BlockMetaPretty::default()),
@@ -177,6 +277,30 @@ impl BlockMetaPrettyPool {
 
         PooledBlockMetaPretty { block_meta, pool: Arc::clone(&self.pool), max_size: self.max_size }
     }
+
+    /// 估算内存占用
+    pub fn memory_estimate(&self) -> PoolStats {
+        estimate_pool(&self.pool, self.max_size, |block_meta| block_meta.block_hash.capacity())
+    }
+
+    /// 将空闲对象裁剪到 `target_size` 个，返回丢弃的对象数量
+    pub fn trim(&self, target_size: usize) -> usize {
+        trim_pool(&self.pool, target_size, |block_meta| {
+            block_meta.block_hash.shrink_to(POOL_TRIM_BUFFER_BASELINE)
+        })
+    }
+
+    /// 在持有池锁时 panic 使锁中毒，用于验证中毒后仍能正常取出与归还对象
+    #[cfg(feature = "test-fixtures")]
+    pub fn poison(&self) {
+        poison_pool(&self.pool);
+    }
+
+    /// 池锁是否已中毒
+    #[cfg(feature = "test-fixtures")]
+    pub fn is_poisoned(&self) -> bool {
+        self.pool.is_poisoned()
+    }
 }
 
 /// 带自动归还的 BlockMetaPretty
@@ -202,7 +326,7 @@ impl PooledBlockMetaPretty {
 
 impl Drop for PooledBlockMetaPretty {
     fn drop(&mut self) {
-        let mut pool = self.pool.lock().unwrap();
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         if pool.len() < self.max_size {
             // 清理数据
             self.block_meta.block_hash.clear();
@@ -245,7 +369,7 @@ impl TransactionPrettyPool {
     }
 
     pub fn acquire(&self) -> PooledTransactionPretty {
-        let mut pool = self.pool.lock().unwrap();
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         let transaction = match pool.pop_front() {
             Some(reused) => reused,
             None => Box::new(TransactionPretty::default()),
@@ -257,6 +381,33 @@ impl TransactionPrettyPool {
             max_size: self.max_size,
         }
     }
+
+    /// 估算内存占用
+    ///
+    /// 只计入 block_hash 的容量：`grpc_tx` 在取出对象时已被整体移走，池中对象不持有其缓冲区
+    pub fn memory_estimate(&self) -> PoolStats {
+        estimate_pool(&self.pool, self.max_size, |transaction| transaction.block_hash.capacity())
+    }
+
+    /// 将空闲对象裁剪到 `target_size` 个，并释放剩余对象持有的交易数据，返回丢弃的对象数量
+    pub fn trim(&self, target_size: usize) -> usize {
+        trim_pool(&self.pool, target_size, |transaction| {
+            transaction.block_hash.shrink_to(POOL_TRIM_BUFFER_BASELINE);
+            transaction.grpc_tx = Default::default();
+        })
+    }
+
+    /// 在持有池锁时 panic 使锁中毒，用于验证中毒后仍能正常取出与归还对象
+    #[cfg(feature = "test-fixtures")]
+    pub fn poison(&self) {
+        poison_pool(&self.pool);
+    }
+
+    /// 池锁是否已中毒
+    #[cfg(feature = "test-fixtures")]
+    pub fn is_poisoned(&self) -> bool {
+        self.pool.is_poisoned()
+    }
 }
 
 /// 带自动归还的 TransactionPretty
@@ -289,7 +440,7 @@ impl PooledTransactionPretty {
 
 impl Drop for PooledTransactionPretty {
     fn drop(&mut self) {
-        let mut pool = self.pool.lock().unwrap();
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         if pool.len() < self.max_size {
             // 清理数据
             self.transaction.block_hash.clear();
@@ -359,6 +510,27 @@ impl PoolManager {
     pub fn get_event_pool(&self) -> &EventPrettyPool {
         &self.event_pool
     }
+
+    /// 估算各对象池的内存占用
+    ///
+    /// 需要遍历池中对象（持锁期间只读取容量），开销与池大小成正比，适合周期性调用
+    pub fn memory_estimate(&self) -> PoolMemoryStats {
+        PoolMemoryStats {
+            account: self.event_pool.account_pool.memory_estimate(),
+            block_meta: self.event_pool.block_pool.memory_estimate(),
+            transaction: self.event_pool.transaction_pool.memory_estimate(),
+        }
+    }
+
+    /// 将各对象池的空闲对象裁剪到最多 `target_size` 个，并收缩保留对象的缓冲区，返回丢弃的对象总数
+    ///
+    /// 用于流量高峰过后回收内存（例如在空闲定时器中调用），可与正在进行的 acquire / 归还并发调用。
+    /// 池之后仍会按需增长到 `max_size`
+    pub fn trim(&self, target_size: usize) -> usize {
+        self.event_pool.account_pool.trim(target_size)
+            + self.event_pool.block_pool.trim(target_size)
+            + self.event_pool.transaction_pool.trim(target_size)
+    }
 }
 
 impl Default for PoolManager {
@@ -4,7 +4,8 @@ use tonic::{transport::channel::ClientTlsConfig, Status};
 use yellowstone_grpc_client::{GeyserGrpcClient, Interceptor};
 use yellowstone_grpc_proto::geyser::{
     CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccounts,
-    SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterTransactions, SubscribeUpdate,
+    SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterSlots,
+    SubscribeRequestFilterTransactions, SubscribeUpdate,
 };
 
 use super::types::AccountsFilterMap;
@@ -46,6 +47,7 @@ impl SubscriptionManager {
         transactions: Option<TransactionsFilterMap>,
         accounts: Option<AccountsFilterMap>,
         commitment: Option<CommitmentLevel>,
+        from_slot: Option<u64>,
         event_type_filter: Option<&EventTypeFilter>,
     ) -> AnyResult<(
         impl Sink<SubscribeRequest, Error = mpsc::SendError>,
@@ -60,15 +62,31 @@ impl SubscriptionManager {
             } else {
                 HashMap::new()
             };
+        // 槽位状态需显式开启；Dead 属于中间状态更新，需要 interslot_updates，其余中间状态在客户端丢弃
+        let slots = if self.config.slot_status
+            && event_type_filter.map_or(true, |filter| filter.include_slot_event())
+        {
+            HashMap::from([(
+                "".to_owned(),
+                SubscribeRequestFilterSlots {
+                    filter_by_commitment: Some(false),
+                    interslot_updates: Some(true),
+                },
+            )])
+        } else {
+            HashMap::new()
+        };
         let subscribe_request = SubscribeRequest {
             accounts: accounts.unwrap_or_default(),
             transactions: transactions.unwrap_or_default(),
             blocks_meta,
+            slots,
             commitment: if let Some(commitment) = commitment {
                 Some(commitment as i32)
             } else {
                 Some(CommitmentLevel::Processed.into())
             },
+            from_slot,
             ..Default::default()
         };
         let mut client = self.connect().await?;
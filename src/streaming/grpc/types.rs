@@ -2,12 +2,14 @@ use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::{collections::HashMap, fmt};
 use yellowstone_grpc_proto::{
     geyser::{
-        SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions,
-        SubscribeUpdateTransactionInfo,
+        SlotStatus as GrpcSlotStatus, SubscribeRequestFilterAccounts,
+        SubscribeRequestFilterTransactions, SubscribeUpdateSlot, SubscribeUpdateTransactionInfo,
     },
     prost_types::Timestamp,
 };
 
+use crate::streaming::event_parser::protocols::SlotStatus;
+
 pub type TransactionsFilterMap = HashMap<String, SubscribeRequestFilterTransactions>;
 pub type AccountsFilterMap = HashMap<String, SubscribeRequestFilterAccounts>;
 
@@ -16,6 +18,7 @@ pub enum EventPretty {
     BlockMeta(BlockMetaPretty),
     Transaction(TransactionPretty),
     Account(AccountPretty),
+    SlotStatus(SlotPretty),
 }
 
 #[derive(Clone, Default)]
@@ -65,6 +68,35 @@ impl fmt::Debug for BlockMetaPretty {
     }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct SlotPretty {
+    pub slot: u64,
+    pub parent: Option<u64>,
+    pub status: SlotStatus,
+    pub dead_error: Option<String>,
+    pub recv_us: i64,
+}
+
+impl SlotPretty {
+    /// 从 gRPC 槽位更新构造；首个 shred、bank 创建、槽位完成等中间状态返回 None
+    pub fn from_update(update: SubscribeUpdateSlot, recv_us: i64) -> Option<Self> {
+        let status = match GrpcSlotStatus::try_from(update.status).ok()? {
+            GrpcSlotStatus::SlotProcessed => SlotStatus::Processed,
+            GrpcSlotStatus::SlotConfirmed => SlotStatus::Confirmed,
+            GrpcSlotStatus::SlotFinalized => SlotStatus::Finalized,
+            GrpcSlotStatus::SlotDead => SlotStatus::Dead,
+            _ => return None,
+        };
+        Some(Self {
+            slot: update.slot,
+            parent: update.parent,
+            status,
+            dead_error: update.dead_error,
+            recv_us,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct TransactionPretty {
     pub slot: u64,
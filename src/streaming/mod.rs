@@ -1,11 +1,15 @@
+pub mod builder;
 pub mod common;
 pub mod event_parser;
 pub mod grpc;
 pub mod shred;
 pub mod shred_stream;
+#[cfg(feature = "test-fixtures")]
+pub mod test_fixtures;
 pub mod yellowstone_grpc;
 pub mod yellowstone_sub_system;
 
+pub use builder::StreamClientBuilder;
 pub use shred::ShredStreamGrpc;
 pub use yellowstone_grpc::YellowstoneGrpc;
 pub use yellowstone_sub_system::{SystemEvent, TransferInfo};
@@ -1,19 +1,52 @@
+use anyhow::anyhow;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use tonic::transport::Channel;
 
 use crate::common::AnyResult;
 use crate::protos::shredstream::shredstream_proxy_client::ShredstreamProxyClient;
 use crate::streaming::common::{
-    MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
+    ActiveProtocols, ConnectionConfig, EventEnricher, MetricsManager, PerformanceMetrics,
+    SlotSummaryCallback, StreamClientConfig, SubscriptionHandle, SubscriptionId,
+    SubscriptionMetricsSnapshot, LOG_TARGET, MAX_CONNECT_RETRY_DELAY_MS,
+};
+use crate::streaming::event_parser::Protocol;
+use crate::streaming::shred::{
+    DebugSnapshot, ProxyLeadCounters, ProxyLeadStats, ShredErrorCallback, GLOBAL_SHRED_POOL_MANAGER,
 };
 
 /// ShredStream gRPC 客户端
+///
+/// 克隆共享同一个订阅；最后一个克隆被 drop 时订阅任务随之中止，尚未送达 callback 的事件会被丢弃。
+/// 需要在 drop 之前确定地结束订阅时调用 `stop`
 #[derive(Clone)]
 pub struct ShredStreamGrpc {
-    pub shredstream_client: Arc<ShredstreamProxyClient<Channel>>,
+    /// 主代理客户端（即 `shredstream_clients[0]`），仅使用自定义数据来源时为 None
+    pub shredstream_client: Option<Arc<ShredstreamProxyClient<Channel>>>,
+    /// 所有代理客户端，多个代理时同时订阅并按签名去重
+    pub shredstream_clients: Vec<Arc<ShredstreamProxyClient<Channel>>>,
+    /// 代理地址，与 `shredstream_clients` 一一对应
+    pub endpoints: Vec<String>,
+    /// 每个代理最先送达的交易数，与 `endpoints` 一一对应
+    pub first_delivery_counts: Arc<Vec<AtomicU64>>,
+    /// 每个代理的领先统计计数器，与 `endpoints` 一一对应
+    pub proxy_lead_counters: Arc<Vec<ProxyLeadCounters>>,
     pub config: StreamClientConfig,
     pub subscription_handle: Arc<Mutex<Option<SubscriptionHandle>>>,
+    /// 解码失败回调（可选）
+    pub error_callback: Option<ShredErrorCallback>,
+    /// 事件增强钩子（可选），在用户 callback 之前运行于解析线程
+    pub enricher: Option<EventEnricher>,
+    /// 槽位汇总回调（可选），ShredStream 没有区块元数据，每个槽位在超时后输出
+    pub slot_summary_callback: Option<SlotSummaryCallback>,
+    /// 当前处理的协议列表，可通过 `set_active_protocols` 在运行时修改
+    pub active_protocols: ActiveProtocols,
+    /// 该客户端记录指标使用的实例，默认为全局实例，见 `set_metrics_manager`
+    pub metrics_manager: MetricsManager,
+    /// 创建客户端时连接代理的重试次数（所有代理合计）
+    pub connect_retries: u64,
 }
 
 impl ShredStreamGrpc {
@@ -24,15 +57,74 @@ impl ShredStreamGrpc {
 
     /// 创建客户端，使用自定义配置
     pub async fn new_with_config(endpoint: String, config: StreamClientConfig) -> AnyResult<Self> {
-        let shredstream_client = ShredstreamProxyClient::connect(endpoint.clone()).await?;
+        Self::new_multi_with_config(vec![endpoint], config).await
+    }
+
+    /// 创建连接多个 ShredStream 代理的客户端，使用默认配置
+    pub async fn new_multi(endpoints: Vec<String>) -> AnyResult<Self> {
+        Self::new_multi_with_config(endpoints, StreamClientConfig::default()).await
+    }
+
+    /// 创建连接多个 ShredStream 代理的客户端，使用自定义配置
+    ///
+    /// 订阅时会同时消费所有代理的 entries，并按交易签名去重（跨代理重复是常态）。
+    /// 代理尚未就绪时按 `ConnectionConfig::connect_max_attempts` 重试连接。
+    /// 订阅时无法订阅的代理会被记录日志并跳过，所有代理都失败时才返回错误
+    pub async fn new_multi_with_config(
+        endpoints: Vec<String>,
+        config: StreamClientConfig,
+    ) -> AnyResult<Self> {
+        if endpoints.is_empty() {
+            return Err(anyhow!("At least one ShredStream endpoint is required"));
+        }
+        let mut shredstream_clients = Vec::with_capacity(endpoints.len());
+        let mut connect_retries = 0;
+        for endpoint in &endpoints {
+            let (client, retries) = connect_with_retry(endpoint, &config.connection).await?;
+            shredstream_clients.push(Arc::new(client));
+            connect_retries += retries;
+        }
         MetricsManager::init(config.enable_metrics);
         Ok(Self {
-            shredstream_client: Arc::new(shredstream_client),
+            shredstream_client: Some(shredstream_clients[0].clone()),
+            first_delivery_counts: Arc::new(endpoints.iter().map(|_| AtomicU64::new(0)).collect()),
+            proxy_lead_counters: Arc::new(
+                endpoints.iter().map(|_| ProxyLeadCounters::default()).collect(),
+            ),
+            shredstream_clients,
+            endpoints,
             config,
             subscription_handle: Arc::new(Mutex::new(None)),
+            error_callback: None,
+            enricher: None,
+            slot_summary_callback: None,
+            active_protocols: ActiveProtocols::default(),
+            metrics_manager: MetricsManager::global(),
+            connect_retries,
         })
     }
 
+    /// 创建不连接 gRPC 代理的客户端，只能通过 `subscribe_with_sources` 订阅自定义数据来源
+    /// （例如 `websocket` feature 提供的 `WebSocketShredSource`）
+    pub fn new_for_sources(config: StreamClientConfig) -> Self {
+        MetricsManager::init(config.enable_metrics);
+        Self {
+            shredstream_client: None,
+            shredstream_clients: Vec::new(),
+            endpoints: Vec::new(),
+            first_delivery_counts: Arc::new(Vec::new()),
+            proxy_lead_counters: Arc::new(Vec::new()),
+            config,
+            subscription_handle: Arc::new(Mutex::new(None)),
+            error_callback: None,
+            enricher: None,
+            slot_summary_callback: None,
+            active_protocols: ActiveProtocols::default(),
+            metrics_manager: MetricsManager::global(),
+            connect_retries: 0,
+        }
+    }
+
     /// 获取当前配置
     pub fn get_config(&self) -> &StreamClientConfig {
         &self.config
@@ -43,9 +135,108 @@ impl ShredStreamGrpc {
         self.config = config;
     }
 
+    /// 设置解码失败回调，在下一次订阅时生效
+    pub fn set_error_callback(&mut self, callback: Option<ShredErrorCallback>) {
+        self.error_callback = callback;
+    }
+
+    /// 设置事件增强钩子，在下一次订阅时生效
+    ///
+    /// 钩子在解析线程上同步执行，必须足够快
+    pub fn set_enricher(&mut self, enricher: Option<EventEnricher>) {
+        self.enricher = enricher;
+    }
+
+    /// 设置槽位汇总回调，在下一次订阅时生效
+    ///
+    /// ShredStream 没有区块元数据，每个槽位在第一个事件之后 `slot_summary_timeout_ms` 输出，
+    /// 汇总的 `timed_out` 恒为 true
+    pub fn set_slot_summary_callback(&mut self, callback: Option<SlotSummaryCallback>) {
+        self.slot_summary_callback = callback;
+    }
+
+    /// 运行时替换处理的协议列表，无需重新订阅，从下一条消息开始生效
+    pub fn set_active_protocols(&self, protocols: Vec<Protocol>) {
+        self.active_protocols.store(protocols);
+    }
+
+    /// 获取当前处理的协议列表
+    pub fn get_active_protocols(&self) -> Vec<Protocol> {
+        self.active_protocols.load().to_vec()
+    }
+
+    /// 获取每个代理最先送达的交易数 `(endpoint, count)`
+    pub fn get_first_delivery_counts(&self) -> Vec<(String, u64)> {
+        self.endpoints
+            .iter()
+            .zip(self.first_delivery_counts.iter())
+            .map(|(endpoint, count)| (endpoint.clone(), count.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// 获取每个代理的领先统计 `(endpoint, stats)`：最先送达数、领先其它代理的时间，以及设置
+    /// `primary_proxy` 时主代理超时后改用该代理副本的次数
+    pub fn get_proxy_lead_stats(&self) -> Vec<(String, ProxyLeadStats)> {
+        self.endpoints
+            .iter()
+            .zip(self.first_delivery_counts.iter())
+            .zip(self.proxy_lead_counters.iter())
+            .map(|((endpoint, count), counters)| {
+                (endpoint.clone(), counters.snapshot(count.load(Ordering::Relaxed)))
+            })
+            .collect()
+    }
+
     /// 获取性能指标
     pub fn get_metrics(&self) -> PerformanceMetrics {
-        MetricsManager::global().get_metrics()
+        self.metrics_manager.get_metrics()
+    }
+
+    /// 设置该客户端使用的指标实例，在下一次订阅时生效
+    ///
+    /// 传入 `MetricsManager::new()` 时该客户端的指标与其它客户端及全局实例互不影响，
+    /// `get_metrics` / `print_metrics` 只反映该客户端自己的统计
+    pub fn set_metrics_manager(&mut self, metrics_manager: MetricsManager) {
+        metrics_manager.configure(self.config.enable_metrics);
+        self.metrics_manager = metrics_manager;
+    }
+
+    /// 当前订阅的 ID，未订阅时返回 None；日志中以 `[sub-N]` 标识该订阅
+    pub async fn subscription_id(&self) -> Option<SubscriptionId> {
+        self.subscription_handle.lock().await.as_ref().map(SubscriptionHandle::id)
+    }
+
+    /// 获取当前订阅的指标，未订阅时返回 None
+    pub async fn get_subscription_metrics(&self) -> Option<SubscriptionMetricsSnapshot> {
+        let subscription_id = self.subscription_id().await?;
+        self.metrics_manager.get_subscription_metrics(subscription_id)
+    }
+
+    /// 汇总客户端内部状态：订阅是否运行、最后一条消息的时间、对象池占用、指标快照、
+    /// 处理的协议与连接重试次数
+    ///
+    /// 只读取计数器并复制少量数据，可以随时调用（例如在管理接口中）
+    pub async fn debug_snapshot(&self) -> DebugSnapshot {
+        let (is_subscribed, subscription_id) = {
+            let handle_guard = self.subscription_handle.lock().await;
+            match handle_guard.as_ref() {
+                Some(handle) => (!handle.is_finished(), Some(handle.id())),
+                None => (false, None),
+            }
+        };
+        let pool = GLOBAL_SHRED_POOL_MANAGER.get_transaction_pool();
+        DebugSnapshot {
+            is_subscribed,
+            subscription: subscription_id
+                .and_then(|id| self.metrics_manager.get_subscription_metrics(id)),
+            active_protocols: self.get_active_protocols(),
+            endpoints: self.endpoints.clone(),
+            proxy_lead_stats: self.get_proxy_lead_stats(),
+            connect_retries: self.connect_retries,
+            pool_available: pool.available(),
+            pool_max_size: pool.max_size(),
+            metrics: self.get_metrics(),
+        }
     }
 
     /// 启用或禁用性能监控
@@ -55,15 +246,15 @@ impl ShredStreamGrpc {
 
     /// 打印性能指标
     pub fn print_metrics(&self) {
-        MetricsManager::global().print_metrics();
+        self.metrics_manager.print_metrics();
     }
 
     /// 启动自动性能监控任务
     pub async fn start_auto_metrics_monitoring(&self) {
-        MetricsManager::global().start_auto_monitoring().await;
+        self.metrics_manager.start_auto_monitoring().await;
     }
 
-    /// 停止当前订阅
+    /// 停止当前订阅，尚未完成的解析任务和 callback 线程中排队的事件直接丢弃
     pub async fn stop(&self) {
         let mut handle_guard = self.subscription_handle.lock().await;
         if let Some(handle) = handle_guard.take() {
@@ -71,3 +262,32 @@ impl ShredStreamGrpc {
         }
     }
 }
+
+/// 连接 ShredStream 代理，失败时按 `ConnectionConfig` 的设置以指数退避重试，同时返回重试次数
+async fn connect_with_retry(
+    endpoint: &str,
+    connection: &ConnectionConfig,
+) -> AnyResult<(ShredstreamProxyClient<Channel>, u64)> {
+    let max_attempts = connection.connect_max_attempts.max(1);
+    let mut delay = Duration::from_millis(connection.connect_retry_delay_ms);
+    let mut attempt = 1;
+    loop {
+        match ShredstreamProxyClient::connect(endpoint.to_string()).await {
+            Ok(client) => return Ok((client, u64::from(attempt - 1))),
+            Err(e) if attempt >= max_attempts => {
+                return Err(anyhow!(
+                    "Failed to connect to ShredStream endpoint {endpoint} after {attempt} attempt(s): {e}"
+                ));
+            }
+            Err(e) => {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "ShredStream endpoint {endpoint} not ready (attempt {attempt}/{max_attempts}): {e}, retrying in {delay:?}"
+                );
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(Duration::from_millis(MAX_CONNECT_RETRY_DELAY_MS));
+                attempt += 1;
+            }
+        }
+    }
+}
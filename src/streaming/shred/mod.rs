@@ -1,12 +1,18 @@
 // ShredStream 相关模块
 pub mod connection;
 pub mod pool;
+pub mod source;
 pub mod types;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 // 重新导出主要类型
 pub use connection::*;
 pub use pool::*;
+pub use source::*;
 pub use types::*;
+#[cfg(feature = "websocket")]
+pub use websocket::*;
 
 // 从公用模块重新导出
 pub use crate::streaming::common::{
@@ -25,7 +25,8 @@ impl TransactionWithSlotPool {
     }
 
     pub fn acquire(&self) -> PooledTransactionWithSlot {
-        let mut pool = self.pool.lock().unwrap();
+        // 锁中毒（持锁线程 panic）时直接复用内部数据，避免单次 panic 级联到所有后续操作
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         let transaction = match pool.pop_front() {
             Some(reused) => reused,
             None => Box::new(TransactionWithSlot::default()),
@@ -37,6 +38,31 @@ impl TransactionWithSlotPool {
             max_size: self.max_size 
         }
     }
+
+    /// 池中当前可复用的对象数
+    pub fn available(&self) -> usize {
+        self.pool.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 池的容量上限，超出部分归还时直接释放
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// 在持有池锁时 panic 使锁中毒，用于验证中毒后仍能正常取出与归还对象
+    #[cfg(feature = "test-fixtures")]
+    pub fn poison(&self) {
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
+            panic!("object pool poisoned on purpose");
+        }));
+    }
+
+    /// 池锁是否已中毒
+    #[cfg(feature = "test-fixtures")]
+    pub fn is_poisoned(&self) -> bool {
+        self.pool.is_poisoned()
+    }
 }
 
 /// 带自动归还的 TransactionWithSlot
@@ -70,7 +96,7 @@ impl PooledTransactionWithSlot {
 
 impl Drop for PooledTransactionWithSlot {
     fn drop(&mut self) {
-        let mut pool = self.pool.lock().unwrap();
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
         if pool.len() < self.max_size {
             // 清理敏感数据
             self.transaction.slot = 0;
@@ -0,0 +1,56 @@
+use futures::StreamExt;
+use prost::Message as _;
+use tonic::codec::Streaming;
+use tonic::transport::Channel;
+
+use crate::common::AnyResult;
+use crate::protos::shredstream::shredstream_proxy_client::ShredstreamProxyClient;
+use crate::protos::shredstream::SubscribeEntriesRequest;
+
+/// 一批 ShredStream entries：所属槽位及 bincode 编码的 `Vec<solana_entry::entry::Entry>`
+///
+/// 保留编码后的字节，由订阅任务增量解码，与传输方式无关
+pub type ShredEntries = crate::protos::shredstream::Entry;
+
+/// ShredStream 数据来源
+///
+/// 将 entries 的获取与解析流程解耦：gRPC 代理与 WebSocket 端点（`websocket` feature）都实现该 trait，
+/// 通过 `ShredStreamGrpc::subscribe_with_sources` 接入同一条解析管线
+#[tonic::async_trait]
+pub trait ShredSource: Send {
+    /// 来源名称，用于日志
+    fn name(&self) -> &str;
+
+    /// 获取下一批 entries，来源结束时返回 None
+    async fn next_entries(&mut self) -> Option<AnyResult<ShredEntries>>;
+}
+
+/// 基于 gRPC ShredStream 代理的数据来源
+pub struct GrpcShredSource {
+    endpoint: String,
+    stream: Streaming<ShredEntries>,
+}
+
+impl GrpcShredSource {
+    /// 通过已连接的代理客户端订阅 entries
+    pub async fn subscribe(
+        endpoint: impl Into<String>,
+        client: &ShredstreamProxyClient<Channel>,
+    ) -> AnyResult<Self> {
+        let mut client = client.clone();
+        let request = tonic::Request::new(SubscribeEntriesRequest {});
+        let stream = client.subscribe_entries(request).await?.into_inner();
+        Ok(Self { endpoint: endpoint.into(), stream })
+    }
+}
+
+#[tonic::async_trait]
+impl ShredSource for GrpcShredSource {
+    fn name(&self) -> &str {
+        &self.endpoint
+    }
+
+    async fn next_entries(&mut self) -> Option<AnyResult<ShredEntries>> {
+        self.stream.next().await.map(|message| message.map_err(Into::into))
+    }
+}
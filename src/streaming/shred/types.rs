@@ -1,4 +1,9 @@
 use solana_sdk::transaction::VersionedTransaction;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::streaming::common::{PerformanceMetrics, SubscriptionMetricsSnapshot};
+use crate::streaming::event_parser::Protocol;
 
 /// 携带槽位信息的交易
 #[derive(Debug, Clone, Default)]
@@ -21,3 +26,93 @@ impl TransactionWithSlot {
         Self { transaction, slot, recv_us, tx_index }
     }
 }
+
+/// ShredStream 消息解码失败信息
+#[derive(Debug, Clone)]
+pub struct ShredDecodeError {
+    /// 出错消息所属的槽位
+    pub slot: u64,
+    /// 解码错误描述
+    pub error: String,
+}
+
+/// ShredStream 解码失败回调
+pub type ShredErrorCallback = std::sync::Arc<dyn Fn(ShredDecodeError) + Send + Sync>;
+
+/// `ShredStreamGrpc::debug_snapshot` 返回的客户端内部状态，用于排查卡住或异常的订阅
+#[derive(Debug, Clone)]
+pub struct DebugSnapshot {
+    /// 是否存在订阅且其流任务仍在运行；单个代理的流出错后订阅结束，此时为 false
+    pub is_subscribed: bool,
+    /// 当前订阅的指标（含最后一条消息的接收时间 `last_update_us`），未订阅时为 None
+    pub subscription: Option<SubscriptionMetricsSnapshot>,
+    /// 当前处理的协议列表
+    pub active_protocols: Vec<Protocol>,
+    /// 代理地址
+    pub endpoints: Vec<String>,
+    /// 每个代理的领先统计 `(endpoint, stats)`
+    pub proxy_lead_stats: Vec<(String, ProxyLeadStats)>,
+    /// 创建客户端时连接代理的重试次数（所有代理合计）。订阅本身不会自动重连
+    pub connect_retries: u64,
+    /// 全局 `TransactionWithSlot` 对象池中可复用的对象数
+    pub pool_available: usize,
+    /// 全局 `TransactionWithSlot` 对象池的容量上限
+    pub pool_max_size: usize,
+    /// 该客户端的性能指标
+    pub metrics: PerformanceMetrics,
+}
+
+/// 单个代理的领先统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProxyLeadStats {
+    /// 最先送达的交易数
+    pub first_deliveries: u64,
+    /// 最先送达且之后被其它代理重复送达的交易数，即领先时间的样本数
+    pub lead_samples: u64,
+    /// 领先其它代理的时间总和（微秒）
+    pub total_lead_us: u64,
+    /// 最大领先时间（微秒）
+    pub max_lead_us: u64,
+    /// 主代理在等待时间内未送达、改用该代理副本的交易数，见 `StreamClientConfig::primary_proxy`
+    pub fallback_deliveries: u64,
+}
+
+impl ProxyLeadStats {
+    /// 平均领先时间（微秒），没有样本时返回 None
+    pub fn avg_lead_us(&self) -> Option<f64> {
+        (self.lead_samples > 0).then(|| self.total_lead_us as f64 / self.lead_samples as f64)
+    }
+}
+
+/// 单个代理的领先统计计数器，最先送达数另见 `ShredStreamGrpc::first_delivery_counts`
+#[derive(Debug, Default)]
+pub struct ProxyLeadCounters {
+    lead_samples: AtomicU64,
+    total_lead_us: AtomicU64,
+    max_lead_us: AtomicU64,
+    fallback_deliveries: AtomicU64,
+}
+
+impl ProxyLeadCounters {
+    pub(crate) fn record_lead(&self, lead: Duration) {
+        let lead_us = lead.as_micros() as u64;
+        self.lead_samples.fetch_add(1, Ordering::Relaxed);
+        self.total_lead_us.fetch_add(lead_us, Ordering::Relaxed);
+        self.max_lead_us.fetch_max(lead_us, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_fallback(&self) {
+        self.fallback_deliveries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 当前统计，`first_deliveries` 由调用方提供
+    pub fn snapshot(&self, first_deliveries: u64) -> ProxyLeadStats {
+        ProxyLeadStats {
+            first_deliveries,
+            lead_samples: self.lead_samples.load(Ordering::Relaxed),
+            total_lead_us: self.total_lead_us.load(Ordering::Relaxed),
+            max_lead_us: self.max_lead_us.load(Ordering::Relaxed),
+            fallback_deliveries: self.fallback_deliveries.load(Ordering::Relaxed),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use futures::StreamExt;
+use prost::Message as _;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::common::AnyResult;
+use crate::streaming::shred::source::{ShredEntries, ShredSource};
+
+/// 基于 WebSocket 的数据来源
+///
+/// 假定服务端以二进制帧推送与 gRPC 代理相同的 protobuf `Entry` 消息（槽位 + bincode 编码的 entries）。
+/// 其它格式的服务端可参照此实现编写自己的 `ShredSource`。Ping 由 tungstenite 自动回复，文本帧被忽略
+pub struct WebSocketShredSource {
+    url: String,
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl WebSocketShredSource {
+    /// 连接 WebSocket 端点（支持 `ws://` 与 `wss://`）
+    pub async fn connect(url: impl Into<String>) -> AnyResult<Self> {
+        let _ = rustls::crypto::ring::default_provider().install_default().ok();
+        let url = url.into();
+        let (stream, _) = tokio_tungstenite::connect_async(url.as_str()).await?;
+        Ok(Self { url, stream })
+    }
+}
+
+#[tonic::async_trait]
+impl ShredSource for WebSocketShredSource {
+    fn name(&self) -> &str {
+        &self.url
+    }
+
+    async fn next_entries(&mut self) -> Option<AnyResult<ShredEntries>> {
+        loop {
+            match self.stream.next().await? {
+                Ok(Message::Binary(data)) => {
+                    return Some(ShredEntries::decode(data).map_err(Into::into));
+                }
+                Ok(Message::Close(_)) => return None,
+                Ok(_) => continue,
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
@@ -1,17 +1,31 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use anyhow::anyhow;
+use futures::stream::{self, select_all};
 use futures::StreamExt;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 
 use crate::common::AnyResult;
-use crate::protos::shredstream::SubscribeEntriesRequest;
-use crate::streaming::common::{process_shred_transaction, SubscriptionHandle};
+use crate::streaming::common::{
+    create_slot_summary_callback, create_subscription_callback, process_shred_transaction,
+    spawn_stream_task, wrap_user_callback, CancellationToken, ParseLimiter, ProxyArrival,
+    ProxyDeduplicator, SlotSummaryAggregator, SubscriptionHandle, SubscriptionId,
+    SubscriptionLogger, LOG_TARGET,
+};
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::event_parser::core::ParseConfig;
 use crate::streaming::event_parser::{Protocol, DexEvent};
 use crate::streaming::grpc::MetricsManager;
 use crate::streaming::shred::pool::factory;
-use log::error;
+use crate::streaming::shred::{
+    GrpcShredSource, ProxyLeadCounters, ShredDecodeError, ShredErrorCallback, ShredSource,
+    TransactionWithSlot,
+};
 use solana_entry::entry::Entry;
 
 use super::ShredStreamGrpc;
@@ -28,67 +42,495 @@ impl ShredStreamGrpc {
     where
         F: Fn(DexEvent) + Send + Sync + 'static,
     {
+        let subscribed_us = get_high_perf_clock();
+        if self.shredstream_clients.is_empty() {
+            return Err(anyhow!(
+                "No ShredStream gRPC endpoint configured, use subscribe_with_sources instead"
+            ));
+        }
+        // 如果已有活跃订阅，先停止它
+        self.stop().await;
+
+        // 订阅所有代理，不可用的代理跳过，其余代理照常工作
+        let mut sources: Vec<(usize, Box<dyn ShredSource>)> =
+            Vec::with_capacity(self.shredstream_clients.len());
+        let mut failures = Vec::new();
+        for (proxy_index, (endpoint, client)) in
+            self.endpoints.iter().zip(&self.shredstream_clients).enumerate()
+        {
+            match GrpcShredSource::subscribe(endpoint.as_str(), client).await {
+                Ok(source) => sources.push((proxy_index, Box::new(source))),
+                Err(e) => {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Failed to subscribe to ShredStream proxy {endpoint}: {e}, skipping it"
+                    );
+                    failures.push(format!("{endpoint}: {e}"));
+                }
+            }
+        }
+        if sources.is_empty() {
+            return Err(anyhow!(
+                "Failed to subscribe to any ShredStream proxy ({})",
+                failures.join("; ")
+            ));
+        }
+        self.subscribe_sources(
+            subscribed_us,
+            sources,
+            protocols,
+            bot_wallet,
+            event_type_filter,
+            callback,
+        )
+        .await
+    }
+
+    /// 从自定义数据来源订阅 ShredStream 事件（例如 `WebSocketShredSource`）
+    ///
+    /// 多个来源时合并消费并按签名去重。`get_first_delivery_counts` 按位置统计，
+    /// 仅在来源与 `endpoints` 一一对应时有意义
+    pub async fn subscribe_with_sources<F>(
+        &self,
+        sources: Vec<Box<dyn ShredSource>>,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> AnyResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        self.subscribe_sources(
+            get_high_perf_clock(),
+            sources.into_iter().enumerate().collect(),
+            protocols,
+            bot_wallet,
+            event_type_filter,
+            callback,
+        )
+        .await
+    }
+
+    /// `subscribed_us` 为调用订阅方法的时间，`subscribe` 中连接代理的耗时也计入首个事件的到达耗时。
+    /// `sources` 中每个来源附带其在 `endpoints` 中的位置，跳过不可用代理后各代理的统计仍能对应
+    async fn subscribe_sources<F>(
+        &self,
+        subscribed_us: i64,
+        sources: Vec<(usize, Box<dyn ShredSource>)>,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+    ) -> AnyResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        if sources.is_empty() {
+            return Err(anyhow!("At least one ShredStream source is required"));
+        }
         // 如果已有活跃订阅，先停止它
         self.stop().await;
 
         let mut metrics_handle = None;
         // 启动自动性能监控（如果启用）
         if self.config.enable_metrics {
-            metrics_handle = MetricsManager::global().start_auto_monitoring().await;
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
         }
 
-        // 启动流处理
-        let mut client = (*self.shredstream_client).clone();
-        let request = tonic::Request::new(SubscribeEntriesRequest {});
-        let mut stream = client.subscribe_entries(request).await?.into_inner();
+        // 启动流处理：合并所有来源为一个流
+        let source_names: BTreeMap<usize, String> = sources
+            .iter()
+            .map(|(proxy_index, source)| (*proxy_index, source.name().to_string()))
+            .collect();
+        let streams = sources.into_iter().map(|(proxy_index, source)| {
+            stream::unfold(source, |mut source| async move {
+                source.next_entries().await.map(|message| (message, source))
+            })
+            .map(move |message| (proxy_index, message))
+            .boxed()
+        });
+        let multi_proxy = source_names.len() > 1;
+        let mut stream = select_all(streams);
+
+        let subscription_id = SubscriptionId::next();
+        let subscription_metrics =
+            self.metrics_manager.register_subscription(subscription_id, subscribed_us);
+        let logger = SubscriptionLogger::new(subscription_id, subscription_metrics.clone());
+        log::info!(
+            target: LOG_TARGET,
+            "[{subscription_id}] Subscribed to {}",
+            source_names.values().cloned().collect::<Vec<_>>().join(", ")
+        );
 
         // Wrap callback once before the async block
-        let callback = Arc::new(callback);
+        let callback =
+            create_subscription_callback(Arc::new(callback), subscription_metrics.clone());
+        let mut slot_summary_handle = None;
+        let callback = match self.slot_summary_callback.clone() {
+            Some(slot_summary_callback) => {
+                let timeout = Duration::from_millis(self.config.slot_summary_timeout_ms);
+                let aggregator =
+                    Arc::new(SlotSummaryAggregator::new(timeout, slot_summary_callback));
+                slot_summary_handle = Some(aggregator.spawn_flush_task());
+                create_slot_summary_callback(callback, aggregator)
+            }
+            None => callback,
+        };
+        let cancellation = CancellationToken::new();
+        let callback = wrap_user_callback(
+            callback,
+            &self.config,
+            self.enricher.clone(),
+            &self.metrics_manager,
+            &cancellation,
+        );
+        let error_callback = self.error_callback.clone();
+        let mut handler = TransactionHandler {
+            callback,
+            logger: logger.clone(),
+            event_type_filter,
+            bot_wallet,
+            parse_config: self.config.parse.clone(),
+            parse_limiter: self.config.max_concurrent_parses.map(|limit| {
+                ParseLimiter::new(limit, self.config.strict_ordering, cancellation.clone())
+            }),
+        };
+        self.active_protocols.store(protocols);
+        let active_protocols = self.active_protocols.clone();
+        // 多个代理会推送相同交易，按签名去重，只处理最先到达的一份（设置主代理时优先主代理的副本）
+        let mut selector = ProxySelector {
+            deduplicator: ProxyDeduplicator::default(),
+            primary_proxy: self
+                .config
+                .primary_proxy
+                .filter(|index| multi_proxy && source_names.contains_key(index)),
+            held: HeldTransactions::new(Duration::from_micros(self.config.primary_proxy_wait_us)),
+            first_delivery_counts: self.first_delivery_counts.clone(),
+            proxy_lead_counters: self.proxy_lead_counters.clone(),
+        };
 
-        let stream_task = tokio::spawn(async move {
-            while let Some(message) = stream.next().await {
+        let batch_cancellation = cancellation.clone();
+        let stream_future = self.metrics_manager.scope(async move {
+            loop {
+                let (proxy_index, message) = tokio::select! {
+                    item = stream.next() => match item {
+                        Some(item) => item,
+                        None => break,
+                    },
+                    _ = sleep_until(selector.held.next_deadline()) => {
+                        let protocols = active_protocols.load();
+                        for transaction_with_slot in selector.take_expired(Instant::now()) {
+                            handler.handle(transaction_with_slot, &protocols).await;
+                        }
+                        continue;
+                    }
+                };
                 match message {
                     Ok(msg) => {
-                        if let Ok(entries) = bincode::deserialize::<Vec<Entry>>(&msg.entries) {
-                            for entry in entries {
-                                for (tx_index, transaction) in entry.transactions.iter().enumerate() {
-                                    let transaction_with_slot =
-                                        factory::create_transaction_with_slot_pooled(
-                                            transaction.clone(),
-                                            msg.slot,
-                                            get_high_perf_clock(),
-                                            Some(tx_index as u64),
-                                        );
-                                    // Process transaction - clone Arc and Vec for each call
-                                    if let Err(e) = process_shred_transaction(
+                        subscription_metrics.record_update();
+                        let protocols = active_protocols.load();
+                        // 增量解码：消息体是 bincode 编码的 Vec<Entry>（u64 长度前缀 + 逐个 Entry），
+                        // 每解出一个 Entry 就立即处理其中的交易，不必等待整批反序列化完成
+                        let mut reader = msg.entries.as_slice();
+                        // 反序列化耗时单独累计，与协议解析耗时区分
+                        let decode_start = Instant::now();
+                        let entry_count = bincode::deserialize_from::<_, u64>(&mut reader);
+                        let mut decode_time = decode_start.elapsed();
+                        let entry_count = match entry_count {
+                            Ok(count) => count,
+                            Err(e) => {
+                                report_decode_error(&logger, error_callback.as_ref(), msg.slot, &e);
+                                continue;
+                            }
+                        };
+
+                        for _ in 0..entry_count {
+                            // 订阅已停止时不再处理批次中剩余的交易
+                            if batch_cancellation.is_cancelled() {
+                                break;
+                            }
+                            let decode_start = Instant::now();
+                            let entry = bincode::deserialize_from::<_, Entry>(&mut reader);
+                            decode_time += decode_start.elapsed();
+                            let entry = match entry {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    // 已解出的 Entry 已处理，剩余部分无法继续解码
+                                    report_decode_error(
+                                        &logger,
+                                        error_callback.as_ref(),
+                                        msg.slot,
+                                        &e,
+                                    );
+                                    break;
+                                }
+                            };
+                            for (tx_index, transaction) in entry.transactions.into_iter().enumerate()
+                            {
+                                let signature =
+                                    transaction.signatures.first().copied().filter(|_| multi_proxy);
+                                let transaction_with_slot =
+                                    factory::create_transaction_with_slot_pooled(
+                                        transaction,
+                                        msg.slot,
+                                        get_high_perf_clock(),
+                                        Some(tx_index as u64),
+                                    );
+                                let transaction_with_slot = match signature {
+                                    Some(signature) => selector.admit(
+                                        signature,
+                                        proxy_index,
                                         transaction_with_slot,
-                                        &protocols,
-                                        event_type_filter.as_ref(),
-                                        callback.clone(),
-                                        bot_wallet,
-                                    )
-                                    .await
-                                    {
-                                        error!("Error handling message: {e:?}");
-                                    }
+                                        Instant::now(),
+                                    ),
+                                    None => Some(transaction_with_slot),
+                                };
+                                if let Some(transaction_with_slot) = transaction_with_slot {
+                                    handler.handle(transaction_with_slot, &protocols).await;
                                 }
                             }
                         }
+                        MetricsManager::current()
+                            .record_decode_time(decode_time.as_secs_f64() * 1_000_000.0);
                         continue;
                     }
                     Err(error) => {
-                        error!("Stream error: {error:?}");
-                        break;
+                        let context = format!("Stream error from {}", source_names[&proxy_index]);
+                        // 单个代理时保持原有行为；多个代理时其余代理继续工作
+                        if !multi_proxy {
+                            logger.fatal(&context, &error);
+                            break;
+                        }
+                        logger.recoverable(&context, &error);
                     }
                 }
             }
+            // 所有来源都已结束，处理剩余暂存的交易
+            let protocols = active_protocols.load();
+            for transaction_with_slot in selector.take_all() {
+                handler.handle(transaction_with_slot, &protocols).await;
+            }
         });
+        let stream_task = spawn_stream_task(&self.config, stream_future);
 
         // 保存订阅句柄
-        let subscription_handle = SubscriptionHandle::new(stream_task, None, metrics_handle);
+        let subscription_handle = SubscriptionHandle::with_id(
+            subscription_id,
+            self.metrics_manager.clone(),
+            stream_task,
+            slot_summary_handle,
+            metrics_handle,
+        )
+        .with_cancellation_token(cancellation);
         let mut handle_guard = self.subscription_handle.lock().await;
         *handle_guard = Some(subscription_handle);
 
         Ok(())
     }
 }
+
+/// 解析单笔交易并交给 callback，设置了 `max_concurrent_parses` 时在独立任务中解析
+struct TransactionHandler {
+    callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
+    logger: SubscriptionLogger,
+    event_type_filter: Option<EventTypeFilter>,
+    bot_wallet: Option<Pubkey>,
+    parse_config: ParseConfig,
+    parse_limiter: Option<ParseLimiter>,
+}
+
+impl TransactionHandler {
+    async fn handle(
+        &mut self,
+        transaction_with_slot: TransactionWithSlot,
+        protocols: &Arc<Vec<Protocol>>,
+    ) {
+        if let Some(parse_limiter) = self.parse_limiter.as_mut() {
+            let protocols = protocols.clone();
+            let event_type_filter = self.event_type_filter.clone();
+            let parse_config = self.parse_config.clone();
+            let bot_wallet = self.bot_wallet;
+            let logger = self.logger.clone();
+            parse_limiter
+                .spawn(self.callback.clone(), |callback| async move {
+                    if let Err(e) = process_shred_transaction(
+                        transaction_with_slot,
+                        &protocols,
+                        event_type_filter.as_ref(),
+                        callback,
+                        bot_wallet,
+                        &parse_config,
+                    )
+                    .await
+                    {
+                        logger.recoverable("Error handling message", &e);
+                    }
+                })
+                .await;
+            return;
+        }
+        if let Err(e) = process_shred_transaction(
+            transaction_with_slot,
+            protocols,
+            self.event_type_filter.as_ref(),
+            self.callback.clone(),
+            self.bot_wallet,
+            &self.parse_config,
+        )
+        .await
+        {
+            self.logger.recoverable("Error handling message", &e);
+        }
+    }
+}
+
+/// 多代理时决定每份交易副本是否处理
+///
+/// 按签名去重并统计各代理的领先时间；设置主代理时，备用代理先送达的交易暂存等待主代理的副本，
+/// 主代理在等待时间内送达则处理主代理的副本，否则到期后处理备用代理的副本
+struct ProxySelector {
+    deduplicator: ProxyDeduplicator,
+    primary_proxy: Option<usize>,
+    held: HeldTransactions,
+    first_delivery_counts: Arc<Vec<AtomicU64>>,
+    proxy_lead_counters: Arc<Vec<ProxyLeadCounters>>,
+}
+
+impl ProxySelector {
+    /// 返回应立即处理的交易，重复或暂存时返回 None
+    fn admit(
+        &mut self,
+        signature: Signature,
+        proxy_index: usize,
+        transaction_with_slot: TransactionWithSlot,
+        now: Instant,
+    ) -> Option<TransactionWithSlot> {
+        match self.deduplicator.arrive(signature, proxy_index, now) {
+            ProxyArrival::Duplicate { first_proxy, lead } => {
+                if first_proxy != proxy_index {
+                    if let Some(counters) = self.proxy_lead_counters.get(first_proxy) {
+                        counters.record_lead(lead);
+                    }
+                }
+                // 主代理在等待时间内送达，取代备用代理暂存的副本
+                let replaces_held =
+                    self.primary_proxy == Some(proxy_index) && self.held.cancel(&signature);
+                replaces_held.then_some(transaction_with_slot)
+            }
+            ProxyArrival::First => {
+                if let Some(count) = self.first_delivery_counts.get(proxy_index) {
+                    count.fetch_add(1, Ordering::Relaxed);
+                }
+                if self.primary_proxy.is_some_and(|primary| primary != proxy_index) {
+                    self.held.hold(signature, proxy_index, transaction_with_slot, now);
+                    return None;
+                }
+                Some(transaction_with_slot)
+            }
+        }
+    }
+
+    /// 取出主代理在等待时间内没有送达的交易，改用备用代理的副本
+    fn take_expired(&mut self, now: Instant) -> Vec<TransactionWithSlot> {
+        let expired = self.held.take_expired(now);
+        self.fall_back(expired)
+    }
+
+    /// 取出全部暂存的交易（所有来源结束时主代理不会再送达）
+    fn take_all(&mut self) -> Vec<TransactionWithSlot> {
+        let held = self.held.drain();
+        self.fall_back(held)
+    }
+
+    fn fall_back(&self, held: Vec<(usize, TransactionWithSlot)>) -> Vec<TransactionWithSlot> {
+        held.into_iter()
+            .map(|(proxy_index, transaction_with_slot)| {
+                if let Some(counters) = self.proxy_lead_counters.get(proxy_index) {
+                    counters.record_fallback();
+                }
+                transaction_with_slot
+            })
+            .collect()
+    }
+}
+
+/// 备用代理先送达、正在等待主代理副本的交易
+struct HeldTransactions {
+    wait: Duration,
+    /// 等待时间固定，按送达顺序排列即按截止时间排列；已取消的签名在到期时跳过
+    deadlines: VecDeque<(Instant, Signature)>,
+    transactions: HashMap<Signature, (usize, TransactionWithSlot)>,
+}
+
+impl HeldTransactions {
+    fn new(wait: Duration) -> Self {
+        Self { wait, deadlines: VecDeque::new(), transactions: HashMap::new() }
+    }
+
+    fn hold(
+        &mut self,
+        signature: Signature,
+        proxy_index: usize,
+        transaction_with_slot: TransactionWithSlot,
+        now: Instant,
+    ) {
+        self.deadlines.push_back((now + self.wait, signature));
+        self.transactions.insert(signature, (proxy_index, transaction_with_slot));
+    }
+
+    /// 主代理送达时取消等待，返回该签名是否仍在等待
+    fn cancel(&mut self, signature: &Signature) -> bool {
+        self.transactions.remove(signature).is_some()
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        self.deadlines.front().map(|(deadline, _)| *deadline)
+    }
+
+    /// 取出已到期的交易 `(代理索引, 交易)`
+    fn take_expired(&mut self, now: Instant) -> Vec<(usize, TransactionWithSlot)> {
+        let mut expired = Vec::new();
+        while let Some(&(deadline, signature)) = self.deadlines.front() {
+            if deadline > now {
+                break;
+            }
+            self.deadlines.pop_front();
+            if let Some(held) = self.transactions.remove(&signature) {
+                expired.push(held);
+            }
+        }
+        expired
+    }
+
+    /// 按送达顺序取出全部交易 `(代理索引, 交易)`
+    fn drain(&mut self) -> Vec<(usize, TransactionWithSlot)> {
+        let deadlines = std::mem::take(&mut self.deadlines);
+        deadlines
+            .into_iter()
+            .filter_map(|(_, signature)| self.transactions.remove(&signature))
+            .collect()
+    }
+}
+
+/// 等待到截止时间，没有截止时间时永不返回
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// 单条消息解码失败不中断订阅，记录并上报
+fn report_decode_error(
+    logger: &SubscriptionLogger,
+    error_callback: Option<&ShredErrorCallback>,
+    slot: u64,
+    error: &bincode::Error,
+) {
+    MetricsManager::current().increment_decode_failures();
+    logger.recoverable(&format!("Failed to decode entries at slot {slot}"), error);
+    if let Some(error_callback) = error_callback {
+        error_callback(ShredDecodeError { slot, error: error.to_string() });
+    }
+}
@@ -0,0 +1,288 @@
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature};
+use spl_token_2022::extension::{
+    mint_close_authority::MintCloseAuthority, permanent_delegate::PermanentDelegate,
+    BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+};
+use spl_token_2022::state::Mint;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+use yellowstone_grpc_proto::prelude::{
+    CompiledInstruction, InnerInstruction, InnerInstructions, Message, MessageHeader, Transaction,
+    TransactionStatusMeta,
+};
+
+use crate::streaming::grpc::AccountPretty;
+
+/// gRPC 交易更新构建器，用于编写解析器测试
+///
+/// 按高层输入（程序 ID、账户、指令数据）组装 `SubscribeUpdateTransactionInfo`：
+/// 账户自动去重并编入静态账户表，指令中的账户与程序 ID 转换为索引。
+/// 手续费支付者固定位于索引 0 并作为唯一签名者
+#[derive(Clone, Debug)]
+pub struct TxFixtureBuilder {
+    signature: Signature,
+    recent_blockhash: Hash,
+    account_keys: Vec<Pubkey>,
+    instructions: Vec<CompiledInstruction>,
+    inner_instructions: Vec<InnerInstructions>,
+    log_messages: Vec<String>,
+    index: u64,
+    is_vote: bool,
+}
+
+impl Default for TxFixtureBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TxFixtureBuilder {
+    /// 使用随机签名与随机手续费支付者创建
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::new_unique(),
+            recent_blockhash: Hash::default(),
+            account_keys: vec![Pubkey::new_unique()],
+            instructions: Vec::new(),
+            inner_instructions: Vec::new(),
+            log_messages: Vec::new(),
+            index: 0,
+            is_vote: false,
+        }
+    }
+
+    pub fn signature(mut self, signature: Signature) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    /// 交易消息中的 recent blockhash，默认为全零
+    pub fn recent_blockhash(mut self, recent_blockhash: Hash) -> Self {
+        self.recent_blockhash = recent_blockhash;
+        self
+    }
+
+    /// 替换索引 0 的手续费支付者，已添加的指令中引用支付者的位置随之改变
+    pub fn fee_payer(mut self, payer: Pubkey) -> Self {
+        self.account_keys[0] = payer;
+        self
+    }
+
+    /// 交易在槽位中的索引
+    pub fn index(mut self, index: u64) -> Self {
+        self.index = index;
+        self
+    }
+
+    pub fn vote(mut self, is_vote: bool) -> Self {
+        self.is_vote = is_vote;
+        self
+    }
+
+    /// 添加外层指令
+    pub fn instruction(mut self, program_id: Pubkey, accounts: &[Pubkey], data: Vec<u8>) -> Self {
+        let program_id_index = self.key_index(program_id);
+        let accounts = self.key_indexes(accounts);
+        self.instructions.push(CompiledInstruction {
+            program_id_index: program_id_index as u32,
+            accounts,
+            data,
+        });
+        self
+    }
+
+    /// 为最近添加的外层指令添加 inner instruction（CPI 调用或 CPI log），
+    /// 尚未添加外层指令时忽略
+    pub fn inner_instruction(
+        mut self,
+        program_id: Pubkey,
+        accounts: &[Pubkey],
+        data: Vec<u8>,
+    ) -> Self {
+        let Some(outer_index) = self.instructions.len().checked_sub(1) else {
+            return self;
+        };
+        let program_id_index = self.key_index(program_id);
+        let accounts = self.key_indexes(accounts);
+        let instruction = InnerInstruction {
+            program_id_index: program_id_index as u32,
+            accounts,
+            data,
+            stack_height: Some(2),
+        };
+        match self.inner_instructions.iter_mut().find(|inner| inner.index == outer_index as u32) {
+            Some(inner) => inner.instructions.push(instruction),
+            None => self.inner_instructions.push(InnerInstructions {
+                index: outer_index as u32,
+                instructions: vec![instruction],
+            }),
+        }
+        self
+    }
+
+    /// 添加程序日志
+    pub fn log_message(mut self, log: impl Into<String>) -> Self {
+        self.log_messages.push(log.into());
+        self
+    }
+
+    /// 组装 gRPC 交易更新
+    pub fn build(self) -> SubscribeUpdateTransactionInfo {
+        let message = Message {
+            header: Some(MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 0,
+            }),
+            account_keys: self.account_keys.iter().map(|key| key.to_bytes().to_vec()).collect(),
+            recent_blockhash: self.recent_blockhash.to_bytes().to_vec(),
+            instructions: self.instructions,
+            ..Default::default()
+        };
+        let meta = TransactionStatusMeta {
+            inner_instructions: self.inner_instructions,
+            log_messages: self.log_messages,
+            ..Default::default()
+        };
+        SubscribeUpdateTransactionInfo {
+            signature: self.signature.as_ref().to_vec(),
+            is_vote: self.is_vote,
+            transaction: Some(Transaction {
+                signatures: vec![self.signature.as_ref().to_vec()],
+                message: Some(message),
+            }),
+            meta: Some(meta),
+            index: self.index,
+        }
+    }
+
+    /// 已构建交易的签名，可直接传给 `EventParser::parse_grpc_transaction_with_config`
+    pub fn get_signature(&self) -> Signature {
+        self.signature
+    }
+
+    fn key_index(&mut self, key: Pubkey) -> u8 {
+        let index = match self.account_keys.iter().position(|existing| *existing == key) {
+            Some(index) => index,
+            None => {
+                self.account_keys.push(key);
+                self.account_keys.len() - 1
+            }
+        };
+        // 静态账户表最多 256 个账户
+        u8::try_from(index).expect("transaction fixture exceeds 256 accounts")
+    }
+
+    fn key_indexes(&mut self, keys: &[Pubkey]) -> Vec<u8> {
+        keys.iter().map(|key| self.key_index(*key)).collect()
+    }
+}
+
+/// Token-2022 Mint 账户构建器，用于编写账户解析器测试
+///
+/// 按设置的扩展计算账户长度并写入 TLV 数据，`build` 输出属于 Token-2022 程序的 `AccountPretty`
+#[derive(Clone, Debug)]
+pub struct MintFixtureBuilder {
+    pubkey: Pubkey,
+    supply: u64,
+    decimals: u8,
+    close_authority: Option<Pubkey>,
+    permanent_delegate: Option<Pubkey>,
+}
+
+impl Default for MintFixtureBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MintFixtureBuilder {
+    /// 使用随机 Mint 地址创建，不带扩展
+    pub fn new() -> Self {
+        Self {
+            pubkey: Pubkey::new_unique(),
+            supply: 0,
+            decimals: 6,
+            close_authority: None,
+            permanent_delegate: None,
+        }
+    }
+
+    pub fn pubkey(mut self, pubkey: Pubkey) -> Self {
+        self.pubkey = pubkey;
+        self
+    }
+
+    pub fn supply(mut self, supply: u64) -> Self {
+        self.supply = supply;
+        self
+    }
+
+    pub fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// 添加 `MintCloseAuthority` 扩展
+    pub fn close_authority(mut self, authority: Pubkey) -> Self {
+        self.close_authority = Some(authority);
+        self
+    }
+
+    /// 添加 `PermanentDelegate` 扩展
+    pub fn permanent_delegate(mut self, delegate: Pubkey) -> Self {
+        self.permanent_delegate = Some(delegate);
+        self
+    }
+
+    /// 组装账户数据
+    pub fn build_data(&self) -> Vec<u8> {
+        let mut extensions = Vec::new();
+        if self.close_authority.is_some() {
+            extensions.push(ExtensionType::MintCloseAuthority);
+        }
+        if self.permanent_delegate.is_some() {
+            extensions.push(ExtensionType::PermanentDelegate);
+        }
+        let len = ExtensionType::try_calculate_account_len::<Mint>(&extensions)
+            .expect("mint fixture extensions have a fixed size");
+        let mut data = vec![0u8; len];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data)
+            .expect("mint fixture buffer is sized for its extensions");
+        if let Some(authority) = self.close_authority {
+            let extension = state
+                .init_extension::<MintCloseAuthority>(true)
+                .expect("mint fixture has room for MintCloseAuthority");
+            extension.close_authority.0 = authority;
+        }
+        if let Some(delegate) = self.permanent_delegate {
+            let extension = state
+                .init_extension::<PermanentDelegate>(true)
+                .expect("mint fixture has room for PermanentDelegate");
+            extension.delegate.0 = delegate;
+        }
+        state.base = Mint {
+            supply: self.supply,
+            decimals: self.decimals,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        // 不带扩展的 Mint 没有账户类型字节
+        if !extensions.is_empty() {
+            state.init_account_type().expect("mint fixture account type is uninitialized");
+        }
+        data
+    }
+
+    /// 组装账户更新，可直接传给 `AccountEventParser::parse_account_event`
+    pub fn build(self) -> AccountPretty {
+        AccountPretty {
+            slot: 1,
+            pubkey: self.pubkey,
+            lamports: 1_461_600,
+            owner: spl_token_2022::ID,
+            data: self.build_data(),
+            ..Default::default()
+        }
+    }
+}
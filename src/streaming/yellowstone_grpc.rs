@@ -1,26 +1,36 @@
 use crate::common::AnyResult;
 use crate::streaming::common::{
-    process_grpc_transaction, MetricsManager, PerformanceMetrics, StreamClientConfig,
-    SubscriptionHandle,
+    create_slot_summary_callback, create_subscription_callback, process_grpc_transaction,
+    spawn_stream_task, wrap_user_callback, ActiveProtocols, CancellationToken, EventEnricher,
+    MetricsManager, ParseLimiter, PerformanceMetrics, SlotSummaryAggregator, SlotSummaryCallback,
+    StreamClientConfig, SubscriptionHandle, SubscriptionId, SubscriptionLogger,
+    SubscriptionMetricsSnapshot, LOG_TARGET,
 };
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::core::account_event_parser::AccountStateCache;
+use crate::streaming::event_parser::core::EventDispatcher;
 use crate::streaming::event_parser::{Protocol, DexEvent};
 use crate::streaming::grpc::pool::factory;
-use crate::streaming::grpc::{EventPretty, SubscriptionManager};
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::grpc::{EventPretty, SlotPretty, SubscriptionManager};
 use anyhow::anyhow;
 use std::time::{SystemTime, UNIX_EPOCH};
 use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt};
-use log::error;
 use solana_sdk::pubkey::Pubkey;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
 use yellowstone_grpc_proto::geyser::{
     CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccountsFilter, SubscribeRequestPing,
+    SubscribeUpdate,
 };
 
+/// 原始 gRPC 更新回调，接收不会被解析为 `DexEvent` 的更新
+pub type RawUpdateCallback = Arc<dyn Fn(SubscribeUpdate) + Send + Sync>;
+
 /// 交易过滤器
 #[derive(Debug, Clone)]
 pub struct TransactionFilter {
@@ -29,6 +39,29 @@ pub struct TransactionFilter {
     pub account_required: Vec<String>,
 }
 
+impl TransactionFilter {
+    /// 只包含指定协议程序 ID 的过滤器，可继续向 `account_include` 添加账户以扩展
+    pub fn for_protocols(protocols: &[Protocol]) -> Self {
+        let mut filter = Self {
+            account_include: vec![],
+            account_exclude: vec![],
+            account_required: vec![],
+        };
+        filter.add_protocol_programs(protocols);
+        filter
+    }
+
+    /// 把协议的程序 ID 追加到 `account_include`，已存在的跳过
+    pub fn add_protocol_programs(&mut self, protocols: &[Protocol]) {
+        for program_id in EventDispatcher::get_program_ids(protocols) {
+            let program_id = program_id.to_string();
+            if !self.account_include.contains(&program_id) {
+                self.account_include.push(program_id);
+            }
+        }
+    }
+}
+
 /// 账户过滤器
 #[derive(Debug, Clone)]
 pub struct AccountFilter {
@@ -37,6 +70,10 @@ pub struct AccountFilter {
     pub filters: Vec<SubscribeRequestFilterAccountsFilter>,
 }
 
+/// Yellowstone gRPC 客户端
+///
+/// 克隆共享同一个订阅；最后一个克隆被 drop 时订阅任务随之中止，尚未送达 callback 的事件会被丢弃。
+/// 需要在 drop 之前确定地结束订阅时调用 `stop`
 pub struct YellowstoneGrpc {
     pub endpoint: String,
     pub x_token: Option<String>,
@@ -49,6 +86,20 @@ pub struct YellowstoneGrpc {
     pub current_request: Arc<tokio::sync::RwLock<Option<SubscribeRequest>>>,
 
     pub event_type_filter: Arc<tokio::sync::RwLock<Option<EventTypeFilter>>>,
+    /// 当前处理的协议列表，可通过 `set_active_protocols` 在运行时修改
+    pub active_protocols: ActiveProtocols,
+    /// 事件增强钩子（可选），在用户 callback 之前运行于解析线程
+    pub enricher: Option<EventEnricher>,
+    /// 原始更新回调（可选），接收 ping、entry 等不会被解析为事件的更新
+    pub raw_update_callback: Option<RawUpdateCallback>,
+    /// 槽位汇总回调（可选），每个槽位输出一次汇总
+    pub slot_summary_callback: Option<SlotSummaryCallback>,
+    /// 默认确认级别，订阅时未指定 commitment 则使用该值，两者都未设置时为 Processed
+    pub commitment: Option<CommitmentLevel>,
+    /// 下一次订阅的起始槽位，用于断线重连后补回缺失的槽位；为 None 时从最新槽位开始
+    pub from_slot: Option<u64>,
+    /// 该客户端记录指标使用的实例，默认为全局实例，见 `set_metrics_manager`
+    pub metrics_manager: MetricsManager,
 }
 
 impl YellowstoneGrpc {
@@ -78,6 +129,13 @@ impl YellowstoneGrpc {
             control_tx: Arc::new(tokio::sync::Mutex::new(None)),
             current_request: Arc::new(tokio::sync::RwLock::new(None)),
             event_type_filter: Arc::new(tokio::sync::RwLock::new(None)),
+            active_protocols: ActiveProtocols::default(),
+            enricher: None,
+            raw_update_callback: None,
+            slot_summary_callback: None,
+            commitment: None,
+            from_slot: None,
+            metrics_manager: MetricsManager::global(),
         })
     }
 
@@ -93,12 +151,32 @@ impl YellowstoneGrpc {
 
     /// 获取性能指标
     pub fn get_metrics(&self) -> PerformanceMetrics {
-        MetricsManager::global().get_metrics()
+        self.metrics_manager.get_metrics()
     }
 
     /// 打印性能指标
     pub fn print_metrics(&self) {
-        MetricsManager::global().print_metrics();
+        self.metrics_manager.print_metrics();
+    }
+
+    /// 设置该客户端使用的指标实例，在下一次订阅时生效
+    ///
+    /// 传入 `MetricsManager::new()` 时该客户端的指标与其它客户端及全局实例互不影响，
+    /// `get_metrics` / `print_metrics` 只反映该客户端自己的统计
+    pub fn set_metrics_manager(&mut self, metrics_manager: MetricsManager) {
+        metrics_manager.configure(self.config.enable_metrics);
+        self.metrics_manager = metrics_manager;
+    }
+
+    /// 当前订阅的 ID，未订阅时返回 None；日志中以 `[sub-N]` 标识该订阅
+    pub async fn subscription_id(&self) -> Option<SubscriptionId> {
+        self.subscription_handle.lock().await.as_ref().map(SubscriptionHandle::id)
+    }
+
+    /// 获取当前订阅的指标，未订阅时返回 None
+    pub async fn get_subscription_metrics(&self) -> Option<SubscriptionMetricsSnapshot> {
+        let subscription_id = self.subscription_id().await?;
+        self.metrics_manager.get_subscription_metrics(subscription_id)
     }
 
     /// 启用或禁用性能监控
@@ -106,7 +184,58 @@ impl YellowstoneGrpc {
         self.config.enable_metrics = enabled;
     }
 
-    /// 停止当前订阅
+    /// 设置事件增强钩子，在下一次订阅时生效
+    ///
+    /// 钩子在 `process_event` 之后、callback 之前对事件原地修改，可看到完整合并后的事件（含 swap_data）。
+    /// 钩子在解析线程上同步执行，必须足够快
+    pub fn set_enricher(&mut self, enricher: Option<EventEnricher>) {
+        self.enricher = enricher;
+    }
+
+    /// 设置原始更新回调，在下一次订阅时生效
+    ///
+    /// 账户、交易、区块元数据与槽位以外的更新（ping / pong、entry、block、交易状态以及今后新增的
+    /// 更新类型）不会被解析为 `DexEvent`，设置后按原样交给该回调。ping 仍会自动回复。
+    /// 回调在订阅任务上同步执行，必须足够快
+    pub fn set_raw_update_callback(&mut self, callback: Option<RawUpdateCallback>) {
+        self.raw_update_callback = callback;
+    }
+
+    /// 设置槽位汇总回调，在下一次订阅时生效
+    ///
+    /// 每个槽位输出一次 `SlotSummary`（事件数、按协议的事件数与 SOL 成交额）：槽位的区块元数据到达时输出，
+    /// 未到达时在 `slot_summary_timeout_ms` 之后输出。只统计通过事件过滤交给 callback 的事件
+    pub fn set_slot_summary_callback(&mut self, callback: Option<SlotSummaryCallback>) {
+        self.slot_summary_callback = callback;
+    }
+
+    /// 设置默认确认级别，在下一次订阅时生效
+    pub fn set_commitment(&mut self, commitment: Option<CommitmentLevel>) {
+        self.commitment = commitment;
+    }
+
+    /// 设置下一次订阅的起始槽位，服务端从该槽位开始重放更新
+    ///
+    /// 需要服务端支持 `from_slot` 并保留足够的历史槽位；起始槽位超出服务端保留范围时订阅返回错误。
+    /// 只作用于建立订阅的请求，`update_subscription` 不会再次重放
+    pub fn set_from_slot(&mut self, from_slot: Option<u64>) {
+        self.from_slot = from_slot;
+    }
+
+    /// 运行时替换处理的协议列表，无需重新订阅，从下一条消息开始生效
+    ///
+    /// 只影响客户端解析；gRPC 订阅的过滤条件不变，因此新启用的协议只能看到现有过滤条件已推送的交易。
+    /// 启用 `auto_program_filter` 时，之后调用 `update_subscription` 会按新的协议列表补全过滤条件
+    pub fn set_active_protocols(&self, protocols: Vec<Protocol>) {
+        self.active_protocols.store(protocols);
+    }
+
+    /// 获取当前处理的协议列表
+    pub fn get_active_protocols(&self) -> Vec<Protocol> {
+        self.active_protocols.load().to_vec()
+    }
+
+    /// 停止当前订阅，尚未完成的解析任务和 callback 线程中排队的事件直接丢弃
     pub async fn stop(&self) {
         let mut handle_guard = self.subscription_handle.lock().await;
         if let Some(handle) = handle_guard.take() {
@@ -122,10 +251,13 @@ impl YellowstoneGrpc {
     /// # Parameters
     /// * `protocols` - List of protocols to monitor
     /// * `bot_wallet` - Optional bot wallet address for filtering related transactions
-    /// * `transaction_filter` - Transaction filter specifying accounts to include/exclude
+    /// * `transaction_filter` - Transaction filter specifying accounts to include/exclude; an empty
+    ///   `account_include` is filled with the protocols' program ids when `auto_program_filter`
+    ///   is enabled
     /// * `account_filter` - Account filter specifying accounts and owners to monitor
     /// * `event_filter` - Optional event filter for further event filtering, no filtering if None
-    /// * `commitment` - Optional commitment level, defaults to Confirmed
+    /// * `commitment` - Optional commitment level, defaults to the client's `commitment`
+    ///   (Processed if that is unset too)
     /// * `callback` - Event callback function that receives parsed unified events
     ///
     /// # Returns
@@ -143,6 +275,7 @@ impl YellowstoneGrpc {
     where
         F: Fn(DexEvent) + Send + Sync + 'static,
     {
+        let subscribed_us = get_high_perf_clock();
         *self.event_type_filter.write().await = event_type_filter.clone();
         if self
             .active_subscription
@@ -155,9 +288,10 @@ impl YellowstoneGrpc {
         let mut metrics_handle = None;
         // 启动自动性能监控（如果启用）
         if self.config.enable_metrics {
-            metrics_handle = MetricsManager::global().start_auto_monitoring().await;
+            metrics_handle = self.metrics_manager.start_auto_monitoring().await;
         }
 
+        let transaction_filter = self.expand_transaction_filters(transaction_filter, &protocols);
         let transactions = self
             .subscription_manager
             .get_subscribe_request_filter(transaction_filter, event_type_filter.as_ref());
@@ -168,73 +302,172 @@ impl YellowstoneGrpc {
         // 订阅事件
         let (subscribe_tx, mut stream, subscribe_request) = self
             .subscription_manager
-            .subscribe_with_request(transactions, accounts, commitment, event_type_filter.as_ref())
+            .subscribe_with_request(
+                transactions,
+                accounts,
+                commitment.or(self.commitment),
+                self.from_slot,
+                event_type_filter.as_ref(),
+            )
             .await?;
 
         // 用 Arc<Mutex<>> 包装 subscribe_tx 以支持多线程共享
         let subscribe_tx = Arc::new(Mutex::new(subscribe_tx));
-        *self.current_request.write().await = Some(subscribe_request);
+        // 之后的过滤条件更新不应再次从起始槽位重放
+        *self.current_request.write().await =
+            Some(SubscribeRequest { from_slot: None, ..subscribe_request });
         let (control_tx, mut control_rx) = mpsc::channel(100);
         *self.control_tx.lock().await = Some(control_tx);
 
+        let subscription_id = SubscriptionId::next();
+        let subscription_metrics =
+            self.metrics_manager.register_subscription(subscription_id, subscribed_us);
+        let logger = SubscriptionLogger::new(subscription_id, subscription_metrics.clone());
+        let update_metrics = subscription_metrics.clone();
+        log::info!(target: LOG_TARGET, "[{subscription_id}] Subscribed to {}", self.endpoint);
+
         // Wrap callback once before the async block
-        let callback = Arc::new(callback);
+        let callback = create_subscription_callback(Arc::new(callback), subscription_metrics);
+        let mut slot_summary_handle = None;
+        let callback = match self.slot_summary_callback.clone() {
+            Some(slot_summary_callback) => {
+                let timeout = Duration::from_millis(self.config.slot_summary_timeout_ms);
+                let aggregator =
+                    Arc::new(SlotSummaryAggregator::new(timeout, slot_summary_callback));
+                slot_summary_handle = Some(aggregator.spawn_flush_task());
+                create_slot_summary_callback(callback, aggregator)
+            }
+            None => callback,
+        };
+        let cancellation = CancellationToken::new();
+        let callback = wrap_user_callback(
+            callback,
+            &self.config,
+            self.enricher.clone(),
+            &self.metrics_manager,
+            &cancellation,
+        );
+        let parse_config = self.config.parse.clone();
+        // 账户 diff 等有状态解析的缓存归本次订阅所有，不与其它客户端共享
+        let account_state = Arc::new(AccountStateCache::new(&parse_config));
+        let mut parse_limiter = self.config.max_concurrent_parses.map(|limit| {
+            ParseLimiter::new(limit, self.config.strict_ordering, cancellation.clone())
+        });
+        self.active_protocols.store(protocols);
+        let active_protocols = self.active_protocols.clone();
+        let raw_update_callback = self.raw_update_callback.clone();
 
-        let stream_handle = tokio::spawn(async move {
+        let stream_future = self.metrics_manager.scope(async move {
             loop {
                 tokio::select! {
                     message = stream.next() => {
                         match message {
                             Some(Ok(msg)) => {
+                                update_metrics.record_update();
+                                if let Some(raw_update_callback) = raw_update_callback.as_ref() {
+                                    if !is_parsed_update(&msg) {
+                                        raw_update_callback(msg.clone());
+                                    }
+                                }
                                 let created_at = msg.created_at;
+                                let protocols = active_protocols.load();
                                 match msg.update_oneof {
                                     Some(UpdateOneof::Account(account)) => {
                                         let account_pretty = factory::create_account_pretty_pooled(account);
-                                        log::debug!("Received account: {:?}", account_pretty);
+                                        log::debug!(target: LOG_TARGET, "Received account: {:?}", account_pretty);
                                         if let Err(e) = process_grpc_transaction(
                                             EventPretty::Account(account_pretty),
                                             &protocols,
                                             event_type_filter.as_ref(),
                                             callback.clone(),
                                             bot_wallet,
+                                            &parse_config,
+                                            &account_state,
                                         )
                                         .await
                                         {
-                                            error!("Error processing account event: {e:?}");
+                                            logger.recoverable("Error processing account event", &e);
                                         }
                                     }
                                     Some(UpdateOneof::BlockMeta(sut)) => {
                                         let block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
-                                        log::debug!("Received block meta: {:?}", block_meta_pretty);
+                                        log::debug!(target: LOG_TARGET, "Received block meta: {:?}", block_meta_pretty);
                                         if let Err(e) = process_grpc_transaction(
                                             EventPretty::BlockMeta(block_meta_pretty),
                                             &protocols,
                                             event_type_filter.as_ref(),
                                             callback.clone(),
                                             bot_wallet,
+                                            &parse_config,
+                                            &account_state,
                                         )
                                         .await
                                         {
-                                            error!("Error processing block meta event: {e:?}");
+                                            logger.recoverable("Error processing block meta event", &e);
+                                        }
+                                    }
+                                    Some(UpdateOneof::Slot(sut)) => {
+                                        // 中间状态不对外发出
+                                        if let Some(slot_pretty) = SlotPretty::from_update(sut, get_high_perf_clock()) {
+                                            log::debug!(target: LOG_TARGET, "Received slot status: {:?}", slot_pretty);
+                                            if let Err(e) = process_grpc_transaction(
+                                                EventPretty::SlotStatus(slot_pretty),
+                                                &protocols,
+                                                event_type_filter.as_ref(),
+                                                callback.clone(),
+                                                bot_wallet,
+                                                &parse_config,
+                                                &account_state,
+                                            )
+                                            .await
+                                            {
+                                                logger.recoverable("Error processing slot status event", &e);
+                                            }
                                         }
                                     }
                                     Some(UpdateOneof::Transaction(sut)) => {
                                         let transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
                                         log::debug!(
+                                            target: LOG_TARGET,
                                             "Received transaction: {} at slot {}",
                                             transaction_pretty.signature,
                                             transaction_pretty.slot
                                         );
-                                        if let Err(e) = process_grpc_transaction(
+                                        if let Some(parse_limiter) = parse_limiter.as_mut() {
+                                            let protocols = protocols.clone();
+                                            let event_type_filter = event_type_filter.clone();
+                                            let parse_config = parse_config.clone();
+                                            let account_state = account_state.clone();
+                                            let logger = logger.clone();
+                                            parse_limiter
+                                                .spawn(callback.clone(), |callback| async move {
+                                                    if let Err(e) = process_grpc_transaction(
+                                                        EventPretty::Transaction(transaction_pretty),
+                                                        &protocols,
+                                                        event_type_filter.as_ref(),
+                                                        callback,
+                                                        bot_wallet,
+                                                        &parse_config,
+                                                        &account_state,
+                                                    )
+                                                    .await
+                                                    {
+                                                        logger.recoverable("Error processing transaction event", &e);
+                                                    }
+                                                })
+                                                .await;
+                                        } else if let Err(e) = process_grpc_transaction(
                                             EventPretty::Transaction(transaction_pretty),
                                             &protocols,
                                             event_type_filter.as_ref(),
                                             callback.clone(),
                                             bot_wallet,
+                                            &parse_config,
+                                            &account_state,
                                         )
                                         .await
                                         {
-                                            error!("Error processing transaction event: {e:?}");
+                                            logger.recoverable("Error processing transaction event", &e);
                                         }
                                     }
                                     Some(UpdateOneof::Ping(_)) => {
@@ -248,19 +481,19 @@ impl YellowstoneGrpc {
                                                 .await;
                                         }
                                         let ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-                                        log::debug!("service is ping: {}", ts);
+                                        log::debug!(target: LOG_TARGET, "service is ping: {}", ts);
                                     }
                                     Some(UpdateOneof::Pong(_)) => {
                                         let ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-                                        log::debug!("service is pong: {}", ts);
+                                        log::debug!(target: LOG_TARGET, "service is pong: {}", ts);
                                     }
                                     _ => {
-                                        log::debug!("Received other message type");
+                                        log::debug!(target: LOG_TARGET, "Received other message type");
                                     }
                                 }
                             }
                             Some(Err(error)) => {
-                                error!("Stream error: {error:?}");
+                                logger.fatal("Stream error", &error);
                                 break;
                             }
                             None => break,
@@ -268,16 +501,24 @@ impl YellowstoneGrpc {
                     }
                     Some(update) = control_rx.next() => {
                         if let Err(e) = subscribe_tx.lock().await.send(update).await {
-                            error!("Failed to send subscription update: {}", e);
+                            logger.fatal("Failed to send subscription update", &e);
                             break;
                         }
                     }
                 }
             }
         });
+        let stream_handle = spawn_stream_task(&self.config, stream_future);
 
         // 保存订阅句柄
-        let subscription_handle = SubscriptionHandle::new(stream_handle, None, metrics_handle);
+        let subscription_handle = SubscriptionHandle::with_id(
+            subscription_id,
+            self.metrics_manager.clone(),
+            stream_handle,
+            slot_summary_handle,
+            metrics_handle,
+        )
+        .with_cancellation_token(cancellation);
         let mut handle_guard = self.subscription_handle.lock().await;
         *handle_guard = Some(subscription_handle);
 
@@ -318,6 +559,8 @@ impl YellowstoneGrpc {
             .ok_or_else(|| anyhow!("No active subscription"))?
             .clone();
 
+        let transaction_filter =
+            self.expand_transaction_filters(transaction_filter, &self.active_protocols.load());
         request.transactions = self
             .subscription_manager
             .get_subscribe_request_filter(
@@ -343,6 +586,36 @@ impl YellowstoneGrpc {
 
         Ok(())
     }
+
+    /// 启用 `auto_program_filter` 时，为 `account_include` 为空的交易过滤器填入协议的程序 ID，
+    /// 让服务端只推送涉及这些程序的交易；已指定 `account_include` 的过滤器原样发送
+    fn expand_transaction_filters(
+        &self,
+        mut transaction_filter: Vec<TransactionFilter>,
+        protocols: &[Protocol],
+    ) -> Vec<TransactionFilter> {
+        if self.config.auto_program_filter {
+            for filter in transaction_filter.iter_mut() {
+                if filter.account_include.is_empty() {
+                    filter.add_protocol_programs(protocols);
+                }
+            }
+        }
+        transaction_filter
+    }
+}
+
+/// 是否为会被解析为 `DexEvent` 的更新类型
+fn is_parsed_update(update: &SubscribeUpdate) -> bool {
+    matches!(
+        update.update_oneof,
+        Some(
+            UpdateOneof::Account(_)
+                | UpdateOneof::BlockMeta(_)
+                | UpdateOneof::Slot(_)
+                | UpdateOneof::Transaction(_)
+        )
+    )
 }
 
 // 实现 Clone trait 以支持模块间共享
@@ -357,7 +630,14 @@ impl Clone for YellowstoneGrpc {
             active_subscription: self.active_subscription.clone(),
             control_tx: self.control_tx.clone(),
             event_type_filter: self.event_type_filter.clone(),
+            active_protocols: self.active_protocols.clone(),
             current_request: self.current_request.clone(),
+            enricher: self.enricher.clone(),
+            raw_update_callback: self.raw_update_callback.clone(),
+            slot_summary_callback: self.slot_summary_callback.clone(),
+            commitment: self.commitment,
+            from_slot: self.from_slot,
+            metrics_manager: self.metrics_manager.clone(),
         }
     }
 }
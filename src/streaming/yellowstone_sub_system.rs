@@ -1,12 +1,12 @@
 use crate::{
     common::AnyResult,
     streaming::{
+        common::{log_fatal, log_recoverable},
         grpc::{pool::factory, EventPretty},
         yellowstone_grpc::{TransactionFilter, YellowstoneGrpc},
     },
 };
 use futures::{SinkExt, StreamExt};
-use log::error;
 use solana_sdk::pubkey;
 use solana_sdk::pubkey::Pubkey;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
@@ -47,7 +47,7 @@ impl YellowstoneGrpc {
         let transactions = self.subscription_manager.get_subscribe_request_filter(tx_filter, None);
         let (mut subscribe_tx, mut stream, _) = self
             .subscription_manager
-            .subscribe_with_request(transactions, None, None, None)
+            .subscribe_with_request(transactions, None, self.commitment, None, None)
             .await?;
 
         let callback = Box::new(callback);
@@ -65,7 +65,7 @@ impl YellowstoneGrpc {
                                 if let Err(e) =
                                     Self::process_system_transaction(event_pretty, &*callback).await
                                 {
-                                    error!("Error processing transaction: {e:?}");
+                                    log_recoverable("Error processing transaction", &e);
                                 }
                             }
                             Some(UpdateOneof::Ping(_)) => {
@@ -85,7 +85,7 @@ impl YellowstoneGrpc {
                         }
                     }
                     Err(error) => {
-                        error!("Stream error: {error:?}");
+                        log_fatal("Stream error", &error);
                         break;
                     }
                 }